@@ -0,0 +1,265 @@
+//! Threshold alerts
+//!
+//! Evaluates the system usage against the thresholds from the config file
+//! on every tick. A threshold has to be exceeded continuously for the
+//! configured duration before its alert fires, so short spikes don't
+//! trigger it. Firing alerts are shown as a banner in the TUI and, with the
+//! `notifications` feature, can also be sent as desktop notifications.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::DEFAULT_ALERT_DURATION_SECS;
+
+/// Alert thresholds, read from the `alerts` section of the config file.
+///
+/// ```json
+/// "alerts": {
+///   "cpu_percent": 90.0,
+///   "memory_percent": 85.0,
+///   "duration_secs": 10,
+///   "desktop_notifications": true
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertThresholds {
+    /// Alert when total CPU usage stays above this percentage
+    pub cpu_percent: Option<f32>,
+
+    /// Alert when memory usage stays above this percentage
+    pub memory_percent: Option<f64>,
+
+    /// How long a threshold must be exceeded before its alert fires
+    pub duration_secs: u64,
+
+    /// Also send a desktop notification when an alert fires. Requires
+    /// building with the `notifications` feature
+    pub desktop_notifications: bool,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_percent: None,
+            memory_percent: None,
+            duration_secs: DEFAULT_ALERT_DURATION_SECS,
+            desktop_notifications: false,
+        }
+    }
+}
+
+impl AlertThresholds {
+    /// Returns whether any threshold is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.cpu_percent.is_some() || self.memory_percent.is_some()
+    }
+}
+
+/// Tracks how long a single threshold has been exceeded.
+#[derive(Debug, Default)]
+struct ThresholdState {
+    /// When the current run of exceeding samples started
+    exceeded_since: Option<Instant>,
+
+    /// Whether the alert is currently firing
+    firing: bool,
+}
+
+impl ThresholdState {
+    /// Records a sample, returning `true` if the alert starts firing with it.
+    fn update(&mut self, exceeded: bool, now: Instant, duration: Duration) -> bool {
+        if !exceeded {
+            self.exceeded_since = None;
+            self.firing = false;
+            return false;
+        }
+
+        let since = *self.exceeded_since.get_or_insert(now);
+        if !self.firing && now.duration_since(since) >= duration {
+            self.firing = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Evaluates alert thresholds tick by tick.
+#[derive(Debug, Default)]
+pub struct AlertMonitor {
+    cpu: ThresholdState,
+    memory: ThresholdState,
+
+    /// Messages of the alerts currently firing
+    pub active: Vec<String>,
+
+    /// Number of evaluations, used to flash the banner
+    ticks: u64,
+
+    /// Time of the last evaluated sample
+    last_evaluated: Option<Instant>,
+}
+
+impl AlertMonitor {
+    /// Evaluates the thresholds against the latest system usage.
+    ///
+    /// # Arguments
+    ///
+    /// * `thresholds` - Configured alert thresholds
+    /// * `cpu_usage` - Total CPU usage in percent
+    /// * `memory_usage` - Memory usage in percent
+    /// * `now` - Time of the sample
+    ///
+    /// # Returns
+    ///
+    /// Messages of the alerts that started firing with this sample, used
+    /// to send a notification once per alert rather than on every tick.
+    pub fn evaluate(
+        &mut self,
+        thresholds: &AlertThresholds,
+        cpu_usage: f32,
+        memory_usage: f64,
+        now: Instant,
+    ) -> Vec<String> {
+        self.ticks += 1;
+        self.last_evaluated = Some(now);
+        self.active.clear();
+        let duration = Duration::from_secs(thresholds.duration_secs);
+        let mut started = Vec::new();
+
+        if let Some(limit) = thresholds.cpu_percent {
+            let fired = self.cpu.update(cpu_usage > limit, now, duration);
+            if self.cpu.firing {
+                let message = format!(
+                    "CPU usage {:.1}% above {:.1}% for {}s",
+                    cpu_usage, limit, thresholds.duration_secs
+                );
+                if fired {
+                    started.push(message.clone());
+                }
+                self.active.push(message);
+            }
+        }
+
+        if let Some(limit) = thresholds.memory_percent {
+            let fired = self.memory.update(memory_usage > limit, now, duration);
+            if self.memory.firing {
+                let message = format!(
+                    "Memory usage {:.1}% above {:.1}% for {}s",
+                    memory_usage, limit, thresholds.duration_secs
+                );
+                if fired {
+                    started.push(message.clone());
+                }
+                self.active.push(message);
+            }
+        }
+
+        started
+    }
+
+    /// Returns whether a new sample should be evaluated at `now`.
+    ///
+    /// Frames are also drawn on key presses, only a full refresh interval
+    /// after the last sample counts as a new collection tick.
+    pub fn is_due(&self, now: Instant, interval: Duration) -> bool {
+        self.last_evaluated
+            .is_none_or(|last| now.duration_since(last) >= interval)
+    }
+
+    /// Returns whether the banner is drawn highlighted, alternating every
+    /// tick so it flashes.
+    pub fn flash_on(&self) -> bool {
+        self.ticks.is_multiple_of(2)
+    }
+}
+
+/// Sends a desktop notification for a fired alert.
+///
+/// Failures are ignored, a missing notification daemon shouldn't stop the UI.
+#[cfg(feature = "notifications")]
+pub fn send_desktop_notification(message: &str) {
+    let _ = notify_rust::Notification::new()
+        .summary("Stomata alert")
+        .body(message)
+        .show();
+}
+
+/// Desktop notifications need the `notifications` feature, without it
+/// alerts are only shown in the TUI.
+#[cfg(not(feature = "notifications"))]
+pub fn send_desktop_notification(_message: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholds() -> AlertThresholds {
+        AlertThresholds {
+            cpu_percent: Some(90.0),
+            memory_percent: Some(80.0),
+            duration_secs: 10,
+            desktop_notifications: false,
+        }
+    }
+
+    #[test]
+    fn test_alert_fires_after_duration() {
+        let mut monitor = AlertMonitor::default();
+        let start = Instant::now();
+
+        assert!(
+            monitor
+                .evaluate(&thresholds(), 95.0, 10.0, start)
+                .is_empty()
+        );
+        assert!(monitor.active.is_empty());
+
+        let started = monitor.evaluate(&thresholds(), 96.0, 10.0, start + Duration::from_secs(10));
+        assert_eq!(started.len(), 1);
+        assert!(started[0].starts_with("CPU usage"));
+        assert_eq!(monitor.active.len(), 1);
+
+        // still firing, but not started again
+        let started = monitor.evaluate(&thresholds(), 97.0, 10.0, start + Duration::from_secs(11));
+        assert!(started.is_empty());
+        assert_eq!(monitor.active.len(), 1);
+    }
+
+    #[test]
+    fn test_dip_below_threshold_resets() {
+        let mut monitor = AlertMonitor::default();
+        let start = Instant::now();
+
+        monitor.evaluate(&thresholds(), 95.0, 10.0, start);
+        monitor.evaluate(&thresholds(), 50.0, 10.0, start + Duration::from_secs(5));
+        let started = monitor.evaluate(&thresholds(), 95.0, 10.0, start + Duration::from_secs(12));
+        assert!(started.is_empty());
+        assert!(monitor.active.is_empty());
+    }
+
+    #[test]
+    fn test_unset_thresholds_never_fire() {
+        let mut monitor = AlertMonitor::default();
+        let start = Instant::now();
+        let thresholds = AlertThresholds::default();
+
+        monitor.evaluate(&thresholds, 100.0, 100.0, start);
+        monitor.evaluate(&thresholds, 100.0, 100.0, start + Duration::from_secs(60));
+        assert!(monitor.active.is_empty());
+        assert!(!thresholds.is_enabled());
+    }
+
+    #[test]
+    fn test_evaluated_once_per_interval() {
+        let mut monitor = AlertMonitor::default();
+        let start = Instant::now();
+        let interval = Duration::from_millis(1000);
+        assert!(monitor.is_due(start, interval));
+
+        monitor.evaluate(&thresholds(), 10.0, 10.0, start);
+        assert!(!monitor.is_due(start + Duration::from_millis(200), interval));
+        assert!(monitor.is_due(start + interval, interval));
+    }
+}
@@ -0,0 +1,98 @@
+//! Recent collector warnings
+//!
+//! Collectors report why a metric is missing (an unreadable cgroup, an
+//! inaccessible Docker socket, ...) instead of only falling back to zeros.
+//! The TUI drains those reports every tick and keeps the recent ones here,
+//! to be shown in a status bar at the bottom of the screen. A warning
+//! disappears once it hasn't been reported again for a while. The outcome
+//! of UI actions, such as where an export was written, is shown the same way.
+
+use std::time::{Duration, Instant};
+
+use crate::constants::{COLLECTOR_WARNING_TTL_SECS, MAX_COLLECTOR_WARNING_LINES};
+
+/// Recently reported collector warnings, newest last.
+#[derive(Debug, Default)]
+pub struct CollectorWarnings {
+    recent: Vec<(String, Instant)>,
+}
+
+impl CollectorWarnings {
+    /// Records the warnings reported this tick and drops expired ones.
+    ///
+    /// A warning reported again moves to the end and its expiry restarts.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - Warnings reported since the last update
+    /// * `now` - Time of the update
+    pub fn update(&mut self, messages: impl IntoIterator<Item = String>, now: Instant) {
+        for message in messages {
+            self.recent.retain(|(recent, _)| *recent != message);
+            self.recent.push((message, now));
+        }
+        let ttl = Duration::from_secs(COLLECTOR_WARNING_TTL_SECS);
+        self.recent
+            .retain(|(_, reported)| now.duration_since(*reported) < ttl);
+    }
+
+    /// Returns the newest warnings to show, at most
+    /// `MAX_COLLECTOR_WARNING_LINES`, oldest first.
+    pub fn visible(&self) -> &[(String, Instant)] {
+        let start = self
+            .recent
+            .len()
+            .saturating_sub(MAX_COLLECTOR_WARNING_LINES);
+        &self.recent[start..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(warnings: &CollectorWarnings) -> Vec<&str> {
+        warnings
+            .visible()
+            .iter()
+            .map(|(message, _)| message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_warnings_expire() {
+        let mut warnings = CollectorWarnings::default();
+        let start = Instant::now();
+
+        warnings.update(["cgroup read failed for pid 1".to_string()], start);
+        warnings.update(
+            ["docker API unavailable".to_string()],
+            start + Duration::from_secs(COLLECTOR_WARNING_TTL_SECS - 1),
+        );
+        assert_eq!(messages(&warnings).len(), 2);
+
+        warnings.update(
+            Vec::new(),
+            start + Duration::from_secs(COLLECTOR_WARNING_TTL_SECS),
+        );
+        assert_eq!(messages(&warnings), vec!["docker API unavailable"]);
+    }
+
+    #[test]
+    fn test_repeated_warning_moves_to_end() {
+        let mut warnings = CollectorWarnings::default();
+        let now = Instant::now();
+
+        warnings.update(["a".to_string(), "b".to_string()], now);
+        warnings.update(["a".to_string()], now);
+        assert_eq!(messages(&warnings), vec!["b", "a"]);
+
+        let many = (0..MAX_COLLECTOR_WARNING_LINES + 2).map(|i| i.to_string());
+        warnings.update(many, now);
+        assert_eq!(messages(&warnings).len(), MAX_COLLECTOR_WARNING_LINES);
+        assert_eq!(
+            messages(&warnings).last().copied(),
+            Some((MAX_COLLECTOR_WARNING_LINES + 1).to_string().as_str())
+        );
+    }
+}
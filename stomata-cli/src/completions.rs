@@ -0,0 +1,116 @@
+//! Shell completion scripts
+//!
+//! `stomata completions <shell>` prints a completion script for bash, zsh,
+//! fish or PowerShell to stdout, so it can be piped or redirected by install
+//! scripts. The top level [`Cli`] takes the feature name as a plain
+//! positional argument, so the feature CLIs are attached as subcommands here
+//! to let shells complete e.g. `stomata web3 key <TAB>`.
+
+use std::io::{self, Write};
+
+use anyhow::bail;
+use clap::{Arg, Command, CommandFactory, ValueEnum, value_parser};
+use clap_complete::Shell;
+
+use crate::{constants::COMPLETIONS_COMMAND, structs::Cli};
+
+/// Shells completion scripts can be generated for
+const SUPPORTED_SHELLS: [Shell; 4] = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+
+/// Builds the full `stomata` command tree used for completions.
+///
+/// # Returns
+///
+/// The [`Cli`] command with every enabled feature CLI and the hidden
+/// `completions` command attached as subcommands
+pub fn completion_command() -> Command {
+    let completions = Command::new(COMPLETIONS_COMMAND)
+        .about("Print a shell completion script")
+        .hide(true)
+        .arg(
+            Arg::new("shell")
+                .required(true)
+                .value_parser(value_parser!(Shell)),
+        );
+
+    let mut command = Cli::command()
+        .args_conflicts_with_subcommands(true)
+        .subcommand(completions);
+
+    #[cfg(feature = "core")]
+    {
+        command = command.subcommand(crate::features::core::cli::CoreCli::command());
+    }
+    #[cfg(feature = "web3")]
+    {
+        command = command.subcommand(crate::features::web3::cli::Web3Cli::command());
+    }
+
+    command
+}
+
+/// Writes the completion script for `shell` to `out`.
+///
+/// # Arguments
+///
+/// * `shell` - Target shell
+/// * `out` - Where the script is written, stdout for the CLI
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    let mut command = completion_command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+/// Handles `stomata completions <shell>`, printing the script to stdout.
+///
+/// # Arguments
+///
+/// * `args` - Arguments following `completions`, the first is the shell name
+///
+/// # Errors
+///
+/// Returns an error if the shell is missing or not one of bash, zsh, fish
+/// or powershell
+pub fn print_completions(args: &[String]) -> anyhow::Result<()> {
+    let shell = match args.first() {
+        Some(name) => parse_shell(name)?,
+        None => bail!("Usage: stomata completions <bash|zsh|fish|powershell>"),
+    };
+    write_completions(shell, &mut io::stdout());
+    Ok(())
+}
+
+fn parse_shell(name: &str) -> anyhow::Result<Shell> {
+    match <Shell as ValueEnum>::from_str(name, true) {
+        Ok(shell) if SUPPORTED_SHELLS.contains(&shell) => Ok(shell),
+        _ => bail!("Unsupported shell '{name}', expected one of bash, zsh, fish or powershell"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_command_is_valid() {
+        completion_command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_shell() {
+        assert_eq!(parse_shell("bash").unwrap(), Shell::Bash);
+        assert_eq!(parse_shell("PowerShell").unwrap(), Shell::PowerShell);
+        assert!(parse_shell("elvish").is_err());
+        assert!(parse_shell("cmd").is_err());
+    }
+
+    #[cfg(feature = "web3")]
+    #[test]
+    fn test_completions_include_key_subcommands() {
+        let mut out = Vec::new();
+        write_completions(Shell::Bash, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.contains("encrypt"));
+        assert!(script.contains("decrypt"));
+    }
+}
@@ -0,0 +1,301 @@
+//! Persistent user configuration
+//!
+//! Settings that should survive restarts, such as pinned processes, alert
+//! thresholds, the process table columns and units, are stored as JSON in
+//! `~/.stomata/config.json`. A missing or unreadable file yields the
+//! default configuration so the UI always starts.
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use anyhow::Context;
+use serde::{Deserialize, Deserializer, Serialize};
+use stomata_core::collectors::custom::CustomMetricCollector;
+
+use crate::{
+    alerts::AlertThresholds,
+    constants::{
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, DEFAULT_CUSTOM_METRIC_TIMEOUT_MS,
+        DEFAULT_HIDDEN_INTERFACES,
+    },
+    palette::Palette,
+    structs::ProcessColumn,
+    units::UnitConfig,
+};
+
+/// User configuration persisted between sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StomataConfig {
+    /// Names of processes pinned to the top of the process list
+    #[serde(default)]
+    pub pinned_processes: Vec<String>,
+
+    /// Thresholds for the CPU and memory alerts
+    #[serde(default)]
+    pub alerts: AlertThresholds,
+
+    /// Columns shown in the process table, in order. An empty list falls
+    /// back to the defaults so the table never ends up without columns
+    #[serde(
+        default = "default_process_columns",
+        deserialize_with = "deserialize_process_columns"
+    )]
+    pub process_columns: Vec<ProcessColumn>,
+
+    /// Units of memory and network values
+    #[serde(default)]
+    pub units: UnitConfig,
+
+    /// Colors of the TUI, overridden by `--palette`
+    #[serde(default)]
+    pub palette: Palette,
+
+    /// Metrics read from shell commands, shown on the Custom page
+    #[serde(default)]
+    pub custom_metrics: Vec<CustomMetricConfig>,
+
+    /// Network interfaces hidden from the Network page, loopback by default
+    #[serde(default = "default_hidden_interfaces")]
+    pub hidden_interfaces: Vec<String>,
+
+    /// EVM addresses shown on the Web3 Watchlist page
+    #[serde(default)]
+    pub watchlist: Vec<String>,
+}
+
+/// A metric read from the stdout of a shell command
+///
+/// The command runs every refresh while the Custom page is shown and must
+/// print a number, e.g. `redis-cli llen jobs` for a queue depth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomMetricConfig {
+    /// Title shown on the page
+    pub name: String,
+
+    /// Shell command printing the value, run with `sh -c` (`cmd /C` on Windows)
+    pub command: String,
+
+    /// Upper bound of the value. With a max the metric is shown as a gauge,
+    /// otherwise as a sparkline of its history
+    #[serde(default)]
+    pub max: Option<f64>,
+
+    /// Unit appended to the value, e.g. `jobs`
+    #[serde(default)]
+    pub unit: String,
+
+    /// Runs taking longer than this are killed and shown as an error
+    #[serde(default = "default_custom_metric_timeout")]
+    pub timeout_ms: u64,
+}
+
+fn default_custom_metric_timeout() -> u64 {
+    DEFAULT_CUSTOM_METRIC_TIMEOUT_MS
+}
+
+impl CustomMetricConfig {
+    /// Creates the collector running this metric's command
+    pub fn collector(&self) -> CustomMetricCollector {
+        CustomMetricCollector::new(
+            &self.name,
+            &self.command,
+            Duration::from_millis(self.timeout_ms),
+        )
+    }
+}
+
+impl Default for StomataConfig {
+    fn default() -> Self {
+        Self {
+            pinned_processes: Vec::new(),
+            alerts: AlertThresholds::default(),
+            process_columns: default_process_columns(),
+            units: UnitConfig::default(),
+            palette: Palette::default(),
+            custom_metrics: Vec::new(),
+            hidden_interfaces: default_hidden_interfaces(),
+            watchlist: Vec::new(),
+        }
+    }
+}
+
+fn default_process_columns() -> Vec<ProcessColumn> {
+    ProcessColumn::DEFAULT.to_vec()
+}
+
+fn deserialize_process_columns<'de, D>(deserializer: D) -> Result<Vec<ProcessColumn>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let columns = Vec::<ProcessColumn>::deserialize(deserializer)?;
+    Ok(if columns.is_empty() {
+        default_process_columns()
+    } else {
+        columns
+    })
+}
+
+fn default_hidden_interfaces() -> Vec<String> {
+    DEFAULT_HIDDEN_INTERFACES.map(String::from).to_vec()
+}
+
+impl StomataConfig {
+    /// Returns the path of the config file, `None` if there is no home directory.
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME))
+    }
+
+    /// Loads the config file, falling back to the default configuration
+    /// when it doesn't exist or can't be parsed.
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the config file, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory can't be found or the file
+    /// can't be written.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path().context("Could not find home directory")?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Pins the process name if it isn't pinned yet, unpins it otherwise.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the name is pinned afterwards
+    pub fn toggle_pinned(&mut self, name: &str) -> bool {
+        if let Some(index) = self.pinned_processes.iter().position(|n| n == name) {
+            self.pinned_processes.remove(index);
+            false
+        } else {
+            self.pinned_processes.push(name.to_string());
+            true
+        }
+    }
+
+    /// Shows the process table column if it is hidden, hides it otherwise.
+    ///
+    /// Shown columns keep the order of [`ProcessColumn::ALL`]. The last
+    /// shown column can't be hidden so the table never ends up empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the column is shown afterwards
+    pub fn toggle_column(&mut self, column: ProcessColumn) -> bool {
+        if self.process_columns.contains(&column) {
+            if self.process_columns.len() > 1 {
+                self.process_columns.retain(|c| *c != column);
+                return false;
+            }
+            true
+        } else {
+            self.process_columns.push(column);
+            self.process_columns
+                .sort_by_key(|c| ProcessColumn::ALL.iter().position(|all| all == c));
+            true
+        }
+    }
+
+    /// Hides the network interface if it is shown, shows it otherwise.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interface is hidden afterwards
+    pub fn toggle_hidden_interface(&mut self, name: &str) -> bool {
+        if let Some(index) = self.hidden_interfaces.iter().position(|n| n == name) {
+            self.hidden_interfaces.remove(index);
+            false
+        } else {
+            self.hidden_interfaces.push(name.to_string());
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_pinned() {
+        let mut config = StomataConfig::default();
+        assert!(config.toggle_pinned("nginx"));
+        assert!(config.toggle_pinned("postgres"));
+        assert_eq!(config.pinned_processes, vec!["nginx", "postgres"]);
+
+        assert!(!config.toggle_pinned("nginx"));
+        assert_eq!(config.pinned_processes, vec!["postgres"]);
+    }
+
+    #[test]
+    fn test_toggle_column() {
+        let mut config = StomataConfig::default();
+        assert!(!config.toggle_column(ProcessColumn::Status));
+        assert!(config.toggle_column(ProcessColumn::User));
+        assert_eq!(
+            config.process_columns,
+            vec![
+                ProcessColumn::Pid,
+                ProcessColumn::Name,
+                ProcessColumn::User,
+                ProcessColumn::Cpu,
+                ProcessColumn::Memory,
+                ProcessColumn::Container,
+            ]
+        );
+
+        config.process_columns = vec![ProcessColumn::Pid];
+        assert!(config.toggle_column(ProcessColumn::Pid));
+        assert_eq!(config.process_columns, vec![ProcessColumn::Pid]);
+    }
+
+    #[test]
+    fn test_toggle_hidden_interface() {
+        let mut config = StomataConfig::default();
+        assert_eq!(config.hidden_interfaces, vec!["lo", "lo0"]);
+
+        assert!(!config.toggle_hidden_interface("lo"));
+        assert!(config.toggle_hidden_interface("docker0"));
+        assert_eq!(config.hidden_interfaces, vec!["lo0", "docker0"]);
+    }
+
+    #[test]
+    fn test_custom_metric_defaults() {
+        let config: StomataConfig = serde_json::from_str(
+            r#"{"custom_metrics": [{"name": "Queue depth", "command": "redis-cli llen jobs"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.custom_metrics,
+            vec![CustomMetricConfig {
+                name: "Queue depth".to_string(),
+                command: "redis-cli llen jobs".to_string(),
+                max: None,
+                unit: String::new(),
+                timeout_ms: DEFAULT_CUSTOM_METRIC_TIMEOUT_MS,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let config: StomataConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, StomataConfig::default());
+    }
+
+    #[test]
+    fn test_empty_process_columns_use_defaults() {
+        let config: StomataConfig = serde_json::from_str(r#"{"process_columns": []}"#).unwrap();
+        assert_eq!(config.process_columns, ProcessColumn::DEFAULT);
+    }
+}
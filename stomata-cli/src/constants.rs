@@ -1,4 +1,39 @@
 pub const MAX_HISTORY: usize = 1000;
 pub const MAX_NETWORK_IN_MEMORY: usize = 40;
+pub const MIN_NETWORK_COLUMN_WIDTH: u16 = 32;
+pub const DEFAULT_HIDDEN_INTERFACES: [&str; 2] = ["lo", "lo0"];
+pub const MIN_TERMINAL_WIDTH: u16 = 60;
+pub const MIN_TERMINAL_HEIGHT: u16 = 20;
 pub const MAX_HISTORY_IN_MEMORY: usize = 60;
 pub const CLAMP_TREND_VALUE: f64 = 0.95;
+pub const TOP_N_PROCESSES: usize = 5;
+pub const REFRESH_INTERVAL_STEP_MS: u64 = 250;
+pub const MIN_REFRESH_INTERVAL_MS: u64 = 100;
+pub const MAX_REFRESH_INTERVAL_MS: u64 = 10_000;
+pub const LEAK_MIN_SAMPLES: usize = 10;
+pub const LEAK_MIN_SLOPE_BYTES: f64 = 64.0 * 1024.0;
+pub const CONFIG_DIR_NAME: &str = ".stomata";
+pub const CONFIG_FILE_NAME: &str = "config.json";
+pub const PREFERENCES_FILE_NAME: &str = "preferences.json";
+pub const MAX_PINNED_ROWS: usize = 5;
+pub const DEFAULT_ALERT_DURATION_SECS: u64 = 10;
+#[cfg(feature = "http")]
+pub const MAX_HTTP_REQUEST_BYTES: usize = 8 * 1024;
+#[cfg(feature = "http")]
+pub const HTTP_REQUEST_TIMEOUT_SECS: u64 = 5;
+pub const SPARKLINE_TREND_THRESHOLD: f64 = 0.25;
+pub const KNOWN_FEATURES: [&str; 2] = ["core", "web3"];
+pub const COMPLETIONS_COMMAND: &str = "completions";
+pub const RECENT_TRANSACTIONS_LIMIT: usize = 10;
+pub const COLLECTOR_WARNING_TTL_SECS: u64 = 10;
+pub const MAX_COLLECTOR_WARNING_LINES: usize = 3;
+pub const DEFAULT_PRECISION: u8 = 2;
+pub const MAX_PRECISION: u8 = 3;
+pub const PROCESS_EXITED_RETURN_SECS: u64 = 3;
+pub const REMOTE_COMMAND: &str = "stomata";
+pub const REMOTE_POLL_MS: u64 = 100;
+pub const WEB3_STALE_AFTER_SECS: u64 = 36;
+pub const GAS_PRICE_HISTORY_LEN: usize = 60;
+pub const WATCHLIST_REFRESH_SECS: u64 = 15;
+pub const WATCHLIST_MAX_CONCURRENT_LOOKUPS: usize = 4;
+pub const DEFAULT_CUSTOM_METRIC_TIMEOUT_MS: u64 = 5_000;
@@ -0,0 +1,92 @@
+//! Layout density of the TUI
+//!
+//! The default spacious layout draws a border around every widget. The
+//! compact layout, toggled with `d`, drops the borders and keeps only the
+//! titles, and shrinks gauges to a single line with the title folded into
+//! the label, which fits a lot more data on a laptop screen.
+//!
+//! The density is kept in the UI state of the core feature and passed to the
+//! widget builders like the `Palette`. The feature selection and the web3
+//! pages, whose tabs and input box are always framed, keep the spacious
+//! layout. The last density is remembered in `~/.stomata/preferences.json`.
+
+use ratatui::{
+    text::Line,
+    widgets::{Block, Borders},
+};
+use serde::{Deserialize, Serialize};
+
+/// Spacing of the widgets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    /// Every widget framed by a border with its title on it
+    #[default]
+    Spacious,
+
+    /// No borders, titles on their own line and single line gauges
+    Compact,
+}
+
+impl Density {
+    /// Returns the other density, for the `d` toggle.
+    pub fn toggled(self) -> Self {
+        match self {
+            Density::Spacious => Density::Compact,
+            Density::Compact => Density::Spacious,
+        }
+    }
+
+    /// Returns the block framing a widget: bordered with the title on the
+    /// top border, or just the title line when compact.
+    pub fn block<'a>(self, title: impl Into<Line<'a>>) -> Block<'a> {
+        match self {
+            Density::Spacious => Block::default().borders(Borders::ALL).title(title),
+            Density::Compact => Block::default().title(title),
+        }
+    }
+
+    /// Returns the rows a framed widget takes for `content_rows` lines of
+    /// content, i.e. the height to give its layout constraint.
+    pub fn rows(self, content_rows: u16) -> u16 {
+        match self {
+            Density::Spacious => content_rows + 2,
+            Density::Compact => content_rows + 1,
+        }
+    }
+
+    /// Returns the rows a gauge takes, see `render_gauge`.
+    pub fn gauge_rows(self) -> u16 {
+        match self {
+            Density::Spacious => 3,
+            Density::Compact => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggled() {
+        assert_eq!(Density::Spacious.toggled(), Density::Compact);
+        assert_eq!(Density::Compact.toggled(), Density::Spacious);
+    }
+
+    #[test]
+    fn test_compact_saves_rows() {
+        assert_eq!(Density::Spacious.rows(8), 10);
+        assert_eq!(Density::Compact.rows(8), 9);
+        assert_eq!(Density::Spacious.gauge_rows(), 3);
+        assert_eq!(Density::Compact.gauge_rows(), 1);
+    }
+
+    #[test]
+    fn test_density_is_serialized_in_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&Density::Compact).unwrap(),
+            r#""compact""#
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::constants::TOP_N_PROCESSES;
+
+/// Core feature CLI
+///
+/// Non-interactive system monitoring commands that print plain text to
+/// stdout and exit, suitable for cron jobs and logging.
+///
+/// # Examples
+///
+/// ```bash
+/// # Print the 5 processes using the most CPU
+/// stomata core --top
+///
+/// # Print the 20 processes using the most CPU
+/// stomata core --top --count 20
+///
+/// # Write Prometheus metrics to a textfile every 15 seconds
+/// stomata --interval 15000 core --prometheus /var/lib/node_exporter/textfile/stomata.prom
+///
+/// # Print a JSON snapshot per line every second, as read by `--remote`
+/// stomata core --stream
+///
+/// # Compare two snapshots taken from `/snapshot.json`
+/// stomata core --diff before.json after.json
+/// ```
+#[derive(Parser, Clone)]
+#[command(name = "core")]
+#[command(about = "System monitoring tools")]
+pub struct CoreCli {
+    /// Print the processes using the most CPU as a text table and exit
+    #[arg(long, default_value_t = false)]
+    pub top: bool,
+
+    /// Number of processes printed by `--top`
+    #[arg(short = 'n', long, default_value_t = TOP_N_PROCESSES)]
+    pub count: usize,
+
+    /// Write system and process metrics in Prometheus format to this file
+    /// every `--interval` until interrupted
+    #[arg(long, value_name = "PATH")]
+    pub prometheus: Option<PathBuf>,
+
+    /// Print a JSON snapshot (as served at `/snapshot.json`) per line every
+    /// `--interval` until interrupted. `--remote` reads this over SSH
+    #[arg(long, default_value_t = false)]
+    pub stream: bool,
+
+    /// Compare two JSON snapshots (from `/snapshot.json`) and print the
+    /// processes that appeared or disappeared and the CPU/memory changes
+    #[arg(long, num_args = 2, value_names = ["BEFORE", "AFTER"])]
+    pub diff: Option<Vec<PathBuf>>,
+}
@@ -4,37 +4,57 @@
 //! and core functionality. This feature displays real-time system metrics
 //! and provides an interactive interface for various system utilities.
 
-use std::{
-    io::Stdout,
-    time::{Duration, Instant},
-};
+use std::{io::Stdout, iter::once, process::exit, time::Duration};
 
+use clap::Parser;
 use ratatui::{
     Terminal,
     crossterm::event::{self, Event},
     prelude::CrosstermBackend,
 };
 
-use crate::{renders::core_displays::display_app::App, structs::Cli};
+use crate::{
+    features::core::{
+        cli::CoreCli, net_log::NetLog, prometheus_exporter::run_textfile_exporter,
+        remote::run_remote, session_summary::SessionSummary, snapshot_stream::run_snapshot_stream,
+    },
+    preferences::UiPreferences,
+    renders::core_displays::{
+        display_app::App, display_snapshot_diff::print_snapshot_diff,
+        display_top_processes::print_top_processes,
+    },
+    structs::{Cli, FeatureExit, ProcessMatcher},
+};
 
-/// Runs the core feature in interactive TUI mode
+/// Runs the core feature in interactive TUI mode or CLI mode
 ///
 /// Initializes and runs the main application loop for the core feature,
-/// which provides system monitoring and interactive utilities. Unlike other
-/// features, core only supports interactive mode (TUI) and requires a terminal.
+/// which provides system monitoring and interactive utilities. Without a
+/// terminal, the feature arguments are parsed as a `CoreCli` and one-shot
+/// commands such as `--top` print plain text and exit.
 ///
 /// # Arguments
 ///
 /// * `cli` - Parsed command-line arguments including:
 ///   - `interval` - Refresh rate in milliseconds (default: 1000ms)
 ///   - `store` - Whether to store metrics data for historical analysis
-/// * `terminal` - Terminal for rendering the TUI. Must be `Some` for core feature.
-///   If `None`, the function returns immediately without doing anything.
+///   - `history_len` - Number of data points kept for sparklines
+///   - `match_pattern` - Only monitor processes matching this pattern
+///   - `confirm_quit` - Ask before quitting
+///   - `palette` - Colors of the TUI, overriding the config
+///   - `remote` - Show another machine's metrics over SSH instead
+///   - `net_log` - Append the network samples to this CSV file
+///   - `summary` - Add the peak usage of this run to `session`
+/// * `terminal` - Terminal for rendering the TUI. If `None`, runs in CLI mode.
+/// * `session` - Peak usage of the whole stomata session, printed by the
+///   caller once the terminal is restored
 ///
 /// # Returns
 ///
-/// * `Ok(true)` - Application exited normally (user pressed quit key)
-/// * `Ok(false)` - No terminal provided, nothing executed
+/// * `Ok(FeatureExit::Back)` - The user pressed `Esc` to go back to the
+///   feature selection
+/// * `Ok(FeatureExit::Quit)` - The user quit, or the CLI command executed
+///   (or no command given)
 ///
 /// # Errors
 ///
@@ -47,8 +67,9 @@ use crate::{renders::core_displays::display_app::App, structs::Cli};
 ///
 /// The function implements an event-driven render loop:
 /// 1. Polls for keyboard input with timeout based on refresh interval
-/// 2. Handles user input immediately and redraws
-/// 3. Redraws at regular intervals (based on `cli.interval`)
+/// 2. Handles user input immediately and redraws, also redraws right away
+///    when the terminal is resized
+/// 3. Redraws at regular intervals (starts at `cli.interval`, adjustable with `+`/`-`)
 /// 4. Continues until user quits or an error occurs
 ///
 /// # Performance
@@ -72,51 +93,129 @@ use crate::{renders::core_displays::display_app::App, structs::Cli};
 /// };
 ///
 /// let mut terminal = setup_terminal()?;
-/// let exited_normally = core_feature::run(&cli, Some(&mut terminal))?;
+/// let mut session = SessionSummary::default();
+/// let exited_normally = core_feature::run(&cli, Some(&mut terminal), &mut session)?;
 /// ```
 ///
-/// # Terminal Requirements
+/// # CLI Mode
+///
+/// ```bash
+/// # print the 10 processes using the most CPU and exit
+/// stomata core --top --count 10
+///
+/// # print the nginx processes using the most CPU
+/// stomata --match nginx core --top
+///
+/// # write Prometheus metrics to a textfile every second
+/// stomata core --prometheus /var/lib/node_exporter/textfile/stomata.prom
 ///
-/// Core feature only works in interactive mode and will return `Ok(false)`
-/// if no terminal is provided. Ensure a terminal is always passed when
-/// running the core feature.
+/// # compare two snapshots around a deployment
+/// stomata core --diff before.json after.json
+///
+/// # print a JSON snapshot per line every second, as read by `--remote`
+/// stomata core --stream
+/// ```
 pub fn run(
     cli: &Cli,
     terminal: Option<&mut Terminal<CrosstermBackend<Stdout>>>,
-) -> anyhow::Result<bool> {
+    session: &mut SessionSummary,
+) -> anyhow::Result<FeatureExit> {
+    let matcher = cli.match_pattern.as_deref().map(ProcessMatcher::new);
     match terminal {
         Some(terminal) => {
+            if let Some(host) = &cli.remote {
+                return run_remote(cli, host, terminal);
+            }
             let store_metrics_data = cli.store;
-            let mut app = App::new(store_metrics_data);
-
-            // get the refresh interval from the cli arg. Default 1000 ms
-            let refresh_interval = Duration::from_millis(cli.interval);
-            let mut last_tick = Instant::now();
+            // start with the refresh interval from the cli arg. Default 1000 ms
+            let mut app = App::new(
+                store_metrics_data,
+                cli.interval,
+                cli.history_len,
+                matcher,
+                cli.confirm_quit,
+            );
+            if let Some(palette) = cli.palette {
+                app.ui_state.palette = palette;
+            }
+            if let Some(path) = &cli.net_log {
+                app.net_log = Some(NetLog::open(path)?);
+            }
+            if cli.summary {
+                app.session_summary = Some(std::mem::take(session));
+            }
+            let preferences = UiPreferences::load();
+            app.select_tab(preferences.core_tab);
+            app.ui_state.density = preferences.density;
 
             // main render loop
             while app.render {
+                // the interval can be changed at runtime with +/-
+                let refresh_interval = Duration::from_millis(app.refresh_interval);
                 let timeout = refresh_interval
-                    .checked_sub(last_tick.elapsed())
+                    .checked_sub(app.last_tick.elapsed())
                     .unwrap_or(Duration::from_secs(0));
 
                 // poll for inputs only until timeout
                 if event::poll(timeout)? {
-                    if let Event::Key(key) = event::read()? {
-                        // handle events
-                        app.handle_events(key)?;
-                        // redraw immediately after an event
-                        terminal.draw(|frame| app.render(frame))?;
+                    match event::read()? {
+                        Event::Key(key) => {
+                            // handle events
+                            app.handle_events(key)?;
+                            // redraw immediately after an event
+                            terminal.draw(|frame| app.render(frame))?;
+                        }
+                        // reflow the layout to the new size right away
+                        Event::Resize(_, _) => {
+                            terminal.draw(|frame| app.render(frame))?;
+                        }
+                        _ => {}
                     }
                 }
 
-                if last_tick.elapsed() >= refresh_interval {
+                // every render refreshes the metrics and restarts the tick
+                if app.last_tick.elapsed() >= refresh_interval {
                     // draw
                     terminal.draw(|frame| app.render(frame))?;
-                    last_tick = Instant::now();
                 }
             }
-            Ok(app.render)
+            UiPreferences::update(|preferences| {
+                preferences.core_tab = app.tab_index;
+                preferences.refresh_interval = Some(app.refresh_interval);
+                preferences.density = app.ui_state.density;
+            });
+            if let Some(summary) = app.session_summary.take() {
+                *session = summary;
+            }
+            Ok(app.exit)
+        }
+        None if cli.remote.is_some() => {
+            eprintln!("--remote needs the interactive mode, try `stomata -i --remote <USER@HOST>`");
+            exit(2);
+        }
+        None => {
+            let core_cli =
+                CoreCli::try_parse_from(once("core".to_string()).chain(cli.args.iter().cloned()));
+            match core_cli {
+                Ok(core_cli) => {
+                    if core_cli.top {
+                        print_top_processes(core_cli.count, matcher.as_ref());
+                    } else if let Some(path) = &core_cli.prometheus {
+                        run_textfile_exporter(path, cli.interval)?;
+                    } else if core_cli.stream {
+                        run_snapshot_stream(cli.interval)?;
+                    } else if let Some([before, after]) = core_cli.diff.as_deref() {
+                        print_snapshot_diff(before, after)?;
+                    } else {
+                        println!("No core command given, try `stomata core --top`");
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    exit(1);
+                }
+            }
+            Ok(FeatureExit::Quit)
         }
-        None => Ok(false),
     }
 }
@@ -0,0 +1,140 @@
+//! Disk I/O history export
+//!
+//! Writes the disk read/write history the single process page collected to
+//! a CSV file (`e` on the page), to analyze a window of I/O behavior of a
+//! flaky service offline. Each export goes to a new timestamped file in
+//! the working directory.
+
+use std::{
+    fs::OpenOptions,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use chrono::{DateTime, Local};
+
+use crate::structs::SingleProcessDiskUsage;
+
+/// First line of an export
+pub const DISK_EXPORT_HEADER: &str = "sample_index,read_bytes,write_bytes";
+
+/// Writes the disk history of `disk_usage` to a new CSV file in `dir`
+///
+/// The file is named after the PID and `now`, e.g.
+/// `stomata-disk-1234-20250102-030405.csv`, and never overwrites an
+/// existing file. Exports within the same second get a counter suffix,
+/// `stomata-disk-1234-20250102-030405-1.csv` and so on.
+///
+/// # Returns
+///
+/// The path of the written file
+///
+/// # Errors
+///
+/// Returns an error if the file can't be created or written.
+pub fn export_disk_history(
+    disk_usage: &SingleProcessDiskUsage,
+    dir: &Path,
+    now: DateTime<Local>,
+) -> anyhow::Result<PathBuf> {
+    let stem = format!(
+        "stomata-disk-{}-{}",
+        disk_usage.pid,
+        now.format("%Y%m%d-%H%M%S")
+    );
+    let mut counter = 0;
+    let (path, mut file) = loop {
+        let path = match counter {
+            0 => dir.join(format!("{stem}.csv")),
+            _ => dir.join(format!("{stem}-{counter}.csv")),
+        };
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => break (path, file),
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => counter += 1,
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to create {}", path.display()));
+            }
+        }
+    };
+    file.write_all(csv(disk_usage).as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Formats the history as CSV in the order of `DISK_EXPORT_HEADER`, oldest
+/// sample first
+///
+/// Bytes are counted per refresh, like the single process page sparklines.
+fn csv(disk_usage: &SingleProcessDiskUsage) -> String {
+    let rows: String = disk_usage
+        .disk_read_usage
+        .iter()
+        .zip(disk_usage.disk_write_usage.iter())
+        .enumerate()
+        .map(|(index, (read, written))| format!("{index},{read},{written}\n"))
+        .collect();
+    format!("{DISK_EXPORT_HEADER}\n{rows}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use chrono::TimeZone;
+    use sysinfo::DiskUsage;
+
+    use super::*;
+
+    fn disk_usage(samples: &[(u64, u64)]) -> SingleProcessDiskUsage {
+        let mut disk_usage = SingleProcessDiskUsage::new(10);
+        for (read_bytes, written_bytes) in samples {
+            let usage = DiskUsage {
+                read_bytes: *read_bytes,
+                written_bytes: *written_bytes,
+                ..Default::default()
+            };
+            disk_usage.update_disk_history(42, &usage, Instant::now(), 1000);
+        }
+        disk_usage
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&disk_usage(&[(4096, 0), (0, 512)])),
+            "sample_index,read_bytes,write_bytes\n0,4096,0\n1,0,512\n"
+        );
+        assert_eq!(
+            csv(&disk_usage(&[])),
+            "sample_index,read_bytes,write_bytes\n"
+        );
+    }
+
+    #[test]
+    fn test_export_disk_history_adds_a_counter_within_the_same_second() {
+        let dir = std::env::temp_dir().join(format!("stomata-disk-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let now = Local.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let disk_usage = disk_usage(&[(1, 2)]);
+
+        let path = export_disk_history(&disk_usage, &dir, now).unwrap();
+        assert_eq!(path, dir.join("stomata-disk-42-20250102-030405.csv"));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "sample_index,read_bytes,write_bytes\n0,1,2\n"
+        );
+
+        let second = export_disk_history(&disk_usage, &dir, now).unwrap();
+        assert_eq!(second, dir.join("stomata-disk-42-20250102-030405-1.csv"));
+        let third = export_disk_history(&disk_usage, &dir, now).unwrap();
+        assert_eq!(third, dir.join("stomata-disk-42-20250102-030405-2.csv"));
+        // the first export is left untouched
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "sample_index,read_bytes,write_bytes\n0,1,2\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,263 @@
+//! Minimal HTTP metrics endpoint
+//!
+//! Serves the latest metrics over plain HTTP/1.1 using tokio, without
+//! pulling in a web framework:
+//!
+//! - `/metrics` - system and process metrics in Prometheus format
+//! - `/snapshot.json` - the same data as JSON
+//!
+//! Metrics are collected on a background thread every refresh interval and
+//! shared with the request handlers, so scrapes never wait on a refresh.
+//! Only compiled with the `http` feature.
+
+use std::{
+    sync::{Arc, RwLock},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use stomata_core::{
+    collectors::{
+        process::metrics::ProcessData, structs::StomataSystemMetrics,
+        system::metrics::SystemMetrics,
+    },
+    prometheus, snapshot,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    time::timeout,
+};
+
+use crate::{
+    constants::{HTTP_REQUEST_TIMEOUT_SECS, MAX_HTTP_REQUEST_BYTES},
+    features::core::prometheus_exporter::collect,
+};
+
+/// Latest collected metrics, pre-rendered for both endpoints
+#[derive(Default)]
+struct Snapshot {
+    prometheus: String,
+    json: String,
+}
+
+impl Snapshot {
+    fn new(system: &SystemMetrics, processes: &[ProcessData]) -> Self {
+        Self {
+            prometheus: prometheus::render(system, processes),
+            json: snapshot::to_json(system, processes).to_string(),
+        }
+    }
+}
+
+/// Binds the metrics endpoint to `addr`
+///
+/// Kept separate from [`serve`] so a bad address or a port already in use
+/// is reported before the TUI takes over the terminal.
+///
+/// # Errors
+///
+/// Returns an error if the address can't be bound.
+pub async fn bind(addr: &str) -> anyhow::Result<TcpListener> {
+    TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint to {addr}"))
+}
+
+/// Serves `/metrics` and `/snapshot.json` until the process exits
+///
+/// # Arguments
+///
+/// * `listener` - Listener returned by [`bind`]
+/// * `interval_ms` - How often the metrics are collected, in milliseconds
+///
+/// # Examples
+///
+/// ```bash
+/// # headless, serve metrics only
+/// stomata --serve 127.0.0.1:9184
+///
+/// # alongside the TUI
+/// stomata -i --serve 127.0.0.1:9184
+/// ```
+pub async fn serve(listener: TcpListener, interval_ms: u64) -> anyhow::Result<()> {
+    let snapshot = Arc::new(RwLock::new(Snapshot::default()));
+
+    // sysinfo refreshes are blocking, collect on a dedicated thread
+    let collector_snapshot = Arc::clone(&snapshot);
+    thread::spawn(move || {
+        let mut metrics = StomataSystemMetrics::new();
+        loop {
+            let (system, processes) = collect(&mut metrics);
+            let latest = Snapshot::new(&system, &processes);
+            if let Ok(mut snapshot) = collector_snapshot.write() {
+                *snapshot = latest;
+            }
+            thread::sleep(Duration::from_millis(interval_ms));
+        }
+    });
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let snapshot = Arc::clone(&snapshot);
+        tokio::spawn(async move {
+            // a failed connection only affects that client
+            let request_timeout = Duration::from_secs(HTTP_REQUEST_TIMEOUT_SECS);
+            let _ = handle_connection(stream, &snapshot, request_timeout).await;
+        });
+    }
+}
+
+/// Answers a single request and closes the connection
+///
+/// Clients get `request_timeout` to send the headers, so a client that
+/// connects and never writes doesn't hold its task forever. The query
+/// string is ignored when matching the path, e.g. `/metrics?format=text`.
+async fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: &RwLock<Snapshot>,
+    request_timeout: Duration,
+) -> std::io::Result<()> {
+    let mut buffer = vec![0u8; MAX_HTTP_REQUEST_BYTES];
+    let read = timeout(request_timeout, read_headers(&mut stream, &mut buffer))
+        .await
+        .map_err(|_| std::io::ErrorKind::TimedOut)??;
+
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line
+        .next()
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default();
+
+    let (status, content_type, body) = if method != "GET" {
+        (
+            "405 Method Not Allowed",
+            "text/plain",
+            "Method not allowed\n".to_string(),
+        )
+    } else {
+        let snapshot = snapshot.read().map_err(|_| std::io::ErrorKind::Other)?;
+        match path {
+            "/metrics" => (
+                "200 OK",
+                "text/plain; version=0.0.4",
+                snapshot.prometheus.clone(),
+            ),
+            "/snapshot.json" => ("200 OK", "application/json", snapshot.json.clone()),
+            _ => ("404 Not Found", "text/plain", "Not found\n".to_string()),
+        }
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Reads into `buffer` until the end of the headers, a full buffer or EOF
+///
+/// # Returns
+///
+/// The number of bytes read
+async fn read_headers(stream: &mut TcpStream, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut read = 0;
+    // only the request line matters, stop at the end of the headers
+    while read < buffer.len() {
+        let n = stream.read(&mut buffer[read..]).await?;
+        if n == 0 {
+            break;
+        }
+        read += n;
+        if buffer[..read].windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+    }
+    Ok(read)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUEST_TIMEOUT: Duration = Duration::from_millis(200);
+
+    /// Serves one connection with a fixed snapshot, returning its address
+    /// and the result of `handle_connection`
+    async fn serve_once() -> (
+        std::net::SocketAddr,
+        tokio::task::JoinHandle<std::io::Result<()>>,
+    ) {
+        let listener = bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = tokio::spawn(async move {
+            let snapshot = RwLock::new(Snapshot {
+                prometheus: "stomata_cpu_usage 1\n".to_string(),
+                json: "{}".to_string(),
+            });
+            let (stream, _) = listener.accept().await?;
+            handle_connection(stream, &snapshot, REQUEST_TIMEOUT).await
+        });
+        (addr, handle)
+    }
+
+    async fn request(request_line: &str) -> String {
+        let (addr, handle) = serve_once().await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("{request_line}\r\nHost: localhost\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        handle.await.unwrap().unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let response = request("GET /metrics HTTP/1.1").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: text/plain; version=0.0.4\r\n"));
+        assert!(response.ends_with("\r\n\r\nstomata_cpu_usage 1\n"));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_json_ignores_query() {
+        let response = request("GET /snapshot.json?pretty=1 HTTP/1.1").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: application/json\r\n"));
+        assert!(response.ends_with("\r\n\r\n{}"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path() {
+        let response = request("GET /other HTTP/1.1").await;
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_method_not_allowed() {
+        let response = request("POST /metrics HTTP/1.1").await;
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_header_timeout() {
+        let (addr, handle) = serve_once().await;
+        // connect without ever sending the headers
+        let _stream = TcpStream::connect(addr).await.unwrap();
+        let err = handle.await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+    }
+}
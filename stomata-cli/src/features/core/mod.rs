@@ -1,9 +1,10 @@
 //! Core application features
 //!
 //! This module provides the main interactive system monitoring and utility
-//! functionality of the application. The core feature runs exclusively in
+//! functionality of the application. The core feature mainly runs in
 //! TUI (Terminal User Interface) mode and offers real-time system metrics,
-//! process monitoring, and various system utilities.
+//! process monitoring, and various system utilities. A small set of
+//! non-interactive commands print plain text for scripting.
 //!
 //! # Features
 //!
@@ -11,10 +12,20 @@
 //! - Interactive process management
 //! - System utility tools
 //! - Optional metrics data storage for historical analysis
+//! - One-shot `--top` process listing for cron/logging
+//! - Prometheus textfile export with `--prometheus`
+//! - HTTP `/metrics` endpoint with `--serve` (`http` feature)
+//! - Diff of two JSON snapshots with `--diff`
+//! - Newline-delimited JSON snapshots with `--stream`
+//! - Monitoring another machine over SSH with `--remote`
+//! - CSV log of the network interfaces with `--net-log`
+//! - CSV export of a process's disk history from the TUI
+//! - Peak usage of the session printed on quit with `--summary`
 //!
 //! # Usage
 //!
-//! The core feature is accessed through the interactive TUI:
+//! The core feature is mainly accessed through the interactive TUI, with a
+//! few one-shot commands available in CLI mode:
 //!
 //! ```bash
 //! # Launch with default 1-second refresh rate
@@ -23,9 +34,35 @@
 //! # Launch with faster refresh (500ms)
 //! stomata -i --interval 500
 //!
+//! # Print the top 10 processes by CPU and exit
+//! stomata core --top --count 10
+//!
+//! # Write metrics for the node_exporter textfile collector every interval
+//! stomata core --prometheus /var/lib/node_exporter/textfile/stomata.prom
+//!
+//! # Monitor a server, which needs `stomata` on its PATH
+//! stomata -i --remote admin@example.com
+//! ```
 //!
 //! # Modules
 //!
+//! - [`cli`] - Command-line interface definitions for non-interactive mode
 //! - [`core_feature`] - Main entry point and render loop implementation
+//! - [`prometheus_exporter`] - Periodic Prometheus textfile writer
+//! - [`http_server`] - Minimal HTTP server exposing the metrics
+//! - [`snapshot_stream`] - JSON snapshot per line on stdout
+//! - [`remote`] - Reads the snapshot stream of another machine over SSH
+//! - [`net_log`] - Appends the network samples of the TUI to a CSV file
+//! - [`disk_export`] - Writes the disk history of a process to a CSV file
+//! - [`session_summary`] - Peak usage printed once the TUI exits
 
+pub mod cli;
 pub mod core_feature;
+pub mod disk_export;
+#[cfg(feature = "http")]
+pub mod http_server;
+pub mod net_log;
+pub mod prometheus_exporter;
+pub mod remote;
+pub mod session_summary;
+pub mod snapshot_stream;
@@ -0,0 +1,130 @@
+//! Network statistics log
+//!
+//! Appends the traffic of every network interface to a CSV file on each
+//! tick of the TUI (`--net-log`), to post-process a network incident in a
+//! spreadsheet afterwards. The samples are the ones the Network page shows,
+//! no extra collection happens.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use stomata_core::collectors::network::metrics::NetworkMetrics;
+
+/// First line of a new log
+pub const NET_LOG_HEADER: &str =
+    "timestamp,interface,rx_bytes,tx_bytes,rx_packets,tx_packets,errors";
+
+/// CSV file the network samples are appended to
+#[derive(Debug)]
+pub struct NetLog {
+    path: PathBuf,
+    file: File,
+}
+
+impl NetLog {
+    /// Opens `path` for appending, writing the header if the file is new
+    /// or empty, so one log can span several runs
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be created or written.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{NET_LOG_HEADER}")
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+        })
+    }
+
+    /// Appends one row per interface of `network_metrics`
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the file if the write fails, e.g. the disk
+    /// is full.
+    pub fn append(&mut self, network_metrics: &NetworkMetrics) -> Result<(), String> {
+        self.file
+            .write_all(csv_rows(network_metrics).as_bytes())
+            .map_err(|e| format!("Failed to write {}: {e}", self.path.display()))
+    }
+}
+
+/// Formats the interfaces as CSV rows in the order of `NET_LOG_HEADER`
+///
+/// Bytes, packets and errors (received plus transmitted) are counted since
+/// the previous sample, like the Network page sparklines.
+fn csv_rows(network_metrics: &NetworkMetrics) -> String {
+    let timestamp = network_metrics.timestamp.to_rfc3339();
+    network_metrics
+        .interfaces
+        .iter()
+        .map(|interface| {
+            format!(
+                "{timestamp},{},{},{},{},{},{}\n",
+                csv_field(&interface.name),
+                interface.bytes_received,
+                interface.bytes_transmitted,
+                interface.packets_received,
+                interface.packets_transmitted,
+                interface.errors_on_received + interface.errors_on_transmitted,
+            )
+        })
+        .collect()
+}
+
+/// Quotes a field containing a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use stomata_core::collectors::network::metrics::NetworkInterfaces;
+
+    use super::*;
+
+    #[test]
+    fn test_csv_rows() {
+        let network_metrics = NetworkMetrics {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+            interfaces: vec![
+                NetworkInterfaces {
+                    name: "eth0".to_string(),
+                    bytes_received: 1500,
+                    bytes_transmitted: 300,
+                    packets_received: 3,
+                    packets_transmitted: 2,
+                    errors_on_received: 1,
+                    errors_on_transmitted: 1,
+                    ..Default::default()
+                },
+                NetworkInterfaces {
+                    name: "odd,name".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+        assert_eq!(
+            csv_rows(&network_metrics),
+            "2025-01-02T03:04:05+00:00,eth0,1500,300,3,2,2\n\
+             2025-01-02T03:04:05+00:00,\"odd,name\",0,0,0,0,0\n"
+        );
+    }
+}
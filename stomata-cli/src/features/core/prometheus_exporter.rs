@@ -0,0 +1,76 @@
+//! Prometheus textfile exporter
+//!
+//! Periodically writes the current system and process metrics in the
+//! Prometheus exposition format, for the node_exporter textfile collector
+//! to pick up and expose to Prometheus/Grafana.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use stomata_core::{
+    collectors::{
+        process::metrics::ProcessData,
+        structs::{Metrics, MetricsToFetch, StomataSystemMetrics},
+        system::metrics::SystemMetrics,
+    },
+    prometheus,
+};
+
+/// Collects the current system and process metrics
+///
+/// # Arguments
+///
+/// * `metrics` - The metrics collector, kept across calls so CPU usage is
+///   measured between consecutive collections
+pub fn collect(metrics: &mut StomataSystemMetrics) -> (SystemMetrics, Vec<ProcessData>) {
+    let system = match metrics.fetch(MetricsToFetch::SystemResource) {
+        Metrics::SystemResource(collector) => collector.system_metrics,
+        _ => Default::default(),
+    };
+    let processes = match metrics.fetch(MetricsToFetch::Process) {
+        Metrics::Processes(processes) => processes,
+        _ => Vec::new(),
+    };
+    (system, processes)
+}
+
+/// Writes the metrics to `path` every `interval_ms` until interrupted
+///
+/// Each write goes to a temporary file next to `path` that is then renamed
+/// over it, so the textfile collector never reads a half written file.
+///
+/// # Arguments
+///
+/// * `path` - Output file, should end in `.prom` for the textfile collector
+/// * `interval_ms` - Time between writes in milliseconds
+///
+/// # Errors
+///
+/// Returns an error if the file can't be written, e.g. the directory
+/// doesn't exist or isn't writable.
+///
+/// # Examples
+///
+/// ```bash
+/// stomata --interval 15000 core --prometheus /var/lib/node_exporter/textfile/stomata.prom
+/// ```
+pub fn run_textfile_exporter(path: &Path, interval_ms: u64) -> anyhow::Result<()> {
+    let mut metrics = StomataSystemMetrics::new();
+    let mut temp_path: PathBuf = path.as_os_str().to_owned().into();
+    temp_path.set_extension("tmp");
+
+    loop {
+        let (system, processes) = collect(&mut metrics);
+        let contents = prometheus::render(&system, &processes);
+        fs::write(&temp_path, contents)
+            .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+        fs::rename(&temp_path, path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        thread::sleep(Duration::from_millis(interval_ms));
+    }
+}
@@ -0,0 +1,283 @@
+//! Monitoring another machine over SSH
+//!
+//! `--remote user@host` runs `stomata core --stream` on the remote machine
+//! through the local `ssh` client and renders the snapshots it prints in the
+//! local TUI, so nothing but the stomata binary is needed on the server.
+//!
+//! SSH runs in batch mode since the TUI owns the terminal, so the host must
+//! accept key based authentication (an agent or an unencrypted key).
+
+use std::{
+    io::{BufRead, BufReader, Read},
+    process::{Child, Command, Stdio},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use ratatui::{
+    Terminal,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    prelude::CrosstermBackend,
+};
+use stomata_core::{
+    collectors::{
+        process::metrics::ProcessData,
+        system::metrics::{SystemCollector, SystemMetrics},
+    },
+    snapshot,
+};
+
+use crate::{
+    config::StomataConfig,
+    constants::{REMOTE_COMMAND, REMOTE_POLL_MS},
+    preferences::UiPreferences,
+    renders::{
+        core_displays::traits::Display, render_widgets::render_popup::render_terminal_too_small,
+    },
+    structs::{Cli, FeatureExit, UIState},
+};
+
+/// Messages from the threads reading the ssh subprocess
+enum RemoteEvent {
+    Snapshot(SystemMetrics, Vec<ProcessData>),
+    Error(String),
+    Disconnected,
+}
+
+/// Latest metrics received from a `--remote` host
+///
+/// Owns the ssh subprocess, which is killed when this is dropped.
+pub struct RemoteSource {
+    /// The `user@host` given to `--remote`
+    pub host: String,
+    /// System metrics of the last snapshot, `None` until the first arrives
+    pub system: Option<SystemCollector>,
+    /// Processes of the last snapshot
+    pub processes: Vec<ProcessData>,
+    /// Last error printed by ssh or the remote stomata, or a bad snapshot
+    pub error: Option<String>,
+    /// `false` once the remote command exited
+    pub connected: bool,
+    child: Child,
+    events: Receiver<RemoteEvent>,
+}
+
+impl RemoteSource {
+    /// Starts `stomata core --stream` on `host` over ssh
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - SSH destination, e.g. `admin@example.com` or a `Host` alias
+    ///   from `~/.ssh/config`
+    /// * `interval_ms` - Refresh interval passed on to the remote stomata
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `host` starts with `-`, which ssh would take for
+    /// an option such as `-oProxyCommand=…`, or the `ssh` client can't be
+    /// started. Connection and authentication failures are reported later
+    /// through `error`.
+    pub fn connect(host: &str, interval_ms: u64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !host.starts_with('-'),
+            "Invalid --remote host {host:?}, it can't start with '-'"
+        );
+        let mut child = Command::new("ssh")
+            .args(ssh_args(host, interval_ms))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to run ssh, is the OpenSSH client installed?")?;
+
+        let (sender, events) = mpsc::channel();
+        if let Some(stdout) = child.stdout.take() {
+            let sender = sender.clone();
+            thread::spawn(move || read_snapshots(stdout, sender));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            thread::spawn(move || read_errors(stderr, sender));
+        }
+
+        Ok(Self {
+            host: host.to_string(),
+            system: None,
+            processes: Vec::new(),
+            error: None,
+            connected: true,
+            child,
+            events,
+        })
+    }
+
+    /// Applies everything received since the last call
+    ///
+    /// New snapshots are also added to the CPU/memory history of `ui_state`,
+    /// and set its CPU count so normalized CPU usage uses the remote one.
+    ///
+    /// # Returns
+    ///
+    /// `true` if anything changed and the view should be redrawn
+    pub fn poll(&mut self, ui_state: &mut UIState) -> bool {
+        let mut changed = false;
+        while let Ok(event) = self.events.try_recv() {
+            changed = true;
+            match event {
+                RemoteEvent::Snapshot(system_metrics, processes) => {
+                    ui_state.units.cpu_count = system_metrics.cpu_count;
+                    ui_state.system_usage.update_history(
+                        system_metrics.cpu_usage,
+                        system_metrics.memory_used,
+                        system_metrics.memory_total,
+                    );
+                    self.system = Some(SystemCollector { system_metrics });
+                    self.processes = processes;
+                    self.error = None;
+                }
+                RemoteEvent::Error(error) => self.error = Some(error),
+                RemoteEvent::Disconnected => self.connected = false,
+            }
+        }
+        changed
+    }
+}
+
+impl Drop for RemoteSource {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Arguments of the `ssh` command streaming snapshots from `host`
+///
+/// `--` ends the ssh options, so `host` is always read as the destination.
+fn ssh_args(host: &str, interval_ms: u64) -> Vec<String> {
+    let interval = interval_ms.to_string();
+    [
+        "-T",
+        "-o",
+        "BatchMode=yes",
+        "--",
+        host,
+        REMOTE_COMMAND,
+        "--interval",
+        interval.as_str(),
+        "core",
+        "--stream",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+fn read_snapshots(stdout: impl Read, sender: Sender<RemoteEvent>) {
+    for line in BufReader::new(stdout).lines() {
+        let Ok(line) = line else { break };
+        let event = match snapshot::parse(&line) {
+            Ok((system, processes)) => RemoteEvent::Snapshot(system, processes),
+            Err(e) => RemoteEvent::Error(format!("Invalid snapshot: {e:#}")),
+        };
+        if sender.send(event).is_err() {
+            return;
+        }
+    }
+    let _ = sender.send(RemoteEvent::Disconnected);
+}
+
+fn read_errors(stderr: impl Read, sender: Sender<RemoteEvent>) {
+    for line in BufReader::new(stderr).lines() {
+        let Ok(line) = line else { break };
+        if !line.trim().is_empty() && sender.send(RemoteEvent::Error(line)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Runs the remote view until the user quits or goes back
+///
+/// # Arguments
+///
+/// * `cli` - Parsed arguments, for the interval and history length
+/// * `host` - SSH destination given to `--remote`
+/// * `terminal` - Terminal the view is drawn on
+///
+/// # Returns
+///
+/// * `Ok(FeatureExit::Back)` - The user pressed `Esc`
+/// * `Ok(FeatureExit::Quit)` - The user pressed `q`
+///
+/// # Errors
+///
+/// Returns an error if ssh can't be started or the terminal fails.
+pub fn run_remote(
+    cli: &Cli,
+    host: &str,
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+) -> anyhow::Result<FeatureExit> {
+    let mut source = RemoteSource::connect(host, cli.interval)?;
+    let config = StomataConfig::load();
+    let mut ui_state = UIState::new(cli.history_len);
+    ui_state.units = config.units;
+    ui_state.hidden_interfaces = config.hidden_interfaces.iter().cloned().collect();
+    ui_state.palette = cli.palette.unwrap_or(config.palette);
+    ui_state.density = UiPreferences::load().density;
+
+    let mut redraw = true;
+    loop {
+        redraw |= source.poll(&mut ui_state);
+        if redraw {
+            terminal.draw(|frame| {
+                if !render_terminal_too_small(frame) {
+                    let _ = source.display(frame, frame.area(), Some(&mut ui_state));
+                }
+            })?;
+            redraw = false;
+        }
+
+        if event::poll(Duration::from_millis(REMOTE_POLL_MS))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') => return Ok(FeatureExit::Quit),
+                    KeyCode::Esc => return Ok(FeatureExit::Back),
+                    _ => {}
+                },
+                Event::Resize(_, _) => redraw = true,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_args() {
+        assert_eq!(
+            ssh_args("admin@example.com", 2000),
+            [
+                "-T",
+                "-o",
+                "BatchMode=yes",
+                "--",
+                "admin@example.com",
+                "stomata",
+                "--interval",
+                "2000",
+                "core",
+                "--stream",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_connect_rejects_hosts_parsed_as_options() {
+        let err = RemoteSource::connect("-oProxyCommand=touch /tmp/pwned", 1000)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("can't start with '-'"));
+    }
+}
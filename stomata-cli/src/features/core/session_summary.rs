@@ -0,0 +1,198 @@
+//! Final summary of a monitoring session
+//!
+//! With `--summary`, the core TUI keeps the peak CPU and memory usage and
+//! the process that used the most CPU while it ran. Once the terminal is
+//! restored the summary is printed to stdout, so a session ends with a
+//! takeaway rather than just dropping back to the prompt.
+
+use std::time::Instant;
+
+use stomata_core::collectors::{process::metrics::ProcessData, system::metrics::SystemMetrics};
+
+use crate::{
+    units::UnitConfig,
+    utils::{format_duration, percentage},
+};
+
+/// Process with the highest CPU usage seen during the session
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopConsumer {
+    pub pid: u32,
+    pub name: String,
+
+    /// CPU usage in percent of one core when the peak was sampled
+    pub cpu_usage: f32,
+}
+
+/// Maxima observed while the core TUI ran
+///
+/// Kept for the whole stomata session, going back to the feature selection
+/// and into the core feature again keeps adding to the same summary.
+#[derive(Debug, Clone, Default)]
+pub struct SessionSummary {
+    /// When the first sample was observed, `None` until then
+    pub started: Option<Instant>,
+
+    /// Highest total CPU usage in percent
+    pub peak_cpu: f32,
+
+    /// Highest used memory in bytes
+    pub peak_memory: u64,
+
+    /// Total memory in bytes, to show the peak as a share of it
+    pub memory_total: u64,
+
+    pub top_consumer: Option<TopConsumer>,
+
+    /// Units of the TUI when last observed, the report uses the same
+    pub units: UnitConfig,
+}
+
+impl SessionSummary {
+    /// Records the system usage of one refresh
+    ///
+    /// # Arguments
+    ///
+    /// * `system_metrics` - System usage fetched for the current frame
+    /// * `now` - Time of the refresh, the first one starts the session
+    pub fn observe_system(&mut self, system_metrics: &SystemMetrics, now: Instant) {
+        self.started.get_or_insert(now);
+        self.peak_cpu = self.peak_cpu.max(system_metrics.cpu_usage);
+        self.peak_memory = self.peak_memory.max(system_metrics.memory_used);
+        self.memory_total = system_metrics.memory_total;
+    }
+
+    /// Records the process list of one refresh, keeping the process with
+    /// the highest CPU usage so far
+    pub fn observe_processes(&mut self, processes: &[ProcessData]) {
+        let Some(top) = processes
+            .iter()
+            .max_by(|a, b| a.cpu_usage.total_cmp(&b.cpu_usage))
+        else {
+            return;
+        };
+        if self
+            .top_consumer
+            .as_ref()
+            .is_none_or(|consumer| top.cpu_usage > consumer.cpu_usage)
+        {
+            self.top_consumer = Some(TopConsumer {
+                pid: top.pid,
+                name: top.name.clone(),
+                cpu_usage: top.cpu_usage,
+            });
+        }
+    }
+
+    /// Formats the summary as plain text, e.g.
+    ///
+    /// ```text
+    /// Session summary (0d 00h 12m 04s)
+    ///   Peak CPU:     87.50%
+    ///   Peak memory:  12.30 GiB of 16.00 GiB (76.88%)
+    ///   Top consumer: firefox (PID 4242) at 145.00% CPU
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `None` when nothing was observed, e.g. quitting before the first refresh
+    pub fn report(&self, now: Instant) -> Option<String> {
+        let started = self.started?;
+        let units = &self.units;
+        let elapsed = now.saturating_duration_since(started).as_secs();
+        let mut report = format!(
+            "Session summary ({})\n  Peak CPU:     {}\n  Peak memory:  {} of {} ({})",
+            format_duration(elapsed),
+            units.format_percent(self.peak_cpu as f64),
+            units.format_bytes(self.peak_memory as f64),
+            units.format_bytes(self.memory_total as f64),
+            units.format_percent(percentage(
+                self.peak_memory as f64,
+                self.memory_total as f64
+            )),
+        );
+        if let Some(consumer) = &self.top_consumer {
+            report.push_str(&format!(
+                "\n  Top consumer: {} (PID {}) at {} CPU",
+                consumer.name,
+                consumer.pid,
+                units.format_cpu(consumer.cpu_usage)
+            ));
+        }
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use super::*;
+
+    fn system_metrics(cpu_usage: f32, memory_used: u64) -> SystemMetrics {
+        SystemMetrics {
+            timestamp: Utc::now(),
+            cpu_count: 4,
+            cpu_usage,
+            memory_used,
+            memory_total: 16 * 1024 * 1024 * 1024,
+            swap_used: 0,
+            swap_total: 0,
+        }
+    }
+
+    fn process(pid: u32, name: &str, cpu_usage: f32) -> ProcessData {
+        ProcessData {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            status: "Run".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keeps_the_peaks() {
+        let mut summary = SessionSummary::default();
+        let now = Instant::now();
+        summary.observe_system(&system_metrics(40.0, 8 * 1024 * 1024 * 1024), now);
+        summary.observe_system(&system_metrics(87.5, 4 * 1024 * 1024 * 1024), now);
+        summary.observe_processes(&[process(1, "init", 1.0), process(42, "cargo", 380.0)]);
+        summary.observe_processes(&[process(42, "cargo", 20.0), process(7, "rustc", 90.0)]);
+
+        assert_eq!(summary.started, Some(now));
+        assert_eq!(summary.peak_cpu, 87.5);
+        assert_eq!(summary.peak_memory, 8 * 1024 * 1024 * 1024);
+        assert_eq!(
+            summary.top_consumer,
+            Some(TopConsumer {
+                pid: 42,
+                name: "cargo".to_string(),
+                cpu_usage: 380.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let mut summary = SessionSummary::default();
+        let now = Instant::now();
+        assert_eq!(summary.report(now), None);
+
+        summary.observe_system(&system_metrics(87.5, 8 * 1024 * 1024 * 1024), now);
+        assert_eq!(
+            summary.report(now + Duration::from_secs(724)).unwrap(),
+            "Session summary (0d 00h 12m 04s)\n  Peak CPU:     87.50%\n  Peak memory:  8.00 GiB of 16.00 GiB (50.00%)"
+        );
+
+        summary.observe_processes(&[process(42, "cargo", 145.0)]);
+        assert!(
+            summary
+                .report(now)
+                .unwrap()
+                .ends_with("\n  Top consumer: cargo (PID 42) at 145.00% CPU")
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Newline-delimited JSON snapshot stream
+//!
+//! Prints one `/snapshot.json` document per line to stdout every refresh
+//! interval. `--remote` runs this on the remote machine over SSH and reads
+//! the lines back, and it works just as well for shipping logs.
+
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use stomata_core::{collectors::structs::StomataSystemMetrics, snapshot};
+
+use crate::features::core::prometheus_exporter::collect;
+
+/// Prints a snapshot every `interval_ms` until interrupted or stdout closes
+///
+/// # Arguments
+///
+/// * `interval_ms` - Time between snapshots in milliseconds
+///
+/// # Errors
+///
+/// Returns an error if stdout can't be written for a reason other than the
+/// reader going away, e.g. the SSH connection of `--remote` closing.
+///
+/// # Examples
+///
+/// ```bash
+/// stomata --interval 5000 core --stream >> metrics.ndjson
+/// ```
+pub fn run_snapshot_stream(interval_ms: u64) -> anyhow::Result<()> {
+    let mut metrics = StomataSystemMetrics::new();
+    let mut stdout = io::stdout().lock();
+
+    loop {
+        let (system, processes) = collect(&mut metrics);
+        let line = snapshot::to_json(&system, &processes).to_string();
+        match writeln!(stdout, "{line}").and_then(|_| stdout.flush()) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+            Err(e) => return Err(e).context("Failed to write the snapshot stream"),
+        }
+        thread::sleep(Duration::from_millis(interval_ms));
+    }
+}
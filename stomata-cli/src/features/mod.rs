@@ -20,7 +20,10 @@ use std::io::Stdout;
 
 use ratatui::{Terminal, prelude::CrosstermBackend};
 
-use crate::structs::{Cli, Feature};
+use crate::{
+    features::core::session_summary::SessionSummary,
+    structs::{Cli, Feature, FeatureExit},
+};
 
 /// Core feature functionality
 ///
@@ -49,11 +52,15 @@ pub mod web3;
 /// * `terminal` - Optional terminal for interactive TUI mode:
 ///   - `Some(terminal)` - Run in interactive mode with TUI
 ///   - `None` - Run in CLI mode, execute command and exit
+/// * `session` - Peak usage collected by the core feature with `--summary`,
+///   kept across runs so it covers the whole session
 ///
 /// # Returns
 ///
-/// * `Ok(true)` - Interactive mode exited normally (user quit)
-/// * `Ok(false)` - CLI mode completed successfully
+/// * `Ok(FeatureExit::Back)` - The user pressed `Esc` to return to the
+///   feature selection menu
+/// * `Ok(FeatureExit::Quit)` - The user quit with `q`, or the CLI command
+///   completed
 ///
 /// # Errors
 ///
@@ -70,11 +77,12 @@ pub mod web3;
 ///
 /// // Run in CLI mode (no terminal)
 /// let cli = Cli::parse();
-/// run_feature(Feature::Web3, &cli, None)?;
+/// let mut session = SessionSummary::default();
+/// run_feature(Feature::Web3, &cli, None, &mut session)?;
 ///
 /// // Run in interactive mode (with terminal)
 /// let mut terminal = setup_terminal()?;
-/// run_feature(Feature::Web3, &cli, Some(&mut terminal))?;
+/// run_feature(Feature::Web3, &cli, Some(&mut terminal), &mut session)?;
 /// ```
 ///
 /// # Feature Availability
@@ -86,10 +94,11 @@ pub async fn run_feature(
     feature: Feature,
     cli: &Cli,
     terminal: Option<&mut Terminal<CrosstermBackend<Stdout>>>,
-) -> anyhow::Result<bool> {
+    session: &mut SessionSummary,
+) -> anyhow::Result<FeatureExit> {
     match feature {
         #[cfg(feature = "core")]
-        Feature::Core => core::core_feature::run(cli, terminal),
+        Feature::Core => core::core_feature::run(cli, terminal, session),
         #[cfg(feature = "web3")]
         Feature::Web3 => web3::web3_feature::run(cli, terminal).await,
     }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand, ValueEnum};
 
 /// Web3 developer tools CLI
@@ -16,6 +18,9 @@ use clap::{Parser, Subcommand, ValueEnum};
 ///
 /// # List all stored keys
 /// stomata web3 key list
+///
+/// # Scriptable JSON output
+/// stomata web3 av -a 0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb --json
 /// ```
 #[derive(Parser, Clone)]
 #[command(name = "web3")]
@@ -24,6 +29,10 @@ pub struct Web3Cli {
     /// The web3 tool to execute
     #[command(subcommand)]
     pub tool: Web3Tool,
+
+    /// Print results as JSON to stdout and errors as `{"error":"..."}` to stderr
+    #[arg(long, global = true, default_value_t = false)]
+    pub json: bool,
 }
 
 /// Available Web3 tools
@@ -79,6 +88,10 @@ pub enum OutputFormat {
 ///
 /// Operations for securely storing and retrieving encrypted keys.
 /// All keys are stored with encryption at rest.
+///
+/// Keys are stored as files in `~/.stomataKeys/keys` by default. Set
+/// `STOMATA_KEY_STORE=keyring` to keep them in the OS keyring instead
+/// (requires the `keyring` feature).
 #[derive(Subcommand, Clone)]
 pub enum KeySubCommands {
     /// Encrypt and store a new key
@@ -91,6 +104,7 @@ pub enum KeySubCommands {
     /// ```bash
     /// stomata web3 key encrypt -n my-api-key
     /// stomata web3 key e -n wallet-seed  # using alias
+    /// stomata web3 key encrypt -n test-key --force  # allow a weak password
     /// ```
     #[command(name = "encrypt", alias = "e")]
     Encrypt {
@@ -100,18 +114,27 @@ pub enum KeySubCommands {
         /// Must be unique among stored keys.
         #[arg(short, long, required = true)]
         name: String,
+
+        /// Encrypt even if the password is rated as weak
+        ///
+        /// By default encryption is refused when the password does not
+        /// have enough estimated entropy.
+        #[arg(short, long, default_value_t = false)]
+        force: bool,
     },
 
     /// Decrypt and display a stored key
     ///
     /// Retrieves the encrypted key by name, decrypts it,
-    /// and displays it in the requested format.
+    /// and displays it in the requested format, or writes it to a file
+    /// with `--out` to keep it out of the terminal scrollback.
     ///
     /// # Examples
     ///
     /// ```bash
     /// stomata web3 key decrypt -n my-api-key
     /// stomata web3 key d -n wallet-seed --format hex  # using alias
+    /// stomata web3 key decrypt -n wallet-seed --out ~/wallet-seed.txt
     /// ```
     #[command(name = "decrypt", alias = "d")]
     Decrypt {
@@ -126,11 +149,52 @@ pub enum KeySubCommands {
         /// Choose 'hex' for binary data or 'utf8' (default) for text.
         #[arg(short, long, value_enum, default_value_t = OutputFormat::Utf8)]
         format: OutputFormat,
+
+        /// Write the decrypted key to this file instead of stdout
+        ///
+        /// The file must not exist yet and is created readable by the owner
+        /// only (0600 on Unix). Directories other users can read or write
+        /// to, like /tmp or a 0755 home, are refused.
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Import an existing private key
+    ///
+    /// Reads a 32 byte private key generated elsewhere, as hex (with or
+    /// without `0x`) or a v3 keystore JSON file (asks for its password),
+    /// validates it and encrypts it under the specified name. The key is
+    /// stored as `0x` prefixed hex, like a key typed into `encrypt`.
+    ///
+    /// # Examples
+    ///
+    /// ```bash
+    /// stomata web3 key import -n wallet --from-file ~/keystore.json
+    /// stomata web3 key i -n wallet < key.txt  # using alias, from stdin
+    /// ```
+    #[command(name = "import", alias = "i")]
+    Import {
+        /// Name identifier for the key
+        ///
+        /// Must be unique among stored keys.
+        #[arg(short, long, required = true)]
+        name: String,
+
+        /// File holding the key, read from stdin when omitted
+        ///
+        /// On a terminal the key is prompted for without echo.
+        #[arg(long)]
+        from_file: Option<PathBuf>,
+
+        /// Encrypt even if the password is rated as weak
+        #[arg(short, long, default_value_t = false)]
+        force: bool,
     },
 
-    /// List all stored key names
+    /// List all stored keys
     ///
-    /// Displays the names of all encrypted keys currently stored.
+    /// Displays the names of all encrypted keys currently stored along with
+    /// their creation time and encryption algorithm.
     /// Does not decrypt or display key values.
     ///
     /// # Examples
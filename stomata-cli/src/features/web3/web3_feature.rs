@@ -8,37 +8,69 @@ use std::{
     io::Stdout,
     iter::once,
     process::exit,
+    sync::Arc,
     time::{Duration, Instant},
 };
 
 use clap::Parser;
 use ratatui::{
     Frame, Terminal,
-    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    crossterm::{
+        event::{
+            self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent,
+            KeyEventKind,
+        },
+        execute,
+    },
     layout::{Constraint, Layout, Rect},
     prelude::CrosstermBackend,
-    style::{Color, Modifier, Style},
+    style::{Color, Style},
     text::Line,
     widgets::{Block, Borders, Tabs},
 };
 use stomata_web3::providers::{
+    explorer::{
+        config::explorer_config,
+        structs::{ExplorerConfig, RecentTransaction},
+    },
     portfolio::{service::get_portfolio, structs::Portfolio},
-    rpc::structs::EVMProvider,
+    price::structs::CoinGeckoPriceProvider,
+    rpc::{
+        config::rpc_endpoints, helper::wei_to_gwei, structs::EVMProvider,
+        subscription::watch_latest_block,
+    },
 };
-use tokio::sync::mpsc;
+use tokio::sync::{Semaphore, mpsc};
 
 use crate::{
+    config::StomataConfig,
+    constants::{
+        GAS_PRICE_HISTORY_LEN, RECENT_TRANSACTIONS_LIMIT, WATCHLIST_MAX_CONCURRENT_LOOKUPS,
+        WATCHLIST_REFRESH_SECS, WEB3_STALE_AFTER_SECS,
+    },
+    density::Density,
     features::web3::cli::{KeySubCommands, Web3Cli, Web3Tool},
+    palette::Palette,
+    preferences::UiPreferences,
     renders::{
         core_displays::traits::Display,
-        render_widgets::{render_input::InputAction, render_paragraph::paragraph_widget},
+        render_widgets::{
+            render_input::InputAction,
+            render_paragraph::paragraph_widget,
+            render_popup::{render_confirm_popup, render_help_popup, render_terminal_too_small},
+            render_sparkline::render_sparkline,
+        },
         web3_displays::{
             address_validation::validate_address,
-            key_encryption::{decrypt_key, delete_encrypted_key, encrypt_key, list_all_keys},
-            portfolio::get_portfolio_data,
+            json_output::print_json_error,
+            key_encryption::{
+                decrypt_key, delete_encrypted_key, encrypt_key, import_key, list_all_keys,
+            },
+            portfolio::{get_portfolio_data, get_recent_transactions_data, price_provider},
         },
     },
-    structs::{Cli, InputWidgetState},
+    structs::{Cli, FeatureExit, InputWidgetState, Ring},
+    utils::{step_refresh_interval, updated_ago},
 };
 
 /// Available pages in the Web3 TUI
@@ -49,6 +81,8 @@ pub enum Web3Page {
     /// Page for validating Ethereum addresses
     AddressValidation,
     Portfolio,
+    /// Summary table of the addresses in the `watchlist` config
+    Watchlist,
 }
 
 impl Web3Page {
@@ -56,7 +90,7 @@ impl Web3Page {
     ///
     /// Used for rendering the tab bar in the TUI.
     pub fn titles() -> Vec<&'static str> {
-        vec!["Address Validation", "Portfolio"]
+        vec!["Address Validation", "Portfolio", "Watchlist"]
     }
 
     /// Converts a tab index to the corresponding page
@@ -73,24 +107,132 @@ impl Web3Page {
         match index {
             0 => Web3Page::AddressValidation,
             1 => Web3Page::Portfolio,
+            2 => Web3Page::Watchlist,
             _ => Web3Page::AddressValidation,
         }
     }
+
+    /// Returns the keybindings active on this page
+    ///
+    /// Global bindings come first, followed by page-specific ones. Used to
+    /// build the `?` help overlay.
+    pub fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        let mut bindings = vec![
+            ("q", "Quit"),
+            ("Esc", "Back to feature selection"),
+            ("?", "Toggle this help"),
+            ("Tab", "Next tab"),
+            ("Shift+Tab", "Previous tab"),
+            ("1-3", "Jump to tab"),
+            ("+ / -", "Increase / decrease refresh interval"),
+        ];
+
+        match self {
+            Web3Page::Portfolio => bindings.extend([
+                ("e", "Start typing an address"),
+                ("← / → / Home / End", "Move the cursor in the input"),
+                ("Del", "Delete the character under the cursor"),
+                ("Enter", "Fetch portfolio for the typed address"),
+                ("Esc", "Leave the address input"),
+            ]),
+            Web3Page::AddressValidation | Web3Page::Watchlist => {}
+        }
+
+        bindings
+    }
 }
 
 /// UI-specific state for the Web3 interactive interface
 ///
 /// Currently a placeholder for future UI state management.
-#[derive(Default)]
 pub struct Web3UIState {
     pub input_area_state: Option<InputWidgetState>,
     pub portfolio: Option<Portfolio>,
     pub loading: bool,
+    /// Explorer API used for the recent transactions panel, `None` hides
+    /// the panel
+    pub explorer: Option<ExplorerConfig>,
+    /// Latest transactions of the looked up address, `None` until fetched
+    pub recent_transactions: Option<Result<Vec<RecentTransaction>, String>>,
+    /// Price provider for the USD values, `None` without the `prices` feature.
+    /// Shared across lookups so its cache is reused
+    pub prices: Option<Arc<CoinGeckoPriceProvider>>,
+    /// Number of the latest Ethereum block, `None` until the first one arrives
+    pub latest_block: Option<u64>,
+    /// Base fee per gas of the latest Ethereum block in wei, `None` until
+    /// fetched
+    pub base_fee_per_gas: Option<u128>,
+    /// Gas price in wei polled with each block, oldest first
+    pub gas_prices: Ring<u64>,
+    /// Addresses of the Watchlist page with their latest lookup
+    pub watchlist: Vec<WatchlistEntry>,
+    /// When the watchlist was last fetched, `None` before the first fetch
+    pub watchlist_fetched: Option<Instant>,
+}
+
+impl Default for Web3UIState {
+    fn default() -> Self {
+        Self {
+            input_area_state: None,
+            portfolio: None,
+            loading: false,
+            explorer: None,
+            recent_transactions: None,
+            prices: None,
+            latest_block: None,
+            base_fee_per_gas: None,
+            gas_prices: Ring::new(GAS_PRICE_HISTORY_LEN),
+            watchlist: Vec::new(),
+            watchlist_fetched: None,
+        }
+    }
+}
+
+/// Wrapper for the recent transactions panel of the Portfolio page.
+pub struct RecentTransactionsUI<'a> {
+    /// Fetched transactions or the error message, `None` until fetched
+    pub transactions: Option<&'a Result<Vec<RecentTransaction>, String>>,
+
+    /// Colors of the table
+    pub palette: Palette,
+}
+
+/// An address of the watchlist and the result of its latest lookup
+pub struct WatchlistEntry {
+    /// Address as written in the config
+    pub address: String,
+
+    /// Latest portfolio or why fetching it failed, `None` until the first
+    /// lookup finishes
+    pub portfolio: Option<Result<Portfolio, String>>,
+
+    /// Whether a lookup is in flight, a refresh skips the entry until it
+    /// finishes so an older result can't overwrite a newer one
+    pub pending: bool,
+}
+
+/// Wrapper for the Watchlist page.
+pub struct WatchlistUI<'a> {
+    /// Rows of the table, in the order of the config
+    pub entries: &'a [WatchlistEntry],
+
+    /// Colors of the table
+    pub palette: Palette,
 }
 
 pub enum Web3AppEvents {
     PortfolioLoaded(Portfolio),
     PortfolioError(String),
+    TransactionsLoaded(Result<Vec<RecentTransaction>, String>),
+    /// A new block was produced, or polled, on Ethereum
+    LatestBlock(u64),
+    /// Base fee per gas in wei of the latest block, fetched after each
+    /// `LatestBlock`
+    BaseFee(u128),
+    /// Gas price in wei, fetched after each `LatestBlock`
+    GasPrice(u128),
+    /// Lookup of the watchlist entry at this index finished
+    WatchlistEntryLoaded(usize, Result<Portfolio, String>),
 }
 
 /// State manager for the Web3 feature
@@ -115,21 +257,183 @@ pub struct Web3State {
 
     /// channel transmitter
     pub tx: mpsc::UnboundedSender<Web3AppEvents>,
+
+    /// Refresh interval of the render loop in milliseconds
+    pub refresh_interval: u64,
+
+    /// Whether the keybinding help overlay is shown
+    pub show_help: bool,
+
+    /// Whether quitting asks for confirmation first
+    pub confirm_quit: bool,
+
+    /// Whether the quit confirmation prompt is shown
+    pub quit_pending: bool,
+
+    /// Where to go once rendering stops, back to the feature selection
+    /// or out of the application
+    pub exit: FeatureExit,
+
+    /// Colors of the tabs and tables
+    pub palette: Palette,
+
+    /// When the last block, portfolio or transactions arrived from the
+    /// background tasks, `None` until the first one does
+    pub last_update: Option<Instant>,
+
+    /// Bounds the watchlist lookups running at once, see
+    /// `WATCHLIST_MAX_CONCURRENT_LOOKUPS`
+    pub watchlist_lookups: Arc<Semaphore>,
 }
 
 impl Web3State {
     /// Creates a new Web3State with default values
     ///
     /// Initializes to the Address Validation page with rendering enabled.
-    pub fn new() -> Self {
+    /// The recent transactions panel is enabled when an explorer API key is
+    /// configured, see [`explorer_config`].
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_interval` - Initial refresh interval in milliseconds
+    /// * `confirm_quit` - Whether quitting asks for confirmation first
+    /// * `palette` - Colors of the tabs and tables
+    /// * `watchlist` - Addresses shown on the Watchlist page
+    pub fn new(
+        refresh_interval: u64,
+        confirm_quit: bool,
+        palette: Palette,
+        watchlist: Vec<String>,
+    ) -> Self {
         let (tx, rx) = mpsc::unbounded_channel::<Web3AppEvents>();
         Self {
             render: true,
             current_page: Web3Page::AddressValidation,
             tab_index: 0,
-            ui_state: Web3UIState::default(),
+            ui_state: Web3UIState {
+                explorer: explorer_config(),
+                prices: price_provider(),
+                watchlist: watchlist
+                    .into_iter()
+                    .map(|address| WatchlistEntry {
+                        address,
+                        portfolio: None,
+                        pending: false,
+                    })
+                    .collect(),
+                ..Default::default()
+            },
             rx,
             tx,
+            refresh_interval,
+            show_help: false,
+            confirm_quit,
+            quit_pending: false,
+            exit: FeatureExit::Back,
+            palette,
+            last_update: None,
+            watchlist_lookups: Arc::new(Semaphore::new(WATCHLIST_MAX_CONCURRENT_LOOKUPS)),
+        }
+    }
+
+    /// Quits the application, or asks for confirmation first when
+    /// `confirm_quit` is set
+    pub fn request_quit(&mut self) {
+        if self.confirm_quit {
+            self.quit_pending = true;
+        } else {
+            self.quit();
+        }
+    }
+
+    /// Stops rendering and quits the application
+    fn quit(&mut self) {
+        self.exit = FeatureExit::Quit;
+        self.render = false;
+    }
+
+    /// Stops rendering and returns to the feature selection menu
+    pub fn go_back(&mut self) {
+        self.exit = FeatureExit::Back;
+        self.render = false;
+    }
+
+    /// Starts following the latest Ethereum block in the background
+    ///
+    /// Uses a `newHeads` subscription when a WebSocket RPC endpoint is
+    /// configured and polls `eth_blockNumber` otherwise, see
+    /// [`watch_latest_block`]. Each block is delivered as
+    /// [`Web3AppEvents::LatestBlock`].
+    ///
+    /// # Returns
+    ///
+    /// The handle of the background task, to abort it when the feature exits
+    pub fn watch_latest_block(&self) -> tokio::task::JoinHandle<()> {
+        let tx = self.tx.clone();
+        tokio::spawn(watch_latest_block(
+            rpc_endpoints("ethereum"),
+            move |block_number| tx.send(Web3AppEvents::LatestBlock(block_number)).is_ok(),
+        ))
+    }
+
+    /// Fetches the base fee of the latest block and the gas price in the
+    /// background, delivered as [`Web3AppEvents::BaseFee`] and
+    /// [`Web3AppEvents::GasPrice`]. A failed fetch keeps the previous values.
+    fn fetch_gas_fees(&self) {
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            let Ok(provider) =
+                EVMProvider::with_fallbacks(String::new(), rpc_endpoints("ethereum"))
+            else {
+                return;
+            };
+            let (latest_block, gas_price) =
+                tokio::join!(provider.latest_block(), provider.gas_price());
+            if let Some(base_fee_per_gas) = latest_block.ok().and_then(|b| b.base_fee_per_gas) {
+                let _ = tx.send(Web3AppEvents::BaseFee(base_fee_per_gas));
+            }
+            if let Ok(gas_price) = gas_price {
+                let _ = tx.send(Web3AppEvents::GasPrice(gas_price));
+            }
+        });
+    }
+
+    /// Looks up every watchlist address concurrently in the background
+    ///
+    /// Only runs while the Watchlist page is shown, at most every
+    /// `WATCHLIST_REFRESH_SECS`. Each lookup is delivered on its own as
+    /// [`Web3AppEvents::WatchlistEntryLoaded`], so a slow or failing address
+    /// doesn't hold back the others. Rows keep their previous values until
+    /// the new ones arrive, entries whose previous lookup is still pending
+    /// are skipped. At most `WATCHLIST_MAX_CONCURRENT_LOOKUPS` lookups hit
+    /// the RPC endpoint at once, the others wait for a permit.
+    pub fn refresh_watchlist(&mut self) {
+        let due = self
+            .ui_state
+            .watchlist_fetched
+            .is_none_or(|fetched| fetched.elapsed() >= Duration::from_secs(WATCHLIST_REFRESH_SECS));
+        if !matches!(self.current_page, Web3Page::Watchlist) || !due {
+            return;
+        }
+        self.ui_state.watchlist_fetched = Some(Instant::now());
+        for (index, entry) in self.ui_state.watchlist.iter_mut().enumerate() {
+            if entry.pending {
+                continue;
+            }
+            entry.pending = true;
+            let tx = self.tx.clone();
+            let address = entry.address.clone();
+            let lookups = Arc::clone(&self.watchlist_lookups);
+            tokio::spawn(async move {
+                // the semaphore is never closed
+                let _permit = lookups.acquire_owned().await;
+                // balance, transaction count and account type only, no
+                // prices or explorer lookups per row
+                let portfolio = get_portfolio_data(&address, None, None)
+                    .await
+                    .map_err(|err| err.to_string());
+                let _ = tx.send(Web3AppEvents::WatchlistEntryLoaded(index, portfolio));
+            });
         }
     }
 
@@ -149,12 +453,23 @@ impl Web3State {
         self.current_page = Web3Page::from_index(self.tab_index);
     }
 
+    /// Switches to the tab at `index`, ignoring indices past the last tab
+    pub fn select_tab(&mut self, index: usize) {
+        if index < Web3Page::titles().len() {
+            self.tab_index = index;
+            self.current_page = Web3Page::from_index(index);
+        }
+    }
+
     /// Renders the current page to the terminal frame
     ///
     /// # Arguments
     ///
     /// * `frame` - The ratatui frame to render into
     pub fn render(&mut self, frame: &mut Frame<'_>) {
+        if render_terminal_too_small(frame) {
+            return;
+        }
         let chunks =
             Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).split(frame.area());
 
@@ -163,55 +478,163 @@ impl Web3State {
 
         match &self.current_page {
             Web3Page::AddressValidation => {
+                let [about_area, gas_price_area] =
+                    Layout::vertical([Constraint::Min(3), Constraint::Length(10)]).areas(chunks[1]);
                 let para = paragraph_widget(
                     "Hi! We are adding more interactive features to Stomata Web3",
                     "About",
+                    Density::Spacious,
                 );
-                frame.render_widget(para, chunks[1]);
+                frame.render_widget(para, about_area);
+                self.render_gas_prices(frame, gas_price_area);
             }
             Web3Page::Portfolio => {
                 // rendering from ui_state
                 let portfolio = self.ui_state.portfolio.as_ref();
                 if let Some(portfolio) = portfolio {
+                    // the transactions panel only exists with an explorer API key
+                    let (portfolio_area, transactions_area) = if self.ui_state.explorer.is_some() {
+                        let [portfolio_area, transactions_area] = Layout::vertical([
+                            Constraint::Min(10),
+                            // borders and header take three rows
+                            Constraint::Length(RECENT_TRANSACTIONS_LIMIT as u16 + 3),
+                        ])
+                        .areas(chunks[1]);
+                        (portfolio_area, Some(transactions_area))
+                    } else {
+                        (chunks[1], None)
+                    };
                     let input_widget = self
                         .ui_state
                         .input_area_state
                         .get_or_insert_with(|| InputWidgetState::new());
-                    portfolio.display(frame, chunks[1], Some(input_widget));
+                    portfolio.display(frame, portfolio_area, Some(input_widget));
+                    if let Some(transactions_area) = transactions_area {
+                        let _ = RecentTransactionsUI {
+                            transactions: self.ui_state.recent_transactions.as_ref(),
+                            palette: self.palette,
+                        }
+                        .display(frame, transactions_area, None);
+                    }
                 }
             }
+            Web3Page::Watchlist => {
+                let _ = WatchlistUI {
+                    entries: &self.ui_state.watchlist,
+                    palette: self.palette,
+                }
+                .display(frame, chunks[1], None);
+            }
+        }
+
+        if self.show_help {
+            render_help_popup(frame, frame.area(), &self.current_page.keybindings());
+        }
+        if self.quit_pending {
+            render_confirm_popup(frame, frame.area(), "Quit?");
         }
     }
 
+    /// Renders the gas price history as a sparkline
+    ///
+    /// The title shows the lowest, highest and current gas price of the
+    /// window in Gwei, and the line is colored by trend, to tell whether gas
+    /// is coming down before sending a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area to render the sparkline in
+    fn render_gas_prices(&self, frame: &mut Frame, area: Rect) {
+        let gas_prices = &self.ui_state.gas_prices;
+        let gwei = |wei: u64| wei_to_gwei(u128::from(wei)).round_dp(2);
+        let title = match (gas_prices.min(), gas_prices.max(), gas_prices.latest()) {
+            (Some(min), Some(max), Some(current)) => format!(
+                "Gas price (Gwei) min {} max {} cur {}",
+                gwei(min),
+                gwei(max),
+                gwei(*current)
+            ),
+            _ => "Gas price (Gwei) …".to_string(),
+        };
+        let sparkline = render_sparkline(
+            gas_prices,
+            &title,
+            false,
+            Some(self.palette),
+            Density::Spacious,
+        );
+        frame.render_widget(sparkline, area);
+    }
+
     /// Renders the tab bar at the top of the interface
     ///
+    /// The title shows the refresh interval, latest block and its base fee
+    /// in Gwei, and on the right how long ago data last arrived from the
+    /// chain, flagged as stale after `WEB3_STALE_AFTER_SECS`.
+    ///
     /// # Arguments
     ///
     /// * `frame` - The ratatui frame to render into
     /// * `area` - The rectangular area to render the tabs in
     pub fn render_tabs(&self, frame: &mut Frame, area: Rect) {
         let titles: Vec<Line> = Web3Page::titles().iter().map(|t| Line::from(*t)).collect();
+        let latest_block = match self.ui_state.latest_block {
+            Some(block_number) => format!("#{block_number}"),
+            None => "…".to_string(),
+        };
+        let base_fee = match self.ui_state.base_fee_per_gas {
+            Some(base_fee_per_gas) => {
+                format!("{} Gwei", wei_to_gwei(base_fee_per_gas).round_dp(2))
+            }
+            None => "…".to_string(),
+        };
+        let mut block = Block::default().borders(Borders::ALL).title(format!(
+            "Stomata | Refresh: {}ms | Latest block: {} | Base fee: {}",
+            self.refresh_interval, latest_block, base_fee
+        ));
+        if let Some(last_update) = self.last_update {
+            // a few missed blocks mean the RPC endpoint is slow or unreachable
+            let (updated, stale) = updated_ago(
+                last_update.elapsed(),
+                Duration::from_secs(WEB3_STALE_AFTER_SECS),
+            );
+            let style = if stale {
+                Style::default().fg(self.palette.warning())
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            block = block.title(Line::styled(updated, style).right_aligned());
+        }
         let tabs = Tabs::new(titles)
-            .block(Block::default().borders(Borders::ALL).title("Stomata"))
+            .block(block)
             .select(self.tab_index)
             .style(Style::default().fg(Color::White))
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(self.palette.tab_highlight());
 
         frame.render_widget(tabs, area);
     }
 
+    /// Applies every event the background tasks sent since the last frame
+    ///
+    /// The watchlist delivers one event per address, so all pending events
+    /// are applied rather than one per frame.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any of them changed what is shown
     pub fn drain_async_events(&mut self) -> bool {
+        let mut changed = false;
         while let Ok(event) = self.rx.try_recv() {
-            return self.apply_rx_event(event);
+            changed |= self.apply_rx_event(event);
         }
-        return false;
+        return changed;
     }
 
     fn apply_rx_event(&mut self, event: Web3AppEvents) -> bool {
+        if !matches!(event, Web3AppEvents::PortfolioError(_)) {
+            self.last_update = Some(Instant::now());
+        }
         match event {
             Web3AppEvents::PortfolioLoaded(portfolio_data) => {
                 self.ui_state.portfolio = Some(portfolio_data);
@@ -223,6 +646,37 @@ impl Web3State {
                 self.ui_state.loading = false;
                 false
             }
+            Web3AppEvents::TransactionsLoaded(transactions) => {
+                self.ui_state.recent_transactions = Some(transactions);
+                true
+            }
+            Web3AppEvents::LatestBlock(block_number) => {
+                self.ui_state.latest_block = Some(block_number);
+                self.fetch_gas_fees();
+                true
+            }
+            Web3AppEvents::BaseFee(base_fee_per_gas) => {
+                self.ui_state.base_fee_per_gas = Some(base_fee_per_gas);
+                true
+            }
+            Web3AppEvents::GasPrice(gas_price) => {
+                // clamped like the network histories so a spike doesn't
+                // flatten the rest of the line
+                self.ui_state
+                    .gas_prices
+                    .push_clamped(u64::try_from(gas_price).unwrap_or(u64::MAX));
+                true
+            }
+            Web3AppEvents::WatchlistEntryLoaded(index, portfolio) => {
+                match self.ui_state.watchlist.get_mut(index) {
+                    Some(entry) => {
+                        entry.portfolio = Some(portfolio);
+                        entry.pending = false;
+                        true
+                    }
+                    None => false,
+                }
+            }
         }
     }
 
@@ -237,6 +691,28 @@ impl Web3State {
     /// Returns an error if event processing fails.
     pub async fn handle_events(&mut self, key: KeyEvent) -> anyhow::Result<()> {
         if key.kind == KeyEventKind::Press {
+            // the quit prompt only answers to y/n and Esc
+            if self.quit_pending {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => self.quit(),
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.quit_pending = false
+                    }
+                    _ => {}
+                }
+                return Ok(());
+            }
+
+            // while the help overlay is open it swallows every key but ?, Esc and q
+            if self.show_help {
+                match key.code {
+                    KeyCode::Char('?') | KeyCode::Esc => self.show_help = false,
+                    KeyCode::Char('q') => self.request_quit(),
+                    _ => {}
+                }
+                return Ok(());
+            }
+
             let mut handled = false;
 
             match self.current_page {
@@ -245,9 +721,30 @@ impl Web3State {
                         Some(input_widget_state) => {
                             match input_widget_state.handle_input_events(key) {
                                 Some(InputAction::Submit(input_data)) => {
+                                    if let Some(explorer) = self.ui_state.explorer.clone() {
+                                        self.ui_state.recent_transactions = None;
+                                        let tx = self.tx.clone();
+                                        let address = input_data.clone();
+                                        tokio::spawn(async move {
+                                            let transactions = get_recent_transactions_data(
+                                                &address,
+                                                explorer,
+                                                RECENT_TRANSACTIONS_LIMIT,
+                                            )
+                                            .await
+                                            .map_err(|err| err.to_string());
+                                            let _ = tx.send(Web3AppEvents::TransactionsLoaded(
+                                                transactions,
+                                            ));
+                                        });
+                                    }
                                     let tx = self.tx.clone();
+                                    let prices = self.ui_state.prices.clone();
+                                    let explorer = self.ui_state.explorer.clone();
                                     tokio::spawn(async move {
-                                        match get_portfolio_data(&input_data).await {
+                                        match get_portfolio_data(&input_data, prices, explorer)
+                                            .await
+                                        {
                                             Ok(portfolio_data) => {
                                                 let _ = tx.send(Web3AppEvents::PortfolioLoaded(
                                                     portfolio_data,
@@ -283,10 +780,31 @@ impl Web3State {
         Ok(())
     }
 
+    /// Handles text pasted into the terminal
+    ///
+    /// Pasted text goes to the portfolio address input while it is being
+    /// edited and is ignored everywhere else.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The pasted text, as delivered by bracketed paste
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.quit_pending || self.show_help {
+            return;
+        }
+        if let (Web3Page::Portfolio, Some(input_widget_state)) =
+            (&self.current_page, &mut self.ui_state.input_area_state)
+        {
+            input_widget_state.handle_paste(text);
+        }
+    }
+
     /// Processes global keyboard shortcuts
     ///
-    /// Handles navigation keys (Tab, arrows), quit command (q),
-    /// and direct tab selection (number keys).
+    /// Handles navigation keys (Tab, arrows), quit command (q), going back
+    /// to the feature selection (Esc), help (?),
+    /// direct tab selection (number keys) and refresh interval
+    /// adjustment (+/-).
     ///
     /// # Arguments
     ///
@@ -294,7 +812,13 @@ impl Web3State {
     async fn process_global_events(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') => {
-                self.render = false;
+                self.request_quit();
+            }
+            KeyCode::Esc => {
+                self.go_back();
+            }
+            KeyCode::Char('?') => {
+                self.show_help = true;
             }
             KeyCode::Tab => {
                 self.next_tab();
@@ -320,6 +844,15 @@ impl Web3State {
                 self.ui_state.portfolio = Some(portfolio);
                 self.current_page = Web3Page::Portfolio;
             }
+            KeyCode::Char('3') => {
+                self.select_tab(2);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.refresh_interval = step_refresh_interval(self.refresh_interval, true);
+            }
+            KeyCode::Char('-') => {
+                self.refresh_interval = step_refresh_interval(self.refresh_interval, false);
+            }
             _ => {}
         }
     }
@@ -337,8 +870,8 @@ impl Web3State {
 ///
 /// # Returns
 ///
-/// * `Ok(true)` - Interactive mode exited normally
-/// * `Ok(false)` - CLI command executed successfully
+/// * `Ok(FeatureExit::Back)` - The user went back to the feature selection
+/// * `Ok(FeatureExit::Quit)` - The user quit, or the CLI command executed
 ///
 /// # Errors
 ///
@@ -349,10 +882,14 @@ impl Web3State {
 ///
 /// # Interactive Mode Keybindings
 ///
-/// - `q` - Quit the application
+/// - `q` - Quit the application (asks first with `--confirm-quit`)
+/// - `Esc` - Return to the feature selection menu
+/// - `?` - Show the keybinding help overlay
 /// - `Tab` or `Right Arrow` - Next tab
 /// - `Left Arrow` - Previous tab
 /// - `1` - Jump to Address Validation tab
+/// - `3` - Jump to Watchlist tab
+/// - `+` / `-` - Increase / decrease the refresh interval
 ///
 /// # Examples
 ///
@@ -368,19 +905,26 @@ impl Web3State {
 pub async fn run(
     cli: &Cli,
     terminal: Option<&mut Terminal<CrosstermBackend<Stdout>>>,
-) -> anyhow::Result<bool> {
-    let mut web3_state = Web3State::new();
+) -> anyhow::Result<FeatureExit> {
+    let config = StomataConfig::load();
+    let palette = cli.palette.unwrap_or(config.palette);
+    let mut web3_state = Web3State::new(cli.interval, cli.confirm_quit, palette, config.watchlist);
 
     match terminal {
         Some(terminal) => {
-            // get the refresh interval from the cli arg. Default 1000 ms
-            let refresh_interval = Duration::from_millis(cli.interval);
             let mut last_tick = Instant::now();
             let mut should_redraw = false;
+            web3_state.select_tab(UiPreferences::load().web3_tab);
+            let block_watcher = web3_state.watch_latest_block();
+            // deliver pastes as one event instead of a key press per character
+            execute!(terminal.backend_mut(), EnableBracketedPaste)?;
 
             /// interactive mode
             while web3_state.render {
                 should_redraw = web3_state.drain_async_events();
+                web3_state.refresh_watchlist();
+                // the interval can be changed at runtime with +/-
+                let refresh_interval = Duration::from_millis(web3_state.refresh_interval);
 
                 let timeout = refresh_interval
                     .checked_sub(last_tick.elapsed())
@@ -388,14 +932,23 @@ pub async fn run(
 
                 // poll for inputs only until timeout
                 if event::poll(timeout)? {
-                    if let Event::Key(key) = event::read()? {
-                        // handle events
-                        web3_state.handle_events(key).await?;
-                        // redraw immediately after an event
-                        should_redraw = true;
-                        // terminal.draw(|frame| {
-                        //     web3_state.render(frame);
-                        // })?;
+                    match event::read()? {
+                        Event::Key(key) => {
+                            // handle events
+                            web3_state.handle_events(key).await?;
+                            // redraw immediately after an event
+                            should_redraw = true;
+                            // terminal.draw(|frame| {
+                            //     web3_state.render(frame);
+                            // })?;
+                        }
+                        Event::Paste(text) => {
+                            web3_state.handle_paste(&text);
+                            should_redraw = true;
+                        }
+                        // reflow the layout to the new size right away
+                        Event::Resize(_, _) => should_redraw = true,
+                        _ => {}
                     }
                 }
 
@@ -408,29 +961,94 @@ pub async fn run(
                     last_tick = Instant::now();
                 }
             }
-            Ok(web3_state.render)
+            execute!(terminal.backend_mut(), DisableBracketedPaste)?;
+            block_watcher.abort();
+            UiPreferences::update(|preferences| {
+                preferences.web3_tab = web3_state.tab_index;
+                preferences.refresh_interval = Some(web3_state.refresh_interval);
+            });
+            Ok(web3_state.exit)
         }
         None => {
             let web3_cli =
                 Web3Cli::try_parse_from(once("web3".to_string()).chain(cli.args.iter().cloned()));
             match web3_cli {
                 Ok(cli) => {
+                    let json = cli.json;
                     match cli.tool {
-                        Web3Tool::AddressValidator { address } => validate_address(&address),
+                        Web3Tool::AddressValidator { address } => validate_address(&address, json),
                         Web3Tool::Key(key_cmd) => match key_cmd {
-                            KeySubCommands::Encrypt { name } => encrypt_key(name),
-                            KeySubCommands::Decrypt { name, format } => decrypt_key(name, format),
-                            KeySubCommands::List {} => list_all_keys(),
-                            KeySubCommands::Delete { name } => delete_encrypted_key(name),
+                            KeySubCommands::Encrypt { name, force } => {
+                                encrypt_key(name, force, json)
+                            }
+                            KeySubCommands::Decrypt { name, format, out } => {
+                                decrypt_key(name, format, out.as_deref(), json)
+                            }
+                            KeySubCommands::Import {
+                                name,
+                                from_file,
+                                force,
+                            } => import_key(name, from_file.as_deref(), force, json),
+                            KeySubCommands::List {} => list_all_keys(json),
+                            KeySubCommands::Delete { name } => delete_encrypted_key(name, json),
                         },
                     };
                 }
+                // help and version requests aren't errors, print them as usual
+                Err(e) if e.use_stderr() && cli.args.iter().any(|arg| arg == "--json") => {
+                    let message = e.to_string();
+                    let first_line = message.lines().next().unwrap_or_default();
+                    print_json_error(first_line.trim_start_matches("error: "));
+                    exit(1);
+                }
                 Err(e) => {
                     eprintln!("{}", e);
                     exit(1);
                 }
             };
-            Ok(false)
+            Ok(FeatureExit::Quit)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn web3_state(watchlist: &[&str]) -> Web3State {
+        Web3State::new(
+            1000,
+            false,
+            Palette::default(),
+            watchlist
+                .iter()
+                .map(|address| address.to_string())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_apply_watchlist_entry_loaded() {
+        let mut state = web3_state(&["0xabc"]);
+        state.ui_state.watchlist[0].pending = true;
+        let changed = state.apply_rx_event(Web3AppEvents::WatchlistEntryLoaded(
+            0,
+            Err("timed out".to_string()),
+        ));
+        assert!(changed);
+        let entry = &state.ui_state.watchlist[0];
+        assert!(!entry.pending);
+        assert!(matches!(&entry.portfolio, Some(Err(err)) if err == "timed out"));
+    }
+
+    #[test]
+    fn test_apply_watchlist_entry_out_of_range() {
+        let mut state = web3_state(&["0xabc"]);
+        let changed = state.apply_rx_event(Web3AppEvents::WatchlistEntryLoaded(
+            5,
+            Err("timed out".to_string()),
+        ));
+        assert!(!changed);
+        assert!(state.ui_state.watchlist[0].portfolio.is_none());
+    }
+}
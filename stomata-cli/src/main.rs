@@ -1,31 +1,65 @@
 use std::time::{Duration, Instant};
 
 use crate::{
-    features::run_feature,
+    constants::COMPLETIONS_COMMAND,
+    features::{core::session_summary::SessionSummary, run_feature},
     renders::core_displays::display_app::App,
-    structs::{AppState, Cli, StomataState},
+    structs::{AppState, Cli, FeatureExit, StomataState},
 };
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, parser::ValueSource};
 use ratatui::crossterm::event::{self, Event};
 
+mod alerts;
+mod collector_warnings;
+mod completions;
+mod config;
 mod constants;
+mod density;
 mod features;
+mod palette;
+mod preferences;
 mod renders;
 mod stomata_state;
 mod structs;
+mod units;
 mod utils;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if cli.feature.as_deref() == Some(COMPLETIONS_COMMAND) {
+        return completions::print_completions(&cli.args);
+    }
+
     let enable_ui = cli.interactive;
     let mut app = StomataState::new();
+    let mut session = SessionSummary::default();
 
+    // the TUI resumes at the last refresh interval unless --interval is given
+    if enable_ui && matches.value_source("interval") != Some(ValueSource::CommandLine) {
+        if let Some(interval) = app.preferences.refresh_interval {
+            cli.interval = interval;
+        }
+    }
     if app.available_features.is_empty() {
         eprintln!("Error: No features enabled. Build with at least one feature:");
         return Ok(());
     }
 
+    #[cfg(feature = "http")]
+    if let Some(addr) = &cli.serve {
+        use crate::features::core::http_server;
+
+        // bind before the TUI starts so errors are visible
+        let listener = http_server::bind(addr).await?;
+        if !enable_ui && cli.feature.is_none() {
+            // headless mode, serve until interrupted
+            return http_server::serve(listener, cli.interval).await;
+        }
+        tokio::spawn(http_server::serve(listener, cli.interval));
+    }
+
     if enable_ui {
         let mut terminal = ratatui::init();
         loop {
@@ -40,12 +74,9 @@ async fn main() -> anyhow::Result<()> {
                 }
                 AppState::RunningFeature(feature) => {
                     // Run the selected feature
-                    match run_feature(feature, &cli, Some(&mut terminal)).await {
-                        Ok(render) => {
-                            if !render {
-                                app.state = AppState::FeatureSelection;
-                            }
-                        }
+                    match run_feature(feature, &cli, Some(&mut terminal), &mut session).await {
+                        Ok(FeatureExit::Back) => app.state = AppState::FeatureSelection,
+                        Ok(FeatureExit::Quit) => break,
                         Err(_) => {
                             eprint!("Error in rendering feature");
                             app.state = AppState::FeatureSelection;
@@ -55,16 +86,27 @@ async fn main() -> anyhow::Result<()> {
             }
         }
         ratatui::restore();
+        // printed after restoring, so it stays in the shell's scrollback
+        if let Some(report) = session.report(Instant::now()) {
+            println!("{report}");
+        }
     } else {
         let cli_clone = cli.clone();
         let cli_feature = cli_clone.feature;
         match cli_feature {
-            Some(feature) => {
-                if let Some(feature) = app.available_features.get(&feature) {
-                    run_feature(*feature, &cli, None);
-                };
-            }
-            None => println!("No feature selected"),
+            Some(feature_name) => match app.resolve_feature(&feature_name) {
+                Ok(feature) => {
+                    run_feature(feature, &cli, None, &mut session).await?;
+                }
+                Err(message) => {
+                    eprintln!("{message}");
+                    std::process::exit(2);
+                }
+            },
+            None => println!(
+                "No feature selected. Available features: {}, or run `stomata -i` for the interactive mode",
+                app.feature_names().join(", ")
+            ),
         }
     }
     Ok(())
@@ -0,0 +1,120 @@
+//! Color palettes of the TUI
+//!
+//! The default palette marks usage with green and red. The `colorblind`
+//! palette swaps them for the blue/orange pair of the Okabe-Ito scheme,
+//! which stays distinguishable with the common red-green deficiencies, and
+//! puts a `▲` in front of critical values so they don't rely on color alone.
+//!
+//! Chosen with `--palette colorblind` or in `~/.stomata/config.json`:
+//!
+//! ```json
+//! "palette": "colorblind"
+//! ```
+
+use clap::ValueEnum;
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+/// Colors used to mark states such as critical usage or the selection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Palette {
+    /// Green for healthy or falling values, red for critical or rising ones
+    #[default]
+    Default,
+
+    /// Blue for healthy or falling values, orange for critical or rising ones
+    Colorblind,
+}
+
+const ORANGE: Color = Color::Rgb(230, 159, 0);
+const BLUE: Color = Color::Rgb(0, 114, 178);
+const SKY_BLUE: Color = Color::Rgb(86, 180, 233);
+const YELLOW: Color = Color::Rgb(240, 228, 66);
+
+impl Palette {
+    /// Color of critical values, e.g. a gauge above 90% or a zombie process
+    pub fn critical(self) -> Color {
+        match self {
+            Palette::Default => Color::Red,
+            Palette::Colorblind => ORANGE,
+        }
+    }
+
+    /// Color of values getting close to critical
+    pub fn warning(self) -> Color {
+        match self {
+            Palette::Default => Color::Yellow,
+            Palette::Colorblind => YELLOW,
+        }
+    }
+
+    /// Color of healthy or improving values
+    pub fn good(self) -> Color {
+        match self {
+            Palette::Default => Color::Green,
+            Palette::Colorblind => BLUE,
+        }
+    }
+
+    /// Fill color of gauges below critical
+    pub fn gauge(self) -> Color {
+        match self {
+            Palette::Default => Color::LightBlue,
+            Palette::Colorblind => SKY_BLUE,
+        }
+    }
+
+    /// Marker put in front of critical values, empty for the default palette
+    pub fn critical_marker(self) -> &'static str {
+        match self {
+            Palette::Default => "",
+            Palette::Colorblind => "▲ ",
+        }
+    }
+
+    /// Style of the selected tab, underlined as well with `colorblind`
+    pub fn tab_highlight(self) -> Style {
+        let style = Style::default()
+            .fg(self.good())
+            .add_modifier(Modifier::BOLD);
+        match self {
+            Palette::Default => style,
+            Palette::Colorblind => style.add_modifier(Modifier::UNDERLINED),
+        }
+    }
+
+    /// Style of the selected table row, bold as well with `colorblind`
+    pub fn row_highlight(self) -> Style {
+        let style = Style::default().bg(Color::White).fg(Color::Black);
+        match self {
+            Palette::Default => style,
+            Palette::Colorblind => style.add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colorblind_avoids_red_and_green() {
+        let palette = Palette::Colorblind;
+        for color in [palette.critical(), palette.good(), palette.gauge()] {
+            assert!(!matches!(
+                color,
+                Color::Red | Color::LightRed | Color::Green | Color::LightGreen
+            ));
+        }
+        assert_ne!(palette.critical(), palette.good());
+        assert_eq!(palette.critical_marker(), "▲ ");
+        assert_eq!(Palette::Default.critical_marker(), "");
+    }
+
+    #[test]
+    fn test_palette_from_config() {
+        let palette: Palette = serde_json::from_str(r#""colorblind""#).unwrap();
+        assert_eq!(palette, Palette::Colorblind);
+    }
+}
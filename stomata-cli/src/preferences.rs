@@ -0,0 +1,126 @@
+//! UI preferences remembered between sessions
+//!
+//! Unlike the user configuration in `config.json`, preferences record the
+//! state the UI was left in, the last active tab of each feature, the
+//! refresh interval and the layout density, so the next launch picks up where the last one ended.
+//! They are written to `~/.stomata/preferences.json` when a feature is
+//! quit. A missing or corrupt file silently yields the defaults.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{
+        CONFIG_DIR_NAME, MAX_REFRESH_INTERVAL_MS, MIN_REFRESH_INTERVAL_MS, PREFERENCES_FILE_NAME,
+    },
+    density::Density,
+};
+
+/// UI state persisted between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UiPreferences {
+    /// Tab index of the core feature when it was last quit
+    #[serde(default)]
+    pub core_tab: usize,
+
+    /// Tab index of the web3 feature when it was last quit
+    #[serde(default)]
+    pub web3_tab: usize,
+
+    /// Refresh interval in milliseconds when a feature was last quit,
+    /// `None` to use the `--interval` default
+    #[serde(default)]
+    pub refresh_interval: Option<u64>,
+
+    /// Layout density when a feature was last quit
+    #[serde(default)]
+    pub density: Density,
+}
+
+impl UiPreferences {
+    /// Returns the path of the preferences file, `None` if there is no
+    /// home directory.
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(CONFIG_DIR_NAME).join(PREFERENCES_FILE_NAME))
+    }
+
+    /// Loads the preferences file, falling back to the defaults when it
+    /// doesn't exist or can't be parsed.
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Parses the preferences, dropping a refresh interval outside the
+    /// range the UI accepts. Invalid JSON yields the defaults.
+    fn parse(contents: &str) -> Self {
+        let mut preferences: Self = serde_json::from_str(contents).unwrap_or_default();
+        preferences.refresh_interval = preferences.refresh_interval.filter(|interval| {
+            (MIN_REFRESH_INTERVAL_MS..=MAX_REFRESH_INTERVAL_MS).contains(interval)
+        });
+        preferences
+    }
+
+    /// Writes the preferences file, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory can't be found or the file
+    /// can't be written.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path().context("Could not find home directory")?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Loads the stored preferences, applies `update` and writes them back.
+    ///
+    /// Loading first keeps the preferences of the other feature intact.
+    /// Saving is best effort, a read-only home directory must not keep the
+    /// UI from quitting.
+    pub fn update(update: impl FnOnce(&mut Self)) {
+        let mut preferences = Self::load();
+        update(&mut preferences);
+        let _ = preferences.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupt_preferences_use_defaults() {
+        assert_eq!(UiPreferences::parse("{not json"), UiPreferences::default());
+        assert_eq!(UiPreferences::parse("{}"), UiPreferences::default());
+    }
+
+    #[test]
+    fn test_parse_preferences() {
+        let preferences =
+            UiPreferences::parse(r#"{"core_tab": 2, "web3_tab": 1, "refresh_interval": 500}"#);
+        assert_eq!(
+            preferences,
+            UiPreferences {
+                core_tab: 2,
+                web3_tab: 1,
+                refresh_interval: Some(500),
+                density: Density::Spacious,
+            }
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_interval_is_dropped() {
+        let preferences = UiPreferences::parse(r#"{"core_tab": 3, "refresh_interval": 0}"#);
+        assert_eq!(preferences.core_tab, 3);
+        assert_eq!(preferences.refresh_interval, None);
+    }
+}
@@ -4,20 +4,41 @@
 //! application state, handles user input, and coordinates rendering of
 //! different pages in the TUI.
 
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use chrono::Local;
 use ratatui::{
     Frame,
     crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::Line,
-    widgets::{Block, Borders, Tabs},
+    widgets::{Paragraph, Tabs},
 };
-use stomata_core::collectors::structs::{Metrics, MetricsToFetch, StomataSystemMetrics};
+use stomata_core::collectors::{
+    process::metrics::ProcessData,
+    status::take_warnings,
+    structs::{Metrics, MetricsToFetch, StomataSystemMetrics},
+    system::metrics::SystemMetrics,
+};
+use sysinfo::Pid;
 
 use crate::{
-    renders::core_displays::traits::{Display, SingleProcessDisplay},
-    structs::{Page, SingleProcessUI, UIState},
-    utils::bytes_to_mb,
+    alerts::{AlertMonitor, send_desktop_notification},
+    collector_warnings::CollectorWarnings,
+    config::StomataConfig,
+    features::core::{
+        disk_export::export_disk_history, net_log::NetLog, session_summary::SessionSummary,
+    },
+    renders::render_widgets::render_popup::{
+        render_column_menu_popup, render_confirm_popup, render_help_popup,
+        render_terminal_too_small,
+    },
+    structs::{FeatureExit, Page, PageContext, ProcessColumn, ProcessMatcher, UIState},
+    utils::{collected_in, percentage, step_refresh_interval},
 };
 
 /// Main application state manager
@@ -32,7 +53,8 @@ pub struct App {
     /// Whether the application should continue rendering
     pub render: bool,
 
-    /// System metrics collector and storage
+    /// System metrics collector and storage, kept for the app's lifetime so
+    /// CPU usage is measured between consecutive ticks
     pub metrics: StomataSystemMetrics,
 
     /// Index of the currently selected tab (0-based)
@@ -46,6 +68,41 @@ pub struct App {
 
     /// UI state for stateful widgets (tables, lists, charts)
     pub ui_state: UIState,
+
+    /// Refresh interval of the render loop in milliseconds
+    pub refresh_interval: u64,
+
+    /// Whether the keybinding help overlay is shown
+    pub show_help: bool,
+
+    /// Whether quitting asks for confirmation first
+    pub confirm_quit: bool,
+
+    /// Whether the quit confirmation prompt is shown
+    pub quit_pending: bool,
+
+    /// Where to go once rendering stops, back to the feature selection
+    /// or out of the application
+    pub exit: FeatureExit,
+
+    /// Persisted user configuration (pinned processes, alert thresholds)
+    pub config: StomataConfig,
+
+    /// Alert threshold state, evaluated every frame
+    pub alerts: AlertMonitor,
+
+    /// Recent warnings from the collectors, shown in the footer
+    pub collector_warnings: CollectorWarnings,
+
+    /// CSV file the network samples are appended to, set by `--net-log`
+    pub net_log: Option<NetLog>,
+
+    /// Peak usage printed after quitting, set by `--summary`
+    pub session_summary: Option<SessionSummary>,
+
+    /// Start of the latest refresh, the render loop times the next one
+    /// from it and the tabs show how long collecting the metrics took
+    pub last_tick: Instant,
 }
 
 impl App {
@@ -53,6 +110,7 @@ impl App {
     ///
     /// Initializes the app with default values and prepares the metrics
     /// collection system. The app starts on the System page with rendering enabled.
+    /// Pinned processes are loaded from the config file.
     ///
     ///
     /// # Examples
@@ -60,23 +118,101 @@ impl App {
     /// ```rust
     /// use stomata::renders::core_displays::display_app::App;
     ///
-    /// // Create app without metrics storage (lower memory usage)
-    /// let app = App::new(false);
+    /// // Create app without metrics storage (lower memory usage), refreshing every second
+    /// let app = App::new(false, 1000, None, None, false);
     ///
-    /// // Create app with metrics storage (enables historical charts)
-    /// let app_with_history = App::new(true);
+    /// // Create app with metrics storage (enables historical charts) and 200 point sparklines
+    /// let app_with_history = App::new(true, 1000, Some(200), None, false);
+    ///
+    /// // Only monitor nginx processes, asking before quitting
+    /// let nginx_app = App::new(false, 1000, None, Some(ProcessMatcher::new("nginx")), true);
     /// ```
-    pub fn new(store_metrics: bool) -> Self {
+    pub fn new(
+        store_metrics: bool,
+        refresh_interval: u64,
+        history_len: Option<usize>,
+        matcher: Option<ProcessMatcher>,
+        confirm_quit: bool,
+    ) -> Self {
+        let config = StomataConfig::load();
+        let mut ui_state = UIState::new(history_len);
+        ui_state.process_table.matcher = matcher;
+        ui_state.process_table.columns = config.process_columns.clone();
+        ui_state.hidden_interfaces = config.hidden_interfaces.iter().cloned().collect();
+        ui_state.units = config.units;
+        ui_state.palette = config.palette;
+        let mut metrics = StomataSystemMetrics::new();
+        ui_state.units.cpu_count = metrics.system.cpus().len();
+        metrics.custom = config
+            .custom_metrics
+            .iter()
+            .map(|custom_metric| custom_metric.collector())
+            .collect();
         Self {
             render: true,
-            metrics: StomataSystemMetrics::new(),
+            metrics,
             tab_index: 0,
             current_page: Page::System,
             store_data: store_metrics, // by default don't store history data
-            ui_state: UIState::default(),
+            ui_state,
+            refresh_interval,
+            show_help: false,
+            confirm_quit,
+            quit_pending: false,
+            exit: FeatureExit::Back,
+            config,
+            alerts: AlertMonitor::default(),
+            collector_warnings: CollectorWarnings::default(),
+            net_log: None,
+            session_summary: None,
+            last_tick: Instant::now(),
         }
     }
 
+    /// Quits the application, or asks for confirmation first when
+    /// `confirm_quit` is set
+    pub fn request_quit(&mut self) {
+        if self.confirm_quit {
+            self.quit_pending = true;
+        } else {
+            self.quit();
+        }
+    }
+
+    /// Stops rendering and quits the application
+    fn quit(&mut self) {
+        self.exit = FeatureExit::Quit;
+        self.render = false;
+    }
+
+    /// Stops rendering and returns to the feature selection menu
+    ///
+    /// Nothing is lost by going back, so this never asks for confirmation.
+    pub fn go_back(&mut self) {
+        self.exit = FeatureExit::Back;
+        self.render = false;
+    }
+
+    /// Writes the disk history of the open process to a CSV file in the
+    /// working directory, the footer shows the path or why it failed
+    fn export_disk_history(&mut self) {
+        let dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        let message =
+            match export_disk_history(&self.ui_state.single_process_disk_usage, &dir, Local::now())
+            {
+                Ok(path) => format!("Disk history written to {}", path.display()),
+                Err(err) => format!("Disk history export failed: {err:#}"),
+            };
+        self.collector_warnings.update([message], Instant::now());
+    }
+
+    /// Leaves the detail page of an exited process for the list it was
+    /// opened from, the Processes or Process Tree tab
+    fn close_exited_process(&mut self) {
+        self.ui_state.exited_process = None;
+        self.current_page = Page::from_index(self.tab_index);
+    }
+
     /// Advances to the next tab, wrapping to the first tab after the last
     ///
     /// Updates both `tab_index` and `current_page` to maintain consistency.
@@ -85,6 +221,16 @@ impl App {
         self.current_page = Page::from_index(self.tab_index);
     }
 
+    /// Switches to the tab at `index`, ignoring indices past the last tab
+    ///
+    /// Updates both `tab_index` and `current_page` to maintain consistency.
+    pub fn select_tab(&mut self, index: usize) {
+        if index < Page::titles().len() {
+            self.tab_index = index;
+            self.current_page = Page::from_index(index);
+        }
+    }
+
     /// Moves to the previous tab, wrapping to the last tab before the first
     ///
     /// Updates both `tab_index` and `current_page` to maintain consistency.
@@ -100,8 +246,8 @@ impl App {
     /// Renders the current page to the terminal frame
     ///
     /// Divides the screen into a tab bar and content area, then renders
-    /// the appropriate content based on the current page. Fetches fresh
-    /// metrics data for the current page before rendering.
+    /// the current page into the content area through its `PageView`, see
+    /// `Page::view`. Each page fetches fresh metrics data before rendering.
     ///
     /// # Arguments
     ///
@@ -110,69 +256,262 @@ impl App {
     /// # Page-specific behavior
     ///
     /// - **System**: Displays static system information (OS, hostname, etc.)
-    /// - **Metrics**: Shows real-time resource usage (CPU, memory, disk)
-    /// - **Processes**: Lists all running processes with sortable columns
+    /// - **Metrics**: Shows real-time resource usage (CPU, memory, disk) with
+    ///   CPU/memory history sparklines, and the top CPU and memory consuming
+    ///   processes
+    /// - **Processes**: Lists all running processes with sortable columns,
+    ///   below a section with the pinned processes when any are pinned
     /// - **SingleProcess**: Detailed view of a specific process
     /// - **Network**: Network interface statistics and traffic
+    /// - **ProcessTree**: Collapsible parent/child hierarchy of processes
+    ///
+    /// With a `--match` pattern the Metrics and Processes pages only consider
+    /// the matching processes, and the Metrics page adds gauges with the
+    /// summed CPU and memory of the group.
+    ///
+    /// Alerts from the config file are evaluated once per refresh interval,
+    /// not on frames drawn for a key press, firing alerts are shown in a banner below the tabs. Warnings reported by
+    /// the collectors while fetching, such as an unreadable cgroup, are
+    /// shown in a footer so a missing metric isn't mistaken for a zero.
+    ///
+    /// When `show_help` is set, the keybindings of the current page are
+    /// drawn in a popup on top of the page, likewise the column menu while
+    /// it is open. A pending quit draws the
+    /// confirmation prompt on top of everything.
     pub fn render(&mut self, frame: &mut Frame) {
-        let chunks =
-            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).split(frame.area());
+        self.last_tick = Instant::now();
+        // system usage feeds the Metrics page, the alerts and the session
+        // summary, fetch it once per frame
+        let system_collector = if self.current_page == Page::Metrics
+            || self.config.alerts.is_enabled()
+            || self.session_summary.is_some()
+        {
+            match self.metrics.fetch(MetricsToFetch::SystemResource) {
+                Metrics::SystemResource(system_collector) => Some(system_collector),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        if let Some(system_collector) = &system_collector {
+            // key presses redraw too, alerts only advance once per tick
+            let interval = Duration::from_millis(self.refresh_interval);
+            if self.alerts.is_due(self.last_tick, interval) {
+                self.evaluate_alerts(&system_collector.system_metrics);
+            }
+            self.observe_session(&system_collector.system_metrics);
+        }
+        // same for the network interfaces, shown on the Network page and logged
+        let network_metrics = if self.current_page == Page::Network || self.net_log.is_some() {
+            let network_metrics = self.metrics.fetch(MetricsToFetch::Networks);
+            self.ui_state
+                .record_network_fetch(Instant::now(), self.refresh_interval);
+            match network_metrics {
+                Metrics::Networks(network_metrics) => Some(network_metrics),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        let mut net_log_error = None;
+        if let (Some(net_log), Some(network_metrics)) = (&mut self.net_log, &network_metrics) {
+            net_log_error = net_log.append(network_metrics).err();
+        }
+        // alerts and the network log keep running, only the page is skipped
+        if render_terminal_too_small(frame) {
+            return;
+        }
 
-        // render tabs
+        // one banner line per firing alert between the tabs and the page
+        let banner_height = self.alerts.active.len() as u16;
+        // warnings are drained after the page fetched its metrics, so the
+        // footer shows the ones of the previous frame
+        let footer_height = self.collector_warnings.visible().len() as u16;
+        let chunks = Layout::vertical([
+            Constraint::Length(self.ui_state.density.rows(1)),
+            Constraint::Length(banner_height),
+            Constraint::Min(0),
+            Constraint::Length(footer_height),
+        ])
+        .split(frame.area());
+        let content_area = chunks[2];
+        if footer_height > 0 {
+            self.render_warnings_footer(frame, chunks[3]);
+        }
+
+        if banner_height > 0 {
+            self.render_alert_banner(frame, chunks[1]);
+        }
+
+        let mut ctx = PageContext {
+            metrics: &mut self.metrics,
+            ui_state: &mut self.ui_state,
+            config: &self.config,
+            refresh_interval: self.refresh_interval,
+            system_collector: system_collector.as_ref(),
+            network_metrics: network_metrics.as_ref(),
+        };
+        let _ = self
+            .current_page
+            .view()
+            .render(frame, content_area, &mut ctx);
+        // after the page, so the duration shown includes collecting its metrics
         self.render_tabs(frame, chunks[0]);
+        if self
+            .ui_state
+            .exited_process
+            .is_some_and(|exited| exited.return_due(Instant::now()))
+        {
+            self.close_exited_process();
+        }
 
-        match &self.current_page {
-            Page::Metrics => {
-                if let Metrics::SystemResource(system_collector) =
-                    self.metrics.fetch(MetricsToFetch::SystemResource)
-                {
-                    let _ = system_collector.display(frame, chunks[1], None);
-                };
-            }
-            Page::System => {
-                if let Metrics::SystemInfo(system_info) =
-                    self.metrics.fetch(MetricsToFetch::SystemInfo)
-                {
-                    let _ = system_info.display(frame, chunks[1], None);
-                };
-            }
-            Page::Processes => {
-                if let Metrics::Processes(processes) = self.metrics.fetch(MetricsToFetch::Process) {
-                    self.ui_state.process_table.process_count = processes.len();
-                    let _ = processes.display(frame, chunks[1], Some(&mut self.ui_state));
-                }
-            }
-            Page::SingleProcess(pid) => {
-                let total_memory = bytes_to_mb(self.metrics.system.total_memory());
-                if let Metrics::SingleProcessPid(Some(process)) =
-                    self.metrics.fetch(MetricsToFetch::SingleProcessPid(*pid))
-                {
-                    self.ui_state
-                        .single_process_disk_usage
-                        .update_disk_history(process.basic_process_data.pid, &process.disk_usage);
-
-                    let _ = SingleProcessUI { data: process }.display_process_metrics(
-                        frame,
-                        chunks[1],
-                        total_memory,
-                        &mut self.ui_state,
-                    );
-                }
-            }
-            Page::Network => {
-                if let Metrics::Networks(network_metrics) =
-                    self.metrics.fetch(MetricsToFetch::Networks)
-                {
-                    let _ = network_metrics.display(frame, chunks[1], Some(&mut self.ui_state));
-                }
+        self.collector_warnings.update(
+            take_warnings()
+                .iter()
+                .map(ToString::to_string)
+                .chain(net_log_error),
+            Instant::now(),
+        );
+
+        if let Some(selected) = self.ui_state.process_table.column_menu {
+            let columns: Vec<(&str, bool)> = ProcessColumn::ALL
+                .iter()
+                .map(|column| {
+                    (
+                        column.header(),
+                        self.config.process_columns.contains(column),
+                    )
+                })
+                .collect();
+            render_column_menu_popup(
+                frame,
+                frame.area(),
+                "Columns (o to close)",
+                &columns,
+                selected,
+            );
+        }
+        if let Some(selected) = self.ui_state.interface_menu {
+            let interfaces: Vec<(&str, bool)> = self
+                .ui_state
+                .interface_names()
+                .into_iter()
+                .map(|name| (name, !self.ui_state.hidden_interfaces.contains(name)))
+                .collect();
+            render_column_menu_popup(
+                frame,
+                frame.area(),
+                "Interfaces (i to close)",
+                &interfaces,
+                selected,
+            );
+        }
+        if self.show_help {
+            render_help_popup(frame, frame.area(), &self.current_page.keybindings());
+        }
+        if self.quit_pending {
+            render_confirm_popup(frame, frame.area(), "Quit?");
+        }
+    }
+
+    /// Adds the latest system usage and process list to the session summary
+    ///
+    /// Does nothing without `--summary`. The process list is refreshed at
+    /// most once per CPU sample, so pages fetching it again get the same one.
+    ///
+    /// # Arguments
+    ///
+    /// * `system_metrics` - System usage fetched for the current frame
+    fn observe_session(&mut self, system_metrics: &SystemMetrics) {
+        let Some(summary) = &mut self.session_summary else {
+            return;
+        };
+        summary.observe_system(system_metrics, self.last_tick);
+        if let Metrics::Processes(processes) = self.metrics.fetch(MetricsToFetch::Process) {
+            summary.observe_processes(&processes);
+        }
+        summary.units = self.ui_state.units;
+    }
+
+    /// Evaluates the alert thresholds against the latest system usage
+    ///
+    /// Sends a desktop notification for every alert that starts firing
+    /// when enabled in the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `system_metrics` - System usage fetched for the current frame
+    fn evaluate_alerts(&mut self, system_metrics: &SystemMetrics) {
+        let thresholds = &self.config.alerts;
+        let started = self.alerts.evaluate(
+            thresholds,
+            system_metrics.cpu_usage,
+            percentage(
+                system_metrics.memory_used as f64,
+                system_metrics.memory_total as f64,
+            ),
+            self.last_tick,
+        );
+        if thresholds.desktop_notifications {
+            for message in &started {
+                send_desktop_notification(message);
             }
         }
     }
 
+    /// Renders the firing alerts as a flashing banner, one line per alert
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area to render the banner in
+    fn render_alert_banner(&self, frame: &mut Frame, area: Rect) {
+        let style = if self.alerts.flash_on() {
+            Style::default()
+                .fg(Color::White)
+                .bg(self.ui_state.palette.critical())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(self.ui_state.palette.critical())
+                .add_modifier(Modifier::BOLD)
+        };
+        let lines: Vec<Line> = self
+            .alerts
+            .active
+            .iter()
+            .map(|message| Line::from(format!(" ⚠ {message}")))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).style(style), area);
+    }
+
+    /// Renders the recent collector warnings, one line per warning
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area to render the footer in
+    fn render_warnings_footer(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = self
+            .collector_warnings
+            .visible()
+            .iter()
+            .map(|(message, _)| Line::from(format!(" ! {message}")))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).style(Style::default().fg(Color::Yellow)),
+            area,
+        );
+    }
+
     /// Renders the tab bar at the top of the screen
     ///
     /// Displays all available pages as tabs with the current tab highlighted
-    /// in green and bold.
+    /// in bold, see `Palette::tab_highlight`. The current refresh interval is
+    /// shown in the title, and how long collecting the metrics of this frame
+    /// took on the right, flagged as stale when it took longer than the
+    /// interval. Called after the page rendered, so `last_tick` marks the
+    /// start of the collection.
     ///
     /// # Arguments
     ///
@@ -180,15 +519,27 @@ impl App {
     /// * `area` - The rectangular area to render the tabs in
     pub fn render_tabs(&self, frame: &mut Frame, area: Rect) {
         let titles: Vec<Line> = Page::titles().iter().map(|t| Line::from(*t)).collect();
+        // collecting takes longer than the interval under heavy load, the
+        // shown metrics then lag behind the refresh rate
+        let (collected, stale) = collected_in(
+            self.last_tick.elapsed(),
+            Duration::from_millis(self.refresh_interval),
+        );
+        let collected_style = if stale {
+            Style::default().fg(self.ui_state.palette.warning())
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
         let tabs = Tabs::new(titles)
-            .block(Block::default().borders(Borders::ALL).title("Stomata"))
+            .block(
+                self.ui_state
+                    .density
+                    .block(format!("Stomata | Refresh: {}ms", self.refresh_interval))
+                    .title(Line::styled(collected, collected_style).right_aligned()),
+            )
             .select(self.tab_index)
             .style(Style::default().fg(Color::White))
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            );
+            .highlight_style(self.ui_state.palette.tab_highlight());
 
         frame.render_widget(tabs, area);
     }
@@ -199,6 +550,12 @@ impl App {
     /// page-specific shortcuts (e.g., process list navigation). Only
     /// key press events are processed; key release events are ignored.
     ///
+    /// While the help overlay is open only `?`, `Esc` and `q` are handled,
+    /// so keys pressed to dismiss it don't act on the page underneath.
+    /// While the quit confirmation is shown `y` quits, `n` or `Esc` cancels
+    /// and every other key is ignored. While the column menu is open keys
+    /// only act on the menu.
+    ///
     /// # Arguments
     ///
     /// * `key` - The keyboard event to process
@@ -208,11 +565,59 @@ impl App {
     /// Returns an error if event processing fails (currently always returns `Ok`).
     pub fn handle_events(&mut self, key: KeyEvent) -> anyhow::Result<()> {
         if key.kind == KeyEventKind::Press {
+            if self.quit_pending {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => self.quit(),
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        self.quit_pending = false
+                    }
+                    _ => {}
+                }
+                return Ok(());
+            }
+
+            if self.show_help {
+                match key.code {
+                    KeyCode::Char('?') | KeyCode::Esc => self.show_help = false,
+                    KeyCode::Char('q') => self.request_quit(),
+                    _ => {}
+                }
+                return Ok(());
+            }
+
+            if self.ui_state.process_table.column_menu.is_some() {
+                self.column_menu_events(key);
+                return Ok(());
+            }
+            if self.ui_state.interface_menu.is_some() {
+                self.interface_menu_events(key);
+                return Ok(());
+            }
+
             self.process_global_events(key);
             match self.current_page {
                 Page::Processes => {
                     self.process_page_events(key);
                 }
+                Page::ProcessTree => {
+                    self.process_tree_page_events(key);
+                }
+                Page::Cgroups => {
+                    self.cgroups_page_events(key);
+                }
+                Page::SingleProcess(_) => {
+                    self.single_process_page_events(key);
+                }
+                Page::Network => match key.code {
+                    // clamped to the interfaces that fit on the next render
+                    KeyCode::Char(']') => self.ui_state.network_scroll += 1,
+                    KeyCode::Char('[') => {
+                        self.ui_state.network_scroll =
+                            self.ui_state.network_scroll.saturating_sub(1)
+                    }
+                    KeyCode::Char('i') => self.ui_state.interface_menu = Some(0),
+                    _ => {}
+                },
                 _ => {}
             }
         }
@@ -223,13 +628,20 @@ impl App {
     ///
     /// # Keybindings
     ///
-    /// - `q` - Quit the application
+    /// - `q` - Quit the application (asks first with `--confirm-quit`)
+    /// - `Esc` - Return to the feature selection menu
+    /// - `?` - Show the keybinding help overlay
     /// - `Tab` or `Right Arrow` - Next tab
     /// - `Left Arrow` - Previous tab
-    /// - `1` - Jump to System page
-    /// - `2` - Jump to Metrics page
-    /// - `3` - Jump to Processes page
-    /// - `4` - Jump to Network page
+    /// - `1`-`9` - Jump to the tab at that position, see `Page::titles` (`1`
+    ///   System, `2` Metrics, `3` Processes, `4` Network, `5` Process Tree,
+    ///   `6` Cgroups, `7` Custom and `8` GPU with the `gpu` feature)
+    /// - `+` - Increase the refresh interval
+    /// - `-` - Decrease the refresh interval
+    /// - `n` - Toggle process CPU usage between per core and normalized to
+    ///   the whole machine
+    /// - `d` - Toggle between the spacious and the compact layout, see
+    ///   `Density`
     ///
     /// # Arguments
     ///
@@ -237,7 +649,13 @@ impl App {
     fn process_global_events(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') => {
-                self.render = false;
+                self.request_quit();
+            }
+            KeyCode::Esc => {
+                self.go_back();
+            }
+            KeyCode::Char('?') => {
+                self.show_help = true;
             }
             KeyCode::Right | KeyCode::Tab => {
                 self.next_tab();
@@ -245,21 +663,22 @@ impl App {
             KeyCode::Left => {
                 self.previous_tab();
             }
-            KeyCode::Char('1') => {
-                self.tab_index = 0;
-                self.current_page = Page::System;
+            KeyCode::Char(digit @ '1'..='9') => {
+                // number keys map to tabs in order, keys past the last tab are ignored
+                self.select_tab(digit as usize - '1' as usize);
             }
-            KeyCode::Char('2') => {
-                self.tab_index = 1;
-                self.current_page = Page::Metrics;
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.refresh_interval = step_refresh_interval(self.refresh_interval, true);
             }
-            KeyCode::Char('3') => {
-                self.tab_index = 2;
-                self.current_page = Page::Processes;
+            KeyCode::Char('-') => {
+                self.refresh_interval = step_refresh_interval(self.refresh_interval, false);
             }
-            KeyCode::Char('4') => {
-                self.tab_index = 3;
-                self.current_page = Page::Network;
+            KeyCode::Char('n') => {
+                let units = &mut self.ui_state.units;
+                units.cpu = units.cpu.toggled();
+            }
+            KeyCode::Char('d') => {
+                self.ui_state.density = self.ui_state.density.toggled();
             }
             _ => {}
         }
@@ -274,13 +693,40 @@ impl App {
     ///
     /// - `Up Arrow` - Select previous process in the list
     /// - `Down Arrow` - Select next process in the list
+    /// - `PageUp` / `PageDown` - Scroll up / down by one page of the table
+    /// - `Home` / `End` - Select the first / last process
     /// - `Enter` - Open detailed view for the selected process
+    /// - `l` - Lock the selection to the selected PID so it follows the
+    ///   process as the list re-sorts, moving the selection releases it
+    /// - `f` - Follow the process using the most CPU on every tick, moving
+    ///   the selection stops following
+    /// - `w` - Watch the subtree of the selected process, scoping the list
+    ///   to it and its descendants, or stop watching
+    /// - `z` - Toggle showing only zombie/defunct processes
+    /// - `r` - Toggle regex matching of the `--match` pattern
+    /// - `c` - Swap the Name and Command columns, to see full command lines
+    /// - `o` - Open the column menu
+    /// - `p` - Pin or unpin the selected process by name, persisted to the
+    ///   config file so it survives restarts of the process and of stomata
     ///
     /// # Arguments
     ///
     /// * `key` - The keyboard event to process
     fn process_page_events(&mut self, key: KeyEvent) {
         let max_processes = self.ui_state.process_table.process_count;
+        if matches!(
+            key.code,
+            KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::PageUp
+                | KeyCode::PageDown
+                | KeyCode::Home
+                | KeyCode::End
+        ) {
+            // moving the selection by hand releases the lock and stops following
+            self.ui_state.process_table.locked_pid = None;
+            self.ui_state.process_table.follow_top_cpu = false;
+        }
         match key.code {
             KeyCode::Down => {
                 if let Some(selected_row) = self.ui_state.process_table.process_list.selected() {
@@ -300,12 +746,274 @@ impl App {
                         .select(Some(next_row));
                 }
             }
+            KeyCode::PageDown => {
+                let page = self.ui_state.process_table.visible_rows.max(1) as isize;
+                self.ui_state.process_table.move_selection(page);
+            }
+            KeyCode::PageUp => {
+                let page = self.ui_state.process_table.visible_rows.max(1) as isize;
+                self.ui_state.process_table.move_selection(-page);
+            }
+            KeyCode::Home => {
+                self.ui_state.process_table.process_list.select_first();
+            }
+            KeyCode::End => {
+                let last = max_processes.saturating_sub(1);
+                self.ui_state.process_table.process_list.select(Some(last));
+            }
             KeyCode::Enter => {
                 if let Some(selected_process_pid) = self.ui_state.process_table.selected_pid {
                     self.current_page = Page::SingleProcess(selected_process_pid);
                 }
             }
+            KeyCode::Char('l') => {
+                self.ui_state.process_table.toggle_lock();
+            }
+            KeyCode::Char('f') => {
+                self.ui_state.process_table.toggle_follow_top_cpu();
+            }
+            KeyCode::Char('w') => {
+                self.ui_state.process_table.toggle_subtree();
+            }
+            KeyCode::Char('z') => {
+                let process_table = &mut self.ui_state.process_table;
+                process_table.zombies_only = !process_table.zombies_only;
+                // the filtered list is a different length, start from the top
+                process_table.process_list.select(Some(0));
+            }
+            KeyCode::Char('k') => {
+                let process_table = &mut self.ui_state.process_table;
+                process_table.show_kernel_threads = !process_table.show_kernel_threads;
+                process_table.process_list.select(Some(0));
+            }
+            KeyCode::Char('p') => {
+                let selected_name = self
+                    .ui_state
+                    .process_table
+                    .selected_pid
+                    .and_then(|pid| self.metrics.system.process(Pid::from_u32(pid)))
+                    .map(|process| ProcessData::sanitize(process.name()));
+                if let Some(name) = selected_name {
+                    self.config.toggle_pinned(&name);
+                    // persisting is best effort, pins still apply to this session
+                    let _ = self.config.save();
+                }
+            }
+            KeyCode::Char('c') => {
+                let process_table = &mut self.ui_state.process_table;
+                process_table.show_command = !process_table.show_command;
+            }
+            KeyCode::Char('o') => {
+                self.ui_state.process_table.column_menu = Some(0);
+            }
+            KeyCode::Char('r') => {
+                let process_table = &mut self.ui_state.process_table;
+                if let Some(matcher) = process_table.matcher.as_mut() {
+                    matcher.toggle_regex_mode();
+                    process_table.process_list.select(Some(0));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Processes keyboard events while the column menu is open
+    ///
+    /// Toggled columns are saved to the config file right away.
+    ///
+    /// # Keybindings (column menu only)
+    ///
+    /// - `Up Arrow` / `Down Arrow` - Select the previous / next column
+    /// - `Space` or `Enter` - Show or hide the selected column
+    /// - `o` or `Esc` - Close the menu
+    /// - `q` - Quit the application
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keyboard event to process
+    fn column_menu_events(&mut self, key: KeyEvent) {
+        let Some(selected) = self.ui_state.process_table.column_menu else {
+            return;
+        };
+        match key.code {
+            KeyCode::Down => {
+                let next = (selected + 1).min(ProcessColumn::ALL.len() - 1);
+                self.ui_state.process_table.column_menu = Some(next);
+            }
+            KeyCode::Up => {
+                self.ui_state.process_table.column_menu = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                self.config.toggle_column(ProcessColumn::ALL[selected]);
+                self.ui_state.process_table.columns = self.config.process_columns.clone();
+                // persisting is best effort, the columns still apply to this session
+                let _ = self.config.save();
+            }
+            KeyCode::Char('o') | KeyCode::Esc => {
+                self.ui_state.process_table.column_menu = None;
+            }
+            KeyCode::Char('q') => self.request_quit(),
+            _ => {}
+        }
+    }
+
+    /// Processes keyboard events while the interface menu is open
+    ///
+    /// Toggled interfaces are saved to the config file right away.
+    ///
+    /// # Keybindings (interface menu only)
+    ///
+    /// - `Up Arrow` / `Down Arrow` - Select the previous / next interface
+    /// - `Space` or `Enter` - Show or hide the selected interface
+    /// - `i` or `Esc` - Close the menu
+    /// - `q` - Quit the application
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keyboard event to process
+    fn interface_menu_events(&mut self, key: KeyEvent) {
+        let Some(selected) = self.ui_state.interface_menu else {
+            return;
+        };
+        let interface_count = self.ui_state.interface_names().len();
+        match key.code {
+            KeyCode::Down => {
+                let next = (selected + 1).min(interface_count.saturating_sub(1));
+                self.ui_state.interface_menu = Some(next);
+            }
+            KeyCode::Up => {
+                self.ui_state.interface_menu = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                let name = self
+                    .ui_state
+                    .interface_names()
+                    .get(selected)
+                    .map(|name| name.to_string());
+                if let Some(name) = name {
+                    self.config.toggle_hidden_interface(&name);
+                    self.ui_state.hidden_interfaces =
+                        self.config.hidden_interfaces.iter().cloned().collect();
+                    // persisting is best effort, the choice still applies to this session
+                    let _ = self.config.save();
+                }
+            }
+            KeyCode::Char('i') | KeyCode::Esc => {
+                self.ui_state.interface_menu = None;
+            }
+            KeyCode::Char('q') => self.request_quit(),
+            _ => {}
+        }
+    }
+
+    /// Processes page-specific keyboard events for the single process page
+    ///
+    /// # Keybindings (single process page only)
+    ///
+    /// - `g` - Group the tasks by thread name, or list them individually
+    /// - `Up Arrow` / `Down Arrow` - Select the previous / next row of the
+    ///   grouped tasks
+    /// - `Space` - Expand or collapse the group of the selected row
+    /// - `Enter` - Back to the process list once the process exited
+    /// - `e` - Export the disk read/write history to a CSV file
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keyboard event to process
+    fn single_process_page_events(&mut self, key: KeyEvent) {
+        let task_state = &mut self.ui_state.tasks;
+        let max_rows = task_state.row_count;
+        match key.code {
+            KeyCode::Enter if self.ui_state.exited_process.is_some() => {
+                self.close_exited_process();
+            }
+            KeyCode::Char('g') => {
+                task_state.grouped = !task_state.grouped;
+            }
+            KeyCode::Char('e') => {
+                self.export_disk_history();
+            }
+            KeyCode::Down if task_state.grouped => {
+                if let Some(selected_row) = task_state.task_list.selected() {
+                    let next_row = (selected_row + 1).min(max_rows.saturating_sub(1));
+                    task_state.task_list.select(Some(next_row));
+                }
+            }
+            KeyCode::Up if task_state.grouped => {
+                if let Some(selected_row) = task_state.task_list.selected() {
+                    task_state
+                        .task_list
+                        .select(Some(selected_row.saturating_sub(1)));
+                }
+            }
+            KeyCode::Char(' ') if task_state.grouped => {
+                task_state.toggle_selected();
+            }
+            _ => {}
+        }
+    }
+
+    /// Processes page-specific keyboard events for the Process Tree page
+    ///
+    /// # Keybindings (Process Tree page only)
+    ///
+    /// - `Up Arrow` - Select previous node in the tree
+    /// - `Down Arrow` - Select next node in the tree
+    /// - `Space` - Collapse or expand the children of the selected node
+    /// - `Enter` - Open detailed view for the selected process
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keyboard event to process
+    fn process_tree_page_events(&mut self, key: KeyEvent) {
+        let tree_state = &mut self.ui_state.process_tree;
+        let max_rows = tree_state.row_count;
+        match key.code {
+            KeyCode::Down => {
+                if let Some(selected_row) = tree_state.tree_list.selected() {
+                    let next_row = (selected_row + 1).min(max_rows.saturating_sub(1));
+                    tree_state.tree_list.select(Some(next_row));
+                }
+            }
+            KeyCode::Up => {
+                if let Some(selected_row) = tree_state.tree_list.selected() {
+                    tree_state
+                        .tree_list
+                        .select(Some(selected_row.saturating_sub(1)));
+                }
+            }
+            KeyCode::Char(' ') => {
+                tree_state.toggle_selected();
+            }
+            KeyCode::Enter => {
+                if let Some(selected_process_pid) = tree_state.selected_pid {
+                    self.current_page = Page::SingleProcess(selected_process_pid);
+                }
+            }
             _ => {}
         }
     }
+
+    /// Processes page-specific keyboard events for the Cgroups page
+    ///
+    /// # Keybindings (Cgroups page only)
+    ///
+    /// - `Up Arrow` - Select the previous cgroup
+    /// - `Down Arrow` - Select the next cgroup
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The keyboard event to process
+    fn cgroups_page_events(&mut self, key: KeyEvent) {
+        let cgroup_state = &mut self.ui_state.cgroups;
+        let selected = cgroup_state.cgroup_list.selected().unwrap_or(0);
+        let next = match key.code {
+            KeyCode::Down => (selected + 1).min(cgroup_state.row_count.saturating_sub(1)),
+            KeyCode::Up => selected.saturating_sub(1),
+            _ => return,
+        };
+        cgroup_state.cgroup_list.select(Some(next));
+        // the path is followed on render, move it along with the selection
+        cgroup_state.selected_path = None;
+    }
 }
@@ -0,0 +1,130 @@
+//! Cgroup usage display implementation
+//!
+//! Shows how much a whole container or service uses. Every cgroup's CPU and
+//! memory is summed over all processes in it and its children, which the
+//! flat process list can't answer.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+};
+
+use crate::{
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_gauge::render_gauge, render_table::render_table},
+    },
+    structs::{CgroupTreeRow, CgroupTreeUI, UIState},
+};
+
+/// Display implementation for the cgroup hierarchy
+///
+/// Renders CPU and memory gauges for the selected cgroup above a table of
+/// all cgroups, indented by depth:
+///
+/// ```text
+/// ┌─ /system.slice/docker-3f2a….scope CPU ─┐┌─ … Memory ─────────────┐
+/// │███ 100.00/800.00 % (12.50%)            ││██ 512/8192 MiB (6.25%)  │
+/// └────────────────────────────────────────┘└────────────────────────┘
+/// ┌─ Cgroups ───────────────────────────────────────────────────────────┐
+/// │ Cgroup                         Processes   CPU        Memory         │
+/// │ /                              312         140.20%    6120.00 MiB    │
+/// │   system.slice                 96          104.50%    2210.00 MiB    │
+/// │     docker-3f2a….scope         7           100.00%    512.00 MiB     │
+/// └──────────────────────────────────────────────────────────────────────┘
+/// ```
+impl Display<UIState> for CgroupTreeUI {
+    /// Renders the gauges of the selected cgroup and the cgroup table
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the cgroups
+    /// * `ui_state` - Required UI state for the table selection. Must be `Some`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    ///
+    /// # State Management
+    ///
+    /// - `cgroups.row_count`: Number of rows for bounds checking
+    /// - `cgroups.selected_path`: Path of the selected row. The selection
+    ///   follows this path when rows are added or removed above it
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        let Some(ui_state) = ui_state else {
+            return Ok(());
+        };
+        let units = ui_state.units;
+        let palette = ui_state.palette;
+        let density = ui_state.density;
+        let rows: Vec<CgroupTreeRow> = self
+            .tree
+            .flatten()
+            .into_iter()
+            .map(|node| CgroupTreeRow { node, units })
+            .collect();
+        let cgroup_state = &mut ui_state.cgroups;
+        cgroup_state.row_count = rows.len();
+
+        // keep the selected cgroup selected, or the row at its index when it's gone
+        let followed = cgroup_state
+            .selected_path
+            .as_ref()
+            .and_then(|path| rows.iter().position(|row| row.node.path == *path));
+        let selected = followed
+            .or(cgroup_state.cgroup_list.selected())
+            .map(|index| index.min(rows.len().saturating_sub(1)));
+        cgroup_state.cgroup_list.select(selected);
+        cgroup_state.selected_path = selected
+            .and_then(|index| rows.get(index))
+            .map(|row| row.node.path.clone());
+
+        let [gauges_area, table_area] =
+            Layout::vertical([Constraint::Length(density.gauge_rows()), Constraint::Min(0)])
+                .areas(area);
+
+        if let Some(CgroupTreeRow { node: row, .. }) = selected.and_then(|index| rows.get(index)) {
+            let [cpu_area, memory_area] =
+                Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(gauges_area);
+            let group = format!("{} ({} processes)", row.path, row.usage.process_count);
+            let cpu_title = format!("{group} CPU");
+            let memory_title = format!("{group} Memory");
+            frame.render_widget(
+                render_gauge(
+                    row.usage.cpu_usage as f64,
+                    100.0 * self.cpu_count as f64,
+                    &cpu_title,
+                    "%",
+                    units.precision(),
+                    palette,
+                    density,
+                ),
+                cpu_area,
+            );
+            frame.render_widget(
+                render_gauge(
+                    units.to_mega(row.usage.memory),
+                    units.to_mega(self.total_memory),
+                    &memory_title,
+                    units.mega_label(),
+                    units.precision(),
+                    palette,
+                    density,
+                ),
+                memory_area,
+            );
+        }
+
+        let headers = vec!["Cgroup", "Processes", "CPU", "Memory"];
+        let table_widget = render_table(headers, &rows, "Cgroups", palette, density);
+        frame.render_stateful_widget(table_widget, table_area, &mut cgroup_state.cgroup_list);
+        Ok(())
+    }
+}
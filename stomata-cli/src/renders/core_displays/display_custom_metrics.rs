@@ -0,0 +1,121 @@
+//! Custom metrics display implementation
+//!
+//! Shows the metrics read from the shell commands in the `custom_metrics`
+//! section of the config, one row each. Metrics with a `max` are drawn as
+//! a gauge, the others as a sparkline of their history.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+};
+
+use crate::{
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{
+            render_gauge::render_gauge, render_paragraph::paragraph_widget,
+            render_sparkline::render_sparkline,
+        },
+    },
+    structs::{CustomMetricsUI, UIState},
+};
+
+/// Display implementation for the custom metrics
+///
+/// ```text
+/// ┌─ Queue depth: 42 jobs (min 3 max 57 avg 31 cur 42) ┐
+/// │        ▂▃▅▇█▆▅▃▂▃▅▆                                │
+/// └────────────────────────────────────────────────────┘
+/// ┌─ Connections: 120 ─────────────────────────────────┐
+/// │█████████████ 120.00/1000.00 (12.00%)               │
+/// └────────────────────────────────────────────────────┘
+/// ┌─ Replication lag ──────────────────────────────────┐
+/// │timed out after 5000 ms                             │
+/// └────────────────────────────────────────────────────┘
+/// ```
+impl Display<UIState> for CustomMetricsUI<'_> {
+    /// Renders one row per custom metric
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the metrics
+    /// * `ui_state` - UI state holding the history of each metric. When
+    ///   `None`, metrics without a `max` only show their latest value
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        let count = self.metrics.len().max(1) as u32;
+        let rows =
+            Layout::vertical(vec![Constraint::Ratio(1, count); self.metrics.len()]).split(area);
+        let precision = self.units.precision();
+        let mut histories = ui_state.map(|state| state.custom_metrics.iter_mut());
+
+        for ((metric, definition), row) in
+            self.metrics.iter().zip(self.definitions).zip(rows.iter())
+        {
+            let history = histories.as_mut().and_then(|histories| histories.next());
+            let value = match &metric.value {
+                Some(Ok(value)) => *value,
+                Some(Err(error)) => {
+                    frame.render_widget(
+                        paragraph_widget(error.clone(), metric.name.clone(), self.density),
+                        *row,
+                    );
+                    continue;
+                }
+                None => {
+                    frame.render_widget(
+                        paragraph_widget(
+                            "Waiting for the first reading",
+                            metric.name.clone(),
+                            self.density,
+                        ),
+                        *row,
+                    );
+                    continue;
+                }
+            };
+
+            let title = format!("{}: {value:.precision$} {}", metric.name, definition.unit)
+                .trim_end()
+                .to_string();
+            match (definition.max, history) {
+                (Some(max), _) => frame.render_widget(
+                    render_gauge(
+                        value,
+                        max,
+                        &title,
+                        &definition.unit,
+                        precision,
+                        self.palette,
+                        self.density,
+                    ),
+                    *row,
+                ),
+                (None, Some(history)) => frame.render_widget(
+                    render_sparkline(
+                        &history.values,
+                        &title,
+                        true,
+                        Some(self.palette),
+                        self.density,
+                    ),
+                    *row,
+                ),
+                (None, None) => {
+                    frame.render_widget(paragraph_widget("", title, self.density), *row)
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
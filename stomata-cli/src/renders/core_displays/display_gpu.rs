@@ -0,0 +1,149 @@
+//! GPU metrics display implementation
+//!
+//! Renders one row of gauges per NVIDIA GPU, in the style of the Metrics
+//! page. Only compiled with the `gpu` feature.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    widgets::Paragraph,
+};
+use stomata_core::collectors::gpu::{GpuData, GpuMetrics};
+
+use crate::{
+    density::Density,
+    palette::Palette,
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_gauge::render_gauge, render_paragraph::paragraph_widget},
+    },
+    structs::UIState,
+    units::UnitConfig,
+};
+
+/// Display implementation for GPU metrics.
+///
+/// Each GPU gets a row with its utilization and memory gauges and its
+/// temperature. Values the driver doesn't report for a GPU are shown as
+/// `n/a` instead of an empty gauge.
+///
+/// ```text
+/// ┌─ GPU 0 Utilization ─┐┌─ GPU 0 Memory ──────┐┌─ Temperature ─┐
+/// │██████ 57%           ││███ 31%              ││     64 °C     │
+/// └─────────────────────┘└─────────────────────┘└───────────────┘
+/// ```
+///
+/// The GPU names are listed in a panel below the gauges.
+impl Display<UIState> for GpuMetrics {
+    /// Renders the GPU gauges to the terminal frame
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the GPU display
+    /// * `ui_state` - UI state for the memory units and palette, the
+    ///   defaults are used when `None`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        let (units, palette, density) = ui_state
+            .map(|state| (state.units, state.palette, state.density))
+            .unwrap_or_default();
+        // one gauge row per GPU, the names panel takes the rest
+        let gauge_rows = density.gauge_rows();
+        let mut constraints: Vec<Constraint> = self
+            .gpus
+            .iter()
+            .map(|_| Constraint::Length(gauge_rows))
+            .collect();
+        constraints.push(Constraint::Min(0));
+        let rows = Layout::vertical(constraints).split(area);
+
+        for (gpu, row) in self.gpus.iter().zip(rows.iter()) {
+            render_gpu_row(frame, *row, gpu, units, palette, density);
+        }
+
+        let names: Vec<String> = self
+            .gpus
+            .iter()
+            .map(|gpu| format!("GPU {}: {}", gpu.index, gpu.name))
+            .collect();
+        frame.render_widget(
+            paragraph_widget(names.join("\n"), "Devices", density),
+            rows[rows.len() - 1],
+        );
+        Ok(())
+    }
+}
+
+/// Renders the utilization, memory and temperature of one GPU side by side
+fn render_gpu_row(
+    frame: &mut Frame,
+    area: Rect,
+    gpu: &GpuData,
+    units: UnitConfig,
+    palette: Palette,
+    density: Density,
+) {
+    let layout = Layout::horizontal([
+        Constraint::Percentage(40),
+        Constraint::Percentage(40),
+        Constraint::Percentage(20),
+    ])
+    .split(area);
+
+    let utilization_title = format!("GPU {} Utilization", gpu.index);
+    match gpu.utilization {
+        Some(utilization) => frame.render_widget(
+            render_gauge(
+                utilization as f64,
+                100.0,
+                &utilization_title,
+                "%",
+                units.precision(),
+                palette,
+                density,
+            ),
+            layout[0],
+        ),
+        None => frame.render_widget(unavailable(utilization_title, density), layout[0]),
+    }
+
+    let memory_title = format!("GPU {} Memory", gpu.index);
+    match (gpu.memory_used, gpu.memory_total) {
+        (Some(used), Some(total)) => frame.render_widget(
+            render_gauge(
+                units.to_mega(used),
+                units.to_mega(total),
+                &memory_title,
+                units.mega_label(),
+                units.precision(),
+                palette,
+                density,
+            ),
+            layout[1],
+        ),
+        _ => frame.render_widget(unavailable(memory_title, density), layout[1]),
+    }
+
+    let temperature = gpu.temperature.map_or_else(
+        || "n/a".to_string(),
+        |temperature| format!("{temperature} °C"),
+    );
+    frame.render_widget(
+        paragraph_widget(temperature, "Temperature", density).alignment(Alignment::Center),
+        layout[2],
+    );
+}
+
+/// Placeholder for a value the driver doesn't report
+fn unavailable(title: String, density: Density) -> Paragraph<'static> {
+    paragraph_widget("n/a", title, density).alignment(Alignment::Center)
+}
@@ -0,0 +1,124 @@
+//! Matched process group display implementation
+//!
+//! Renders the summed CPU and memory usage of the processes selected with
+//! `--match` or of a watched process subtree, so a set of related processes
+//! (e.g. all nginx workers or a rust build) can be watched as a single unit.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    widgets::Paragraph,
+};
+
+use crate::{
+    density::Density,
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_gauge::render_gauge, render_paragraph::paragraph_widget},
+    },
+    structs::MatchedProcessesUI,
+};
+
+impl MatchedProcessesUI<'_> {
+    /// Returns the summed CPU usage of the group in percent of one core.
+    pub fn total_cpu_usage(&self) -> f32 {
+        self.processes.iter().map(|p| p.cpu_usage).sum()
+    }
+
+    /// Returns the summed memory usage of the group in bytes.
+    pub fn total_memory_usage(&self) -> u64 {
+        self.processes.iter().map(|p| p.memory).sum()
+    }
+}
+
+/// Display implementation for the matched process group
+///
+/// Renders two side-by-side gauges with the group totals:
+///
+/// ```text
+/// ┌─ nginx (12 processes) CPU ─┐┌─ nginx (12 processes) Memory ─┐
+/// │████ 50.00/800.00 % (6.25%) ││██ 250/8000 MiB (3.10%)        │
+/// └────────────────────────────┘└───────────────────────────────┘
+/// ```
+///
+/// CPU usage is measured against all cores (`100% * cpu_count`) since the
+/// per-process values are relative to a single core. With `disk_rates` set a
+/// third section shows the summed disk read and write rates.
+impl Display<()> for MatchedProcessesUI<'_> {
+    /// Renders the group CPU and memory gauges
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the gauges
+    /// * `_ui_state` - Unused for this display (no interactive state needed)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        _ui_state: Option<&mut ()>,
+    ) -> anyhow::Result<()> {
+        let layout = if self.disk_rates.is_some() {
+            Layout::horizontal([
+                Constraint::Percentage(35),
+                Constraint::Percentage(35),
+                Constraint::Percentage(30),
+            ])
+            .split(area)
+        } else {
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area)
+        };
+
+        let group = format!("{} ({} processes)", self.label, self.processes.len());
+        let cpu_title = format!("{group} CPU");
+        let memory_title = format!("{group} Memory");
+
+        frame.render_widget(
+            render_gauge(
+                self.total_cpu_usage() as f64,
+                100.0 * self.cpu_count as f64,
+                &cpu_title,
+                "%",
+                self.units.precision(),
+                self.palette,
+                self.density,
+            ),
+            layout[0],
+        );
+        frame.render_widget(
+            render_gauge(
+                self.units.to_mega(self.total_memory_usage()),
+                self.units.to_mega(self.total_memory),
+                &memory_title,
+                self.units.mega_label(),
+                self.units.precision(),
+                self.palette,
+                self.density,
+            ),
+            layout[1],
+        );
+        if let Some((read_rate, write_rate)) = self.disk_rates {
+            let disk = format!(
+                "R {}/s W {}/s",
+                self.units.format_bytes(read_rate),
+                self.units.format_bytes(write_rate)
+            );
+            let title = format!("{group} Disk");
+            match self.density {
+                Density::Spacious => {
+                    frame.render_widget(paragraph_widget(disk, title, self.density), layout[2])
+                }
+                // single line like the compact gauges
+                Density::Compact => {
+                    frame.render_widget(Paragraph::new(format!("{title} {disk}")), layout[2])
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
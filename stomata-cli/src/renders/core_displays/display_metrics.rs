@@ -2,7 +2,8 @@
 //!
 //! Provides the visual rendering logic for real-time system resource metrics
 //! including CPU, memory, and swap usage. This module implements the `Display`
-//! trait for `SystemCollector` to render gauges and detailed statistics.
+//! trait for `SystemCollector` to render gauges, usage history sparklines and
+//! detailed statistics.
 
 use ratatui::{
     Frame,
@@ -13,23 +14,37 @@ use stomata_core::collectors::system::metrics::SystemCollector;
 use crate::{
     renders::{
         core_displays::traits::Display,
-        render_widgets::{render_gauge::render_gauge, render_paragraph::paragraph_widget},
+        render_widgets::{
+            render_gauge::render_gauge, render_paragraph::paragraph_widget,
+            render_sparkline::render_sparkline,
+        },
     },
     structs::UIState,
-    utils::bytes_to_mb,
+    utils::percentage,
 };
 
 // Display implementation for system resource metrics
 ///
 /// Renders a comprehensive view of system resources divided into four sections:
-/// 1. Memory usage gauge
+/// 1. Memory usage gauge and history sparkline
 /// 2. Swap usage gauge
-/// 3. CPU usage gauge
+/// 3. CPU usage gauge and history sparkline
 /// 4. Detailed statistics panels
 ///
+/// ```text
+/// ┌─ Memory Usage ──────┐┌─ Memory History (%) ─┐
+/// │████████ 62%         ││▂▃▃▄▄▅▅▄▄▃            │
+/// └─────────────────────┘└──────────────────────┘
+/// ┌─ Swap Usage ────────────────────────────────┐
+/// └─────────────────────────────────────────────┘
+/// ┌─ CPU Usage ─────────┐┌─ CPU History (%) ────┐
+/// │███ 23%              ││▁▁▂▇▃▂▁▁▂▃            │
+/// └─────────────────────┘└──────────────────────┘
+/// ```
+///
 /// The detailed statistics section is horizontally divided into three equal panels
 /// showing memory info, swap info, and CPU count.
-impl Display<()> for SystemCollector {
+impl Display<UIState> for SystemCollector {
     /// Renders system metrics to the terminal frame
     ///
     /// Creates a vertical layout with visual gauges for quick assessment
@@ -40,7 +55,8 @@ impl Display<()> for SystemCollector {
     ///
     /// * `frame` - The ratatui frame to render into
     /// * `area` - The rectangular area allocated for system metrics display
-    /// * `_ui_state` - Unused for this display (no interactive state needed)
+    /// * `ui_state` - UI state holding the CPU/memory usage history. When
+    ///   `None`, the gauges take the full width and no sparklines are drawn
     ///
     /// # Returns
     ///
@@ -48,14 +64,21 @@ impl Display<()> for SystemCollector {
     ///
     /// # Gauge Details
     ///
-    /// - **Memory Gauge**: Shows used vs total memory in MB with percentage
-    /// - **Swap Gauge**: Shows used vs total swap space in MB with percentage
+    /// - **Memory Gauge**: Shows used vs total memory in MiB or MB (see
+    ///   `UnitConfig`) with percentage
+    /// - **Swap Gauge**: Shows used vs total swap space in MiB or MB with percentage
     /// - **CPU Gauge**: Shows overall CPU utilization as a percentage (0-100%)
     ///
+    /// # History Sparklines
+    ///
+    /// - **Memory History**: Memory usage percentage over the last samples
+    /// - **CPU History**: CPU usage percentage over the last samples
+    ///
     /// # Statistics Panels
     ///
     /// - **Memory Info**: Exact bytes used/total and usage percentage
-    /// - **Swap Info**: Exact bytes used/total and usage percentage
+    /// - **Swap Info**: Exact bytes used/total and usage percentage, or
+    ///   "No swap configured" when the system has no swap
     /// - **CPU Count**: Number of logical CPU cores available
     ///
     /// # Examples
@@ -65,13 +88,13 @@ impl Display<()> for SystemCollector {
     /// use stomata::renders::core_displays::traits::Display;
     ///
     /// let collector = SystemCollector::new();
-    /// collector.display(frame, area, None)?;
+    /// collector.display(frame, area, Some(&mut ui_state))?;
     /// ```
     fn display(
         &self,
         frame: &mut Frame,
         area: Rect,
-        _ui_state: Option<&mut ()>,
+        ui_state: Option<&mut UIState>,
     ) -> anyhow::Result<()> {
         let layout = Layout::vertical([
             Constraint::Percentage(23),
@@ -80,25 +103,49 @@ impl Display<()> for SystemCollector {
             Constraint::Percentage(30),
         ])
         .split(area);
+        let (units, palette, density) = ui_state
+            .as_ref()
+            .map(|state| (state.units, state.palette, state.density))
+            .unwrap_or_default();
+
+        // gauges on the left, history sparklines on the right
+        let history_split = if ui_state.is_some() {
+            [Constraint::Percentage(50), Constraint::Percentage(50)]
+        } else {
+            [Constraint::Percentage(100), Constraint::Percentage(0)]
+        };
+        let memory_layout = Layout::horizontal(history_split).split(layout[0]);
+        let cpu_layout = Layout::horizontal(history_split).split(layout[2]);
 
         // render memory usage gauge
         frame.render_widget(
             render_gauge(
-                bytes_to_mb(self.system_metrics.memory_used),
-                bytes_to_mb(self.system_metrics.memory_total),
+                units.to_mega(self.system_metrics.memory_used),
+                units.to_mega(self.system_metrics.memory_total),
                 "Memory Usage",
-                "MB",
+                units.mega_label(),
+                units.precision(),
+                palette,
+                density,
             ),
-            layout[0],
+            memory_layout[0],
         );
 
         // render swap usage gauge
+        let swap_title = if self.system_metrics.swap_total == 0 {
+            "Swap Usage (no swap configured)"
+        } else {
+            "Swap Usage"
+        };
         frame.render_widget(
             render_gauge(
-                bytes_to_mb(self.system_metrics.swap_used),
-                bytes_to_mb(self.system_metrics.swap_total),
-                "Swap Usage",
-                "MB",
+                units.to_mega(self.system_metrics.swap_used),
+                units.to_mega(self.system_metrics.swap_total),
+                swap_title,
+                units.mega_label(),
+                units.precision(),
+                palette,
+                density,
             ),
             layout[1],
         );
@@ -110,32 +157,68 @@ impl Display<()> for SystemCollector {
                 100.0,
                 "CPU Usage",
                 "%",
+                units.precision(),
+                palette,
+                density,
             ),
-            layout[2],
+            cpu_layout[0],
         );
 
+        // render cpu and memory history sparklines
+        // scaled to a fixed 100% so the height reflects absolute usage
+        if let Some(ui_state) = ui_state {
+            let history = &ui_state.system_usage;
+            frame.render_widget(
+                render_sparkline(
+                    &history.memory_usage,
+                    "Memory History (%)",
+                    true,
+                    None,
+                    density,
+                )
+                .max(100),
+                memory_layout[1],
+            );
+            frame.render_widget(
+                render_sparkline(&history.cpu_usage, "CPU History (%)", true, None, density)
+                    .max(100),
+                cpu_layout[1],
+            );
+        }
+
         // --- PARAGRAPH ---
-        let memory_used = self.system_metrics.memory_used as f64
-            / self.system_metrics.memory_total as f64
-            * 100.0;
+        let memory_used = percentage(
+            self.system_metrics.memory_used as f64,
+            self.system_metrics.memory_total as f64,
+        );
 
         let text = format!(
-            "Memory Used: {:.2} Bytes\nTotal Memory: {:.2} Bytes\nUsage: {:.2}%",
-            self.system_metrics.memory_used, self.system_metrics.memory_total, memory_used,
+            "Memory Used: {} Bytes\nTotal Memory: {} Bytes\nUsage: {}",
+            self.system_metrics.memory_used,
+            self.system_metrics.memory_total,
+            units.format_percent(memory_used),
         );
 
-        let swap_used =
-            self.system_metrics.swap_used as f64 / self.system_metrics.swap_total as f64 * 100.0;
-        let text_swap = format!(
-            "Swap Used: {:.2} Bytes\nTotal Swap: {:.2} Bytes\nUsage: {:.2}%",
-            self.system_metrics.swap_used, self.system_metrics.swap_total, swap_used,
-        );
+        let text_swap = if self.system_metrics.swap_total == 0 {
+            format!("No swap configured\nUsage: {}", units.format_percent(0.0))
+        } else {
+            let swap_used = percentage(
+                self.system_metrics.swap_used as f64,
+                self.system_metrics.swap_total as f64,
+            );
+            format!(
+                "Swap Used: {} Bytes\nTotal Swap: {} Bytes\nUsage: {}",
+                self.system_metrics.swap_used,
+                self.system_metrics.swap_total,
+                units.format_percent(swap_used),
+            )
+        };
 
         let processes_count_text = format!("CPU count: {}", self.system_metrics.cpu_count);
-        let process_paragraph = paragraph_widget(processes_count_text, "Processes Count");
+        let process_paragraph = paragraph_widget(processes_count_text, "Processes Count", density);
 
-        let paragraph = paragraph_widget(text, "Memory Info");
-        let swap_paragraph = paragraph_widget(text_swap, "Swap Info");
+        let paragraph = paragraph_widget(text, "Memory Info", density);
+        let swap_paragraph = paragraph_widget(text_swap, "Swap Info", density);
 
         let layout_paragraph = Layout::horizontal([
             Constraint::Percentage(33),
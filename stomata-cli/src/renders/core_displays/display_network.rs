@@ -8,16 +8,21 @@ use std::collections::HashMap;
 
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style},
+    widgets::Paragraph,
 };
 use stomata_core::NetworkMetrics;
+use stomata_core::collectors::network::metrics::NetworkInterfaces;
 
 use crate::{
+    constants::MIN_NETWORK_COLUMN_WIDTH,
     renders::{
         core_displays::traits::Display,
         render_widgets::{render_paragraph::paragraph_widget, render_sparkline::render_sparkline},
     },
     structs::{NetworkInterfaceData, UIState},
+    utils::{bytes_per_second, visible_columns},
 };
 
 /// Display implementation for network interface metrics
@@ -28,7 +33,11 @@ use crate::{
 /// - Four sparkline charts showing traffic history
 ///
 /// The display automatically adapts to the number of active interfaces,
-/// distributing screen space equally among them.
+/// distributing screen space equally among them. When they don't all fit
+/// at `MIN_NETWORK_COLUMN_WIDTH`, as many as fit are shown and `[` / `]`
+/// scroll through the rest, e.g. on hosts with a dozen Docker bridges.
+/// Interfaces in `ui_state.hidden_interfaces` (loopback by default) are left
+/// out, `i` opens a menu to choose them.
 impl Display<UIState> for NetworkMetrics {
     /// Renders network metrics for all active interfaces
     ///
@@ -39,7 +48,7 @@ impl Display<UIState> for NetworkMetrics {
     ///
     /// ```text
     /// ┌────────────────────────────────────────┐
-    /// │  Interface 1  │  Interface 2  │  ...   │ (8 lines)
+    /// │  Interface 1  │  Interface 2  │  ...   │ (10 lines)
     /// │   Metadata    │   Metadata    │  ...   │
     /// ├────────────────────────────────────────┤
     /// │  Bytes RX     │  Bytes RX     │  ...   │
@@ -69,24 +78,32 @@ impl Display<UIState> for NetworkMetrics {
     ///
     /// # Metadata Panel Contents
     ///
-    /// Each interface's metadata panel shows cumulative statistics:
-    /// - Total bytes received (since boot/interface up)
-    /// - Total bytes transmitted
+    /// Each interface's metadata panel shows the link state, followed by
+    /// cumulative statistics:
+    /// - Link state (up/down) and speed, when known
+    /// - MAC address, when known
+    /// - Total traffic received (since boot/interface up)
+    /// - Total traffic transmitted
     /// - Total packets received
     /// - Total packets transmitted
     /// - Total receive errors
     /// - Total transmit errors
     ///
+    /// Traffic is shown in bytes or bits depending on `ui_state.units`, the
+    /// sparkline titles as rates per second over `ui_state.network_elapsed`.
+    ///
     /// # Sparkline Charts
     ///
     /// Four sparkline charts per interface showing recent trends:
-    /// 1. **Bytes Received**: Current receive rate with history
-    /// 2. **Bytes Transmitted**: Current transmit rate with history
+    /// 1. **Received**: Current receive rate with history
+    /// 2. **Transmitted**: Current transmit rate with history
     /// 3. **Packets Received**: Current packet receive rate with history
     /// 4. **Packets Transmitted**: Current packet transmit rate with history
     ///
     /// Each sparkline displays the most recent data point in the title
-    /// and shows historical trend as a mini ASCII chart.
+    /// and shows historical trend as a mini ASCII chart. Sparklines of
+    /// interfaces whose link is down are dimmed, so an idle interface can be
+    /// told apart from a down one.
     ///
     /// # State Management
     ///
@@ -105,6 +122,18 @@ impl Display<UIState> for NetworkMetrics {
     /// network_metrics.display(frame, area, Some(&mut ui_state))?;
     /// ```
     ///
+    /// # Scrolling
+    ///
+    /// `ui_state.network_scroll` is the first interface shown, clamped here
+    /// so the last window is always full. A line above the columns shows
+    /// which interfaces are visible while some are scrolled out of view, and
+    /// how many are hidden.
+    ///
+    /// # Empty State
+    ///
+    /// When there are no network interfaces (e.g. in minimal containers) a
+    /// "No active network interfaces" message is rendered instead.
+    ///
     /// # Panics
     ///
     /// This implementation expects `ui_state` to be `Some`. If `None` is passed,
@@ -115,45 +144,138 @@ impl Display<UIState> for NetworkMetrics {
         area: Rect,
         ui_state: Option<&mut UIState>,
     ) -> anyhow::Result<()> {
-        let parent_layout =
-            Layout::vertical([Constraint::Length(8), Constraint::Min(1)]).split(area);
-
-        let number_of_interfaces: u16 = self.interfaces.len().try_into().unwrap_or(5);
-        let constraints =
-            vec![Constraint::Percentage(100 / number_of_interfaces); number_of_interfaces.into()];
-
-        let para_layout = Layout::horizontal(&constraints).split(parent_layout[0]);
-        let sparkline_layout = Layout::horizontal(&constraints).split(parent_layout[1]);
+        let density = ui_state
+            .as_ref()
+            .map(|state| state.density)
+            .unwrap_or_default();
+        // minimal containers can have no interface up at all
+        if self.interfaces.is_empty() {
+            frame.render_widget(
+                paragraph_widget("No active network interfaces", "Network", density),
+                area,
+            );
+            return Ok(());
+        }
 
         if let Some(ui_state) = ui_state {
+            let history_len = ui_state.network_history_len;
+            let units = ui_state.units;
+            let elapsed = ui_state.network_elapsed;
+            let palette = ui_state.palette;
             let map = ui_state.networks_state.get_or_insert(HashMap::new());
 
-            for (index, interface) in self.interfaces.iter().enumerate() {
-                let iface = map
-                    .entry(interface.name.clone())
-                    .or_insert_with(NetworkInterfaceData::default);
+            // history of hidden interfaces and of those scrolled out of view
+            // keeps recording, so it is complete when they are shown again
+            for interface in &self.interfaces {
+                map.entry(interface.name.clone())
+                    .or_insert_with(|| NetworkInterfaceData::new(history_len))
+                    .update_network_history(interface);
+            }
 
-                iface.update_network_history(interface);
+            let interfaces: Vec<&NetworkInterfaces> = self
+                .interfaces
+                .iter()
+                .filter(|interface| !ui_state.hidden_interfaces.contains(&interface.name))
+                .collect();
+            let hidden_count = self.interfaces.len() - interfaces.len();
+            if interfaces.is_empty() {
+                frame.render_widget(
+                    paragraph_widget(
+                        format!("All {hidden_count} interfaces are hidden, press i to choose"),
+                        "Network",
+                        density,
+                    ),
+                    area,
+                );
+                return Ok(());
+            }
+
+            // columns narrower than MIN_NETWORK_COLUMN_WIDTH are unreadable,
+            // show as many as fit and scroll through the rest
+            let number_of_interfaces = interfaces.len();
+            let (first, count) = visible_columns(
+                number_of_interfaces,
+                area.width,
+                MIN_NETWORK_COLUMN_WIDTH,
+                ui_state.network_scroll,
+            );
+            ui_state.network_scroll = first;
+
+            let mut hints = Vec::new();
+            if count < number_of_interfaces {
+                hints.push(format!(
+                    "{} interfaces {}-{} of {number_of_interfaces} {}  [ / ] to scroll",
+                    if first > 0 { "◀" } else { " " },
+                    first + 1,
+                    first + count,
+                    if first + count < number_of_interfaces {
+                        "▶"
+                    } else {
+                        " "
+                    },
+                ));
+            }
+            if hidden_count > 0 {
+                hints.push(format!("{hidden_count} hidden, i to choose"));
+            }
+            let area = if hints.is_empty() {
+                area
+            } else {
+                let [hint_area, area] =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+                frame.render_widget(
+                    Paragraph::new(hints.join("  |  ")).alignment(Alignment::Center),
+                    hint_area,
+                );
+                area
+            };
+
+            let parent_layout =
+                // eight lines of metadata per interface
+                Layout::vertical([
+                    Constraint::Length(density.rows(8)),
+                    Constraint::Min(1),
+                ])
+                .split(area);
+
+            // one equal share per visible interface
+            let constraints = vec![Constraint::Ratio(1, count as u32); count];
+            let para_layout = Layout::horizontal(&constraints).split(parent_layout[0]);
+            let sparkline_layout = Layout::horizontal(&constraints).split(parent_layout[1]);
+
+            for (index, interface) in interfaces[first..first + count].iter().enumerate() {
+                let Some(iface) = map.get(&interface.name) else {
+                    continue;
+                };
 
                 // -- para widgets --
                 let interface_metadata_info = format!(
-                    "Total Bytes received: {}\nTotal Bytes Transmitted: {}\nTotal Packets Received: {}\nTotal Packets Transmitted: {}\nTotal Errors on receive: {}\nTotal Errors on transmit: {}",
-                    interface.total_bytes_received,
-                    interface.total_bytes_transmitted,
+                    "{}\nMAC: {}\nTotal received: {}\nTotal transmitted: {}\nTotal Packets Received: {}\nTotal Packets Transmitted: {}\nTotal Errors on receive: {}\nTotal Errors on transmit: {}",
+                    link_state(interface),
+                    interface.mac_address.as_deref().unwrap_or("N/A"),
+                    units.format_network(interface.total_bytes_received as f64),
+                    units.format_network(interface.total_bytes_transmitted as f64),
                     interface.total_packets_received,
                     interface.total_packets_transmitted,
                     interface.total_errors_on_received,
                     interface.total_errors_on_transmitted
                 );
                 let metadata_para_widget =
-                    paragraph_widget(interface_metadata_info, interface.name.clone());
+                    paragraph_widget(interface_metadata_info, interface.name.clone(), density);
 
                 // -- sparkline widgets --
-                let received_bytes_sparkline_title =
-                    format!("Bytes received: {}", interface.bytes_received);
+                let received_bytes_sparkline_title = format!(
+                    "Received: {}",
+                    units.format_network_rate(bytes_per_second(interface.bytes_received, elapsed))
+                );
 
-                let transmitted_bytes_sparkline_title =
-                    format!("Bytes transmitted: {}", interface.bytes_transmitted);
+                let transmitted_bytes_sparkline_title = format!(
+                    "Transmitted: {}",
+                    units.format_network_rate(bytes_per_second(
+                        interface.bytes_transmitted,
+                        elapsed
+                    ))
+                );
 
                 let packets_received_sparkline_title =
                     format!("Packets received: {}", interface.packets_received);
@@ -164,32 +286,49 @@ impl Display<UIState> for NetworkMetrics {
                 //-- widgets --
                 let sparkline_widgets = vec![
                     render_sparkline(
-                        iface.received_bytes.make_contiguous(),
+                        &iface.received_bytes,
                         &received_bytes_sparkline_title,
+                        true,
+                        Some(palette),
+                        density,
                     ),
                     render_sparkline(
-                        iface.transmitted_bytes.make_contiguous(),
+                        &iface.transmitted_bytes,
                         &transmitted_bytes_sparkline_title,
+                        true,
+                        Some(palette),
+                        density,
                     ),
                     render_sparkline(
-                        iface.packets_received.make_contiguous(),
+                        &iface.packets_received,
                         &packets_received_sparkline_title,
+                        true,
+                        Some(palette),
+                        density,
                     ),
                     render_sparkline(
-                        iface.packets_transmitted.make_contiguous(),
+                        &iface.packets_transmitted,
                         &packets_transmitted_sparkline_title,
+                        true,
+                        Some(palette),
+                        density,
                     ),
                 ];
 
                 let secondart_constraints =
                     vec![
-                        Constraint::Percentage(100 / sparkline_widgets.len() as u16);
+                        Constraint::Ratio(1, sparkline_widgets.len() as u32);
                         sparkline_widgets.len()
                     ];
                 let secondary_layout =
                     Layout::vertical(&secondart_constraints).split(sparkline_layout[index]);
 
-                for (widget_index, widget) in sparkline_widgets.iter().enumerate() {
+                for (widget_index, widget) in sparkline_widgets.into_iter().enumerate() {
+                    let widget = if interface.is_up {
+                        widget
+                    } else {
+                        widget.style(Style::default().fg(Color::DarkGray))
+                    };
                     frame.render_widget(widget, secondary_layout[widget_index]);
                 }
                 frame.render_widget(metadata_para_widget, para_layout[index]);
@@ -198,3 +337,17 @@ impl Display<UIState> for NetworkMetrics {
         Ok(())
     }
 }
+
+/// Formats the link state line of the interface metadata panel.
+///
+/// # Returns
+///
+/// `"Link: up (1000 Mb/s)"`, `"Link: up"` when the speed is unknown, or
+/// `"Link: down"`
+fn link_state(interface: &NetworkInterfaces) -> String {
+    match (interface.is_up, interface.speed_mbps) {
+        (true, Some(speed)) => format!("Link: up ({speed} Mb/s)"),
+        (true, None) => "Link: up".to_string(),
+        (false, _) => "Link: down".to_string(),
+    }
+}
@@ -0,0 +1,107 @@
+//! Process tree display implementation
+//!
+//! Provides an interactive, collapsible view of the parent/child hierarchy
+//! of all running processes. Users can fold subtrees and select a process
+//! to view its detailed information.
+
+use std::collections::HashMap;
+
+use ratatui::{Frame, layout::Rect};
+use stomata_core::collectors::process::metrics::ProcessData;
+
+use crate::{
+    renders::{core_displays::traits::Display, render_widgets::render_table::render_table},
+    structs::{ProcessTreeRow, ProcessTreeUI, UIState},
+};
+
+/// Display implementation for the process tree
+///
+/// Renders the process hierarchy as a table where each process name is
+/// indented by its depth. Collapsed nodes hide all of their descendants.
+impl Display<UIState> for ProcessTreeUI {
+    /// Renders the process tree as an interactive table
+    ///
+    /// # Table Structure
+    ///
+    /// ```text
+    /// ┌──────────────────────────────────────────────────┐
+    /// │                  Process Tree                    │
+    /// ├──────────────────┬─────┬──────┬────────┬─────────┤
+    /// │ Tree             │ PID │ CPU  │ Memory │ Status  │
+    /// ├──────────────────┼─────┼──────┼────────┼─────────┤
+    /// │ ▼ systemd        │ 1   │ 0.1  │  45 MB │Sleeping │
+    /// │   ▶ sshd         │ 512 │ 0.0  │   8 MB │Sleeping │
+    /// │   • cron         │ 530 │ 0.0  │   3 MB │Sleeping │
+    /// └──────────────────┴─────┴──────┴────────┴─────────┘
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the process tree
+    /// * `ui_state` - Required UI state for selection and collapsed nodes. Must be `Some`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    ///
+    /// # State Management
+    ///
+    /// - `process_tree.collapsed`: PIDs whose children are hidden
+    /// - `process_tree.row_count`: Number of visible rows for bounds checking
+    /// - `process_tree.selected_pid`: PID of the selected row, used for
+    ///   collapsing (Space) and opening the detailed view (Enter)
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        if let Some(ui_state) = ui_state {
+            let units = ui_state.units;
+            let palette = ui_state.palette;
+            let density = ui_state.density;
+            let processes_by_pid: HashMap<u32, &ProcessData> =
+                self.processes.iter().map(|p| (p.pid, p)).collect();
+
+            let rows: Vec<ProcessTreeRow> = self
+                .tree
+                .flatten(&ui_state.process_tree.collapsed)
+                .into_iter()
+                .filter_map(|node| {
+                    processes_by_pid
+                        .get(&node.pid)
+                        .copied()
+                        .map(|process| ProcessTreeRow {
+                            node,
+                            process,
+                            units,
+                        })
+                })
+                .collect();
+
+            let tree_state = &mut ui_state.process_tree;
+            tree_state.row_count = rows.len();
+
+            // keep the selection inside the tree after nodes are collapsed or exit
+            if let Some(selected_index) = tree_state.tree_list.selected() {
+                if selected_index >= rows.len() {
+                    tree_state
+                        .tree_list
+                        .select(Some(rows.len().saturating_sub(1)));
+                }
+            }
+            tree_state.selected_pid = tree_state
+                .tree_list
+                .selected()
+                .and_then(|index| rows.get(index))
+                .map(|row| row.node.pid);
+
+            let headers = vec!["Tree", "PID", "CPU", "Memory", "Status"];
+            let title = format!("Process Tree | CPU {}", units.cpu.label());
+            let table_widget = render_table(headers, &rows, &title, palette, density);
+            frame.render_stateful_widget(table_widget, area, &mut tree_state.tree_list);
+        }
+        Ok(())
+    }
+}
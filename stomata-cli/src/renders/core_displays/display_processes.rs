@@ -8,8 +8,11 @@ use ratatui::{Frame, layout::Rect};
 use stomata_core::collectors::process::metrics::ProcessData;
 
 use crate::{
-    renders::{core_displays::traits::Display, render_widgets::render_table::render_table},
-    structs::UIState,
+    renders::{
+        core_displays::traits::Display, render_widgets::render_table::render_table_with_widths,
+    },
+    structs::{PinnedProcessesUI, ProcessColumn, ProcessColumnsRow, UIState},
+    utils::is_zombie_status,
 };
 
 /// Display implementation for process list
@@ -28,16 +31,16 @@ impl Display<UIState> for Vec<ProcessData> {
     /// # Table Structure
     ///
     /// ```text
-    /// ┌─────────────────────────────────────────┐
-    /// │               Processes                  │
-    /// ├─────┬────────────┬─────┬────────┬───────┤
-    /// │ PID │ Name       │ CPU │ Memory │Status │
-    /// ├─────┼────────────┼─────┼────────┼───────┤
-    /// │ 1   │ systemd    │ 0.1 │  45 MB │Running│
-    /// │ 123 │ firefox    │ 5.2 │ 850 MB │Running│
-    /// │ 456 │ code       │ 3.1 │ 420 MB │Sleeping│
-    /// │ ... │ ...        │ ... │ ...    │ ...   │
-    /// └─────┴────────────┴─────┴────────┴───────┘
+    /// ┌──────────────────────────────────────────────────┐
+    /// │               Processes                          │
+    /// ├─────┬────────────┬─────┬────────┬───────┬────────┤
+    /// │ PID │ Name       │ CPU │ Memory │Status │Container│
+    /// ├─────┼────────────┼─────┼────────┼───────┼────────┤
+    /// │ 1   │ systemd    │ 0.1 │  45 MB │Running│        │
+    /// │ 123 │ nginx      │ 5.2 │ 850 MB │Running│ web    │
+    /// │ 456 │ code       │ 3.1 │ 420 MB │Sleeping│       │
+    /// │ ... │ ...        │ ... │ ...    │ ...   │ ...    │
+    /// └─────┴────────────┴─────┴────────┴───────┴────────┘
     /// ```
     ///
     /// # Arguments
@@ -53,17 +56,35 @@ impl Display<UIState> for Vec<ProcessData> {
     ///
     /// # Table Columns
     ///
+    /// By default:
+    ///
     /// - **PID**: Process ID (unique system identifier)
     /// - **Name**: Process name/command
     /// - **CPU**: Current CPU usage percentage
     /// - **Memory**: Current memory consumption
     /// - **Status**: Process state (Running, Sleeping, Stopped, Zombie, etc.)
+    /// - **Container**: Container the process runs in, if any
+    ///
+    /// User, Threads, Command and Start time can be added and any column
+    /// hidden from the column menu (`o`), see `process_table.columns`.
     ///
     /// # Interactive Features
     ///
     /// - **Keyboard Navigation**: Up/Down arrow keys to select processes
     /// - **Selection Tracking**: Selected PID is stored in `ui_state.process_table.selected_pid`
     /// - **Enter Key**: Press Enter on a selected process to view detailed metrics
+    /// - **Selection Lock**: Press `l` to lock the selection to the selected
+    ///   PID, the cursor then follows its row as the list re-sorts
+    /// - **Follow Top CPU**: Press `f` to select the process using the most
+    ///   CPU on every refresh, until the selection is moved by hand
+    /// - **Watch Subtree**: Press `w` to scope the list to the selected
+    ///   process and its descendants, with their totals in the title
+    /// - **Zombie Filter**: Press `z` to list only zombie/defunct processes.
+    ///   Zombie rows are always highlighted in red, marked with `▲` and
+    ///   counted in the title
+    /// - **Kernel Threads**: Hidden on Linux unless toggled with `k`, then
+    ///   listed dimmed
+    /// - **Command Line**: Press `c` to swap the Name and Command columns
     ///
     /// # State Management
     ///
@@ -71,6 +92,14 @@ impl Display<UIState> for Vec<ProcessData> {
     /// - `process_table.process_list`: Ratatui's `TableState` for row selection
     /// - `process_table.selected_pid`: The PID of the currently selected process
     /// - `process_table.process_count`: Total number of processes for bounds checking
+    /// - `process_table.zombies_only`: Whether the zombie filter is active
+    /// - `process_table.show_kernel_threads`: Whether kernel threads are listed
+    /// - `process_table.show_command`: Whether Name and Command are swapped
+    /// - `process_table.columns`: Columns to show, loaded from the config file
+    /// - `process_table.locked_pid`: PID the selection follows, if locked
+    /// - `process_table.follow_top_cpu`: Whether the selection follows the
+    ///   busiest process
+    /// - `process_table.subtree`: Process whose subtree is listed, if watched
     ///
     /// When a process is selected, its PID is stored for navigation to the
     /// detailed single-process view (accessible via Enter key).
@@ -98,14 +127,89 @@ impl Display<UIState> for Vec<ProcessData> {
         &self,
         frame: &mut Frame,
         area: Rect,
-        ui_state: Option<&mut UIState>,
+        mut ui_state: Option<&mut UIState>,
     ) -> anyhow::Result<()> {
-        let headers = vec!["PID", "Name", "CPU", "Memory", "Status"];
-        let table_widget = render_table(headers, &self, "Processes");
+        let columns = ui_state
+            .as_ref()
+            .map(|state| state.process_table.visible_columns())
+            .unwrap_or_else(|| ProcessColumn::DEFAULT.to_vec());
+        let (units, palette, density) = ui_state
+            .as_ref()
+            .map(|state| (state.units, state.palette, state.density))
+            .unwrap_or_default();
+        let zombie_count = self.iter().filter(|p| is_zombie_status(&p.status)).count();
+        let zombies_only = ui_state
+            .as_ref()
+            .is_some_and(|state| state.process_table.zombies_only);
+
+        // the group totals lead the title so they stay visible
+        let group = ui_state.as_ref().and_then(|state| {
+            let process_table = &state.process_table;
+            match (&process_table.subtree, &process_table.matcher) {
+                (Some(subtree), _) => Some(format!("Processes in {}", subtree.label())),
+                (None, Some(matcher)) => Some(format!("Processes matching {}", matcher.label())),
+                (None, None) => None,
+            }
+        });
+        let base_title = match group {
+            Some(group) => {
+                let cpu: f32 = self.iter().map(|p| p.cpu_usage).sum();
+                let memory: u64 = self.iter().map(|p| p.memory).sum();
+                format!(
+                    "{group}: {} | CPU {:.2}% | Memory {}",
+                    self.len(),
+                    cpu,
+                    units.format_mega(memory)
+                )
+            }
+            None => "Processes".to_string(),
+        };
+        let title = match (zombies_only, zombie_count) {
+            (true, _) => format!("{base_title} (zombies only: {zombie_count})"),
+            (false, 0) => base_title,
+            (false, 1) => format!("{base_title} (1 zombie)"),
+            (false, _) => format!("{base_title} ({zombie_count} zombies)"),
+        };
+        let (locked_pid, follow_top_cpu) = ui_state
+            .as_mut()
+            .map(|state| {
+                let process_table = &mut state.process_table;
+                process_table.follow_locked_pid(self.iter().map(|p| p.pid));
+                process_table.follow_top_cpu_row(self.iter().map(|p| p.cpu_usage));
+                (process_table.locked_pid, process_table.follow_top_cpu)
+            })
+            .unwrap_or_default();
+        let title = match (locked_pid, follow_top_cpu) {
+            (Some(pid), _) => format!("{title} (locked to PID {pid})"),
+            (None, true) => format!("{title} (following top CPU)"),
+            (None, false) => title,
+        };
+        let title = format!("{title} | CPU {}", units.cpu.label());
+        let rows: Vec<ProcessColumnsRow> = self
+            .iter()
+            .map(|process| ProcessColumnsRow {
+                process,
+                columns: &columns,
+                units,
+            })
+            .collect();
+        let table_widget = render_table_with_widths(
+            columns.iter().map(ProcessColumn::header).collect(),
+            &rows,
+            &title,
+            columns.iter().map(ProcessColumn::width).collect(),
+            palette,
+            density,
+        );
         if let Some(ui_state) = ui_state {
-            if let Some(selected_index) = ui_state.process_table.process_list.selected() {
-                ui_state.process_table.selected_pid = Some(self[selected_index].pid);
-            };
+            // borders and the header row take three lines
+            ui_state.process_table.visible_rows = area.height.saturating_sub(3) as usize;
+            ui_state.process_table.selected_pid = ui_state
+                .process_table
+                .process_list
+                .selected()
+                .and_then(|selected_index| self.get(selected_index))
+                .map(|process| process.pid);
             frame.render_stateful_widget(
                 table_widget,
                 area,
@@ -115,3 +219,60 @@ impl Display<UIState> for Vec<ProcessData> {
         Ok(())
     }
 }
+
+/// Display implementation for the pinned processes section
+///
+/// Renders the running instances of every pinned process name in a small
+/// table above the process list. Pinned names without a running process
+/// are listed in the title so a stopped service is easy to spot:
+///
+/// ```text
+/// ┌─ Pinned (not running: redis) ───────────┐
+/// │ PID │ Name   │ CPU   │ Memory  │ Status │
+/// │ 812 │ nginx  │ 0.10% │ 12 MB   │ Sleep  │
+/// └─────────────────────────────────────────┘
+/// ```
+impl Display<()> for PinnedProcessesUI<'_> {
+    /// Renders the pinned processes table
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the pinned section
+    /// * `_ui_state` - Unused, the pinned rows are not selectable
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        _ui_state: Option<&mut ()>,
+    ) -> anyhow::Result<()> {
+        let title = if self.not_running.is_empty() {
+            "Pinned".to_string()
+        } else {
+            format!("Pinned (not running: {})", self.not_running.join(", "))
+        };
+        let rows: Vec<ProcessColumnsRow> = self
+            .processes
+            .iter()
+            .map(|process| ProcessColumnsRow {
+                process,
+                columns: &self.columns,
+                units: self.units,
+            })
+            .collect();
+        let table_widget = render_table_with_widths(
+            self.columns.iter().map(ProcessColumn::header).collect(),
+            &rows,
+            &title,
+            self.columns.iter().map(ProcessColumn::width).collect(),
+            self.palette,
+            self.density,
+        );
+        frame.render_widget(table_widget, area);
+        Ok(())
+    }
+}
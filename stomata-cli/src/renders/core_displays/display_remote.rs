@@ -0,0 +1,92 @@
+//! Remote machine display implementation
+//!
+//! Renders the snapshots streamed by a `--remote` host with the same
+//! system gauges and top-process charts as the local Metrics page.
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+};
+
+use crate::{
+    features::core::remote::RemoteSource,
+    renders::{core_displays::traits::Display, render_widgets::render_paragraph::paragraph_widget},
+    structs::{TopProcessesUI, UIState},
+    utils::format_local_datetime,
+};
+
+/// Display implementation for a `--remote` host
+///
+/// ```text
+/// ┌─ Remote ──────────────────────────────────────┐
+/// │admin@example.com | last snapshot 2026-10-16 …  │
+/// │q quit | Esc back                              │
+/// └───────────────────────────────────────────────┘
+/// ┌─ Memory Usage ──────┐┌─ Memory History (%) ───┐
+/// │...                  ││...                     │
+/// ┌─ Top CPU ───────────┐┌─ Top Memory ───────────┐
+/// │...                  ││...                     │
+/// └─────────────────────┘└────────────────────────┘
+/// ```
+impl Display<UIState> for RemoteSource {
+    /// Renders the connection status and the latest remote metrics
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the remote view
+    /// * `ui_state` - UI state holding the remote CPU/memory history and
+    ///   the units from the user's config
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        let [status_area, system_area, processes_area] = Layout::vertical([
+            Constraint::Length(4),
+            Constraint::Percentage(65),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+        let (units, density) = ui_state
+            .as_ref()
+            .map(|state| (state.units, state.density))
+            .unwrap_or_default();
+
+        let last_snapshot = match &self.system {
+            Some(system) => format!(
+                "last snapshot {}",
+                format_local_datetime(system.system_metrics.timestamp.timestamp() as u64)
+            ),
+            None if self.connected => "connecting...".to_string(),
+            None => "no snapshot received".to_string(),
+        };
+        let mut status = format!("{} | {last_snapshot}", self.host);
+        if !self.connected {
+            status.push_str(" | disconnected");
+        }
+        if let Some(error) = &self.error {
+            status.push_str(&format!("\n{error}"));
+        } else {
+            status.push_str("\nq quit | Esc back");
+        }
+        frame.render_widget(paragraph_widget(status, "Remote", density), status_area);
+
+        if let Some(system) = &self.system {
+            system.display(frame, system_area, ui_state)?;
+            TopProcessesUI {
+                processes: self.processes.clone(),
+                units,
+                density,
+            }
+            .display(frame, processes_area, None)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 //! Single process detailed view implementation
 //!
 //! Provides a comprehensive, multi-panel view of a single process including
-//! basic information, resource usage gauges, disk I/O trends, and associated
+//! basic information, resource usage gauges, disk I/O and network trends, and associated
 //! tasks/threads. This is the detailed view accessible by pressing Enter on
 //! a process in the process list.
 
@@ -13,14 +13,126 @@ use crate::{
             render_sparkline::render_sparkline, render_table::render_table,
         },
     },
-    structs::{SingleProcessUI, UIState},
-    utils::bytes_to_mb,
+    structs::{SingleProcessUI, TaskGroup, TaskGroupRow, UIState},
+    units::UnitConfig,
+    utils::{format_duration, format_local_datetime},
 };
-use chrono::DateTime;
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
+    widgets::Wrap,
 };
+use std::collections::HashMap;
+use stomata_core::collectors::process::metrics::ProcessData;
+
+impl SingleProcessUI<'_> {
+    /// Formats the memory the process has swapped out, e.g. `"12.50 MiB"`.
+    fn swap_summary(&self, units: UnitConfig) -> String {
+        match self.data.basic_process_data.swap {
+            Some(swap) => units.format_mega(swap),
+            None if cfg!(target_os = "linux") => "Not available".to_string(),
+            None => "Unsupported on this OS".to_string(),
+        }
+    }
+
+    /// Summarises the process's tasks by status.
+    ///
+    /// Statuses are ordered by count, most common first, e.g.
+    /// `"12 tasks: 8 sleeping, 3 runnable, 1 zombie"`.
+    fn task_summary(&self) -> String {
+        let tasks = &self.data.tasks;
+        if tasks.is_empty() {
+            return if cfg!(target_os = "linux") {
+                "No thread data available".to_string()
+            } else {
+                "No thread data (unsupported on this OS)".to_string()
+            };
+        }
+
+        let mut status_counts: HashMap<String, usize> = HashMap::new();
+        for task in tasks {
+            *status_counts
+                .entry(task.status().to_string().to_lowercase())
+                .or_default() += 1;
+        }
+
+        let mut status_counts: Vec<(String, usize)> = status_counts.into_iter().collect();
+        status_counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let breakdown: Vec<String> = status_counts
+            .iter()
+            .map(|(status, count)| format!("{count} {status}"))
+            .collect();
+        let noun = if tasks.len() == 1 { "task" } else { "tasks" };
+
+        format!("{} {}: {}", tasks.len(), noun, breakdown.join(", "))
+    }
+
+    /// Renders the tasks grouped by thread name, with the tasks of expanded
+    /// groups listed below their summary row.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the task table
+    /// * `headers` - Column headers, the same as the ungrouped table
+    /// * `ui_state` - UI state holding the expanded groups and the selection
+    fn display_task_groups(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        headers: Vec<&str>,
+        ui_state: &mut UIState,
+    ) {
+        let tasks = &self.data.tasks;
+        let groups = TaskGroup::group(tasks.iter().map(|task| {
+            (
+                ProcessData::sanitize(task.name()),
+                task.cpu_usage(),
+                task.memory(),
+            )
+        }));
+
+        let task_state = &mut ui_state.tasks;
+        let mut rows: Vec<TaskGroupRow> = Vec::new();
+        for group in &groups {
+            let expanded = task_state.expanded.contains(&group.name);
+            rows.push(TaskGroupRow {
+                group,
+                task: None,
+                expanded,
+                units: ui_state.units,
+            });
+            if expanded {
+                rows.extend(group.indices.iter().map(|&index| TaskGroupRow {
+                    group,
+                    task: Some(tasks[index]),
+                    expanded,
+                    units: ui_state.units,
+                }));
+            }
+        }
+
+        task_state.row_count = rows.len();
+        // keep the selection inside the table after groups are collapsed
+        if let Some(selected_index) = task_state.task_list.selected() {
+            if selected_index >= rows.len() {
+                task_state
+                    .task_list
+                    .select(Some(rows.len().saturating_sub(1)));
+            }
+        }
+        task_state.selected_group = task_state
+            .task_list
+            .selected()
+            .and_then(|index| rows.get(index))
+            .map(|row| row.group.name.clone());
+
+        let title = format!("Tasks ({} groups by name)", groups.len());
+        let table_widget = render_table(headers, &rows, &title, ui_state.palette, ui_state.density);
+        frame.render_stateful_widget(table_widget, area, &mut task_state.task_list);
+    }
+}
 
 /// Display implementation for detailed single process view
 ///
@@ -40,6 +152,8 @@ use ratatui::{
 /// │   (35%)     │             │             │
 /// │ Memory      │ Disk Write  │             │
 /// │ Gauge (35%) │ Sparkline   │             │
+/// │             │ Net RX / TX │             │
+/// │             │ Sparklines  │             │
 /// └─────────────┴─────────────┴─────────────┘
 ///    33%            33%            33%
 /// ```
@@ -54,6 +168,8 @@ use ratatui::{
 /// │   (35%)     │             │
 /// │ Memory      │ Disk Write  │
 /// │ Gauge (35%) │ Sparkline   │
+/// │             │ Net RX / TX │
+/// │             │ Sparklines  │
 /// └─────────────┴─────────────┘
 ///      50%            50%
 /// ```
@@ -68,7 +184,8 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
     ///
     /// * `frame` - The ratatui frame to render into
     /// * `area` - The rectangular area allocated for the process view
-    /// * `total_memory` - Total system memory in MB (for memory gauge scaling)
+    /// * `total_memory` - Total system memory in MiB or MB, matching
+    ///   `ui_state.units` (for memory gauge scaling)
     /// * `ui_state` - UI state containing disk I/O history buffers for sparklines
     ///
     /// # Returns
@@ -78,16 +195,49 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
     /// # State Management
     ///
     /// The `ui_state.single_process_disk_usage` maintains rolling buffers of:
-    /// - `disk_read_usage`: Historical read byte rates for sparkline
-    /// - `disk_write_usage`: Historical write byte rates for sparkline
+    /// - `disk_read_usage`: Bytes read per refresh for the sparkline
+    /// - `disk_write_usage`: Bytes written per refresh for the sparkline
+    ///
+    /// and the latest read/write rates in bytes per second.
+    ///
+    /// The `ui_state.single_process_network_usage` maintains rolling buffers of
+    /// bytes received/transmitted between refreshes (Linux only).
     ///
     /// These buffers are automatically updated when the display is rendered,
-    /// providing smooth animated sparkline charts of disk activity.
+    /// providing smooth animated sparkline charts of disk activity annotated
+    /// with their min/max/average/current values.
+    ///
+    /// # Disk I/O Labels
+    ///
+    /// The "More info" panel shows the cumulative bytes read and written
+    /// since the process started ("Disk read total"/"Disk write total").
+    /// The disk sparkline titles show the current rate in bytes per second,
+    /// while the sparklines themselves plot the bytes per refresh.
+    ///
+    /// # Task Summary
+    ///
+    /// The basic info panel ends with the number of tasks (threads) and a
+    /// breakdown by status, which helps spot thread leaks on Linux. Where no
+    /// task data is collected a short notice is shown instead.
+    ///
+    /// # Open File Descriptors
+    ///
+    /// The "More info" panel shows the number of open file descriptors and
+    /// how many are sockets (Linux only), useful for spotting fd leaks.
+    /// Other users' descriptors can't be read without elevated privileges
+    /// and show as "N/A (permission)".
+    ///
+    /// # Leak Detection
+    ///
+    /// `ui_state.single_process_memory_usage` keeps the process's memory on
+    /// every refresh. When it grows monotonically over the window faster
+    /// than `LEAK_MIN_SLOPE_BYTES` per refresh, the memory gauge title shows
+    /// a "possible leak" warning with the growth rate.
     ///
     /// # Memory Calculation
     ///
     /// The memory gauge shows:
-    /// - Used: Process memory consumption in MB
+    /// - Used: Process memory consumption in MiB or MB, see `UnitConfig`
     /// - Total: System total memory (passed as parameter)
     /// - This provides context for how much of system memory the process uses
     ///
@@ -98,7 +248,7 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
     /// use stomata::renders::core_displays::traits::SingleProcessDisplay;
     ///
     /// let process_ui = SingleProcessUI { data: process_data };
-    /// let total_memory_mb = bytes_to_mb(system.total_memory());
+    /// let total_memory_mb = ui_state.units.to_mega(system.total_memory());
     ///
     /// process_ui.display_process_metrics(
     ///     frame,
@@ -121,6 +271,9 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
         total_memory: f64,
         ui_state: &mut UIState,
     ) -> anyhow::Result<()> {
+        let units = ui_state.units;
+        let palette = ui_state.palette;
+        let density = ui_state.density;
         let constraints: Vec<Constraint>;
 
         let tasks = &self.data.tasks;
@@ -140,36 +293,66 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
                 .split(primary_layout[0]);
 
         let p_info = format!(
-            "PID: {}\nName: {}\nStatus: {}",
+            "PID: {}\nName: {}\nStatus: {}\nTasks: {}\nSwap: {}",
             self.data.basic_process_data.pid,
             self.data.basic_process_data.name,
-            self.data.basic_process_data.status
+            self.data.basic_process_data.status,
+            self.task_summary(),
+            self.swap_summary(units)
         );
+        let p_info = match &self.data.basic_process_data.container_name {
+            Some(container) => format!("{p_info}\nContainer: {container}"),
+            None => p_info,
+        };
 
-        let basic_info_paragraph = paragraph_widget(p_info, "Basic Task info");
-        let start_timestamp = DateTime::from_timestamp_secs(self.data.start_time as i64).unwrap();
+        let basic_info_paragraph = paragraph_widget(p_info, "Basic Task info", density);
         let mut extra_info = format!(
-            "Start time: {:?}\nRunning time: {}\nCWD: {}\nTotal written bytes: {}\nTotal read bytes: {}\nLatest Read bytes: {}\nLatest write bytes: {}",
-            start_timestamp,
-            self.data.running_time,
+            "Start time: {}\nRunning time: {}\nCWD: {}\nDisk read total: {}\nDisk write total: {}",
+            format_local_datetime(self.data.start_time),
+            format_duration(self.data.running_time),
             self.data
                 .current_working_dir
-                .clone()
-                .unwrap_or(String::new()),
-            self.data.disk_usage.total_written_bytes,
-            self.data.disk_usage.total_read_bytes,
-            self.data.disk_usage.read_bytes,
-            self.data.disk_usage.written_bytes
+                .as_deref()
+                .map(|cwd| ProcessData::sanitize(cwd.as_ref()))
+                .unwrap_or_default(),
+            units.format_bytes(self.data.disk_usage.total_read_bytes as f64),
+            units.format_bytes(self.data.disk_usage.total_written_bytes as f64),
         );
         if let Some(parent_pid) = self.data.parent_pid {
             extra_info.push_str(&format!("\nParent PID: {}", parent_pid.as_u32()));
         };
-        let extra_info_paragraph = paragraph_widget(extra_info, "More info");
+        let open_fds = match (self.data.open_fds, self.data.open_sockets) {
+            (Some(fds), Some(sockets)) => format!("{fds} ({sockets} sockets)"),
+            (Some(fds), None) => fds.to_string(),
+            // /proc/<pid>/fd is only unreadable for other users' processes
+            (None, _) if cfg!(target_os = "linux") => "N/A (permission)".to_string(),
+            (None, _) => "N/A".to_string(),
+        };
+        extra_info.push_str(&format!("\nOpen FDs: {open_fds}"));
+        if let Some(exe) = &self.data.basic_process_data.exe {
+            extra_info.push_str(&format!(
+                "\nExecutable: {}",
+                ProcessData::sanitize(exe.as_os_str())
+            ));
+        }
+        if !self.data.basic_process_data.cmd.is_empty() {
+            extra_info.push_str(&format!(
+                "\nCommand: {}",
+                self.data.basic_process_data.command_line()
+            ));
+        }
+        // long command lines would be cut off at the pane edge
+        let extra_info_paragraph =
+            paragraph_widget(extra_info, "More info", density).wrap(Wrap { trim: false });
+        let cpu_label = format!("CPU ({})", units.cpu.label());
         let cpu_gauge = render_gauge(
-            self.data.basic_process_data.cpu_usage.into(),
+            units.to_cpu(self.data.basic_process_data.cpu_usage).into(),
             100.0,
-            "CPU",
+            &cpu_label,
             "%",
+            units.precision(),
+            palette,
+            density,
         );
 
         frame.render_widget(
@@ -179,36 +362,95 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
 
         // ---- Primary 1 layout -----
         let primary_1_layout = Layout::vertical([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
+            Constraint::Percentage(28),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(36),
         ])
         .split(primary_layout[1]);
 
-        let disk_read_data = ui_state
-            .single_process_disk_usage
-            .disk_read_usage
-            .make_contiguous();
-        let disk_write_data = ui_state
-            .single_process_disk_usage
-            .disk_write_usage
-            .make_contiguous();
-        let disk_read_sparkline = render_sparkline(disk_read_data, "Disk Read Bytes");
-        let disk_write_sparkline = render_sparkline(disk_write_data, "Disk Write Bytes");
+        let disk_usage = &ui_state.single_process_disk_usage;
+        let disk_read_title = format!(
+            "Disk Read rate: {}/s | bytes/refresh",
+            units.format_bytes(disk_usage.read_rate)
+        );
+        let disk_write_title = format!(
+            "Disk Write rate: {}/s | bytes/refresh",
+            units.format_bytes(disk_usage.write_rate)
+        );
+        let disk_read_sparkline = render_sparkline(
+            &disk_usage.disk_read_usage,
+            &disk_read_title,
+            true,
+            Some(palette),
+            density,
+        );
+        let disk_write_sparkline = render_sparkline(
+            &disk_usage.disk_write_usage,
+            &disk_write_title,
+            true,
+            Some(palette),
+            density,
+        );
 
         frame.render_widget(extra_info_paragraph, primary_1_layout[0]);
         frame.render_widget(disk_read_sparkline, primary_1_layout[1]);
         frame.render_widget(disk_write_sparkline, primary_1_layout[2]);
 
+        // only collected on linux, and accounted per network namespace, so
+        // the titles say so instead of implying this process' own traffic
+        if self.data.network_usage.is_some() {
+            let network_layout =
+                Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(primary_1_layout[3]);
+            let network_received_sparkline = render_sparkline(
+                &ui_state.single_process_network_usage.received,
+                "Namespace RX Bytes",
+                true,
+                Some(palette),
+                density,
+            );
+            frame.render_widget(network_received_sparkline, network_layout[0]);
+
+            let network_transmitted_sparkline = render_sparkline(
+                &ui_state.single_process_network_usage.transmitted,
+                "Namespace TX Bytes",
+                true,
+                Some(palette),
+                density,
+            );
+            frame.render_widget(network_transmitted_sparkline, network_layout[1]);
+        } else {
+            let message = if cfg!(target_os = "linux") {
+                "Namespace network usage unavailable for this process"
+            } else {
+                "Namespace network usage is unsupported on this platform"
+            };
+            frame.render_widget(
+                paragraph_widget(message, "Namespace Network", density),
+                primary_1_layout[3],
+            );
+        }
+
         //---- Conditional Render ----
 
         let tertiary_constraints = [Constraint::Percentage(50), Constraint::Percentage(50)];
         let process_memory_use = self.data.basic_process_data.memory;
+        let memory_title = match ui_state.single_process_memory_usage.possible_leak() {
+            Some(slope) => format!(
+                "Memory ⚠ possible leak (+{}/refresh)",
+                units.format_mega(slope as u64)
+            ),
+            None => "Memory".to_string(),
+        };
         let memory_gauge = render_gauge(
-            bytes_to_mb(process_memory_use),
+            units.to_mega(process_memory_use),
             total_memory,
-            "Memory",
-            "MB",
+            &memory_title,
+            units.mega_label(),
+            units.precision(),
+            palette,
+            density,
         );
 
         let tertiary_layout = Layout::vertical(tertiary_constraints).split(secondary_layout[1]);
@@ -217,8 +459,13 @@ impl SingleProcessDisplay for SingleProcessUI<'_> {
 
         if tasks.len() > 0 {
             let task_headers = vec!["PID", "Name", "CPU", "Memory", "Status"];
-            let task_widget = render_table(task_headers, &self.data.tasks, "Tasks");
-            frame.render_widget(task_widget, primary_layout[2]);
+            if ui_state.tasks.grouped {
+                self.display_task_groups(frame, primary_layout[2], task_headers, ui_state);
+            } else {
+                let task_widget =
+                    render_table(task_headers, &self.data.tasks, "Tasks", palette, density);
+                frame.render_widget(task_widget, primary_layout[2]);
+            }
         }
         Ok(())
     }
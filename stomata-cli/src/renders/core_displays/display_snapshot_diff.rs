@@ -0,0 +1,131 @@
+//! Snapshot diff display implementation
+//!
+//! Prints the differences between two `/snapshot.json` captures as plain
+//! text tables, for before/after comparisons around a deployment.
+
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use stomata_core::{
+    ProcessData,
+    snapshot_diff::{SnapshotDiff, diff, parse_snapshot},
+};
+
+use crate::{config::StomataConfig, units::UnitConfig};
+
+/// Loads two snapshots and prints which processes appeared, disappeared
+/// and how the CPU and memory usage of the others changed. Memory is
+/// printed in the units of the user's config.
+///
+/// # Arguments
+///
+/// * `before` - Path of the earlier snapshot
+/// * `after` - Path of the later snapshot
+///
+/// # Errors
+///
+/// Returns an error if a file can't be read or isn't a valid snapshot.
+///
+/// # Examples
+///
+/// ```ignore
+/// print_snapshot_diff(Path::new("before.json"), Path::new("after.json"))?;
+/// // Output:
+/// // Appeared (1)
+/// // PID   NAME   CPU%   MEMORY
+/// // 5120  nginx  1.20%  12.00 MiB
+/// //
+/// // Disappeared (0)
+/// //
+/// // Changed (1)
+/// // PID   NAME      CPU%    ΔCPU%    MEMORY      ΔMEMORY
+/// // 4211  postgres  12.40%  +10.20%  310.00 MiB  +64.00 MiB
+/// ```
+pub fn print_snapshot_diff(before: &Path, after: &Path) -> anyhow::Result<()> {
+    let load = |path: &Path| {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        parse_snapshot(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    };
+    let diff = diff(&load(before)?, &load(after)?);
+    print_diff(&diff, StomataConfig::load().units);
+    Ok(())
+}
+
+fn print_diff(diff: &SnapshotDiff, units: UnitConfig) {
+    let usage_rows = |processes: &[ProcessData]| -> Vec<Vec<String>> {
+        processes
+            .iter()
+            .map(|p| {
+                vec![
+                    p.pid.to_string(),
+                    p.name.clone(),
+                    format!("{:.2}%", p.cpu_usage),
+                    units.format_mega(p.memory),
+                ]
+            })
+            .collect()
+    };
+
+    println!("Appeared ({})", diff.appeared.len());
+    print_table(
+        &["PID", "NAME", "CPU%", "MEMORY"],
+        &usage_rows(&diff.appeared),
+    );
+
+    println!("\nDisappeared ({})", diff.disappeared.len());
+    print_table(
+        &["PID", "NAME", "CPU%", "MEMORY"],
+        &usage_rows(&diff.disappeared),
+    );
+
+    let changed: Vec<Vec<String>> = diff
+        .changed
+        .iter()
+        .map(|change| {
+            let memory_delta = change.memory_delta();
+            let sign = if memory_delta < 0 { "-" } else { "+" };
+            vec![
+                change.after.pid.to_string(),
+                change.after.name.clone(),
+                format!("{:.2}%", change.after.cpu_usage),
+                format!("{:+.2}%", change.cpu_delta()),
+                units.format_mega(change.after.memory),
+                format!("{sign}{}", units.format_mega(memory_delta.unsigned_abs())),
+            ]
+        })
+        .collect();
+    println!("\nChanged ({})", diff.changed.len());
+    print_table(
+        &["PID", "NAME", "CPU%", "ΔCPU%", "MEMORY", "ΔMEMORY"],
+        &changed,
+    );
+}
+
+/// Prints rows left aligned in columns as wide as their longest cell,
+/// nothing when there are no rows.
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    if rows.is_empty() {
+        return;
+    }
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: Vec<&str>| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+
+    print_row(headers.to_vec());
+    for row in rows {
+        print_row(row.iter().map(String::as_str).collect());
+    }
+}
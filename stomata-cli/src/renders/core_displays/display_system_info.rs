@@ -1,8 +1,8 @@
 //! System information display implementation
 //!
 //! Provides a centered view of core system details including OS information,
-//! kernel version, and hostname, along with keyboard navigation instructions
-//! for the UI.
+//! kernel version, hostname, CPU architecture and boot time, along with
+//! keyboard navigation instructions for the UI.
 
 use ratatui::{Frame, layout::Rect};
 use stomata_core::collectors::SystemInfo;
@@ -10,6 +10,7 @@ use stomata_core::collectors::SystemInfo;
 use crate::{
     renders::{core_displays::traits::Display, render_widgets::render_paragraph::paragraph_widget},
     structs::UIState,
+    utils::{format_duration, format_local_datetime},
 };
 
 /// Display implementation for system information.
@@ -18,18 +19,23 @@ use crate::{
 /// - Operating system name and version
 /// - Kernel version
 /// - System hostname
+/// - CPU architecture
+/// - Boot time and uptime
 /// - UI navigation helper text
 ///
+/// Values the OS doesn't report are shown as "Unknown".
+///
 /// The display uses vertical spacing for visual balance and center alignment
 /// for improved readability.
-impl Display<()> for SystemInfo {
+impl Display<UIState> for SystemInfo {
     /// Renders the system information to the terminal UI.
     ///
     /// # Arguments
     ///
     /// * `frame` - Mutable reference to the ratatui frame for rendering
     /// * `area` - Screen area where the system info should be displayed
-    /// * `_ui_state` - Unused UI state parameter (reserved for future use)
+    /// * `ui_state` - UI state for the density, the default is used when
+    ///   `None`
     ///
     /// # Returns
     ///
@@ -39,7 +45,7 @@ impl Display<()> for SystemInfo {
     ///
     /// The output includes:
     /// - Top padding with newlines for vertical centering
-    /// - System details (OS, kernel, hostname)
+    /// - System details (OS, kernel, hostname, architecture, boot time)
     /// - Bottom padding before helper text
     /// - Keyboard navigation instructions:
     ///   - Switch tabs: Number keys, Tab, or arrow keys (←/→)
@@ -49,8 +55,9 @@ impl Display<()> for SystemInfo {
         &self,
         frame: &mut Frame,
         area: Rect,
-        _ui_state: Option<&mut ()>,
+        ui_state: Option<&mut UIState>,
     ) -> anyhow::Result<()> {
+        let density = ui_state.map(|state| state.density).unwrap_or_default();
         let logo = r#"
 ███████╗████████╗ ██████╗ ███╗   ███╗ █████╗ ████████╗ █████╗ 
 ██╔════╝╚══██╔══╝██╔═══██╗████╗ ████║██╔══██╗╚══██╔══╝██╔══██╗
@@ -60,14 +67,24 @@ impl Display<()> for SystemInfo {
 ╚══════╝   ╚═╝    ╚═════╝ ╚═╝     ╚═╝╚═╝  ╚═╝   ╚═╝   ╚═╝  ╚═╝
 "#;
 
+        let boot_time = match self.boot_time {
+            0 => "Unknown".to_string(),
+            boot_time => format_local_datetime(boot_time),
+        };
         let mut system_info_str = format!(
-            "\n{logo}\n\nOS name: {}\nOS version: {}\nKernel Version: {}\nHostname: {}",
-            self.os_name, self.os_version, self.kernel_version, self.hostname
+            "\n{logo}\n\nOS name: {}\nOS version: {}\nKernel Version: {}\nHostname: {}\nArchitecture: {}\nBoot time: {}\nUptime: {}",
+            self.os_name,
+            self.os_version,
+            self.kernel_version,
+            self.hostname,
+            self.cpu_arch,
+            boot_time,
+            format_duration(self.uptime)
         );
 
         let helper_instructions = "\n\n\nSwitch Tabs: Use number keys OR Tab btn OR <-, -> arrow keys\nMove selector: Up. Down arrow keys\nSelect: Enter key";
         system_info_str.push_str(helper_instructions);
-        let paragraph = paragraph_widget(system_info_str, "System Info");
+        let paragraph = paragraph_widget(system_info_str, "System Info", density);
         frame.render_widget(
             paragraph.alignment(ratatui::layout::Alignment::Center),
             area,
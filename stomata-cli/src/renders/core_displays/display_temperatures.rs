@@ -0,0 +1,125 @@
+//! Temperature sensors display implementation
+//!
+//! Lists every temperature sensor with its current, highest and critical
+//! temperature. Rows are colored by how close the sensor is to critical so
+//! thermal throttling stands out.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Style},
+    widgets::Cell,
+};
+use stomata_core::collectors::temperature::{
+    SensorTemperature, TemperatureCollector, ThermalState,
+};
+
+use crate::{
+    palette::Palette,
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_paragraph::paragraph_widget, render_table::render_table},
+    },
+    structs::{TableRow, UIState},
+};
+
+/// Formats a temperature in °C, `-` when the sensor doesn't report it
+fn format_temperature(temperature: Option<f32>) -> String {
+    temperature.map_or_else(|| "-".to_string(), |t| format!("{t:.1} °C"))
+}
+
+/// Implements table row conversion for temperature sensors.
+///
+/// # Column Layout
+///
+/// 1. **Sensor** (12+ chars, flexible): Component label
+/// 2. **Current** (9 chars): Current temperature
+/// 3. **Max** (9 chars): Highest temperature since startup
+/// 4. **Critical** (9 chars): Temperature the hardware throttles at
+impl TableRow for SensorTemperature {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        vec![
+            Cell::from(self.label.clone()),
+            Cell::from(format_temperature(self.current)),
+            Cell::from(format_temperature(self.max)),
+            Cell::from(format_temperature(self.critical)),
+        ]
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Min(12),   // Sensor
+            Constraint::Length(9), // Current
+            Constraint::Length(9), // Max
+            Constraint::Length(9), // Critical
+        ]
+    }
+
+    /// Yellow within a few degrees of critical, red at or above it (orange
+    /// with the `colorblind` palette)
+    fn row_style(&self, palette: Palette) -> Style {
+        match self.thermal_state() {
+            ThermalState::Critical => Style::default().fg(palette.critical()),
+            ThermalState::Hot => Style::default().fg(palette.warning()),
+            ThermalState::Normal => Style::default().fg(palette.good()),
+            ThermalState::Unknown => Style::default().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// Display implementation for temperature sensors
+///
+/// Renders a table of the sensors, or a message when the machine exposes
+/// none, which is common in virtual machines and containers.
+///
+/// ```text
+/// ┌─ Temperatures ──────────────────────────────┐
+/// │Sensor         Current   Max       Critical  │
+/// │coretemp Core 0 58.0 °C   71.0 °C   100.0 °C │
+/// │nvme Composite  41.9 °C   44.9 °C   84.8 °C  │
+/// └─────────────────────────────────────────────┘
+/// ```
+impl Display<UIState> for TemperatureCollector {
+    /// Renders the sensor table
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the sensors
+    /// * `ui_state` - UI state for the palette, the default palette is used
+    ///   when `None`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ui_state: Option<&mut UIState>,
+    ) -> anyhow::Result<()> {
+        let (palette, density) = ui_state
+            .map(|state| (state.palette, state.density))
+            .unwrap_or_default();
+        if self.sensors.is_empty() {
+            frame.render_widget(
+                paragraph_widget("\nNo temperature sensors exposed", "Temperatures", density)
+                    .alignment(Alignment::Center),
+                area,
+            );
+            return Ok(());
+        }
+
+        frame.render_widget(
+            render_table(
+                vec!["Sensor", "Current", "Max", "Critical"],
+                &self.sensors,
+                "Temperatures",
+                palette,
+                density,
+            ),
+            area,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+//! Top processes display implementation
+//!
+//! Provides a quick "what's eating my machine" view showing the processes
+//! with the highest CPU and memory usage as horizontal bar charts, and a
+//! plain text variant for non-interactive use.
+
+use std::{iter::Sum, ops::Sub};
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+};
+use stomata_core::collectors::{
+    process::metrics::ProcessData,
+    structs::{Metrics, MetricsToFetch, StomataSystemMetrics},
+};
+
+use crate::{
+    config::StomataConfig,
+    constants::TOP_N_PROCESSES,
+    density::Density,
+    renders::{core_displays::traits::Display, render_widgets::render_bar::horizontal_bar_chart},
+    structs::{ProcessMatcher, TopProcessesUI},
+};
+
+impl TopProcessesUI {
+    /// Returns the `count` processes with the highest value of `key`.
+    ///
+    /// Processes are sorted in descending order of `key`.
+    pub fn top_by<K, F>(&self, key: F, count: usize) -> Vec<&ProcessData>
+    where
+        K: PartialOrd,
+        F: Fn(&ProcessData) -> K,
+    {
+        let mut processes: Vec<&ProcessData> = self.processes.iter().collect();
+        processes.sort_by(|a, b| {
+            key(b)
+                .partial_cmp(&key(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        processes.truncate(count);
+        processes
+    }
+
+    /// Sums `key` over the processes left out of the `count` highest, so
+    /// the charts can show what the top processes don't account for.
+    ///
+    /// # Returns
+    ///
+    /// The number of remaining processes and their summed `key`, `None`
+    /// when every process is in the top `count`
+    pub fn other_by<K, F>(&self, key: F, count: usize) -> Option<(usize, K)>
+    where
+        K: PartialOrd + Sum + Sub<Output = K>,
+        F: Fn(&ProcessData) -> K,
+    {
+        let others = self
+            .processes
+            .len()
+            .checked_sub(count)
+            .filter(|others| *others > 0)?;
+        let total: K = self.processes.iter().map(&key).sum();
+        let top: K = self.top_by(&key, count).into_iter().map(&key).sum();
+        Some((others, total - top))
+    }
+}
+
+/// Label of the bar aggregating the processes outside the top ones
+fn other_label(others: usize) -> String {
+    match others {
+        1 => "Other (1 process)".to_string(),
+        _ => format!("Other ({others} processes)"),
+    }
+}
+
+/// Prints the `count` processes using the most CPU as a plain text table.
+///
+/// CPU usage is a delta between two refreshes, so the process list is
+/// sampled again `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL` after the first
+/// sample taken by `StomataSystemMetrics::new` before printing. Intended for CLI mode where no terminal UI is available.
+///
+/// With a matcher only the matching processes are considered, followed by
+/// a line with the summed CPU and memory of the whole group. CPU and
+/// memory are printed in the units of the user's config.
+///
+/// # Arguments
+///
+/// * `count` - Number of processes to print
+/// * `matcher` - Optional `--match` filter
+///
+/// # Examples
+///
+/// ```ignore
+/// print_top_processes(3, None);
+/// // Output:
+/// // PID    NAME     CPU%    MEMORY
+/// // 4211   firefox  52.30%  850.12 MiB
+/// // 3120   code     31.00%  420.50 MiB
+/// // 1      systemd  0.10%   12.00 MiB
+/// ```
+pub fn print_top_processes(count: usize, matcher: Option<&ProcessMatcher>) {
+    // the first sample always reports 0%, wait for the second one
+    let mut metrics = StomataSystemMetrics::new();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+
+    let Metrics::Processes(mut processes) = metrics.fetch(MetricsToFetch::Process) else {
+        eprintln!("Error in fetching processes");
+        return;
+    };
+    if let Some(matcher) = matcher {
+        processes.retain(|p| matcher.matches(p));
+    }
+
+    let mut units = StomataConfig::load().units;
+    units.cpu_count = metrics.system.cpus().len();
+    let top_processes = TopProcessesUI {
+        processes,
+        units,
+        density: Density::default(),
+    };
+    let rows: Vec<[String; 4]> = top_processes
+        .top_by(|p| p.cpu_usage, count)
+        .into_iter()
+        .map(|p| {
+            [
+                p.pid.to_string(),
+                p.name.clone(),
+                top_processes.units.format_cpu(p.cpu_usage),
+                top_processes.units.format_mega(p.memory),
+            ]
+        })
+        .collect();
+
+    let headers = ["PID", "NAME", "CPU%", "MEMORY"];
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let print_row = |cells: [&str; 4]| {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+    };
+
+    print_row(headers);
+    for row in &rows {
+        print_row([&row[0], &row[1], &row[2], &row[3]]);
+    }
+
+    if let Some(matcher) = matcher {
+        let cpu: f32 = top_processes.processes.iter().map(|p| p.cpu_usage).sum();
+        let memory: u64 = top_processes.processes.iter().map(|p| p.memory).sum();
+        println!(
+            "\n{} processes matching {}: CPU {}, Memory {}",
+            top_processes.processes.len(),
+            matcher.label(),
+            top_processes.units.format_cpu(cpu),
+            top_processes.units.format_mega(memory)
+        );
+    }
+}
+
+/// Display implementation for the top-N processes bar charts
+///
+/// Renders two side-by-side horizontal bar charts, recomputed from the
+/// process list on every tick. The last bar sums all the other processes,
+/// so the bars add up to the total usage:
+///
+/// ```text
+/// ┌─ Top CPU ─────────────────┐┌─ Top Memory ──────────────┐
+/// │firefox ██████████ 52.30%  ││firefox ██████████ 850 MiB │
+/// │code    ██████ 31.00%      ││code    █████ 420 MiB     │
+/// │...                        ││...                        │
+/// │Other (212 processes) ███  ││Other (212 processes) ████ │
+/// └───────────────────────────┘└───────────────────────────┘
+/// ```
+impl Display<()> for TopProcessesUI {
+    /// Renders the top CPU and memory consumers
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the bar charts
+    /// * `_ui_state` - Unused for this display (no interactive state needed)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        _ui_state: Option<&mut ()>,
+    ) -> anyhow::Result<()> {
+        let layout = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        // cpu usage is scaled by 100 so that fractional percentages still produce bars
+        let cpu_bar =
+            |label: String, cpu: f32| (label, (cpu * 100.0) as u64, self.units.format_cpu(cpu));
+        let mut top_cpu: Vec<(String, u64, String)> = self
+            .top_by(|p| p.cpu_usage, TOP_N_PROCESSES)
+            .into_iter()
+            .map(|p| cpu_bar(p.name.clone(), p.cpu_usage))
+            .collect();
+        if let Some((others, cpu)) = self.other_by(|p| p.cpu_usage, TOP_N_PROCESSES) {
+            // float rounding can leave a tiny negative remainder
+            top_cpu.push(cpu_bar(other_label(others), cpu.max(0.0)));
+        }
+
+        let memory_bar =
+            |label: String, memory: u64| (label, memory, self.units.format_mega(memory));
+        let mut top_memory: Vec<(String, u64, String)> = self
+            .top_by(|p| p.memory, TOP_N_PROCESSES)
+            .into_iter()
+            .map(|p| memory_bar(p.name.clone(), p.memory))
+            .collect();
+        if let Some((others, memory)) = self.other_by(|p| p.memory, TOP_N_PROCESSES) {
+            top_memory.push(memory_bar(other_label(others), memory));
+        }
+
+        frame.render_widget(
+            horizontal_bar_chart(&top_cpu, "Top CPU", self.density),
+            layout[0],
+        );
+        frame.render_widget(
+            horizontal_bar_chart(&top_memory, "Top Memory", self.density),
+            layout[1],
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::UnitConfig;
+
+    fn top_processes(memory: &[u64]) -> TopProcessesUI {
+        TopProcessesUI {
+            processes: memory
+                .iter()
+                .enumerate()
+                .map(|(pid, memory)| ProcessData {
+                    pid: pid as u32,
+                    memory: *memory,
+                    ..Default::default()
+                })
+                .collect(),
+            units: UnitConfig::default(),
+            density: Density::default(),
+        }
+    }
+
+    #[test]
+    fn test_other_by_sums_the_remaining_processes() {
+        let top = top_processes(&[10, 50, 20, 40, 30]);
+        assert_eq!(top.other_by(|p| p.memory, 2), Some((3, 60)));
+    }
+
+    #[test]
+    fn test_other_by_none_when_every_process_is_in_the_top() {
+        let top = top_processes(&[10, 50, 20]);
+        assert_eq!(top.other_by(|p| p.memory, 3), None);
+        assert_eq!(top.other_by(|p| p.memory, 10), None);
+    }
+
+    #[test]
+    fn test_other_by_zero_count_sums_every_process() {
+        let top = top_processes(&[10, 50, 20]);
+        assert_eq!(top.other_by(|p| p.memory, 0), Some((3, 80)));
+    }
+}
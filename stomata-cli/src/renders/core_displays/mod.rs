@@ -7,18 +7,39 @@
 //! # Modules
 //!
 //! - `display_app` - Application-level display and layout
+//! - `display_cgroups` - Summed CPU and memory per cgroup (container/service)
+//! - `display_custom_metrics` - Gauges and sparklines of user defined metrics
+//! - `display_gpu` - Per-GPU utilization, memory and temperature (`gpu` feature)
+//! - `display_matched_processes` - Summed usage of the `--match` process group
 //! - `display_metrics` - System metrics visualization (CPU, memory, disk)
 //! - `display_network` - Network interface statistics and connections
 //! - `display_processes` - Interactive process list
+//! - `display_remote` - Metrics streamed from a `--remote` host
+//! - `display_process_tree` - Collapsible parent/child process hierarchy
 //! - `display_single_process` - Detailed view of individual processes
+//! - `display_snapshot_diff` - Text diff of two JSON snapshots
 //! - `display_system_info` - OS and kernel information display
+//! - `display_temperatures` - Temperature sensors colored by thermal state
+//! - `display_top_processes` - Top CPU and memory consumers as bar charts
+//! - `pages` - One `PageView` per page of the TUI, composed of the displays
 //! - `traits` - Common display trait definitions
 
 pub mod display_app;
+pub mod display_cgroups;
+pub mod display_custom_metrics;
+#[cfg(feature = "gpu")]
+pub mod display_gpu;
+pub mod display_matched_processes;
 pub mod display_metrics;
 pub mod display_network;
+pub mod display_process_tree;
 pub mod display_processes;
+pub mod display_remote;
 pub mod display_single_process;
+pub mod display_snapshot_diff;
 pub mod display_system_info;
+pub mod display_temperatures;
+pub mod display_top_processes;
+pub mod pages;
 
 pub mod traits;
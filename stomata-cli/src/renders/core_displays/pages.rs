@@ -0,0 +1,440 @@
+//! Page implementations of the core TUI
+//!
+//! Each page fetches the metrics it shows and lays out its widgets within
+//! the content area below the tabs. All pages render from the same
+//! `PageContext`, so `App::render` dispatches the current page through
+//! `Box<dyn PageView>` instead of matching on every variant.
+//!
+//! Adding a page means adding a `Page` variant, a page struct implementing
+//! `PageView` here, and mapping one to the other in `Page::view`.
+
+use std::time::Instant;
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+};
+use stomata_core::collectors::{
+    process::{cgroup::CgroupTree, metrics::ProcessData, tree::ProcessTree},
+    structs::{Metrics, MetricsToFetch},
+};
+
+#[cfg(feature = "gpu")]
+use crate::structs::GpuPage;
+use crate::{
+    constants::MAX_PINNED_ROWS,
+    renders::{
+        core_displays::traits::{Display, PageView, SingleProcessDisplay},
+        render_widgets::render_paragraph::paragraph_widget,
+    },
+    structs::{
+        CgroupTreeUI, CgroupsPage, CustomMetricHistory, CustomMetricsUI, CustomPage, ExitedProcess,
+        MatchedProcessesUI, MetricsPage, NetworkPage, PageContext, PinnedProcessesUI,
+        ProcessTreePage, ProcessTreeUI, ProcessesPage, SingleProcessPage, SingleProcessUI,
+        SystemPage, TopProcessesUI,
+    },
+    utils::is_zombie_status,
+};
+
+/// System information (OS, kernel, hostname, architecture, boot time and uptime)
+impl PageView for SystemPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        if let Metrics::SystemInfo(system_info) = ctx.metrics.fetch(MetricsToFetch::SystemInfo) {
+            system_info.display(frame, area, Some(&mut *ctx.ui_state))?;
+        }
+        Ok(())
+    }
+}
+
+/// Real-time resource usage (CPU, memory, disk) with CPU/memory history
+/// sparklines next to the temperature sensors, and the top CPU and memory
+/// consuming processes
+///
+/// With a `--match` pattern a section with the summed CPU and memory of the
+/// matching processes is added between the two, and the top processes only
+/// consider the matching ones.
+impl PageView for MetricsPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        let metrics_layout = if ctx.ui_state.process_table.matcher.is_some() {
+            Layout::vertical([
+                Constraint::Percentage(55),
+                Constraint::Percentage(15),
+                Constraint::Percentage(30),
+            ])
+            .split(area)
+        } else {
+            Layout::vertical([
+                Constraint::Percentage(70),
+                Constraint::Percentage(0),
+                Constraint::Percentage(30),
+            ])
+            .split(area)
+        };
+        let [usage_area, temperatures_area] =
+            Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .areas(metrics_layout[0]);
+        if let Some(system_collector) = ctx.system_collector {
+            let system_metrics = &system_collector.system_metrics;
+            ctx.ui_state.system_usage.update_history(
+                system_metrics.cpu_usage,
+                system_metrics.memory_used,
+                system_metrics.memory_total,
+            );
+            let _ = system_collector.display(frame, usage_area, Some(&mut *ctx.ui_state));
+        };
+        if let Metrics::Temperatures(temperatures) = ctx.metrics.fetch(MetricsToFetch::Temperatures)
+        {
+            let _ = temperatures.display(frame, temperatures_area, Some(&mut *ctx.ui_state));
+        }
+        if let Metrics::Processes(mut processes) = ctx.metrics.fetch(MetricsToFetch::Process) {
+            if let Some(matcher) = &ctx.ui_state.process_table.matcher {
+                processes.retain(|p| matcher.matches(p));
+                let _ = MatchedProcessesUI {
+                    label: &matcher.label(),
+                    processes: &processes,
+                    cpu_count: ctx.metrics.system.cpus().len(),
+                    total_memory: ctx.metrics.system.total_memory(),
+                    units: ctx.ui_state.units,
+                    palette: ctx.ui_state.palette,
+                    density: ctx.ui_state.density,
+                    disk_rates: None,
+                }
+                .display(frame, metrics_layout[1], None);
+            }
+            let _ = TopProcessesUI {
+                processes,
+                units: ctx.ui_state.units,
+                density: ctx.ui_state.density,
+            }
+            .display(frame, metrics_layout[2], None);
+        }
+        Ok(())
+    }
+}
+
+/// All running processes, below a section with the pinned processes when
+/// any are pinned
+///
+/// While a subtree is watched the list is scoped to it, replacing the
+/// `--match` filter, and gauges with its summed CPU, memory and disk usage
+/// are shown above the list. The watch ends when its root process exits.
+impl PageView for ProcessesPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        let Metrics::Processes(mut processes) = ctx.metrics.fetch(MetricsToFetch::Process) else {
+            return Ok(());
+        };
+
+        // pinned processes are picked before filtering so they always show
+        let pinned_names = &ctx.config.pinned_processes;
+        let list_area = if pinned_names.is_empty() {
+            area
+        } else {
+            let mut pinned: Vec<ProcessData> = processes
+                .iter()
+                .filter(|p| pinned_names.contains(&p.name))
+                .cloned()
+                .collect();
+            pinned.sort_by_key(|p| (pinned_names.iter().position(|n| *n == p.name), p.pid));
+            let not_running: Vec<&str> = pinned_names
+                .iter()
+                .filter(|name| !processes.iter().any(|p| p.name == **name))
+                .map(String::as_str)
+                .collect();
+
+            // plus the header row and the borders
+            let rows = ctx
+                .ui_state
+                .density
+                .rows(pinned.len().clamp(1, MAX_PINNED_ROWS) as u16 + 1);
+            let [pinned_area, list_area] =
+                Layout::vertical([Constraint::Length(rows), Constraint::Min(0)]).areas(area);
+            let _ = PinnedProcessesUI {
+                processes: pinned,
+                not_running,
+                columns: ctx.ui_state.process_table.visible_columns(),
+                units: ctx.ui_state.units,
+                palette: ctx.ui_state.palette,
+                density: ctx.ui_state.density,
+            }
+            .display(frame, pinned_area, None);
+            list_area
+        };
+
+        let subtree_pids = ctx
+            .ui_state
+            .process_table
+            .subtree
+            .as_ref()
+            .map(|subtree| subtree.pids(&processes));
+        let list_area = match subtree_pids {
+            Some(pids) if pids.is_empty() => {
+                ctx.ui_state.process_table.subtree = None;
+                list_area
+            }
+            Some(pids) => {
+                processes.retain(|p| pids.contains(&p.pid));
+                let pids: Vec<u32> = pids.into_iter().collect();
+                let disk_usage = ctx.metrics.disk_usage_of(&pids);
+                let [gauges_area, list_area] = Layout::vertical([
+                    Constraint::Length(ctx.ui_state.density.gauge_rows()),
+                    Constraint::Min(0),
+                ])
+                .areas(list_area);
+                if let Some(subtree) = ctx.ui_state.process_table.subtree.as_mut() {
+                    let disk_rates =
+                        subtree.disk_rates(&disk_usage, Instant::now(), ctx.refresh_interval);
+                    let _ = MatchedProcessesUI {
+                        label: &subtree.label(),
+                        processes: &processes,
+                        cpu_count: ctx.metrics.system.cpus().len(),
+                        total_memory: ctx.metrics.system.total_memory(),
+                        units: ctx.ui_state.units,
+                        palette: ctx.ui_state.palette,
+                        density: ctx.ui_state.density,
+                        disk_rates: Some(disk_rates),
+                    }
+                    .display(frame, gauges_area, None);
+                }
+                list_area
+            }
+            None => {
+                if let Some(matcher) = &ctx.ui_state.process_table.matcher {
+                    processes.retain(|p| matcher.matches(p));
+                }
+                list_area
+            }
+        };
+        if !ctx.ui_state.process_table.show_kernel_threads {
+            processes.retain(|p| !p.is_kernel_thread());
+        }
+        if ctx.ui_state.process_table.zombies_only {
+            processes.retain(|p| is_zombie_status(&p.status));
+        }
+        ctx.ui_state.process_table.process_count = processes.len();
+        processes.display(frame, list_area, Some(&mut *ctx.ui_state))
+    }
+}
+
+/// Detailed view of one process, updating its disk, memory and network
+/// history on every frame
+///
+/// Once the process exited a message counts down until `App::render`
+/// returns to the process list, instead of leaving the page blank.
+impl PageView for SingleProcessPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        let total_memory = ctx
+            .ui_state
+            .units
+            .to_mega(ctx.metrics.system.total_memory());
+        let Metrics::SingleProcessPid(Some(process)) = ctx
+            .metrics
+            .fetch(MetricsToFetch::SingleProcessPid(self.pid))
+        else {
+            let now = Instant::now();
+            let exited = match ctx.ui_state.exited_process {
+                Some(exited) if exited.pid == self.pid => exited,
+                _ => ExitedProcess {
+                    pid: self.pid,
+                    noticed_at: now,
+                },
+            };
+            ctx.ui_state.exited_process = Some(exited);
+            frame.render_widget(
+                paragraph_widget(
+                    format!(
+                        "\n\nProcess {} has exited\n\nReturning to the process list in {}s, press Enter to return now",
+                        self.pid,
+                        exited.seconds_left(now)
+                    ),
+                    "Process",
+                    ctx.ui_state.density,
+                )
+                .alignment(Alignment::Center),
+                area,
+            );
+            return Ok(());
+        };
+        // the PID was reused by a new process
+        ctx.ui_state.exited_process = None;
+
+        ctx.ui_state.single_process_disk_usage.update_disk_history(
+            process.basic_process_data.pid,
+            &process.disk_usage,
+            Instant::now(),
+            ctx.refresh_interval,
+        );
+        ctx.ui_state
+            .single_process_memory_usage
+            .update_memory_history(
+                process.basic_process_data.pid,
+                process.basic_process_data.memory,
+            );
+        ctx.ui_state
+            .single_process_network_usage
+            .update_network_history(
+                process.basic_process_data.pid,
+                process.network_usage.as_ref(),
+            );
+
+        SingleProcessUI { data: process }.display_process_metrics(
+            frame,
+            area,
+            total_memory,
+            ctx.ui_state,
+        )
+    }
+}
+
+/// Network interface statistics and traffic
+impl PageView for NetworkPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        if let Some(network_metrics) = ctx.network_metrics {
+            network_metrics.display(frame, area, Some(&mut *ctx.ui_state))?;
+        }
+        Ok(())
+    }
+}
+
+/// Collapsible parent/child hierarchy of processes
+impl PageView for ProcessTreePage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        if let Metrics::Processes(processes) = ctx.metrics.fetch(MetricsToFetch::Process) {
+            let tree = ProcessTree::build(&processes);
+            ProcessTreeUI { processes, tree }.display(frame, area, Some(&mut *ctx.ui_state))?;
+        }
+        Ok(())
+    }
+}
+
+/// Summed usage of every cgroup, i.e. of whole containers and services
+impl PageView for CgroupsPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        let cpu_count = ctx.metrics.system.cpus().len();
+        let total_memory = ctx.metrics.system.total_memory();
+        if let Metrics::Processes(processes) = ctx.metrics.fetch(MetricsToFetch::Process) {
+            CgroupTreeUI {
+                tree: CgroupTree::build(&processes),
+                cpu_count,
+                total_memory,
+            }
+            .display(frame, area, Some(&mut *ctx.ui_state))?;
+        }
+        Ok(())
+    }
+}
+
+/// Metrics read from the shell commands in the config
+///
+/// Commands run in the background, the page shows the last finished
+/// reading of each and explains how to add metrics while there are none.
+impl PageView for CustomPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        let Metrics::Custom(custom_metrics) = ctx.metrics.fetch(MetricsToFetch::Custom) else {
+            return Ok(());
+        };
+        if custom_metrics.is_empty() {
+            frame.render_widget(
+                paragraph_widget(
+                    "\n\nNo custom metrics configured\n\nAdd commands printing a number to `custom_metrics` in ~/.stomata/config.json, e.g.\n\n{\"custom_metrics\": [{\"name\": \"Queue depth\", \"command\": \"redis-cli llen jobs\"}]}",
+                    "Custom",
+                    ctx.ui_state.density,
+                )
+                .alignment(Alignment::Center),
+                area,
+            );
+            return Ok(());
+        }
+
+        let history_len = ctx.ui_state.history_len;
+        ctx.ui_state
+            .custom_metrics
+            .resize_with(custom_metrics.len(), || {
+                CustomMetricHistory::new(history_len)
+            });
+        for (history, metric) in ctx.ui_state.custom_metrics.iter_mut().zip(&custom_metrics) {
+            history.update(metric);
+        }
+
+        CustomMetricsUI {
+            metrics: &custom_metrics,
+            definitions: &ctx.config.custom_metrics,
+            units: ctx.ui_state.units,
+            palette: ctx.ui_state.palette,
+            density: ctx.ui_state.density,
+        }
+        .display(frame, area, Some(&mut *ctx.ui_state))
+    }
+}
+
+/// Usage of every NVIDIA GPU
+///
+/// Without an NVIDIA GPU or driver the page explains why instead of
+/// failing, the rest of the TUI is unaffected.
+#[cfg(feature = "gpu")]
+impl PageView for GpuPage {
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()> {
+        match ctx.metrics.fetch(MetricsToFetch::Gpu) {
+            Metrics::Gpu(Ok(gpu_metrics)) => {
+                gpu_metrics.display(frame, area, Some(&mut *ctx.ui_state))?
+            }
+            Metrics::Gpu(Err(reason)) => frame.render_widget(
+                paragraph_widget(
+                    format!(
+                        "\n\nGPU metrics are unsupported on this machine\n\n{reason}\n\nAn NVIDIA GPU with its driver installed is required"
+                    ),
+                    "GPU",
+                    ctx.ui_state.density,
+                )
+                .alignment(Alignment::Center),
+                area,
+            ),
+            _ => {}
+        }
+        Ok(())
+    }
+}
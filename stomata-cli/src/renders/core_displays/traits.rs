@@ -4,7 +4,7 @@
 //! follow. These traits ensure consistent rendering behavior across different
 //! system information views.
 
-use crate::structs::UIState;
+use crate::structs::{PageContext, UIState};
 use ratatui::{Frame, layout::Rect};
 
 /// Core display trait for rendering system information to the terminal UI.
@@ -50,6 +50,55 @@ pub trait Display<S> {
     ) -> anyhow::Result<()>;
 }
 
+/// Object-safe display trait for a full page of the core TUI.
+///
+/// `Display` is generic over its state because widgets need different
+/// state (or none). A page instead fetches its own metrics and renders
+/// from the `PageContext` shared by every page, so pages can be stored
+/// and dispatched uniformly as `Box<dyn PageView>`, see `Page::view`.
+///
+/// Only the active page is rendered, so a tick refreshes just the metrics
+/// it fetches, e.g. the Network page never refreshes processes.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::renders::core_displays::traits::PageView;
+///
+/// impl PageView for MyPage {
+///     fn render(
+///         &self,
+///         frame: &mut Frame,
+///         area: Rect,
+///         ctx: &mut PageContext<'_>,
+///     ) -> anyhow::Result<()> {
+///         if let Metrics::Networks(networks) = ctx.metrics.fetch(MetricsToFetch::Networks) {
+///             networks.display(frame, area, Some(&mut *ctx.ui_state))?;
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait PageView {
+    /// Fetches the page's metrics and renders the page to the given area.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - Mutable reference to the ratatui frame for rendering
+    /// * `area` - Content area below the tabs and alert banner
+    /// * `ctx` - Metrics, UI state and configuration of the app
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful rendering, or an error if rendering fails.
+    fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        ctx: &mut PageContext<'_>,
+    ) -> anyhow::Result<()>;
+}
+
 /// Specialized display trait for rendering individual process metrics.
 ///
 /// This trait extends the basic display functionality with process-specific
@@ -68,7 +117,8 @@ pub trait SingleProcessDisplay {
     ///
     /// * `frame` - Mutable reference to the ratatui frame for rendering
     /// * `area` - Screen rectangle defining where to render the metrics
-    /// * `total_memory` - Total system memory in bytes, used for percentage calculations
+    /// * `total_memory` - Total system memory in MiB or MB, matching
+    ///   `ui_state.units`, used for percentage calculations
     /// * `ui_state` - Mutable UI state for managing view state and interactions
     ///
     /// # Returns
@@ -6,9 +6,10 @@
 //!
 //! # Modules
 //!
-//! - `render_bar` - Bar chart widgets for categorical data visualization
+//! - `render_bar` - Vertical and horizontal bar charts for categorical data visualization
 //! - `render_gauge` - Progress gauges for percentage-based metrics
 //! - `render_paragraph` - Text paragraph widgets with borders and titles
+//! - `render_popup` - Centered popup overlays such as the help screen
 //! - `render_sparkline` - Compact line charts for time-series data
 //! - `render_table` - Tabular data display with sortable columns
 
@@ -16,5 +17,6 @@ pub mod render_bar;
 pub mod render_gauge;
 pub mod render_input;
 pub mod render_paragraph;
+pub mod render_popup;
 pub mod render_sparkline;
 pub mod render_table;
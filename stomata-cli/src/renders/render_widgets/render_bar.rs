@@ -1,16 +1,18 @@
 //! Bar chart rendering utilities
 //!
-//! Provides functions for creating and rendering vertical bar charts,
-//! primarily used for visualizing memory usage and other percentage-based
-//! metrics in the system monitor.
+//! Provides functions for creating and rendering vertical and horizontal bar
+//! charts, used for visualizing memory usage, top processes and other
+//! percentage-based metrics in the system monitor.
 
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout},
+    layout::{Constraint, Direction, Layout},
     style::{Style, Stylize},
-    widgets::{Bar, BarChart, BarGroup, Block, Borders},
+    widgets::{Bar, BarChart, BarGroup},
 };
 
+use crate::density::Density;
+
 /// Renders a vertical bar chart with a title to the frame.
 ///
 /// Creates a two-section layout with a title area at the top and the
@@ -20,19 +22,20 @@ use ratatui::{
 ///
 /// * `frame` - Mutable reference to the ratatui frame for rendering
 /// * `data` - Slice of f32 values representing percentages to display as bars
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Layout
 ///
 /// - Top section: Fixed 1-line height for the title "Memory In Use"
 /// - Bottom section: Remaining space for the vertical bar chart
 /// - Spacing: 1 line between sections
-pub fn render_bar(frame: &mut Frame, data: &[f32]) {
+pub fn render_bar(frame: &mut Frame, data: &[f32], density: Density) {
     let [title, vertical] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
         .spacing(1)
         .areas(frame.area());
 
     frame.render_widget("Memory In Use", title);
-    frame.render_widget(vertical_bar_chart(data), vertical);
+    frame.render_widget(vertical_bar_chart(data, density), vertical);
 }
 
 /// Creates a styled vertical bar chart widget from percentage data.
@@ -44,6 +47,7 @@ pub fn render_bar(frame: &mut Frame, data: &[f32]) {
 /// # Arguments
 ///
 /// * `data` - Slice of f32 values representing percentages (0-100)
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Returns
 ///
@@ -55,13 +59,13 @@ pub fn render_bar(frame: &mut Frame, data: &[f32]) {
 /// - Bar width: 10 characters
 /// - Bar color: Blue with white-on-blue value labels
 /// - Group label: "Usage"
-pub fn vertical_bar_chart(data: &[f32]) -> BarChart<'_> {
+pub fn vertical_bar_chart(data: &[f32], density: Density) -> BarChart<'_> {
     let bars: Vec<Bar> = data
         .iter()
         .map(|value| vertical_bar(value, String::from("Memory")))
         .collect();
     BarChart::default()
-        .block(Block::default().borders(Borders::ALL).title("Memory Usage"))
+        .block(density.block("Memory Usage"))
         .data(BarGroup::default().label("Usage".into()).bars(&bars))
         .bar_width(10)
 }
@@ -83,3 +87,56 @@ fn vertical_bar(data: &f32, label: String) -> Bar<'_> {
         .value_style(Style::new().blue().on_white())
         .text_value(format!("{:.2}%", data))
 }
+
+/// Creates a styled horizontal bar chart widget from labelled values.
+///
+/// Each entry becomes one bar, drawn top to bottom in the order given.
+/// Bars are scaled relative to the largest value in `data`.
+///
+/// # Arguments
+///
+/// * `data` - Slice of `(label, value, text_value)` tuples, where `value`
+///   sets the bar length and `text_value` is the text drawn on the bar
+/// * `title` - Title text displayed in the border
+/// * `density` - Spacing of the layout, see `Density`
+///
+/// # Returns
+///
+/// A configured `BarChart` widget ready for rendering
+///
+/// # Styling
+///
+/// - Border: All sides with title
+/// - Bar width: 1 line with a 0 line gap
+/// - Bar color: Green with black-on-green value labels
+///
+/// # Examples
+///
+/// ```ignore
+/// let data = vec![("firefox".to_string(), 5230, "52.30%".to_string())];
+/// frame.render_widget(horizontal_bar_chart(&data, "Top CPU", Density::Spacious), area);
+/// ```
+pub fn horizontal_bar_chart<'a>(
+    data: &[(String, u64, String)],
+    title: &'a str,
+    density: Density,
+) -> BarChart<'a> {
+    let bars: Vec<Bar> = data
+        .iter()
+        .map(|(label, value, text_value)| {
+            Bar::default()
+                .label(label.clone().into())
+                .value(*value)
+                .style(Style::new().green())
+                .value_style(Style::new().black().on_green())
+                .text_value(text_value.clone())
+        })
+        .collect();
+
+    BarChart::default()
+        .block(density.block(title))
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(1)
+        .bar_gap(0)
+}
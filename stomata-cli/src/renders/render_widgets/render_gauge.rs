@@ -7,14 +7,16 @@
 use ratatui::{
     style::{Color, Modifier, Style},
     text::Span,
-    widgets::{Block, Borders, Gauge},
+    widgets::Gauge,
 };
 
+use crate::{density::Density, palette::Palette};
+
 /// Creates a styled gauge widget for displaying resource usage.
 ///
 /// Renders a progress bar showing both percentage and absolute values
-/// (current/max) with the specified unit. The gauge color changes to red
-/// when usage exceeds 90% to indicate critical levels.
+/// (current/max) with the specified unit. The gauge changes to the critical
+/// color of the palette when usage exceeds 90% to indicate critical levels.
 ///
 /// # Arguments
 ///
@@ -22,6 +24,9 @@ use ratatui::{
 /// * `max` - Maximum available value (e.g., total memory in GB)
 /// * `label` - Title text displayed in the gauge border (e.g., "CPU Usage")
 /// * `unit` - Unit string for the values (e.g., "GB", "%", "MB/s")
+/// * `precision` - Decimals of the percentage and values, see `UnitConfig::precision`
+/// * `palette` - Colors of the gauge, see `Palette`
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Returns
 ///
@@ -29,14 +34,23 @@ use ratatui::{
 ///
 /// # Display Format
 ///
-/// The gauge label shows: `{percentage:.2}% ({value:.2} {unit}/ {max:.2} {unit})`
+/// The gauge label shows the raw value and the percentage together, see
+/// `gauge_label`: `{value}/{max} {unit} ({percentage}%)`
+///
+/// Example with a precision of 2: `6.04/8.00 GB (75.50%)`, with 0: `6/8 GB (76%)`
 ///
-/// Example: `75.50% (6.04 GB/ 8.00 GB)`
+/// A `%` unit means the value already is a percentage, so only that is
+/// shown: `85.50%` rather than `85.50/100.00 % (85.50%)`
+///
+/// With the compact density the gauge has no border and takes a single
+/// line, the title is put in front of the label instead:
+/// `Memory: 6.04/8.00 GB (75.50%)`
 ///
 /// # Color Scheme
 ///
-/// - **Normal (0-90%)**: Light blue gauge on black background
-/// - **Critical (>90%)**: Red gauge on black background
+/// - **Normal (0-90%)**: Light blue (sky blue with `colorblind`) gauge on black background
+/// - **Critical (>90%)**: Red (orange with `colorblind`) gauge on black
+///   background, the `colorblind` palette also prefixes the label with `▲`
 /// - Label text: White
 /// - Style: Bold
 ///
@@ -46,11 +60,11 @@ use ratatui::{
 /// use crate::renders::render_widgets::render_gauge::render_gauge;
 ///
 /// // Memory usage gauge
-/// let gauge = render_gauge(6.04, 8.0, "Memory", "GB");
+/// let gauge = render_gauge(6.04, 8.0, "Memory", "GB", 2, Palette::Default, Density::Spacious);
 /// frame.render_widget(gauge, area);
 ///
 /// // CPU usage gauge
-/// let gauge = render_gauge(85.5, 100.0, "CPU", "%");
+/// let gauge = render_gauge(85.5, 100.0, "CPU", "%", 0, Palette::Colorblind, Density::Compact);
 /// frame.render_widget(gauge, area);
 /// ```
 ///
@@ -58,28 +72,52 @@ use ratatui::{
 ///
 /// - Ratio is clamped between 0.0 and 1.0 to prevent rendering issues
 /// - Negative values are treated as 0.0
-/// - All numeric values are formatted with 2 decimal places
-pub fn render_gauge<'a>(value: f64, max: f64, label: &'a str, unit: &'a str) -> Gauge<'a> {
-    let ratio = if value > 0.0 { value / max } else { 0.0 };
+/// - A `max` of zero (e.g. no swap configured) renders an empty gauge
+///   labelled with the raw value and `—%`
+/// - All numeric values are formatted with `precision` decimal places
+pub fn render_gauge<'a>(
+    value: f64,
+    max: f64,
+    label: &'a str,
+    unit: &'a str,
+    precision: usize,
+    palette: Palette,
+    density: Density,
+) -> Gauge<'a> {
+    let ratio = if value > 0.0 && max > 0.0 {
+        value / max
+    } else {
+        0.0
+    };
     let ratio = ratio.clamp(0.0, 1.0);
+    let critical = ratio > 0.9;
+    let marker = if critical {
+        palette.critical_marker()
+    } else {
+        ""
+    };
 
+    let title = match density {
+        Density::Spacious => String::new(),
+        Density::Compact => format!("{label}: "),
+    };
     let display_label = format!(
-        "{:.2}% ({:.2} {}/ {:.2} {})",
-        ratio * 100.0,
-        value,
-        unit,
-        max,
-        unit
+        "{title}{marker}{}",
+        gauge_label(value, max, unit, precision)
     );
 
-    let gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(label))
+    let gauge = Gauge::default();
+    let gauge = match density {
+        Density::Spacious => gauge.block(density.block(label)),
+        Density::Compact => gauge,
+    };
+    let gauge = gauge
         .gauge_style(
             Style::default()
-                .fg(if ratio > 0.9 {
-                    Color::Red
+                .fg(if critical {
+                    palette.critical()
                 } else {
-                    Color::LightBlue
+                    palette.gauge()
                 })
                 .bg(Color::Black)
                 .add_modifier(Modifier::BOLD),
@@ -92,3 +130,58 @@ pub fn render_gauge<'a>(value: f64, max: f64, label: &'a str, unit: &'a str) ->
 
     return gauge;
 }
+
+/// Formats the value of a gauge together with its share of `max`.
+///
+/// # Returns
+///
+/// `{value}/{max} {unit} ({percentage}%)`, e.g. `6.20/16.00 GB (38.75%)`.
+/// When `max` is zero only the value is known: `0.00 GB (—%)`. An empty
+/// `unit` is left out along with its space, and a `%` unit gives just the
+/// percentage, e.g. `42.50%`.
+fn gauge_label(value: f64, max: f64, unit: &str, precision: usize) -> String {
+    if unit == "%" {
+        let percentage = if max > 0.0 {
+            value / max * 100.0
+        } else {
+            value
+        };
+        return format!("{:.precision$}%", percentage.max(0.0));
+    }
+    let unit = if unit.is_empty() {
+        String::new()
+    } else {
+        format!(" {unit}")
+    };
+    if max > 0.0 {
+        let percentage = (value / max * 100.0).max(0.0);
+        format!("{value:.precision$}/{max:.precision$}{unit} ({percentage:.precision$}%)")
+    } else {
+        format!("{value:.precision$}{unit} (—%)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gauge_label_shows_value_and_percentage() {
+        assert_eq!(gauge_label(6.2, 16.0, "GB", 1), "6.2/16.0 GB (38.8%)");
+        assert_eq!(gauge_label(6.2, 16.0, "GB", 0), "6/16 GB (39%)");
+        assert_eq!(gauge_label(120.0, 1000.0, "", 2), "120.00/1000.00 (12.00%)");
+    }
+
+    #[test]
+    fn test_gauge_label_of_a_percentage() {
+        assert_eq!(gauge_label(42.5, 100.0, "%", 2), "42.50%");
+        assert_eq!(gauge_label(99.6, 100.0, "%", 0), "100%");
+        assert_eq!(gauge_label(-1.0, 100.0, "%", 1), "0.0%");
+    }
+
+    #[test]
+    fn test_gauge_label_without_max() {
+        assert_eq!(gauge_label(0.0, 0.0, "MiB", 2), "0.00 MiB (—%)");
+        assert_eq!(gauge_label(3.0, 0.0, "", 0), "3 (—%)");
+    }
+}
@@ -37,12 +37,31 @@ impl InputWidgetState {
         self.character_index = self.clamp_cursor(cursor_moved_right);
     }
 
+    fn move_cursor_start(&mut self) {
+        self.reset_cursor();
+    }
+
+    fn move_cursor_end(&mut self) {
+        self.character_index = self.input.chars().count();
+    }
+
     fn enter_char(&mut self, new_char: char) {
         let index = self.byte_index();
         self.input.insert(index, new_char);
         self.move_cursor_right();
     }
 
+    /// Inserts text at the cursor and moves the cursor past it.
+    ///
+    /// Line breaks and other control characters are dropped, so a pasted
+    /// address with a trailing newline stays on one line.
+    fn enter_str(&mut self, text: &str) {
+        let text: String = text.chars().filter(|c| !c.is_control()).collect();
+        let index = self.byte_index();
+        self.input.insert_str(index, &text);
+        self.character_index += text.chars().count();
+    }
+
     /// Returns the byte index based on the character position.
     ///
     /// Since each character in a string can be contain multiple bytes, it's necessary to calculate
@@ -77,6 +96,23 @@ impl InputWidgetState {
         }
     }
 
+    /// Deletes the character under the cursor, the cursor stays in place.
+    fn delete_char_forward(&mut self) {
+        let current_index = self.character_index;
+        if current_index < self.input.chars().count() {
+            let before_cursor = self.input.chars().take(current_index);
+            let after_char_to_delete = self.input.chars().skip(current_index + 1);
+            self.input = before_cursor.chain(after_char_to_delete).collect();
+        }
+    }
+
+    /// Returns the number of characters scrolled out on the left so the
+    /// cursor stays visible in a field `width` characters wide.
+    fn scroll_offset(&self, width: usize) -> usize {
+        // keep one column free for the cursor after the last character
+        self.character_index.saturating_sub(width.saturating_sub(1))
+    }
+
     fn clamp_cursor(&self, new_cursor_pos: usize) -> usize {
         new_cursor_pos.clamp(0, self.input.chars().count())
     }
@@ -92,8 +128,11 @@ impl InputWidgetState {
     }
 
     pub fn render_input(&self, input_area: Rect, frame: &mut Frame) {
+        // long input scrolls horizontally to keep the cursor in view
+        let scroll_offset = self.scroll_offset(input_area.width.saturating_sub(2) as usize);
         // this is our input widget
         let input = Paragraph::new(self.input.as_str())
+            .scroll((0, scroll_offset as u16))
             .style(match self.input_mode {
                 InputMode::Normal => Style::default(),
                 InputMode::Editing => Style::default().fg(Color::Yellow.into()),
@@ -110,13 +149,27 @@ impl InputWidgetState {
             InputMode::Editing => frame.set_cursor_position(Position::new(
                 // Draw the cursor at the current position in the input field.
                 // This position is can be controlled via the left and right arrow key
-                input_area.x + self.character_index as u16 + 1,
+                input_area.x + (self.character_index - scroll_offset) as u16 + 1,
                 // Move one line down, from the border to the input line
                 input_area.y + 1,
             )),
         }
     }
 
+    /// Inserts pasted text at the cursor while editing.
+    ///
+    /// Requires bracketed paste to be enabled on the terminal, otherwise a
+    /// paste arrives as one key event per character.
+    pub fn handle_paste(&mut self, text: &str) -> Option<InputAction> {
+        match self.input_mode {
+            InputMode::Editing => {
+                self.enter_str(text);
+                Some(InputAction::Changed(self.input.clone()))
+            }
+            InputMode::Normal => None,
+        }
+    }
+
     pub fn handle_input_events(&mut self, key: KeyEvent) -> Option<InputAction> {
         match self.input_mode {
             InputMode::Normal => match key.code {
@@ -139,6 +192,18 @@ impl InputWidgetState {
                     self.delete_char();
                     Some(InputAction::Changed(self.input.clone()))
                 }
+                KeyCode::Delete => {
+                    self.delete_char_forward();
+                    Some(InputAction::Changed(self.input.clone()))
+                }
+                KeyCode::Home => {
+                    self.move_cursor_start();
+                    None
+                }
+                KeyCode::End => {
+                    self.move_cursor_end();
+                    None
+                }
                 KeyCode::Left => {
                     self.move_cursor_left();
                     None
@@ -147,9 +212,10 @@ impl InputWidgetState {
                     self.move_cursor_right();
                     None
                 }
+                // consumed so Esc doesn't also leave the feature
                 KeyCode::Esc => {
                     self.input_mode = InputMode::Normal;
-                    None
+                    Some(InputAction::Cancel)
                 }
                 _ => None,
             },
@@ -157,3 +223,105 @@ impl InputWidgetState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    fn editing(input: &str, character_index: usize) -> InputWidgetState {
+        InputWidgetState {
+            input: input.to_string(),
+            character_index,
+            input_mode: InputMode::Editing,
+            messages: String::new(),
+        }
+    }
+
+    fn press(state: &mut InputWidgetState, code: KeyCode) {
+        state.handle_input_events(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_cursor_movement_is_clamped() {
+        let mut state = editing("abc", 1);
+        press(&mut state, KeyCode::End);
+        assert_eq!(state.character_index, 3);
+        press(&mut state, KeyCode::Right);
+        assert_eq!(state.character_index, 3);
+        press(&mut state, KeyCode::Home);
+        assert_eq!(state.character_index, 0);
+        press(&mut state, KeyCode::Left);
+        assert_eq!(state.character_index, 0);
+    }
+
+    #[test]
+    fn test_insert_at_cursor() {
+        let mut state = editing("ac", 1);
+        press(&mut state, KeyCode::Char('b'));
+        assert_eq!(state.input, "abc");
+        assert_eq!(state.character_index, 2);
+
+        // multi-byte characters are counted as one position
+        let mut state = editing("€€", 1);
+        press(&mut state, KeyCode::Char('x'));
+        assert_eq!(state.input, "€x€");
+    }
+
+    #[test]
+    fn test_backspace_and_delete() {
+        let mut state = editing("abcd", 2);
+        press(&mut state, KeyCode::Backspace);
+        assert_eq!(state.input, "acd");
+        assert_eq!(state.character_index, 1);
+
+        press(&mut state, KeyCode::Delete);
+        assert_eq!(state.input, "ad");
+        assert_eq!(state.character_index, 1);
+
+        press(&mut state, KeyCode::End);
+        press(&mut state, KeyCode::Delete);
+        assert_eq!(state.input, "ad");
+    }
+
+    #[test]
+    fn test_esc_stops_editing() {
+        let mut state = editing("0x", 2);
+        let action = state.handle_input_events(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(matches!(action, Some(InputAction::Cancel)));
+        assert!(matches!(state.input_mode, InputMode::Normal));
+
+        // outside editing Esc is left to the feature
+        let action = state.handle_input_events(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(action.is_none());
+    }
+
+    #[test]
+    fn test_paste_inserts_at_cursor() {
+        let mut state = editing("0x", 2);
+        let address = "d8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        state.handle_paste(&format!("{address}\n"));
+        assert_eq!(state.input, format!("0x{address}"));
+        assert_eq!(state.character_index, 42);
+
+        let mut state = editing("ad", 1);
+        state.handle_paste("bc");
+        assert_eq!(state.input, "abcd");
+        assert_eq!(state.character_index, 3);
+    }
+
+    #[test]
+    fn test_paste_ignored_outside_editing() {
+        let mut state = InputWidgetState::new();
+        assert!(state.handle_paste("0xabc").is_none());
+        assert!(state.input.is_empty());
+    }
+
+    #[test]
+    fn test_scroll_offset_keeps_cursor_visible() {
+        assert_eq!(editing("abc", 3).scroll_offset(10), 0);
+        assert_eq!(editing(&"a".repeat(42), 42).scroll_offset(10), 33);
+        assert_eq!(editing(&"a".repeat(42), 5).scroll_offset(10), 0);
+    }
+}
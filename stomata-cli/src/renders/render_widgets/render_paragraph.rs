@@ -6,9 +6,11 @@
 
 use ratatui::{
     text::{Line, Text},
-    widgets::{Block, Borders, Paragraph},
+    widgets::Paragraph,
 };
 
+use crate::density::Density;
+
 /// Creates a styled paragraph widget with a border and title.
 ///
 /// Wraps text content in a bordered block with a title header, providing
@@ -18,6 +20,7 @@ use ratatui::{
 ///
 /// * `text` - The text content to display in the paragraph
 /// * `title` - The title text to show in the border
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Returns
 ///
@@ -29,13 +32,14 @@ use ratatui::{
 /// use crate::renders::render_widgets::render_paragraph::paragraph_widget;
 ///
 /// let text = "OS: Linux\nKernel: 5.15.0\nHostname: server";
-/// let widget = paragraph_widget(text, "System Info");
+/// let widget = paragraph_widget(text, "System Info", Density::Spacious);
 /// frame.render_widget(widget, area);
 /// ```
 ///
 /// # Styling
 ///
-/// - Border: All sides (top, bottom, left, right)
+/// - Border: All sides (top, bottom, left, right), none with the compact
+///   density, see `Density::block`
 /// - Title: Positioned at the top-left of the border
 /// - Text: Rendered as-is with newlines preserved
 ///
@@ -46,6 +50,7 @@ use ratatui::{
 pub fn paragraph_widget<T: Into<Text<'static>>, U: Into<Line<'static>>>(
     text: T,
     title: U,
+    density: Density,
 ) -> Paragraph<'static> {
-    Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title))
+    Paragraph::new(text).block(density.block(title))
 }
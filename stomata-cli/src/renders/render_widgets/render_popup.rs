@@ -0,0 +1,255 @@
+//! Popup overlay rendering utilities
+//!
+//! Provides helpers for drawing bordered popups centered on top of the
+//! current page, such as the keybinding help overlay, the process table
+//! column menu and the quit confirmation prompt, and the notice shown
+//! instead of a page when the terminal is too small.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::constants::{MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH};
+
+/// Computes a rectangle of the given size centered within `area`.
+///
+/// # Arguments
+///
+/// * `width` - Desired width in columns, clamped to the width of `area`
+/// * `height` - Desired height in rows, clamped to the height of `area`
+/// * `area` - The area to center the rectangle in
+///
+/// # Returns
+///
+/// The centered `Rect`
+pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width.min(area.width))])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Renders a centered help popup listing keybindings.
+///
+/// Clears the area underneath the popup so the page behind it does not
+/// bleed through, then draws one line per binding with the key highlighted:
+///
+/// ```text
+/// ┌─ Help (? to close) ──────────┐
+/// │  q        Quit               │
+/// │  Tab / →  Next tab           │
+/// │  ...                         │
+/// └──────────────────────────────┘
+/// ```
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui frame to render into
+/// * `area` - The area the popup is centered in, usually the whole frame
+/// * `bindings` - `(key, description)` pairs to list
+pub fn render_help_popup(frame: &mut Frame, area: Rect, bindings: &[(&str, &str)]) {
+    let key_width = bindings
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+    let description_width = bindings
+        .iter()
+        .map(|(_, description)| description.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines: Vec<Line> = bindings
+        .iter()
+        .map(|(key, description)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<key_width$}  ", key),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(*description),
+            ])
+        })
+        .collect();
+
+    // borders + padding on either side of the key column
+    let width = (key_width + description_width + 8) as u16;
+    let height = lines.len() as u16 + 2;
+    let popup_area = centered_rect(width, height, area);
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Help (? to close)"),
+    );
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Renders a centered checkbox menu, used for choosing the process table
+/// columns and the network interfaces to show.
+///
+/// Lists every column with a checkbox showing whether it is shown, the
+/// selected entry is highlighted:
+///
+/// ```text
+/// ┌─ Columns (o to close) ─┐
+/// │  [x] PID               │
+/// │  [x] Name              │
+/// │  [ ] Command           │
+/// │  ...                   │
+/// └────────────────────────┘
+/// ```
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui frame to render into
+/// * `area` - The area the popup is centered in, usually the whole frame
+/// * `title` - Title of the popup, e.g. `Columns (o to close)`
+/// * `columns` - `(header, shown)` pairs, one per column
+/// * `selected` - Index of the highlighted entry in `columns`
+pub fn render_column_menu_popup(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    columns: &[(&str, bool)],
+    selected: usize,
+) {
+    let lines: Vec<Line> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, (header, shown))| {
+            let checkbox = if *shown { "[x]" } else { "[ ]" };
+            let style = if index == selected {
+                Style::default().fg(Color::Black).bg(Color::White)
+            } else {
+                Style::default()
+            };
+            Line::from(Span::styled(format!("  {checkbox} {header}  "), style))
+        })
+        .collect();
+
+    let content_width = columns
+        .iter()
+        .map(|(header, _)| header.chars().count() + 8)
+        .max()
+        .unwrap_or(0);
+    // borders on either side
+    let width = content_width.max(title.chars().count()) as u16 + 2;
+    let height = lines.len() as u16 + 2;
+    let popup_area = centered_rect(width, height, area);
+
+    let popup = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Renders a small centered confirmation prompt.
+///
+/// ```text
+/// ┌─ Confirm ─────┐
+/// │  Quit? (y/n)  │
+/// └───────────────┘
+/// ```
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui frame to render into
+/// * `area` - The area the popup is centered in, usually the whole frame
+/// * `question` - The question to ask, the `(y/n)` hint is appended
+pub fn render_confirm_popup(frame: &mut Frame, area: Rect, question: &str) {
+    let text = format!("{question} (y/n)");
+    // borders + two spaces of padding on either side
+    let width = text.chars().count() as u16 + 6;
+    let popup_area = centered_rect(width, 3, area);
+
+    let popup = Paragraph::new(Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).title("Confirm"));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Renders a "terminal too small" notice instead of the page when the
+/// frame is below `MIN_TERMINAL_WIDTH` x `MIN_TERMINAL_HEIGHT`.
+///
+/// Page layouts split the frame into fixed-height gauges, sparklines and
+/// tables, which render as garbage once there's no room for them, so the
+/// render functions call this first and skip their layout when it returns
+/// `true`. The notice is redrawn on every frame and disappears as soon as
+/// the terminal is resized to fit.
+///
+/// # Arguments
+///
+/// * `frame` - The ratatui frame to render into
+///
+/// # Returns
+///
+/// `true` if the terminal is too small and the notice was rendered
+pub fn render_terminal_too_small(frame: &mut Frame) -> bool {
+    let area = frame.area();
+    if area.width >= MIN_TERMINAL_WIDTH && area.height >= MIN_TERMINAL_HEIGHT {
+        return false;
+    }
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(
+                "Terminal too small (need at least {MIN_TERMINAL_WIDTH}x{MIN_TERMINAL_HEIGHT})"
+            ),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("Current size {}x{}", area.width, area.height)),
+    ];
+    // a few rows of room for the lines to wrap in
+    let text_area = centered_rect(area.width, 4, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        text_area,
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use super::*;
+
+    fn renders_notice(width: u16, height: u16) -> bool {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut too_small = false;
+        terminal
+            .draw(|frame| too_small = render_terminal_too_small(frame))
+            .unwrap();
+        too_small
+    }
+
+    #[test]
+    fn test_terminal_too_small() {
+        assert!(renders_notice(MIN_TERMINAL_WIDTH - 1, MIN_TERMINAL_HEIGHT));
+        assert!(renders_notice(MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT - 1));
+        assert!(renders_notice(1, 1));
+        assert!(!renders_notice(MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT));
+    }
+}
@@ -6,9 +6,22 @@
 
 use ratatui::{
     style::{Color, Style},
-    widgets::{Block, Borders, Sparkline},
+    widgets::Sparkline,
 };
 
+use crate::{
+    constants::SPARKLINE_TREND_THRESHOLD, density::Density, palette::Palette, structs::Ring,
+    utils::linear_regression_slope,
+};
+
+/// Direction a series is heading in, used to color trend sparklines
+#[derive(Debug, PartialEq)]
+enum Trend {
+    Rising,
+    Falling,
+    Steady,
+}
+
 // Creates a styled sparkline widget for displaying time-series data.
 ///
 /// Renders a compact line chart with a border and title, showing the
@@ -17,9 +30,16 @@ use ratatui::{
 ///
 /// # Arguments
 ///
-/// * `data` - Slice of u64 values representing the time-series data points,
-///            ordered from oldest (left) to newest (right)
+/// * `history` - Ring buffer of the time-series data points, ordered from
+///   oldest (left) to newest (right)
 /// * `title` - Title text displayed in the border
+/// * `annotate` - When `true`, appends the min/max/average/current values
+///   of `history` to the title so flat lines can be told apart
+/// * `trend_colors` - With a palette, colors the line in its critical color
+///   (red, or orange with `colorblind`) while the series is sharply rising
+///   and its good color (green, or blue) while it is falling, white otherwise.
+///   `None` keeps the line white
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Returns
 ///
@@ -31,20 +51,29 @@ use ratatui::{
 /// use crate::renders::render_widgets::render_sparkline::render_sparkline;
 ///
 /// // CPU usage over time (0-100%)
-/// let cpu_history = vec![45, 52, 48, 65, 72, 68, 55, 50];
-/// let sparkline = render_sparkline(&cpu_history, "CPU History");
+/// let mut cpu_history = Ring::new(60);
+/// cpu_history.push(45);
+/// let sparkline = render_sparkline(&cpu_history, "CPU History", false, None, Density::Spacious);
 /// frame.render_widget(sparkline, area);
 ///
-/// // Network throughput in KB/s
-/// let network_data = vec![120, 340, 560, 420, 380, 450];
-/// let sparkline = render_sparkline(&network_data, "Network TX");
+/// // Network throughput in KB/s, titled "Network TX (min 120 max 560 avg 378 cur 450)"
+/// // and colored by trend
+/// let sparkline = render_sparkline(
+///     &network_history,
+///     "Network TX",
+///     true,
+///     Some(Palette::Default),
+///     Density::Compact,
+/// );
 /// frame.render_widget(sparkline, area);
 /// ```
 ///
 /// # Styling
 ///
-/// - Border: All sides with title at top-left
-/// - Line color: White
+/// - Border: All sides with title at top-left, only the title with the
+///   compact density
+/// - Line color: White, or the critical/good color of the palette by trend
+///   when `trend_colors` is set
 /// - Background: Transparent (inherits from terminal)
 ///
 /// # Notes
@@ -52,12 +81,102 @@ use ratatui::{
 /// - Data is displayed left-to-right (oldest to newest)
 /// - The chart automatically scales vertically based on min/max values
 /// - Works best with at least 10-20 data points for visible trends
-/// - Empty data will render an empty chart area
-pub fn render_sparkline<'a>(data: &'a [u64], title: &'a str) -> Sparkline<'a> {
+/// - Empty data will render an empty chart area without annotations
+pub fn render_sparkline<'a>(
+    history: &Ring<u64>,
+    title: &'a str,
+    annotate: bool,
+    trend_colors: Option<Palette>,
+    density: Density,
+) -> Sparkline<'a> {
+    let title = match sparkline_annotation(history) {
+        Some(annotation) if annotate => format!("{title} {annotation}"),
+        _ => title.to_string(),
+    };
+    let color = match (sparkline_trend(history.iter().copied()), trend_colors) {
+        (Trend::Rising, Some(palette)) => palette.critical(),
+        (Trend::Falling, Some(palette)) => palette.good(),
+        _ => Color::White,
+    };
+
     let sparkline = Sparkline::default()
-        .block(Block::new().borders(Borders::ALL).title(title))
-        .data(data)
-        .style(Style::default().fg(Color::White));
+        .block(density.block(title))
+        .data(history.iter().copied())
+        .style(Style::default().fg(color));
 
     sparkline
 }
+
+/// Classifies the direction of a series.
+///
+/// A series is rising when its latest value lies more than
+/// `SPARKLINE_TREND_THRESHOLD` above the window average and the least
+/// squares slope over the window is positive, falling in the mirrored case.
+/// Anything else, including series shorter than two points, is steady.
+fn sparkline_trend(data: impl IntoIterator<Item = u64>) -> Trend {
+    let values: Vec<f64> = data.into_iter().map(|v| v as f64).collect();
+    let (Some(latest), Some(slope)) = (values.last(), linear_regression_slope(&values)) else {
+        return Trend::Steady;
+    };
+    let average = values.iter().sum::<f64>() / values.len() as f64;
+    let margin = average * SPARKLINE_TREND_THRESHOLD;
+
+    if *latest > average + margin && slope > 0.0 {
+        Trend::Rising
+    } else if *latest < average - margin && slope < 0.0 {
+        Trend::Falling
+    } else {
+        Trend::Steady
+    }
+}
+
+/// Formats the min, max, average and current (latest) values of a series.
+///
+/// # Returns
+///
+/// `None` for empty data, otherwise a string like `(min 1 max 9 avg 4 cur 3)`
+fn sparkline_annotation(history: &Ring<u64>) -> Option<String> {
+    let current = history.latest()?;
+    let min = history.min()?;
+    let max = history.max()?;
+    let avg = history.mean()?;
+
+    Some(format!("(min {min} max {max} avg {avg:.0} cur {current})"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_annotation() {
+        let mut history = Ring::new(10);
+        assert_eq!(sparkline_annotation(&history), None);
+
+        for value in [1, 9, 2, 3] {
+            history.push(value);
+        }
+        assert_eq!(
+            sparkline_annotation(&history).as_deref(),
+            Some("(min 1 max 9 avg 4 cur 3)")
+        );
+    }
+
+    #[test]
+    fn test_trend_rising() {
+        assert_eq!(sparkline_trend([10, 10, 12, 11, 30, 60]), Trend::Rising);
+    }
+
+    #[test]
+    fn test_trend_falling() {
+        assert_eq!(sparkline_trend([60, 55, 58, 40, 20, 5]), Trend::Falling);
+    }
+
+    #[test]
+    fn test_trend_steady() {
+        assert_eq!(sparkline_trend([50, 52, 49, 51, 50, 53]), Trend::Steady);
+        assert_eq!(sparkline_trend([0, 0, 0, 0]), Trend::Steady);
+        assert_eq!(sparkline_trend([7]), Trend::Steady);
+        assert_eq!(sparkline_trend([]), Trend::Steady);
+    }
+}
@@ -6,35 +6,72 @@
 
 use ratatui::{
     layout::Constraint,
-    style::{Color, Style},
-    widgets::{Block, Borders, Cell, Row, Table},
+    style::{Color, Modifier, Style},
+    widgets::{Cell, Row, Table},
 };
 use stomata_core::collectors::process::metrics::ProcessData;
 use sysinfo::Process;
 
-use crate::{structs::TableRow, utils::bytes_to_mb};
+use crate::{
+    density::Density,
+    palette::Palette,
+    structs::{
+        CgroupTreeRow, ProcessColumn, ProcessColumnsRow, ProcessTreeRow, TableRow, TaskGroupRow,
+    },
+    units::UnitConfig,
+    utils::{format_local_datetime, is_zombie_status},
+};
+
+/// Marker put in front of the name of zombie/defunct processes, so they
+/// stand out without relying on color.
+fn zombie_marker(status: &str) -> &'static str {
+    if is_zombie_status(status) { "▲ " } else { "" }
+}
+
+/// Style used to highlight zombie/defunct process rows.
+fn zombie_row_style(status: &str, palette: Palette) -> Style {
+    if is_zombie_status(status) {
+        Style::default().fg(palette.critical())
+    } else {
+        Style::default()
+    }
+}
+
+/// Style of a process row, zombies highlighted and kernel threads dimmed.
+fn process_row_style(process: &ProcessData, palette: Palette) -> Style {
+    if process.is_kernel_thread() && !is_zombie_status(&process.status) {
+        Style::default().add_modifier(Modifier::DIM)
+    } else {
+        zombie_row_style(&process.status, palette)
+    }
+}
 
 /// Implements table row conversion for `ProcessData`.
 ///
-/// Formats process data into a 5-column table row with PID, name, CPU usage,
-/// memory consumption, and process status. Memory values are converted from
-/// bytes to megabytes for readability.
+/// Formats process data into a 6-column table row with PID, name, CPU usage,
+/// memory consumption, process status and container. Memory values are
+/// shown in the default binary units (MiB), without a `UnitConfig` at hand.
 ///
 /// # Column Layout
 ///
 /// 1. **PID** (8 chars): Process identifier
 /// 2. **Name** (20+ chars, flexible): Process name
 /// 3. **CPU%** (10 chars): CPU usage percentage with 2 decimal places
-/// 4. **Memory** (12 chars): Memory usage in MB
+/// 4. **Memory** (12 chars): Memory usage in MiB
 /// 5. **Status** (10 chars): Process status string
+/// 6. **Container** (16 chars): Container name, empty outside containers
+///
+/// Zombie/defunct processes are rendered in red and marked with `▲`, kernel
+/// threads dimmed.
 impl TableRow for ProcessData {
     fn to_cells(&self) -> Vec<Cell<'_>> {
         vec![
             Cell::from(self.pid.to_string()),
-            Cell::from(self.name.clone()),
+            Cell::from(format!("{}{}", zombie_marker(&self.status), self.name)),
             Cell::from(format!("{:.2}%", self.cpu_usage)),
-            Cell::from(format!("{} MB", bytes_to_mb(self.memory))),
+            Cell::from(UnitConfig::default().format_mega(self.memory)),
             Cell::from(self.status.clone()),
+            Cell::from(self.container_name.clone().unwrap_or_default()),
         ]
     }
 
@@ -45,8 +82,74 @@ impl TableRow for ProcessData {
             Constraint::Length(10), // CPU%
             Constraint::Length(12), // Memory
             Constraint::Length(10), // Status
+            Constraint::Length(16), // Container
         ]
     }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        process_row_style(self, palette)
+    }
+}
+
+/// Implements table row conversion for process rows with a chosen column set.
+///
+/// Renders only the columns in `columns`, in their order. Since the widths
+/// depend on the columns, tables of these rows are built with
+/// [`render_table_with_widths`] and [`ProcessColumn::width`]; the static
+/// `column_widths()` covers the default column set.
+///
+/// Zombie/defunct processes are rendered in red and marked with `▲`, kernel
+/// threads dimmed.
+impl TableRow for ProcessColumnsRow<'_> {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        let process = self.process;
+        // the name or command line carries the zombie marker, the first
+        // column when both are hidden
+        let marked = self
+            .columns
+            .iter()
+            .position(|column| matches!(column, ProcessColumn::Name | ProcessColumn::Command))
+            .unwrap_or(0);
+        self.columns
+            .iter()
+            .map(|column| match column {
+                ProcessColumn::Pid => process.pid.to_string(),
+                ProcessColumn::Name => process.name.clone(),
+                // kernel threads have no command line, fall back to the name
+                ProcessColumn::Command if process.cmd.is_empty() => process.name.clone(),
+                ProcessColumn::Command => process.command_line(),
+                ProcessColumn::User => process.user.clone().unwrap_or_default(),
+                ProcessColumn::Threads => process
+                    .threads
+                    .map(|threads| threads.to_string())
+                    .unwrap_or_default(),
+                ProcessColumn::Cpu => self.units.format_cpu(process.cpu_usage),
+                ProcessColumn::Memory => self.units.format_mega(process.memory),
+                ProcessColumn::Status => process.status.clone(),
+                ProcessColumn::StartTime => format_local_datetime(process.start_time),
+                ProcessColumn::Container => process.container_name.clone().unwrap_or_default(),
+            })
+            .enumerate()
+            .map(|(index, text)| {
+                if index == marked {
+                    Cell::from(format!("{}{text}", zombie_marker(&process.status)))
+                } else {
+                    Cell::from(text)
+                }
+            })
+            .collect()
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        ProcessColumn::DEFAULT
+            .iter()
+            .map(ProcessColumn::width)
+            .collect()
+    }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        process_row_style(self.process, palette)
+    }
 }
 
 /// Implements table row conversion for `sysinfo::Process` references.
@@ -59,15 +162,21 @@ impl TableRow for ProcessData {
 /// 1. **PID** (8 chars): Process identifier
 /// 2. **Name** (20+ chars, flexible): Process name
 /// 3. **CPU%** (10 chars): CPU usage percentage with 2 decimal places
-/// 4. **Memory** (12 chars): Memory usage in MB
+/// 4. **Memory** (12 chars): Memory usage in MiB
 /// 5. **Status** (10 chars): Process status string
+///
+/// Zombie/defunct processes are rendered in red and marked with `▲`.
 impl TableRow for &Process {
     fn to_cells(&self) -> Vec<Cell<'_>> {
         vec![
             Cell::from(self.pid().as_u32().to_string()),
-            Cell::from(self.name().to_string_lossy().to_string()),
+            Cell::from(format!(
+                "{}{}",
+                zombie_marker(&self.status().to_string()),
+                ProcessData::sanitize(self.name())
+            )),
             Cell::from(format!("{:.2}%", self.cpu_usage())),
-            Cell::from(format!("{} MB", bytes_to_mb(self.memory()))),
+            Cell::from(UnitConfig::default().format_mega(self.memory())),
             Cell::from(self.status().to_string()),
         ]
     }
@@ -81,6 +190,158 @@ impl TableRow for &Process {
             Constraint::Length(10), // Status
         ]
     }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        zombie_row_style(&self.status().to_string(), palette)
+    }
+}
+
+/// Implements table row conversion for the grouped task table.
+///
+/// Uses the same columns as the ungrouped task table. The summary row of a
+/// group shows the task count instead of a PID, the summed CPU usage and
+/// the memory the tasks share, with a marker showing whether its tasks are
+/// listed below it:
+///
+/// ```text
+/// │ PID  │ Name             │ CPU    │ Memory    │ Status │
+/// │ 12×  │ ▼ GC Thread      │ 5.00%  │ 1.00 GiB  │        │
+/// │ 4811 │     GC Thread#0  │ 2.00%  │ 1.00 GiB  │ Sleep  │
+/// │ 4812 │     GC Thread#1  │ 3.00%  │ 1.00 GiB  │ Sleep  │
+/// │ 1×   │ ▶ C2 Compiler    │ 5.00%  │ 1.00 GiB  │        │
+/// ```
+///
+/// Zombie/defunct tasks are rendered in red and marked with `▲`.
+impl TableRow for TaskGroupRow<'_> {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        match self.task {
+            Some(task) => vec![
+                Cell::from(task.pid().as_u32().to_string()),
+                Cell::from(format!(
+                    "    {}{}",
+                    zombie_marker(&task.status().to_string()),
+                    ProcessData::sanitize(task.name())
+                )),
+                Cell::from(self.units.format_cpu(task.cpu_usage())),
+                Cell::from(self.units.format_mega(task.memory())),
+                Cell::from(task.status().to_string()),
+            ],
+            None => vec![
+                Cell::from(format!("{}×", self.group.indices.len())),
+                Cell::from(format!(
+                    "{} {}",
+                    if self.expanded { "▼" } else { "▶" },
+                    self.group.name
+                )),
+                Cell::from(self.units.format_cpu(self.group.cpu_usage)),
+                Cell::from(self.units.format_mega(self.group.memory)),
+                Cell::from(""),
+            ],
+        }
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Length(8),  // PID or task count
+            Constraint::Min(20),    // Name (flexible)
+            Constraint::Length(10), // CPU%
+            Constraint::Length(12), // Memory
+            Constraint::Length(10), // Status
+        ]
+    }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        self.task
+            .map(|task| zombie_row_style(&task.status().to_string(), palette))
+            .unwrap_or_default()
+    }
+}
+
+/// Implements table row conversion for process tree rows.
+///
+/// Indents the process name by its depth in the tree and prefixes it with
+/// a marker showing whether the node is expanded, collapsed or a leaf.
+///
+/// # Column Layout
+///
+/// 1. **Tree** (30+ chars, flexible): Indented process name
+/// 2. **PID** (8 chars): Process identifier
+/// 3. **CPU%** (10 chars): CPU usage per core or normalized, see `UnitConfig`
+/// 4. **Memory** (12 chars): Memory usage in MiB or MB, see `UnitConfig`
+/// 5. **Status** (10 chars): Process status string
+///
+/// Zombie/defunct processes are rendered in red and marked with `▲`, kernel
+/// threads dimmed.
+impl TableRow for ProcessTreeRow<'_> {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        let marker = match (self.node.has_children, self.node.collapsed) {
+            (true, true) => "▶",
+            (true, false) => "▼",
+            (false, _) => "•",
+        };
+        vec![
+            Cell::from(format!(
+                "{}{} {}{}",
+                "  ".repeat(self.node.depth),
+                marker,
+                zombie_marker(&self.process.status),
+                self.process.name
+            )),
+            Cell::from(self.process.pid.to_string()),
+            Cell::from(self.units.format_cpu(self.process.cpu_usage)),
+            Cell::from(self.units.format_mega(self.process.memory)),
+            Cell::from(self.process.status.clone()),
+        ]
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Min(30),    // Tree (flexible)
+            Constraint::Length(8),  // PID
+            Constraint::Length(10), // CPU%
+            Constraint::Length(12), // Memory
+            Constraint::Length(10), // Status
+        ]
+    }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        process_row_style(self.process, palette)
+    }
+}
+
+/// Implements table row conversion for cgroup tree rows.
+///
+/// # Column Layout
+///
+/// 1. **Cgroup** (30+ chars, flexible): Last path segment, indented by depth.
+///    The root is shown as `/`
+/// 2. **Processes** (10 chars): Processes in the cgroup and its children
+/// 3. **CPU** (10 chars): Summed CPU usage in percent of one core
+/// 4. **Memory** (12 chars): Summed memory in MiB or MB, see `UnitConfig`
+impl TableRow for CgroupTreeRow {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        let node = &self.node;
+        let name = node.path.rsplit('/').next().filter(|name| !name.is_empty());
+        vec![
+            Cell::from(format!(
+                "{}{}",
+                "  ".repeat(node.depth),
+                name.unwrap_or(&node.path)
+            )),
+            Cell::from(node.usage.process_count.to_string()),
+            Cell::from(format!("{:.2}%", node.usage.cpu_usage)),
+            Cell::from(self.units.format_mega(node.usage.memory)),
+        ]
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Min(30),    // Cgroup (flexible)
+            Constraint::Length(10), // Processes
+            Constraint::Length(10), // CPU%
+            Constraint::Length(12), // Memory
+        ]
+    }
 }
 
 /// Creates a generic styled table widget from any type implementing `TableRow`.
@@ -99,6 +360,8 @@ impl TableRow for &Process {
 /// * `headers` - Column header labels (e.g., `["PID", "Name", "CPU%"]`)
 /// * `items` - Slice of data items to display in the table
 /// * `title` - Title text displayed in the border
+/// * `palette` - Colors of highlighted rows, see `Palette`
+/// * `density` - Spacing of the layout, see `Density`
 ///
 /// # Returns
 ///
@@ -107,8 +370,10 @@ impl TableRow for &Process {
 /// # Styling
 ///
 /// - **Header**: White text on black background
-/// - **Normal rows**: White text on terminal background
-/// - **Selected row**: Black text on white background with ">>" highlight symbol
+/// - **Normal rows**: White text on terminal background, overridden per row
+///   by `TableRow::row_style()` (e.g. zombie processes in the critical color)
+/// - **Selected row**: Black text on white background with ">>" highlight symbol,
+///   bold as well with the `colorblind` palette
 /// - **Border**: All sides with title
 ///
 /// # Examples
@@ -118,7 +383,7 @@ impl TableRow for &Process {
 ///
 /// let headers = vec!["PID", "Name", "CPU%", "Memory", "Status"];
 /// let processes: Vec<ProcessData> = get_processes();
-/// let table = render_table(headers, &processes, "Process List");
+/// let table = render_table(headers, &processes, "Process List", Palette::Default, Density::Spacious);
 ///
 /// // Render with state for selection
 /// frame.render_stateful_widget(table, area, &mut table_state);
@@ -129,7 +394,45 @@ impl TableRow for &Process {
 /// - Column widths are defined by the `TableRow::column_widths()` implementation
 /// - The table requires a `TableState` for rendering selection state
 /// - All rows have a fixed height of 1 line
-pub fn render_table<'a, T>(headers: Vec<&'a str>, items: &'a [T], title: &'a str) -> Table<'a>
+pub fn render_table<'a, T>(
+    headers: Vec<&'a str>,
+    items: &'a [T],
+    title: &'a str,
+    palette: Palette,
+    density: Density,
+) -> Table<'a>
+where
+    T: TableRow,
+{
+    render_table_with_widths(headers, items, title, T::column_widths(), palette, density)
+}
+
+/// Creates a styled table widget like [`render_table`], with column widths
+/// given at runtime instead of by `TableRow::column_widths()`.
+///
+/// Used for tables whose column set is chosen by the user, such as the
+/// process table.
+///
+/// # Arguments
+///
+/// * `headers` - Column header labels, one per width
+/// * `items` - Slice of data items to display in the table
+/// * `title` - Title text displayed in the border
+/// * `widths` - Width constraint of each column
+/// * `palette` - Colors of highlighted rows, see `Palette`
+/// * `density` - Spacing of the layout, see `Density`
+///
+/// # Returns
+///
+/// A configured `Table` widget ready for rendering with a `TableState`
+pub fn render_table_with_widths<'a, T>(
+    headers: Vec<&'a str>,
+    items: &'a [T],
+    title: &'a str,
+    widths: Vec<Constraint>,
+    palette: Palette,
+    density: Density,
+) -> Table<'a>
 where
     T: TableRow,
 {
@@ -146,14 +449,62 @@ where
         .iter()
         .map(|item| {
             let cells = item.to_cells();
-            Row::new(cells).height(1)
+            Row::new(cells).height(1).style(item.row_style(palette))
         })
         .collect();
 
-    Table::new(rows, T::column_widths())
-        .row_highlight_style(Style::default().bg(Color::White).fg(Color::Black))
+    Table::new(rows, widths)
+        .row_highlight_style(palette.row_highlight())
         .highlight_symbol(">>")
         .header(header)
-        .block(Block::default().title(title).borders(Borders::ALL))
+        .block(density.block(title))
         .style(Style::default().fg(Color::White))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(status: &str) -> ProcessData {
+        ProcessData {
+            pid: 42,
+            name: "worker".to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_zombie_rows_are_marked_without_color() {
+        let zombie = process("Zombie");
+        let row = ProcessColumnsRow {
+            process: &zombie,
+            columns: &[ProcessColumn::Pid, ProcessColumn::Name],
+            units: UnitConfig::default(),
+        };
+        let cells = row.to_cells();
+        assert_eq!(cells[0], Cell::from("42"));
+        assert_eq!(cells[1], Cell::from("▲ worker"));
+        assert_eq!(zombie.to_cells()[1], Cell::from("▲ worker"));
+
+        // without a name column the first one is marked
+        let row = ProcessColumnsRow {
+            process: &zombie,
+            columns: &[ProcessColumn::Pid, ProcessColumn::Cpu],
+            units: UnitConfig::default(),
+        };
+        assert_eq!(row.to_cells()[0], Cell::from("▲ 42"));
+    }
+
+    #[test]
+    fn test_running_rows_have_no_marker() {
+        let running = process("Runnable");
+        let row = ProcessColumnsRow {
+            process: &running,
+            columns: &[ProcessColumn::Name],
+            units: UnitConfig::default(),
+        };
+        assert_eq!(row.to_cells()[0], Cell::from("worker"));
+        assert_eq!(running.to_cells()[1], Cell::from("worker"));
+    }
+}
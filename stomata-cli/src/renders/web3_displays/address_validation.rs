@@ -4,24 +4,34 @@
 //! stomata_web3 address validation system. Used for verifying address
 //! format and checksums across different blockchain networks.
 
-use stomata_web3::providers::address::{AddressValidator, ValidationResult};
+use serde_json::{Value, json};
+use stomata_web3::providers::address::{AddressValidator, Chain, ValidationResult};
+
+use crate::renders::web3_displays::json_output::print_json;
 
 /// Validates a blockchain address and prints the validation result.
 ///
 /// Performs comprehensive validation on the provided address string,
-/// checking format, checksum, and network compatibility. The validation
-/// result is printed to stdout for debugging purposes.
+/// checking format, checksum, and network compatibility. The detected chain
+/// (EVM, Bitcoin or Solana) is printed along with the validation result.
 ///
 /// # Arguments
 ///
 /// * `address` - The blockchain address string to validate (e.g., Ethereum address)
+/// * `json` - Print the result as a JSON object instead of text
 ///
 /// # Validation Checks
 ///
 /// The validator typically checks:
 /// - Address format (length, character set)
-/// - Checksum validity (if applicable)
+/// - Checksum validity (if applicable). Mixed-case EVM addresses whose
+///   casing does not match EIP-55 are reported as a checksum mismatch, while
+///   all-lowercase/uppercase addresses pass with a note that there was no
+///   checksum to verify
 /// - Network-specific requirements
+/// - Chain detection (EVM hex, Bitcoin base58check/bech32, Solana base58)
+/// - Special EVM addresses (zero, burn and precompile addresses), which are
+///   well-formed but almost always a mistake, are reported with a warning
 ///
 /// # Examples
 ///
@@ -29,18 +39,34 @@ use stomata_web3::providers::address::{AddressValidator, ValidationResult};
 /// use crate::validate_address;
 ///
 /// // Valid Ethereum address
-/// validate_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb");
-/// // Output: ValidationResult::Valid
+/// validate_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb", false);
+/// // Output: Chain: EVM
+/// //         Valid { chain: Evm, checksummed: "0x..." }
+///
+/// // Mistyped checksum
+/// validate_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", false);
+/// // Output: WARNING: checksum mismatch, expected 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed
+///
+/// // Burn address
+/// validate_address("0x000000000000000000000000000000000000dEaD", false);
+/// // Output: WARNING: burn address, burn addresses have no known key, funds sent to them are lost
 ///
 /// // Invalid address
-/// validate_address("0xinvalid");
+/// validate_address("0xinvalid", false);
 /// // Output: ValidationResult::Invalid { reason: ... }
+///
+/// // Scriptable output
+/// validate_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb", true);
+/// // Output: {"chain":"EVM","checksummed":"0x...","valid":true}
 /// ```
 ///
 /// # Output Format
 ///
-/// The function prints the `ValidationResult` enum using debug formatting,
-/// which includes detailed information about validation success or failure.
+/// For valid addresses the detected chain is printed first, followed by the
+/// `ValidationResult` enum using debug formatting, which includes detailed
+/// information about validation success or failure.
+///
+/// With `json` a single object is printed instead, see [`validation_json`].
 ///
 /// # Notes
 ///
@@ -48,7 +74,80 @@ use stomata_web3::providers::address::{AddressValidator, ValidationResult};
 /// - For production use, consider using `AddressValidator::validate()` directly
 ///   and handling the `ValidationResult` programmatically
 /// - The validation logic is provided by the `stomata_web3` crate
-pub fn validate_address(address: &str) {
+pub fn validate_address(address: &str, json: bool) {
     let result = AddressValidator::validate(address);
+    if json {
+        print_json(&validation_json(&result));
+        return;
+    }
+    match &result {
+        ValidationResult::Valid { chain, checksummed } => {
+            println!("Chain: {}", chain);
+            if *chain == Chain::Evm && address != checksummed {
+                println!("Note: address is single-case, there was no checksum to verify");
+            }
+        }
+        ValidationResult::InvalidChecksum { chain } => println!("Chain: {}", chain),
+        ValidationResult::ChecksumMismatch { expected } => {
+            println!("Chain: {}", Chain::Evm);
+            println!(
+                "WARNING: checksum mismatch, expected {}. The address may be corrupted or mistyped, do not send funds to it.",
+                expected
+            );
+        }
+        ValidationResult::SpecialAddress { kind, .. } => {
+            println!("Chain: {}", Chain::Evm);
+            println!("WARNING: {}, {}", kind, kind.explanation());
+        }
+        _ => println!("Chain: unknown"),
+    }
     println!("{:?}", result);
 }
+
+/// Converts a validation result to the JSON printed with `--json`.
+///
+/// Valid addresses give `{"valid":true,"chain":"EVM","checksummed":"0x..."}`.
+/// Invalid ones give `{"valid":false,"chain":...,"reason":"..."}`, where
+/// `chain` is `null` when it couldn't be detected and a checksum mismatch
+/// also has the `expected` address. Zero, burn and precompile addresses are
+/// reported as invalid with their `checksummed` form and an `explanation`.
+///
+/// # Arguments
+///
+/// * `result` - Result of `AddressValidator::validate`
+///
+/// # Returns
+///
+/// The JSON object for the result
+pub fn validation_json(result: &ValidationResult) -> Value {
+    let invalid = |chain: Option<Chain>, reason: &str| {
+        json!({
+            "valid": false,
+            "chain": chain.map(|c| c.to_string()),
+            "reason": reason,
+        })
+    };
+
+    match result {
+        ValidationResult::Valid { chain, checksummed } => json!({
+            "valid": true,
+            "chain": chain.to_string(),
+            "checksummed": checksummed,
+        }),
+        ValidationResult::InvalidLength => invalid(None, "invalid length"),
+        ValidationResult::InvalidPrefix => invalid(None, "invalid prefix"),
+        ValidationResult::InvalidCharacters => invalid(None, "invalid characters"),
+        ValidationResult::InvalidChecksum { chain } => invalid(Some(*chain), "invalid checksum"),
+        ValidationResult::ChecksumMismatch { expected } => {
+            let mut value = invalid(Some(Chain::Evm), "checksum mismatch");
+            value["expected"] = json!(expected);
+            value
+        }
+        ValidationResult::SpecialAddress { kind, checksummed } => {
+            let mut value = invalid(Some(Chain::Evm), &kind.to_string());
+            value["checksummed"] = json!(checksummed);
+            value["explanation"] = json!(kind.explanation());
+            value
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! JSON output for the non-interactive Web3 commands
+//!
+//! With `--json` results are printed to stdout as a single JSON value and
+//! errors to stderr as `{"error":"..."}`, so the commands can be scripted.
+
+use std::fmt::Display;
+
+use serde_json::{Value, json};
+use zeroize::Zeroizing;
+
+/// Prints `value` to stdout on a single line.
+pub fn print_json(value: &Value) {
+    println!("{}", value);
+}
+
+/// Prints `{"error":"<message>"}` to stderr.
+///
+/// # Arguments
+///
+/// * `message` - Human readable error, formatted with `Display`
+pub fn print_json_error(message: impl Display) {
+    eprintln!("{}", error_json(message));
+}
+
+/// Prints `{"name":"<name>","plaintext":"<plaintext>"}` to stdout.
+///
+/// The document is serialized by hand rather than through a `Value`, so
+/// the only copy of the plaintext besides the caller's is wiped after
+/// printing.
+pub fn print_json_plaintext(name: &str, plaintext: &str) {
+    println!("{}", plaintext_json(name, plaintext).as_str());
+}
+
+fn plaintext_json(name: &str, plaintext: &str) -> Zeroizing<String> {
+    // serializing a str can't fail
+    let plaintext = Zeroizing::new(serde_json::to_string(plaintext).unwrap_or_default());
+    Zeroizing::new(format!(
+        "{{\"name\":{},\"plaintext\":{}}}",
+        Value::from(name),
+        plaintext.as_str()
+    ))
+}
+
+fn error_json(message: impl Display) -> Value {
+    json!({ "error": message.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_json_shape() {
+        assert_eq!(
+            error_json("Key 'wallet' not found").to_string(),
+            r#"{"error":"Key 'wallet' not found"}"#
+        );
+    }
+
+    #[test]
+    fn test_plaintext_json_shape() {
+        let document = plaintext_json("api \"key\"", "line\nbreak");
+        assert_eq!(
+            document.as_str(),
+            r#"{"name":"api \"key\"","plaintext":"line\nbreak"}"#
+        );
+        let value: Value = serde_json::from_str(&document).unwrap();
+        assert_eq!(value["plaintext"], "line\nbreak");
+    }
+}
@@ -4,11 +4,26 @@
 //! using password-based encryption. Keys are stored locally in encrypted
 //! form and can only be decrypted with the correct password.
 
-use std::process::exit;
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal, Read, Write},
+    path::Path,
+    process::exit,
+};
 
-use stomata_web3::providers::{delete_key, list_keys, retrieve_key, store_key};
+use stomata_web3::{
+    constants::MIN_PASSWORD_ENTROPY_BITS,
+    providers::{
+        delete_key, estimate_entropy, key_exists, list_keys_with_metadata, parse_private_key,
+        retrieve_key, store_key,
+    },
+};
+use zeroize::Zeroizing;
 
-use crate::features::web3::cli::OutputFormat;
+use crate::{
+    features::web3::cli::OutputFormat,
+    renders::web3_displays::json_output::{print_json, print_json_error, print_json_plaintext},
+};
 
 /// Securely prompts the user for sensitive information without echoing to terminal.
 ///
@@ -21,7 +36,7 @@ use crate::features::web3::cli::OutputFormat;
 ///
 /// # Returns
 ///
-/// The entered string, or exits the program on error
+/// The entered string, wiped from memory when dropped, or exits the program on error
 ///
 /// # Examples
 ///
@@ -33,9 +48,9 @@ use crate::features::web3::cli::OutputFormat;
 /// # Panics
 ///
 /// Calls `exit(0)` if reading from stdin fails
-fn ask_sensitive_info(ask_text: &str) -> String {
+fn ask_sensitive_info(ask_text: &str) -> Zeroizing<String> {
     let info = match rpassword::prompt_password(ask_text) {
-        Ok(pw) => pw,
+        Ok(pw) => Zeroizing::new(pw),
         Err(err) => {
             eprintln!("Error in reading entered data");
             exit(0)
@@ -53,6 +68,8 @@ fn ask_sensitive_info(ask_text: &str) -> String {
 /// # Arguments
 ///
 /// * `name` - Identifier for the stored key (used for later retrieval)
+/// * `force` - Encrypt even if the password is rated as weak
+/// * `json` - Print errors as `{"error":"..."}` JSON
 ///
 /// # User Prompts
 ///
@@ -64,38 +81,181 @@ fn ask_sensitive_info(ask_text: &str) -> String {
 /// Prints an error message to stderr if encryption or storage fails,
 /// but does not exit the program.
 ///
+/// Refuses to encrypt (and never asks for the key) if the password's
+/// estimated entropy is below `MIN_PASSWORD_ENTROPY_BITS`, unless `force` is set.
+///
+/// A name that is already taken is refused before any prompt, an existing
+/// key is never overwritten.
+///
 /// # Examples
 ///
 /// ```ignore
 /// use crate::features::web3::crypto::encrypt_key;
 ///
 /// // User will be prompted for password and key
-/// encrypt_key("my_wallet_key".to_string());
+/// encrypt_key("my_wallet_key".to_string(), false, false);
 /// ```
 ///
 /// # Security Notes
 ///
 /// - Password is never stored, only used for encryption
 /// - Key input is not echoed to terminal
+/// - Password and key are wiped from memory once encrypted
 /// - Encrypted data is stored locally by `stomata_web3`
-pub fn encrypt_key(name: String) {
-    let password = ask_sensitive_info("Password: ");
+pub fn encrypt_key(name: String, force: bool, json: bool) {
+    if !check_new_key_name(&name, json) {
+        return;
+    }
+    let Some(password) = ask_encryption_password(force, json) else {
+        return;
+    };
     let pk = ask_sensitive_info("Key to encrypt: ");
     let res = store_key(name.as_str(), pk.as_bytes(), password.as_str());
     if let Err(err) = res {
-        eprintln!("Error in encrypting key {:?}", err);
+        if json {
+            print_json_error(err);
+        } else {
+            eprintln!("Error in encrypting key {:?}", err);
+        }
+    }
+}
+
+/// Checks that no key is stored under `name` yet, so a new key can't
+/// replace one.
+///
+/// # Returns
+///
+/// `false` after printing why if the name is taken or invalid
+fn check_new_key_name(name: &str, json: bool) -> bool {
+    let message = match key_exists(name) {
+        Ok(false) => return true,
+        Ok(true) => format!(
+            "Key '{name}' already exists. Choose another name, or delete the old key first with `stomata web3 key delete -n {name}`"
+        ),
+        Err(err) => err.to_string(),
+    };
+    if json {
+        print_json_error(message);
+    } else {
+        eprintln!("{}", message);
+    }
+    false
+}
+
+/// Prompts for the password to encrypt a key with and checks its strength.
+///
+/// # Returns
+///
+/// The password, or `None` after printing why if its estimated entropy is
+/// below `MIN_PASSWORD_ENTROPY_BITS` and `force` isn't set
+fn ask_encryption_password(force: bool, json: bool) -> Option<Zeroizing<String>> {
+    let password = ask_sensitive_info("Password: ");
+    let entropy = estimate_entropy(&password);
+    if !force && entropy < MIN_PASSWORD_ENTROPY_BITS {
+        let message = format!(
+            "Password is too weak (estimated {:.0} bits of entropy, at least {:.0} required).\nUse a longer password mixing upper and lowercase letters, digits and symbols, or pass --force to encrypt anyway.",
+            entropy, MIN_PASSWORD_ENTROPY_BITS
+        );
+        if json {
+            print_json_error(message);
+        } else {
+            eprintln!("{}", message);
+        }
+        return None;
+    }
+    Some(password)
+}
+
+/// Imports a private key generated elsewhere into the key store.
+///
+/// Reads the key from `from_file`, or from stdin (prompted without echo on
+/// a terminal), validates it and encrypts it like [`encrypt_key`]. The name
+/// and key are validated before any password is asked for.
+///
+/// # Arguments
+///
+/// * `name` - Identifier for the stored key
+/// * `from_file` - File holding the key, stdin when `None`
+/// * `force` - Encrypt even if the password is rated as weak
+/// * `json` - Print the confirmation and errors as JSON
+///
+/// # Accepted Formats
+///
+/// - 64 hex characters, with or without `0x`
+/// - v3 keystore JSON (scrypt or pbkdf2, aes-128-ctr), decrypted with the
+///   password prompted as "Keystore password: "
+///
+/// The key must be 32 bytes within the secp256k1 range. It is stored as
+/// `0x` prefixed lowercase hex so `decrypt` shows it like a typed key.
+///
+/// # Errors
+///
+/// Prints an error message (never the key) if the input can't be read or
+/// isn't a valid key, the keystore password is wrong or storing fails.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Import a geth keystore, prompting for its password and the new one
+/// import_key("wallet".to_string(), Some(Path::new("keystore.json")), false, false);
+/// ```
+pub fn import_key(name: String, from_file: Option<&Path>, force: bool, json: bool) {
+    if !check_new_key_name(&name, json) {
+        return;
+    }
+    let print_error = |message: String| {
+        if json {
+            print_json_error(message);
+        } else {
+            eprintln!("{}", message);
+        }
+    };
+
+    let input = match from_file {
+        Some(path) => fs::read_to_string(path)
+            .map(Zeroizing::new)
+            .map_err(|err| format!("Failed to read {}: {err}", path.display())),
+        None if io::stdin().is_terminal() => Ok(ask_sensitive_info("Private key: ")),
+        None => {
+            let mut input = Zeroizing::new(String::new());
+            io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|err| format!("Failed to read the key from stdin: {err}"))
+        }
+    };
+    let key = match input.and_then(|input| {
+        parse_private_key(&input, || ask_sensitive_info("Keystore password: "))
+            .map_err(|err| format!("Invalid key: {err}"))
+    }) {
+        Ok(key) => key,
+        Err(message) => return print_error(message),
+    };
+
+    let Some(password) = ask_encryption_password(force, json) else {
+        return;
+    };
+    let hex_key = Zeroizing::new(format!("0x{}", hex::encode(key.as_slice())));
+    match store_key(name.as_str(), hex_key.as_bytes(), password.as_str()) {
+        Ok(()) if json => print_json(&serde_json::json!({ "name": name, "imported": true })),
+        Ok(()) => println!("Key '{}' imported", name),
+        Err(err) => print_error(format!("Error in importing key: {err}")),
     }
 }
 
 /// Decrypts and displays a stored encrypted key.
 ///
 /// Prompts the user for the password used during encryption, then retrieves
-/// and decrypts the key. The decrypted key is displayed in the requested format.
+/// and decrypts the key. The decrypted key is displayed in the requested format,
+/// or written to `out` with only a confirmation printed.
 ///
 /// # Arguments
 ///
 /// * `name` - Identifier of the stored key to decrypt
 /// * `format` - Output format for the decrypted key (Hex or UTF-8)
+/// * `out` - File to write the key to instead of stdout, see [`write_secret_file`]
+/// * `json` - Print the key as `{"name","plaintext"}` JSON, or where it was
+///   written as `{"name","out"}`, and errors as `{"error":"..."}`
 ///
 /// # User Prompts
 ///
@@ -112,7 +272,12 @@ pub fn encrypt_key(name: String) {
 /// - Key name doesn't exist
 /// - Password is incorrect
 /// - Decryption fails
-/// - UTF-8 conversion fails (for UTF-8 format)
+///
+/// unless `json` is set, in which case the failure is printed as JSON.
+///
+/// Prints an error message (without the key bytes) if the key is not
+/// valid UTF-8 and the UTF-8 format was requested, or if `out` can't be
+/// written.
 ///
 /// # Examples
 ///
@@ -120,61 +285,211 @@ pub fn encrypt_key(name: String) {
 /// use crate::features::web3::crypto::{decrypt_key, OutputFormat};
 ///
 /// // Display as hex
-/// decrypt_key("my_wallet_key".to_string(), OutputFormat::Hex);
+/// decrypt_key("my_wallet_key".to_string(), OutputFormat::Hex, None, false);
 ///
 /// // Display as UTF-8
-/// decrypt_key("my_api_key".to_string(), OutputFormat::Utf8);
+/// decrypt_key("my_api_key".to_string(), OutputFormat::Utf8, None, false);
+///
+/// // Write to a file only the owner can read
+/// decrypt_key("my_wallet_key".to_string(), OutputFormat::Hex, Some(Path::new("key.txt")), false);
 /// ```
 ///
 /// # Security Notes
 ///
 /// - Password verification is implicit (wrong password = decryption failure)
-/// - Decrypted data is printed to stdout (use with caution)
-pub fn decrypt_key(name: String, format: OutputFormat) {
+/// - Decrypted data is printed to stdout (use with caution) unless `out`
+///   is given
+/// - Decrypted data is wiped from memory after printing and never written
+///   to error output
+pub fn decrypt_key(name: String, format: OutputFormat, out: Option<&Path>, json: bool) {
     let password = ask_sensitive_info("Password: ");
-    let res = retrieve_key(name.as_str(), password.as_str());
-    if let Ok(data) = res {
-        match format {
-            OutputFormat::Hex => println!("{:?}", hex::encode(&data)),
-            OutputFormat::Utf8 => println!(
-                "{:?}",
-                String::from_utf8(data).expect("Failed to decrypt key to utf-8")
-            ),
+    let data = match retrieve_key(name.as_str(), password.as_str()) {
+        Ok(data) => data,
+        Err(err) => {
+            if json {
+                print_json_error(err);
+            }
+            return;
         }
     };
+    let text = match format {
+        OutputFormat::Hex => Zeroizing::new(hex::encode(data.as_slice())),
+        OutputFormat::Utf8 => match std::str::from_utf8(&data) {
+            Ok(text) => Zeroizing::new(text.to_string()),
+            Err(_) => {
+                let message = "Decrypted key is not valid utf-8, use the hex format instead";
+                if json {
+                    print_json_error(message);
+                } else {
+                    eprintln!("{}", message);
+                }
+                return;
+            }
+        },
+    };
+    let Some(out) = out else {
+        if json {
+            print_json_plaintext(&name, &text);
+        } else {
+            println!("{:?}", text.as_str());
+        }
+        return;
+    };
+    match write_secret_file(out, text.as_bytes()) {
+        Ok(()) if json => {
+            print_json(&serde_json::json!({ "name": name, "out": out.display().to_string() }))
+        }
+        Ok(()) => println!("Key '{}' written to {}", name, out.display()),
+        Err(err) if json => print_json_error(err),
+        Err(err) => eprintln!("{}", err),
+    }
 }
 
-/// Lists all stored encrypted key names.
+/// Writes `contents` to a new file at `path` that only the owner can read.
+///
+/// The file is created with `0600` permissions on Unix and never replaces an
+/// existing file, which could already be readable by others. Directories
+/// other users can read (e.g. a `0755` home) or write to (e.g. `/tmp`) are
+/// refused, as they could see the file or swap it.
+///
+/// # Errors
+///
+/// Returns a message naming the path if the directory is refused, the file
+/// exists or the write fails.
+fn write_secret_file(path: &Path, contents: &[u8]) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mode = std::fs::metadata(dir)
+            .map_err(|err| format!("Failed to read {}: {err}", dir.display()))?
+            .permissions()
+            .mode();
+        if mode & 0o006 != 0 {
+            return Err(format!(
+                "Refusing to write the key to {}, other users can read or write to it",
+                dir.display()
+            ));
+        }
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .map_err(|err| format!("Failed to create {}: {err}", path.display()))?;
+    file.write_all(contents)
+        .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+/// Lists all stored encrypted keys with their metadata.
 ///
 /// Displays the identifiers of all keys currently stored in the encrypted
-/// key storage. Does not display the actual key data or require passwords.
+/// key storage along with their creation time, encryption algorithm and
+/// key store version. Does not display the actual key data or require passwords.
 ///
 /// # Output
 ///
-/// Prints each key name on a separate line to stdout.
+/// Prints a table to stdout with one key per row. Fields missing from
+/// keys stored by older versions are shown as "unknown".
+///
+/// With `json` a JSON array is printed instead, with one
+/// `{"name","created_at","algorithm","version"}` object per key and
+/// missing fields as `null`.
 ///
 /// # Errors
 ///
-/// Silently fails if unable to retrieve the key list.
+/// Prints an error message to stderr if unable to retrieve the key list,
+/// as `{"error":"..."}` with `json`.
 ///
 /// # Examples
 ///
 /// ```ignore
 /// use crate::features::web3::crypto::list_all_keys;
 ///
-/// list_all_keys();
+/// list_all_keys(false);
 /// // Output:
-/// // my_wallet_key
-/// // my_api_key
-/// // backup_key
+/// // NAME           CREATED                    ALGORITHM              VERSION
+/// // my_wallet_key  2025-01-01T00:00:00+00:00  aes-256-gcm+argon2id   1
+/// // backup_key     unknown                    aes-256-gcm            unknown
 /// ```
-pub fn list_all_keys() {
-    let keys = list_keys();
-    if let Ok(res) = keys {
-        for key in res {
-            println!("{key}");
+pub fn list_all_keys(json: bool) {
+    let keys = match list_keys_with_metadata() {
+        Ok(keys) => keys,
+        Err(err) => {
+            if json {
+                print_json_error(err);
+            } else {
+                eprintln!("Error in listing keys {:?}", err);
+            }
+            return;
+        }
+    };
+
+    if json {
+        let keys: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                serde_json::json!({
+                    "name": key.name,
+                    "created_at": key.created_at,
+                    "algorithm": key.algorithm,
+                    "version": key.version,
+                })
+            })
+            .collect();
+        print_json(&serde_json::Value::Array(keys));
+        return;
+    }
+
+    let unknown = || "unknown".to_string();
+    let rows: Vec<[String; 4]> = keys
+        .into_iter()
+        .map(|key| {
+            [
+                key.name,
+                key.created_at.unwrap_or_else(unknown),
+                key.algorithm.unwrap_or_else(unknown),
+                key.version.map(|v| v.to_string()).unwrap_or_else(unknown),
+            ]
+        })
+        .collect();
+
+    let headers = ["NAME", "CREATED", "ALGORITHM", "VERSION"];
+    let mut widths = headers.map(|h| h.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
         }
     }
+
+    let print_row = |cells: [&str; 4]| {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+    };
+
+    print_row(headers);
+    for row in &rows {
+        print_row([&row[0], &row[1], &row[2], &row[3]]);
+    }
 }
 
 /// Deletes a stored encrypted key.
@@ -185,6 +500,7 @@ pub fn list_all_keys() {
 /// # Arguments
 ///
 /// * `name` - Identifier of the key to delete
+/// * `json` - Print errors as `{"error":"..."}` JSON
 ///
 /// # Errors
 ///
@@ -197,7 +513,7 @@ pub fn list_all_keys() {
 /// ```ignore
 /// use crate::features::web3::crypto::delete_encrypted_key;
 ///
-/// delete_encrypted_key("old_wallet_key".to_string());
+/// delete_encrypted_key("old_wallet_key".to_string(), false);
 /// ```
 ///
 /// # Security Notes
@@ -205,8 +521,66 @@ pub fn list_all_keys() {
 /// - No password verification required (intentional for key rotation)
 /// - Deletion is permanent
 /// - Consider backing up important keys before deletion
-pub fn delete_encrypted_key(name: String) {
+pub fn delete_encrypted_key(name: String, json: bool) {
     if let Err(err) = delete_key(&name) {
-        eprintln!("Error in deleting key {name}: {:?}", err);
+        if json {
+            print_json_error(err);
+        } else {
+            eprintln!("Error in deleting key {name}: {:?}", err);
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+
+    fn private_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("stomata-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_write_secret_file_owner_only() {
+        let dir = private_dir("secret-mode");
+        let path = dir.join("key.txt");
+
+        write_secret_file(&path, b"secret").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"secret");
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_secret_file_never_overwrites() {
+        let dir = private_dir("secret-exists");
+        let path = dir.join("key.txt");
+        std::fs::write(&path, b"existing").unwrap();
+
+        assert!(write_secret_file(&path, b"secret").is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"existing");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_secret_file_refuses_shared_dir() {
+        let dir = private_dir("secret-shared");
+        let path = dir.join("key.txt");
+
+        for mode in [0o755, 0o733] {
+            std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(mode)).unwrap();
+            let err = write_secret_file(&path, b"secret").unwrap_err();
+            assert!(err.contains("other users can read or write"));
+            assert!(!path.exists());
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
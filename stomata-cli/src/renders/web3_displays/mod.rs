@@ -1,3 +1,5 @@
 pub mod address_validation;
+pub mod json_output;
 pub mod key_encryption;
 pub mod portfolio;
+pub mod watchlist;
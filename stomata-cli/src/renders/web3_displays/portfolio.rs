@@ -1,15 +1,35 @@
+use std::sync::Arc;
+
 use anyhow::{Result, anyhow};
-use ratatui::layout::{Constraint, Layout};
-use stomata_web3::providers::{
-    address::{AddressValidator, ValidationResult},
-    portfolio::{service::get_portfolio, structs::Portfolio},
-    rpc::structs::EVMProvider,
+use chrono::Local;
+use ratatui::{
+    layout::{Constraint, Layout},
+    widgets::Cell,
+};
+use stomata_web3::{
+    constants::NATIVE_PRICE_ASSET_ID,
+    providers::{
+        address::{AddressValidator, Chain, ValidationResult},
+        explorer::structs::{
+            ExplorerConfig, ExplorerProvider, RecentTransaction, TransactionDirection,
+        },
+        portfolio::{
+            service::get_portfolio,
+            structs::{AccountType, Portfolio},
+        },
+        price::{config::price_config, structs::CoinGeckoPriceProvider, traits::PriceProvider},
+        rpc::{config::rpc_endpoints, structs::EVMProvider},
+    },
 };
 
 use crate::{
-    features::web3::web3_feature::Web3UIState,
-    renders::{core_displays::traits::Display, render_widgets::render_paragraph::paragraph_widget},
-    structs::InputWidgetState,
+    density::Density,
+    features::web3::web3_feature::{RecentTransactionsUI, Web3UIState},
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_paragraph::paragraph_widget, render_table::render_table},
+    },
+    structs::{InputWidgetState, TableRow},
 };
 
 impl Display<InputWidgetState> for Portfolio {
@@ -32,13 +52,18 @@ impl Display<InputWidgetState> for Portfolio {
         // paragraph to render messages
         let mut data;
         if !input_field_widget.messages.is_empty() {
-            let balance = self.native_balance.to_string();
+            let balance = self.native_balance.normalize().to_string();
             let transaction_count = self.transaction_count.to_string();
-            let portfolio_data =
-                format!("Native Balance (wei): {balance}\nTransaction Count: {transaction_count}");
-            data = paragraph_widget(portfolio_data, "Portfolio");
+            let mut portfolio_data = format!(
+                "Native Balance (ETH): {balance}\nTransaction Count: {transaction_count}\n{}",
+                account_summary(self)
+            );
+            if cfg!(feature = "prices") {
+                portfolio_data.push_str(&usd_summary(self));
+            }
+            data = paragraph_widget(portfolio_data, "Portfolio", Density::Spacious);
         } else {
-            data = paragraph_widget("Input address", "Info");
+            data = paragraph_widget("Input address", "Info", Density::Spacious);
         }
 
         frame.render_widget(data, layout[1]);
@@ -46,14 +71,247 @@ impl Display<InputWidgetState> for Portfolio {
     }
 }
 
-pub async fn get_portfolio_data(address: &str) -> Result<Portfolio> {
+/// Describes the account type, with the bytecode size and source
+/// verification of contracts and the implementation of EIP-1967 proxies.
+///
+/// # Examples
+///
+/// ```text
+/// Account Type: EOA
+/// Account Type: Contract | Bytecode: 24576 bytes | Source verified: yes
+/// Account Type: Contract | Bytecode: 1024 bytes | Source verified: yes
+/// Proxy implementation: 0x43506849D7C04F9138D1A2050bbF3A0c054402dd | Admin: none
+/// ```
+fn account_summary(portfolio: &Portfolio) -> String {
+    match portfolio.account_type {
+        AccountType::EOA => "Account Type: EOA".to_string(),
+        AccountType::CONTRACT => {
+            let bytecode = portfolio
+                .bytecode_size
+                .map_or("unknown".to_string(), |size| format!("{size} bytes"));
+            let verified = match portfolio.source_verified {
+                Some(true) => "yes",
+                Some(false) => "no",
+                // no explorer API key or the lookup failed
+                None => "unknown",
+            };
+            let mut summary = format!(
+                "Account Type: Contract | Bytecode: {bytecode} | Source verified: {verified}"
+            );
+            if let Some(proxy) = &portfolio.proxy {
+                summary.push_str(&format!(
+                    "\nProxy implementation: {} | Admin: {}",
+                    proxy.implementation,
+                    proxy.admin.as_deref().unwrap_or("none")
+                ));
+            }
+            summary
+        }
+    }
+}
+
+/// Formats the per-asset and total USD values of the portfolio, one per
+/// line, or "price unavailable" when no price could be fetched.
+fn usd_summary(portfolio: &Portfolio) -> String {
+    match (portfolio.native_usd_price, portfolio.native_usd_value()) {
+        (Some(price), Some(value)) => format!(
+            "\nETH: {} × ${} = ${}\nTotal (USD): ${}",
+            portfolio.native_balance.normalize(),
+            price.normalize(),
+            value,
+            value
+        ),
+        _ => "\nETH (USD): price unavailable\nTotal (USD): price unavailable".to_string(),
+    }
+}
+
+/// Implements table row conversion for recent transactions.
+///
+/// # Column Layout
+///
+/// 1. **Direction** (4 chars): `IN`, `OUT` or `SELF`
+/// 2. **Hash** (20+ chars, flexible): Transaction hash
+/// 3. **Value** (20 chars): Transferred value in ETH
+/// 4. **Time** (19 chars): Local time the transaction was mined
+impl TableRow for RecentTransaction {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        let direction = match self.direction {
+            TransactionDirection::Incoming => "IN",
+            TransactionDirection::Outgoing => "OUT",
+            TransactionDirection::SelfTransfer => "SELF",
+        };
+        vec![
+            Cell::from(direction),
+            Cell::from(self.hash.clone()),
+            Cell::from(format!("{} ETH", self.value.normalize())),
+            Cell::from(
+                self.timestamp
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+            ),
+        ]
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Length(4),  // Direction
+            Constraint::Min(20),    // Hash (flexible)
+            Constraint::Length(20), // Value
+            Constraint::Length(19), // Time
+        ]
+    }
+}
+
+/// Display implementation for the recent transactions panel
+///
+/// Lists the latest transactions of the looked up address below the
+/// portfolio, newest first:
+///
+/// ```text
+/// ┌─ Recent transactions ──────────────────────────────────────┐
+/// │ Dir │ Hash          │ Value           │ Time                │
+/// │ IN  │ 0x5c50…       │ 1.5 ETH         │ 2026-01-02 10:00:00 │
+/// └────────────────────────────────────────────────────────────┘
+/// ```
+///
+/// Only rendered when an explorer API key is configured.
+impl Display<()> for RecentTransactionsUI<'_> {
+    /// Renders the transactions table, or a message while loading, on
+    /// error or when the address has no transactions
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the panel
+    /// * `_ui_state` - Unused, the rows are not selectable
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        area: ratatui::prelude::Rect,
+        _ui_state: Option<&mut ()>,
+    ) -> anyhow::Result<()> {
+        let title = "Recent transactions";
+        match self.transactions {
+            None => frame.render_widget(
+                paragraph_widget("Input address", title, Density::Spacious),
+                area,
+            ),
+            Some(Err(err)) => frame.render_widget(
+                paragraph_widget(
+                    format!("Failed to fetch transactions: {err}"),
+                    title,
+                    Density::Spacious,
+                ),
+                area,
+            ),
+            Some(Ok(transactions)) if transactions.is_empty() => frame.render_widget(
+                paragraph_widget("No transactions found", title, Density::Spacious),
+                area,
+            ),
+            Some(Ok(transactions)) => {
+                let headers = vec!["Dir", "Hash", "Value", "Time"];
+                frame.render_widget(
+                    render_table(
+                        headers,
+                        transactions,
+                        title,
+                        self.palette,
+                        Density::Spacious,
+                    ),
+                    area,
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates the price provider for the Portfolio's USD values
+///
+/// Returns `None` when built without the `prices` feature.
+pub fn price_provider() -> Option<Arc<CoinGeckoPriceProvider>> {
+    if cfg!(feature = "prices") {
+        Some(Arc::new(CoinGeckoPriceProvider::new(price_config())))
+    } else {
+        None
+    }
+}
+
+/// Fetches the portfolio of `address` and, given a price provider, the USD
+/// price of its native balance
+///
+/// With an explorer API configured, contracts are also checked for a
+/// verified source. A failed price or verification lookup (e.g. offline)
+/// leaves the value empty instead of failing the whole portfolio.
+///
+/// # Errors
+///
+/// Returns an error if the address isn't a valid EVM address, with an
+/// explanation for zero, burn and precompile addresses, or the RPC lookup
+/// fails.
+pub async fn get_portfolio_data(
+    address: &str,
+    prices: Option<Arc<CoinGeckoPriceProvider>>,
+    explorer: Option<ExplorerConfig>,
+) -> Result<Portfolio> {
     let validated_address = AddressValidator::validate(address);
     match validated_address {
-        ValidationResult::Valid { checksummed } => {
-            let provider = EVMProvider::new(checksummed, String::from("https://rpc.fullsend.to"));
-            let portfolio = get_portfolio(provider).await;
-            portfolio
+        ValidationResult::Valid {
+            chain: Chain::Evm,
+            checksummed,
+        } => {
+            let provider =
+                EVMProvider::with_fallbacks(checksummed.clone(), rpc_endpoints("ethereum"))?;
+            let mut portfolio = get_portfolio(provider).await?;
+            if let Some(prices) = prices {
+                portfolio.native_usd_price = prices.usd_price(NATIVE_PRICE_ASSET_ID).await.ok();
+            }
+            if let (AccountType::CONTRACT, Some(explorer)) = (&portfolio.account_type, explorer) {
+                portfolio.source_verified = ExplorerProvider::new(explorer)
+                    .is_source_verified(&checksummed)
+                    .await
+                    .ok();
+            }
+            Ok(portfolio)
+        }
+        ValidationResult::SpecialAddress { kind, .. } => Err(anyhow!(
+            "Refusing to look up a {kind}: {}",
+            kind.explanation()
+        )),
+        _ => Err(anyhow!("Error in validating address")),
+    }
+}
+
+/// Fetches the latest `limit` transactions of `address` from the explorer API
+///
+/// # Errors
+///
+/// Returns an error if the address isn't a valid EVM address, with an
+/// explanation for zero, burn and precompile addresses, or the explorer
+/// request fails.
+pub async fn get_recent_transactions_data(
+    address: &str,
+    explorer: ExplorerConfig,
+    limit: usize,
+) -> Result<Vec<RecentTransaction>> {
+    match AddressValidator::validate(address) {
+        ValidationResult::Valid {
+            chain: Chain::Evm,
+            checksummed,
+        } => {
+            ExplorerProvider::new(explorer)
+                .get_recent_transactions(&checksummed, limit)
+                .await
         }
+        ValidationResult::SpecialAddress { kind, .. } => Err(anyhow!(
+            "Refusing to look up a {kind}: {}",
+            kind.explanation()
+        )),
         _ => Err(anyhow!("Error in validating address")),
     }
 }
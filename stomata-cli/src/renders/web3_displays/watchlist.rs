@@ -0,0 +1,184 @@
+//! Watchlist page of the Web3 feature
+//!
+//! Shows a summary row for every address of the `watchlist` config, with
+//! its native balance, transaction count and account type. A failed lookup
+//! only marks its own row as errored.
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    widgets::Cell,
+};
+use stomata_web3::providers::portfolio::structs::AccountType;
+
+use crate::{
+    constants::WATCHLIST_REFRESH_SECS,
+    density::Density,
+    features::web3::web3_feature::{WatchlistEntry, WatchlistUI},
+    palette::Palette,
+    renders::{
+        core_displays::traits::Display,
+        render_widgets::{render_paragraph::paragraph_widget, render_table::render_table},
+    },
+    structs::TableRow,
+};
+
+/// Implements table row conversion for watchlist entries.
+///
+/// # Column Layout
+///
+/// 1. **Address** (42 chars): Address as configured
+/// 2. **Balance** (24+ chars, flexible): Native balance in ETH, or the
+///    fetch error, which takes the remaining width so it isn't cut short
+/// 3. **Txs** (8 chars): Transaction count
+/// 4. **Type** (8 chars): `EOA` or `Contract`
+///
+/// Rows whose lookup failed are rendered in red.
+impl TableRow for WatchlistEntry {
+    fn to_cells(&self) -> Vec<Cell<'_>> {
+        let address = Cell::from(self.address.as_str());
+        match &self.portfolio {
+            None => vec![address, Cell::from("…"), Cell::from(""), Cell::from("")],
+            Some(Err(err)) => vec![
+                address,
+                Cell::from(format!("error: {err}")),
+                Cell::from(""),
+                Cell::from(""),
+            ],
+            Some(Ok(portfolio)) => vec![
+                address,
+                Cell::from(format!("{} ETH", portfolio.native_balance.normalize())),
+                Cell::from(portfolio.transaction_count.to_string()),
+                Cell::from(match portfolio.account_type {
+                    AccountType::EOA => "EOA",
+                    AccountType::CONTRACT => "Contract",
+                }),
+            ],
+        }
+    }
+
+    fn column_widths() -> Vec<Constraint> {
+        vec![
+            Constraint::Length(42), // Address
+            Constraint::Min(24),    // Balance (flexible)
+            Constraint::Length(8),  // Txs
+            Constraint::Length(8),  // Type
+        ]
+    }
+
+    fn row_style(&self, palette: Palette) -> Style {
+        match self.portfolio {
+            Some(Err(_)) => Style::default().fg(palette.critical()),
+            _ => Style::default(),
+        }
+    }
+}
+
+/// Display implementation for the Watchlist page
+///
+/// ```text
+/// ┌─ Watchlist (refreshed every 15s) ────────────────────────────────────────┐
+/// │ Address                                    Balance       Txs     Type     │
+/// │ 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045 1.23 ETH      1520    EOA      │
+/// │ 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48 0 ETH         1       Contract │
+/// │ 0x0000000000000000000000000000000000000000 error: Refusing to look up …   │
+/// └───────────────────────────────────────────────────────────────────────────┘
+/// ```
+impl Display<()> for WatchlistUI<'_> {
+    /// Renders the watchlist table, or how to add addresses when the
+    /// watchlist is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The ratatui frame to render into
+    /// * `area` - The rectangular area allocated for the page
+    /// * `_ui_state` - Unused, the rows are not selectable
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rendering completed successfully
+    fn display(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        area: Rect,
+        _ui_state: Option<&mut ()>,
+    ) -> anyhow::Result<()> {
+        if self.entries.is_empty() {
+            frame.render_widget(
+                paragraph_widget(
+                    "No addresses yet, add them to the `watchlist` section of ~/.stomata/config.json",
+                    "Watchlist",
+                    Density::Spacious,
+                ),
+                area,
+            );
+            return Ok(());
+        }
+        let title = format!("Watchlist (refreshed every {WATCHLIST_REFRESH_SECS}s)");
+        let headers = vec!["Address", "Balance", "Txs", "Type"];
+        frame.render_widget(
+            render_table(
+                headers,
+                self.entries,
+                &title,
+                self.palette,
+                Density::Spacious,
+            ),
+            area,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stomata_web3::providers::portfolio::structs::Portfolio;
+
+    use super::*;
+
+    fn entry(portfolio: Option<Result<Portfolio, String>>) -> WatchlistEntry {
+        WatchlistEntry {
+            address: "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string(),
+            portfolio,
+            pending: false,
+        }
+    }
+
+    #[test]
+    fn test_to_cells_pending() {
+        let entry = entry(None);
+        let cells = entry.to_cells();
+        assert_eq!(cells[1], Cell::from("…"));
+        assert_eq!(entry.row_style(Palette::default()), Style::default());
+    }
+
+    #[test]
+    fn test_to_cells_error() {
+        let entry = entry(Some(Err("connection refused".to_string())));
+        let cells = entry.to_cells();
+        assert_eq!(cells[1], Cell::from("error: connection refused"));
+        assert_eq!(cells[2], Cell::from(""));
+        assert_eq!(
+            entry.row_style(Palette::default()),
+            Style::default().fg(Palette::default().critical())
+        );
+    }
+
+    #[test]
+    fn test_to_cells_loaded() {
+        let entry = entry(Some(Ok(Portfolio {
+            account_type: AccountType::CONTRACT,
+            bytecode_size: Some(100),
+            proxy: None,
+            native_balance: "1.50".parse().unwrap(),
+            transaction_count: 1520,
+            native_usd_price: None,
+            source_verified: None,
+        })));
+        let cells = entry.to_cells();
+        assert_eq!(cells[1], Cell::from("1.5 ETH"));
+        assert_eq!(cells[2], Cell::from("1520"));
+        assert_eq!(cells[3], Cell::from("Contract"));
+        assert_eq!(entry.row_style(Palette::default()), Style::default());
+    }
+}
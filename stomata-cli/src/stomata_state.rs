@@ -13,14 +13,17 @@ use ratatui::{
     layout::{Constraint, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Wrap},
 };
 
-#[cfg(feature = "web3")]
-use crate::structs::Feature;
 use crate::{
-    renders::render_widgets::render_paragraph::{self, paragraph_widget},
-    structs::{AppState, StomataState},
+    constants::KNOWN_FEATURES,
+    density::Density,
+    preferences::UiPreferences,
+    renders::render_widgets::{
+        render_paragraph::paragraph_widget, render_popup::render_terminal_too_small,
+    },
+    structs::{AppState, Feature, StomataState},
 };
 
 impl StomataState {
@@ -28,7 +31,8 @@ impl StomataState {
     ///
     /// Initializes the application state and builds a map of enabled features
     /// based on compile-time feature flags. Only features that are compiled
-    /// into the binary will appear in the feature selection menu.
+    /// into the binary will appear in the feature selection menu. The UI
+    /// preferences of the last session are loaded from disk.
     ///
     /// # Available Features
     ///
@@ -56,70 +60,155 @@ impl StomataState {
         let mut available_features = HashMap::new();
 
         #[cfg(feature = "core")]
-        available_features.insert("core".to_string(), Feature::Core);
+        available_features.insert(Feature::Core.name().to_string(), Feature::Core);
 
         #[cfg(feature = "web3")]
-        available_features.insert("web3".to_string(), Feature::Web3);
+        available_features.insert(Feature::Web3.name().to_string(), Feature::Web3);
 
         Self {
             state: AppState::FeatureSelection,
             selected_feature: 0,
+            feature_search: None,
             available_features,
+            preferences: UiPreferences::load(),
+        }
+    }
+
+    /// Returns the names of the compiled-in features, sorted alphabetically.
+    pub fn feature_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.available_features.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// Returns the features listed in the menu, in the order of
+    /// [`Self::feature_names`].
+    ///
+    /// While searching only the features whose name, title or description
+    /// contains the query (case-insensitive) are listed.
+    pub fn visible_features(&self) -> Vec<Feature> {
+        let query = self
+            .feature_search
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase();
+        self.feature_names()
+            .into_iter()
+            .filter_map(|name| self.available_features.get(name).copied())
+            .filter(|feature| {
+                [feature.name(), feature.title(), feature.description()]
+                    .iter()
+                    .any(|text| text.to_lowercase().contains(&query))
+            })
+            .collect()
+    }
+
+    /// Looks up a feature by the name given on the command line.
+    ///
+    /// Names are matched case-insensitively. Features that exist but were
+    /// left out of this build get a hint on how to enable them.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Feature name from the command line, e.g. `core`
+    ///
+    /// # Returns
+    ///
+    /// The feature, or an error message listing the available features
+    pub fn resolve_feature(&self, name: &str) -> Result<Feature, String> {
+        let name = name.to_lowercase();
+        if let Some(feature) = self.available_features.get(&name) {
+            return Ok(*feature);
+        }
+
+        let available = self.feature_names().join(", ");
+        if KNOWN_FEATURES.contains(&name.as_str()) {
+            Err(format!(
+                "Feature '{name}' is not included in this build, reinstall with `cargo install stomata-cli --features {name}`. Available features: {available}"
+            ))
+        } else {
+            Err(format!(
+                "Unknown feature '{name}'. Available features: {available}"
+            ))
         }
     }
 
     /// Renders the feature selection menu to the terminal UI.
     ///
-    /// Displays a two-section layout with a title area and a selectable list
-    /// of available features. Each feature shows a name and description to
-    /// help users choose the appropriate tool for their needs.
+    /// Displays a title area with the keys (or the search query while
+    /// searching) above a selectable list of the visible features and a
+    /// details panel. Each feature shows its title and description, the
+    /// panel the longer details of the selected one and how to start it
+    /// from the command line.
+    ///
+    /// ```text
+    /// ┌ Stomata CLI ─────────────────────────────────────────────┐
+    /// │ ↑/↓ or 1-9 select, Enter open, / search, q quit          │
+    /// └──────────────────────────────────────────────────────────┘
+    /// ┌ Available Features ─────────┐┌ Details ──────────────────┐
+    /// │► 1. System Monitor          ││ Live system metrics ...   │
+    /// │  Monitor CPU, Memory ...    ││                           │
+    /// │  2. Web3 Tools              ││ stomata core              │
+    /// └─────────────────────────────┘└───────────────────────────┘
+    /// ```
     ///
     /// # Arguments
     ///
     /// * `frame` - Mutable reference to the ratatui frame for rendering
     ///
-    ///
     /// # Error Handling
     ///
     /// If no features are compiled, displays an error message instructing
     /// the user to rebuild with feature flags enabled.
     ///
     pub fn render_feature_selection(&self, frame: &mut Frame) {
+        if render_terminal_too_small(frame) {
+            return;
+        }
         let chunks =
             Layout::vertical([Constraint::Length(5), Constraint::Min(10)]).split(frame.area());
 
-        let title = paragraph_widget("Select a feature", "Stomata ClI");
+        let header = match &self.feature_search {
+            Some(query) => format!("Search: {query}_ (Enter open, Esc clear)"),
+            None => "Select a feature: ↑/↓ or 1-9 select, Enter open, / search, q quit".to_string(),
+        };
+        let title = paragraph_widget(header, "Stomata CLI", Density::Spacious);
         frame.render_widget(title, chunks[0]);
 
+        let features = self.visible_features();
+
         // feature list
         if self.available_features.is_empty() {
             let msg = paragraph_widget(
                 "No features available. Please install with a feature flag",
                 "Error",
+                Density::Spacious,
+            );
+            frame.render_widget(msg, chunks[1]);
+        } else if features.is_empty() {
+            let msg = paragraph_widget(
+                "No features match the search",
+                "Available Features",
+                Density::Spacious,
             );
             frame.render_widget(msg, chunks[1]);
         } else {
-            let items: Vec<ListItem> = self
-                .available_features
+            let [list_area, details_area] =
+                Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(chunks[1]);
+            let items: Vec<ListItem> = features
                 .iter()
-                .map(|(key, feature)| {
-                    let (name, desc) = match feature {
-                        Feature::Core => (
-                            "System Monitor",
-                            "Monitor CPU, Memory and Swap, Disk and Network metrics",
-                        ),
-                        Feature::Web3 => (
-                            "Web3 Tools",
-                            "Access web3 tools for address validation and more...",
-                        ),
-                    };
-
+                .enumerate()
+                .map(|(index, feature)| {
                     ListItem::new(vec![
                         Line::from(Span::styled(
-                            name,
+                            format!("{}. {}", index + 1, feature.title()),
                             Style::default().add_modifier(Modifier::BOLD),
                         )),
-                        Line::from(Span::styled(desc, Style::default().fg(Color::Gray))),
+                        Line::from(Span::styled(
+                            format!("   {}", feature.description()),
+                            Style::default().fg(Color::Gray),
+                        )),
                     ])
                 })
                 .collect();
@@ -137,9 +226,17 @@ impl StomataState {
                 )
                 .highlight_symbol("► ");
 
+            let selected = self.selected_feature.min(features.len() - 1);
             let mut state = ListState::default();
-            state.select(Some(self.selected_feature));
-            frame.render_stateful_widget(list, chunks[1], &mut state);
+            state.select(Some(selected));
+            frame.render_stateful_widget(list, list_area, &mut state);
+
+            let feature = features[selected];
+            let details = format!("{}\n\nstomata {}", feature.details(), feature.name());
+            frame.render_widget(
+                paragraph_widget(details, "Details", Density::Spacious).wrap(Wrap { trim: true }),
+                details_area,
+            );
         }
     }
 
@@ -148,29 +245,90 @@ impl StomataState {
     /// Processes key events to navigate the feature list, select features,
     /// or quit the application. Updates the application state based on
     /// user input.
+    ///
+    /// `/` starts a search: typed characters then narrow the list, `Esc`
+    /// clears the search and `Enter` opens the selected match. Outside of
+    /// a search `1`-`9` open the feature with that number directly.
     pub fn handle_feature_selection(&mut self, key: KeyEvent) -> bool {
-        match key.code {
-            KeyCode::Up => {
-                if self.selected_feature > 0 {
-                    self.selected_feature -= 1;
-                }
+        let visible = self.visible_features().len();
+        match (key.code, &mut self.feature_search) {
+            (KeyCode::Up, _) => {
+                self.selected_feature = self.selected_feature.saturating_sub(1);
             }
-            KeyCode::Down => {
-                if self.selected_feature < self.available_features.len().saturating_sub(1) {
+            (KeyCode::Down, _) => {
+                if self.selected_feature < visible.saturating_sub(1) {
                     self.selected_feature += 1;
                 }
             }
-            KeyCode::Enter => {
-                if let Some(&feature) = self.available_features.values().nth(self.selected_feature)
-                {
-                    self.state = AppState::RunningFeature(feature);
-                }
+            (KeyCode::Enter, _) => self.open_feature(self.selected_feature),
+            (KeyCode::Esc, Some(_)) => {
+                self.feature_search = None;
+                self.selected_feature = 0;
             }
-            KeyCode::Char('q') => {
+            (KeyCode::Backspace, Some(query)) => {
+                query.pop();
+                self.selected_feature = 0;
+            }
+            (KeyCode::Char(c), Some(query)) => {
+                query.push(c);
+                self.selected_feature = 0;
+            }
+            (KeyCode::Char('/'), None) => self.feature_search = Some(String::new()),
+            (KeyCode::Char(c @ '1'..='9'), None) => {
+                self.open_feature(c as usize - '1' as usize);
+            }
+            (KeyCode::Char('q'), None) => {
                 return false; // Exit app
             }
             _ => {}
         }
         true // Continue running
     }
+
+    /// Runs the visible feature at `index`, ignoring indices past the list.
+    ///
+    /// The search is cleared so the full list is back on return.
+    fn open_feature(&mut self, index: usize) {
+        if let Some(&feature) = self.visible_features().get(index) {
+            self.state = AppState::RunningFeature(feature);
+            self.selected_feature = index;
+            self.feature_search = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_feature_is_case_insensitive() {
+        let app = StomataState::new();
+        for name in app.feature_names() {
+            assert!(app.resolve_feature(&name.to_uppercase()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_visible_features_filters_by_search() {
+        let mut app = StomataState::new();
+        assert_eq!(app.visible_features().len(), app.available_features.len());
+
+        let names: Vec<String> = app.feature_names().into_iter().map(String::from).collect();
+        for name in names {
+            app.feature_search = Some(name.to_uppercase());
+            assert!(app.visible_features().iter().any(|f| f.name() == name));
+        }
+
+        app.feature_search = Some("no such feature".to_string());
+        assert!(app.visible_features().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_unknown_feature_lists_available() {
+        let app = StomataState::new();
+        let message = app.resolve_feature("bogus").unwrap_err();
+        assert!(message.starts_with("Unknown feature 'bogus'"));
+        assert!(message.ends_with(&app.feature_names().join(", ")));
+    }
 }
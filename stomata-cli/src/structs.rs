@@ -4,26 +4,54 @@
 //! feature enums, application state, CLI arguments, page navigation,
 //! UI state management, and ring buffers for time-series data storage.
 
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
+use chrono::{DateTime, Utc};
 use clap::Parser;
 use ratatui::{
     Frame,
     layout::Constraint,
+    style::Style,
     widgets::{Cell, TableState},
 };
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use stomata_core::collectors::{
-    network::metrics::NetworkInterfaces, process::metrics::SingleProcessData,
+    custom::CustomMetric,
+    network::metrics::{NetworkInterfaces, NetworkMetrics},
+    process::{
+        cgroup::{CgroupTree, CgroupTreeNode},
+        metrics::{ProcessData, ProcessNetworkUsage, SingleProcessData},
+        tree::{ProcessTree, ProcessTreeNode},
+    },
+    structs::StomataSystemMetrics,
+    system::metrics::SystemCollector,
 };
-use sysinfo::DiskUsage;
+use sysinfo::{DiskUsage, Process};
 
-use crate::constants::{CLAMP_TREND_VALUE, MAX_HISTORY_IN_MEMORY, MAX_NETWORK_IN_MEMORY};
+use crate::{
+    config::{CustomMetricConfig, StomataConfig},
+    constants::{
+        CLAMP_TREND_VALUE, LEAK_MIN_SAMPLES, LEAK_MIN_SLOPE_BYTES, MAX_HISTORY,
+        MAX_HISTORY_IN_MEMORY, MAX_NETWORK_IN_MEMORY, PROCESS_EXITED_RETURN_SECS,
+    },
+    density::Density,
+    palette::Palette,
+    preferences::UiPreferences,
+    renders::core_displays::traits::PageView,
+    units::UnitConfig,
+    utils::{bytes_per_second, linear_regression_slope, parse_refresh_interval, percentage},
+};
 
 /// Available application features determined by compile-time flags.
 ///
 /// Each variant corresponds to a major feature set that can be enabled
 /// or disabled at build time using Cargo feature flags.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Feature {
     /// Core system monitoring features (CPU, memory, disk, network, processes)
     #[cfg(feature = "core")]
@@ -34,6 +62,71 @@ pub enum Feature {
     Web3,
 }
 
+impl Feature {
+    /// Returns the name used on the command line, e.g. `core` in `stomata core`
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "core")]
+            Feature::Core => "core",
+            #[cfg(feature = "web3")]
+            Feature::Web3 => "web3",
+        }
+    }
+
+    /// Returns the title shown in the feature selection menu
+    pub fn title(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "core")]
+            Feature::Core => "System Monitor",
+            #[cfg(feature = "web3")]
+            Feature::Web3 => "Web3 Tools",
+        }
+    }
+
+    /// Returns the one line summary shown under the title in the menu
+    pub fn description(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "core")]
+            Feature::Core => "Monitor CPU, Memory and Swap, Disk and Network metrics",
+            #[cfg(feature = "web3")]
+            Feature::Web3 => "Access web3 tools for address validation and more...",
+        }
+    }
+
+    /// Returns the longer description shown in the details panel of the
+    /// menu for the selected feature
+    pub fn details(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "core")]
+            Feature::Core => {
+                "Live system metrics with history charts: CPU and memory usage, disks, \
+                 network interfaces, the process table and tree, and a detailed view of \
+                 a single process. Alerts, custom metrics and exports are configured \
+                 from the command line."
+            }
+            #[cfg(feature = "web3")]
+            Feature::Web3 => {
+                "EVM tools: address validation for EVM, Bitcoin and Solana, the \
+                 portfolio and recent transactions of an address, the latest block \
+                 with its gas prices, and encrypted key storage from the command line."
+            }
+        }
+    }
+}
+
+/// How the user left a running feature.
+///
+/// Every interactive feature uses the same keys: `Esc` goes back to the
+/// feature selection menu and `q` quits stomata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureExit {
+    /// Return to the feature selection menu (`Esc`)
+    Back,
+
+    /// Quit the whole application (`q`)
+    Quit,
+}
+
 /// Application state machine for managing UI flow.
 ///
 /// Tracks whether the user is selecting a feature or currently running one.
@@ -54,11 +147,18 @@ pub struct StomataState {
     /// Current state in the application flow
     pub state: AppState,
 
-    /// Index of the currently selected feature in the menu
+    /// Index of the currently selected feature among the visible ones, see
+    /// `StomataState::visible_features`
     pub selected_feature: usize,
 
+    /// Search query of the menu while searching (`/`), `None` otherwise
+    pub feature_search: Option<String>,
+
     /// Map of available features (feature name -> Feature enum)
     pub available_features: HashMap<String, Feature>,
+
+    /// UI preferences of the last session, loaded at startup
+    pub preferences: UiPreferences,
 }
 
 /// Command-line interface arguments.
@@ -77,6 +177,27 @@ pub struct StomataState {
 ///
 /// # Custom refresh interval
 /// stomata -i --interval 500
+///
+/// # Keep 200 points of history for sparklines
+/// stomata -i --history-len 200
+///
+/// # Ask before quitting so a stray `q` doesn't end the session
+/// stomata -i --confirm-quit
+///
+/// # Blue/orange instead of green/red
+/// stomata -i --palette colorblind
+///
+/// # Serve Prometheus metrics without the TUI (`http` feature)
+/// stomata --serve 127.0.0.1:9184
+///
+/// # Monitor only nginx processes as one group
+/// stomata -i --match nginx
+///
+/// # Monitor a server over SSH, stomata must be installed there too
+/// stomata -i --remote admin@example.com
+///
+/// # Monitor kernel worker threads using a regex
+/// stomata -i --match '/^kworker/'
 /// ```
 #[derive(Parser, Debug, Clone)]
 #[command(name = "stomata")]
@@ -86,17 +207,62 @@ pub struct Cli {
     #[arg(short = 'i', long, default_value_t = false)]
     pub interactive: bool,
 
-    /// Refresh interval in milliseconds for system monitoring
-    #[arg(short = 't', long, default_value_t = 1000)]
+    /// Refresh interval in milliseconds for system monitoring (at least 100)
+    #[arg(short = 't', long, default_value_t = 1000, value_parser = parse_refresh_interval)]
     pub interval: u64,
 
+    /// Number of data points kept for sparkline history (capped at 1000).
+    /// Defaults to 60 for process disk/network and 40 for network interfaces
+    #[arg(long)]
+    pub history_len: Option<usize>,
+
     /// Enable data storage/persistence (feature-dependent behavior)
     #[arg(short, long, default_value_t = false)]
     pub store: bool,
 
+    /// Colors of the TUI, `colorblind` swaps green/red for blue/orange and
+    /// marks critical values with `▲`. Defaults to the `palette` config
+    #[arg(long, value_enum)]
+    pub palette: Option<Palette>,
+
+    /// Ask for confirmation before quitting with `q` in interactive mode
+    #[arg(long, default_value_t = false)]
+    pub confirm_quit: bool,
+
+    /// Serve `/metrics` (Prometheus) and `/snapshot.json` on this address,
+    /// alongside the TUI or on its own when no feature is given
+    #[cfg(feature = "http")]
+    #[arg(long, value_name = "ADDR")]
+    pub serve: Option<String>,
+
+    /// Show the metrics of another machine in the core feature, streamed
+    /// over SSH from `stomata core --stream` running there. Needs `-i` and
+    /// key based SSH authentication
+    #[cfg(feature = "core")]
+    #[arg(long, value_name = "USER@HOST")]
+    pub remote: Option<String>,
+
+    /// Append the traffic of every network interface to this CSV file on
+    /// each refresh of the core TUI, creating it if needed
+    #[cfg(feature = "core")]
+    #[arg(long, value_name = "PATH")]
+    pub net_log: Option<PathBuf>,
+
+    /// Print the peak CPU and memory usage and the top CPU consumer of the
+    /// core TUI session to stdout after quitting
+    #[cfg(feature = "core")]
+    #[arg(long, default_value_t = false)]
+    pub summary: bool,
+
     /// Feature to run in CLI mode (ignored in interactive mode)
     pub feature: Option<String>,
 
+    /// Only monitor processes whose name or command line contains this
+    /// pattern (case-insensitive), shown as one group with summed usage.
+    /// Wrap the pattern in slashes to use a regex, e.g. `/^kworker/`
+    #[arg(long = "match", value_name = "PATTERN")]
+    pub match_pattern: Option<String>,
+
     /// Arguments passed to the feature
     /// Allows arbitrary arguments including those starting with hyphens
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
@@ -109,7 +275,7 @@ pub struct Cli {
 /// Users can switch between pages using number keys or arrow keys.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Page {
-    /// System information overview (OS, kernel, hostname)
+    /// System information overview (OS, kernel, hostname, architecture, boot time)
     System,
 
     /// Real-time metrics gauges (CPU, memory, disk usage)
@@ -123,6 +289,19 @@ pub enum Page {
 
     /// Network interface statistics and trends
     Network,
+
+    /// Collapsible parent/child hierarchy of processes
+    ProcessTree,
+
+    /// Summed CPU and memory per cgroup, i.e. per container or service
+    Cgroups,
+
+    /// Metrics read from user defined shell commands
+    Custom,
+
+    /// NVIDIA GPU utilization, memory and temperature
+    #[cfg(feature = "gpu")]
+    Gpu,
 }
 
 impl Page {
@@ -132,9 +311,21 @@ impl Page {
     ///
     /// # Returns
     ///
-    /// Vector of static strings: `["System", "Metrics", "Processes", "Network"]`
+    /// Vector of static strings: `["System", "Metrics", "Processes", "Network", "Process Tree", "Cgroups", "Custom"]`,
+    /// followed by `"GPU"` when built with the `gpu` feature
     pub fn titles() -> Vec<&'static str> {
-        vec!["System", "Metrics", "Processes", "Network"]
+        let titles = vec![
+            "System",
+            "Metrics",
+            "Processes",
+            "Network",
+            "Process Tree",
+            "Cgroups",
+            "Custom",
+        ];
+        #[cfg(feature = "gpu")]
+        let titles = [titles, vec!["GPU"]].concat();
+        titles
     }
 
     /// Converts a tab index to its corresponding page.
@@ -152,11 +343,156 @@ impl Page {
             1 => Page::Metrics,
             2 => Page::Processes,
             3 => Page::Network,
+            4 => Page::ProcessTree,
+            5 => Page::Cgroups,
+            6 => Page::Custom,
+            #[cfg(feature = "gpu")]
+            7 => Page::Gpu,
             _ => Page::System,
         }
     }
+
+    /// Returns the keybindings active on this page.
+    ///
+    /// Global bindings come first, followed by the bindings specific to
+    /// the page. Used to build the `?` help overlay, so any new key handled
+    /// in `App` should be listed here as well.
+    ///
+    /// # Returns
+    ///
+    /// Vector of `(key, description)` pairs
+    pub fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        let mut bindings = vec![
+            ("q", "Quit"),
+            ("Esc", "Back to feature selection"),
+            ("?", "Toggle this help"),
+            ("Tab / →", "Next tab"),
+            ("←", "Previous tab"),
+            ("1-9", "Jump to tab by position"),
+            ("+ / -", "Increase / decrease refresh interval"),
+            ("n", "Toggle per core / normalized process CPU"),
+            ("d", "Toggle compact / spacious layout"),
+        ];
+
+        match self {
+            Page::Processes => bindings.extend([
+                ("↑ / ↓", "Select process"),
+                ("PgUp / PgDn", "Scroll a page"),
+                ("Home / End", "Jump to first / last process"),
+                ("Enter", "Open process details"),
+                ("l", "Lock / unlock the selection to the selected PID"),
+                ("f", "Follow / stop following the top CPU process"),
+                ("w", "Watch / stop watching the selected process subtree"),
+                ("z", "Show only zombie processes"),
+                ("k", "Show / hide kernel threads"),
+                ("p", "Pin / unpin the selected process by name"),
+                ("r", "Toggle regex for the --match pattern"),
+                ("c", "Swap Name / Command columns"),
+                ("o", "Choose columns"),
+            ]),
+            Page::ProcessTree => bindings.extend([
+                ("↑ / ↓", "Select process"),
+                ("Space", "Collapse / expand children"),
+                ("Enter", "Open process details"),
+            ]),
+            Page::Cgroups => bindings.push(("↑ / ↓", "Select cgroup")),
+            Page::Network => bindings.extend([
+                ("[ / ]", "Scroll interfaces"),
+                ("i", "Show / hide interfaces"),
+            ]),
+            Page::SingleProcess(_) => bindings.extend([
+                ("g", "Group / ungroup tasks by name"),
+                ("↑ / ↓", "Select task group"),
+                ("Space", "Expand / collapse the selected group"),
+                ("Enter", "Back to the process list once the process exited"),
+                ("e", "Export the disk history to a CSV file"),
+            ]),
+            Page::System | Page::Metrics | Page::Custom => {}
+            #[cfg(feature = "gpu")]
+            Page::Gpu => {}
+        }
+
+        bindings
+    }
+
+    /// Returns the view that renders this page.
+    ///
+    /// # Returns
+    ///
+    /// The page's `PageView`, boxed so every page is handled the same way
+    pub fn view(&self) -> Box<dyn PageView> {
+        match self {
+            Page::System => Box::new(SystemPage),
+            Page::Metrics => Box::new(MetricsPage),
+            Page::Processes => Box::new(ProcessesPage),
+            Page::SingleProcess(pid) => Box::new(SingleProcessPage { pid: *pid }),
+            Page::Network => Box::new(NetworkPage),
+            Page::ProcessTree => Box::new(ProcessTreePage),
+            Page::Cgroups => Box::new(CgroupsPage),
+            Page::Custom => Box::new(CustomPage),
+            #[cfg(feature = "gpu")]
+            Page::Gpu => Box::new(GpuPage),
+        }
+    }
+}
+
+/// Everything a page needs from the app to render itself.
+///
+/// Built by `App::render` every frame and passed to the current page's
+/// `PageView`.
+pub struct PageContext<'a> {
+    /// System metrics collector the page fetches its data from
+    pub metrics: &'a mut StomataSystemMetrics,
+
+    /// UI state for stateful widgets (tables, lists, charts)
+    pub ui_state: &'a mut UIState,
+
+    /// Persisted user configuration (pinned processes, columns)
+    pub config: &'a StomataConfig,
+
+    /// Refresh interval of the render loop in milliseconds
+    pub refresh_interval: u64,
+
+    /// System usage fetched once per frame for the alerts, `None` when it
+    /// wasn't needed this frame
+    pub system_collector: Option<&'a SystemCollector>,
+
+    /// Network interfaces fetched once per frame for the network log,
+    /// `None` when they weren't needed this frame
+    pub network_metrics: Option<&'a NetworkMetrics>,
+}
+
+/// System page: OS, kernel, hostname, architecture and boot time
+pub struct SystemPage;
+
+/// Metrics page: resource gauges, history and top processes
+pub struct MetricsPage;
+
+/// Processes page: pinned processes and the process list
+pub struct ProcessesPage;
+
+/// Detail page of one process
+pub struct SingleProcessPage {
+    /// PID of the process shown
+    pub pid: u32,
 }
 
+/// Network page: interface statistics and traffic
+pub struct NetworkPage;
+
+/// Process tree page: parent/child hierarchy of processes
+pub struct ProcessTreePage;
+
+/// Cgroups page: usage of each cgroup including its children
+pub struct CgroupsPage;
+
+/// Custom page: metrics read from the shell commands in the config
+pub struct CustomPage;
+
+/// GPU page: utilization, memory and temperature per NVIDIA GPU
+#[cfg(feature = "gpu")]
+pub struct GpuPage;
+
 /// Trait for types that can be displayed as table rows.
 ///
 /// Provides a consistent interface for converting data structures into
@@ -187,6 +523,14 @@ pub trait TableRow {
 
     /// Returns the column width constraints for the table.
     fn column_widths() -> Vec<Constraint>;
+
+    /// Returns the style applied to the whole row, in the colors of `palette`.
+    ///
+    /// Defaults to no extra styling; override to highlight rows such as
+    /// zombie processes.
+    fn row_style(&self, _palette: Palette) -> Style {
+        Style::default()
+    }
 }
 
 /// Comprehensive UI state management for all monitoring views.
@@ -201,8 +545,94 @@ pub struct UIState {
     /// Disk I/O history for the currently viewed process
     pub single_process_disk_usage: SingleProcessDiskUsage,
 
+    /// Network usage history for the currently viewed process
+    pub single_process_network_usage: SingleProcessNetworkUsage,
+
+    /// Memory history for the currently viewed process, used for leak detection
+    pub single_process_memory_usage: SingleProcessMemoryUsage,
+
+    /// CPU and memory usage history for the Metrics page
+    pub system_usage: SystemUsageHistory,
+
     /// Time-series data for all network interfaces
     pub networks_state: Option<HashMap<String, NetworkInterfaceData>>,
+
+    /// Index of the first network interface shown when not all fit
+    pub network_scroll: usize,
+
+    /// When the network interfaces were last refreshed
+    pub last_network_fetch: Option<Instant>,
+
+    /// Time the latest per-interface byte counts were measured over, used
+    /// to show them as rates
+    pub network_elapsed: Duration,
+
+    /// Names of the network interfaces hidden from the Network page, from
+    /// the config file
+    pub hidden_interfaces: HashSet<String>,
+
+    /// Selected entry of the interface menu, `None` while the menu is closed
+    pub interface_menu: Option<usize>,
+
+    /// State for the process tree table (selection, collapsed nodes)
+    pub process_tree: ProcessTreeUIState,
+
+    /// State for the cgroup table (selection)
+    pub cgroups: CgroupsUIState,
+
+    /// Number of data points kept for process disk/network history
+    pub history_len: usize,
+
+    /// Number of data points kept for network interface history
+    pub network_history_len: usize,
+
+    /// Units of memory and network values, from the config file
+    pub units: UnitConfig,
+
+    /// Process whose detail page is open but that has exited
+    pub exited_process: Option<ExitedProcess>,
+
+    /// History of each custom metric, in config order
+    pub custom_metrics: Vec<CustomMetricHistory>,
+
+    /// Colors of gauges, tables and sparklines
+    pub palette: Palette,
+
+    /// Spacing of the widgets, toggled with `d`
+    pub density: Density,
+
+    /// Grouping and selection of the single process task table
+    pub tasks: TasksUIState,
+}
+
+/// A process that exited while its detail page was open.
+///
+/// The page shows an exit message and returns to the process list
+/// `PROCESS_EXITED_RETURN_SECS` after the exit was noticed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitedProcess {
+    /// PID of the exited process
+    pub pid: u32,
+
+    /// When the detail page first failed to find the process
+    pub noticed_at: Instant,
+}
+
+impl ExitedProcess {
+    /// Returns the whole seconds left until the page returns to the list,
+    /// rounded up so the countdown never shows 0 before returning.
+    pub fn seconds_left(&self, now: Instant) -> u64 {
+        let elapsed = now.saturating_duration_since(self.noticed_at);
+        Duration::from_secs(PROCESS_EXITED_RETURN_SECS)
+            .saturating_sub(elapsed)
+            .as_millis()
+            .div_ceil(1000) as u64
+    }
+
+    /// Returns whether the page should return to the process list.
+    pub fn return_due(&self, now: Instant) -> bool {
+        self.seconds_left(now) == 0
+    }
 }
 
 /// State management for the process list table.
@@ -219,219 +649,1320 @@ pub struct ProcessesUIState {
 
     /// PID of the selected process (if any)
     pub selected_pid: Option<u32>,
-}
 
-impl Default for UIState {
-    fn default() -> Self {
-        Self {
-            process_table: ProcessesUIState {
-                process_list: TableState::default().with_selected(0),
-                process_count: 0,
-                selected_pid: None,
-            },
-            single_process_disk_usage: SingleProcessDiskUsage::default(),
-            networks_state: None,
-        }
-    }
-}
+    /// Whether only zombie/defunct processes are listed
+    pub zombies_only: bool,
 
-/// Wrapper for single process data display.
-///
-/// Used to pass process details to the detailed process view.
-pub struct SingleProcessUI<'a> {
-    /// Process data including metrics and metadata
-    pub data: SingleProcessData<'a>,
-}
+    /// Whether Linux kernel threads are listed, hidden by default
+    pub show_kernel_threads: bool,
 
-/// Time-series storage for a single process's disk I/O activity.
-///
-/// Maintains historical read and write byte counts for visualizing
-/// disk usage trends in sparkline charts.
-#[derive(Debug)]
-pub struct SingleProcessDiskUsage {
-    /// PID of the process being tracked
-    pub pid: u32,
+    /// Restricts the list to processes matching `--match`, if given
+    pub matcher: Option<ProcessMatcher>,
+
+    /// Whether the Name and Command columns are swapped
+    pub show_command: bool,
 
-    /// Historical disk read bytes (up to MAX_HISTORY_IN_MEMORY points)
-    pub disk_read_usage: VecDeque<u64>,
+    /// Columns shown in the process table, from the config file
+    pub columns: Vec<ProcessColumn>,
 
-    /// Historical disk write bytes (up to MAX_HISTORY_IN_MEMORY points)
-    pub disk_write_usage: VecDeque<u64>,
+    /// Selected entry of the column menu, `None` while the menu is closed
+    pub column_menu: Option<usize>,
+
+    /// Number of rows visible in the table as last rendered, the page size
+    /// for PageUp/PageDown
+    pub visible_rows: usize,
+
+    /// PID the selection is locked to, followed across re-sorts and refreshes
+    pub locked_pid: Option<u32>,
+
+    /// Whether the selection follows the process using the most CPU
+    pub follow_top_cpu: bool,
+
+    /// Process whose subtree the list is scoped to, if any
+    pub subtree: Option<WatchedSubtree>,
 }
 
-impl Default for SingleProcessDiskUsage {
-    fn default() -> Self {
-        Self {
-            pid: 0,
-            disk_read_usage: VecDeque::<u64>::with_capacity(MAX_HISTORY_IN_MEMORY),
-            disk_write_usage: VecDeque::<u64>::with_capacity(MAX_HISTORY_IN_MEMORY),
+impl ProcessesUIState {
+    /// Moves the selection by `delta` rows, clamped to the list.
+    ///
+    /// The view scrolls by the same amount, so moving by `visible_rows`
+    /// turns a whole page. It stops once the last row is at the bottom, so
+    /// the last page is still full.
+    pub fn move_selection(&mut self, delta: isize) {
+        let last = self.process_count.saturating_sub(1);
+        let last_offset = self.process_count.saturating_sub(self.visible_rows);
+        let selected = self.process_list.selected().unwrap_or(0);
+        let offset = self.process_list.offset();
+        *self.process_list.offset_mut() = offset.saturating_add_signed(delta).min(last_offset);
+        self.process_list
+            .select(Some(selected.saturating_add_signed(delta).min(last)));
+    }
+
+    /// Locks the selection to the selected PID, or releases an existing lock.
+    ///
+    /// Locking stops following the top CPU consumer.
+    pub fn toggle_lock(&mut self) {
+        self.locked_pid = match self.locked_pid {
+            Some(_) => None,
+            None => self.selected_pid,
+        };
+        if self.locked_pid.is_some() {
+            self.follow_top_cpu = false;
         }
     }
-}
 
-impl SingleProcessDiskUsage {
-    /// Updates disk I/O history with new measurements.
+    /// Starts or stops following the process using the most CPU.
     ///
-    /// Maintains a sliding window of disk read/write data. When the tracked
-    /// PID changes, clears the history to start fresh for the new process.
+    /// Following releases a lock on the selected PID.
+    pub fn toggle_follow_top_cpu(&mut self) {
+        self.follow_top_cpu = !self.follow_top_cpu;
+        if self.follow_top_cpu {
+            self.locked_pid = None;
+        }
+    }
+
+    /// Moves the selection to the row using the most CPU while
+    /// `follow_top_cpu` is set.
     ///
     /// # Arguments
     ///
-    /// * `pid` - Process ID of the current process
-    /// * `disk_usage` - Current disk I/O statistics
-    ///
-    /// # Behavior
-    ///
-    /// - If PID changes: Clears all history and updates tracked PID
-    /// - If history exceeds 60 points: Removes oldest entry (FIFO)
-    /// - Appends new read/write byte counts to history
-    pub fn update_disk_history(&mut self, pid: u32, disk_usage: &DiskUsage) {
-        // reset the UI state data for disk write/read when changed at current displaying pid
-        if pid != self.pid {
-            self.disk_read_usage.clear();
-            self.disk_write_usage.clear();
-            self.pid = pid;
-        }
-
-        if self.disk_read_usage.len() > 60 {
-            self.disk_read_usage.pop_front();
+    /// * `cpu_usages` - CPU usage of the listed processes, in row order
+    pub fn follow_top_cpu_row(&mut self, cpu_usages: impl IntoIterator<Item = f32>) {
+        if !self.follow_top_cpu {
+            return;
         }
-        self.disk_read_usage.push_back(disk_usage.read_bytes);
-
-        if self.disk_write_usage.len() > 60 {
-            self.disk_write_usage.pop_front();
+        let top_row = cpu_usages
+            .into_iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(row, _)| row);
+        if let Some(row) = top_row {
+            self.process_list.select(Some(row));
         }
-        self.disk_write_usage.push_back(disk_usage.written_bytes);
     }
-}
-
-/// Time-series storage for a single network interface's statistics.
-///
-/// Maintains historical data for bytes, packets, and errors in both
-/// transmit and receive directions using ring buffers for efficient
-/// memory usage and visualization.
-#[derive(Debug)]
-pub struct NetworkInterfaceData {
-    /// Bytes received over time
-    pub received_bytes: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-    /// Bytes transmitted over time
-    pub transmitted_bytes: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-    /// Packets received over time
-    pub packets_received: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-    /// Packets transmitted over time
-    pub packets_transmitted: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-    /// Receive errors over time
-    pub errors_received: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-    /// Transmit errors over time
-    pub errors_transmitted: Ring<u64, MAX_NETWORK_IN_MEMORY>,
-}
 
-impl Default for NetworkInterfaceData {
-    fn default() -> Self {
-        Self {
-            received_bytes: Ring::new(),
-            transmitted_bytes: Ring::new(),
-            packets_received: Ring::new(),
-            packets_transmitted: Ring::new(),
-            errors_received: Ring::new(),
-            errors_transmitted: Ring::new(),
-        }
+    /// Scopes the list to the selected process and its descendants, or
+    /// stops watching the current subtree.
+    pub fn toggle_subtree(&mut self) {
+        self.subtree = match self.subtree {
+            Some(_) => None,
+            None => self.selected_pid.map(WatchedSubtree::new),
+        };
+        // the scoped list is a different length, start from the top
+        self.process_list.select(Some(0));
     }
-}
 
-impl NetworkInterfaceData {
-    /// Updates all network statistics with new measurements.
+    /// Moves the selection to the row of the locked PID.
     ///
-    /// Pushes new values to all six ring buffers with clamping to prevent
-    /// visualization distortion from transient spikes.
+    /// The lock is released when the PID is no longer listed, leaving the
+    /// selection at its current row.
     ///
     /// # Arguments
     ///
-    /// * `network_data` - Current network interface statistics
-    ///
-    /// # Notes
-    ///
-    /// Uses `push_clamped` to smooth out extreme spikes that could distort
-    /// trend visualization while preserving genuine traffic patterns.
-    pub fn update_network_history(&mut self, network_data: &NetworkInterfaces) {
-        self.received_bytes
-            .push_clamped(network_data.bytes_received);
-        self.transmitted_bytes
-            .push_clamped(network_data.bytes_transmitted);
-        self.packets_received
-            .push_clamped(network_data.packets_received);
-        self.packets_transmitted
-            .push_clamped(network_data.packets_transmitted);
-        self.errors_received
-            .push_clamped(network_data.errors_on_received);
-        self.errors_transmitted
-            .push_clamped(network_data.errors_on_transmitted);
+    /// * `pids` - PIDs of the listed processes, in row order
+    pub fn follow_locked_pid(&mut self, pids: impl IntoIterator<Item = u32>) {
+        let Some(locked_pid) = self.locked_pid else {
+            return;
+        };
+        match pids.into_iter().position(|pid| pid == locked_pid) {
+            Some(row) => self.process_list.select(Some(row)),
+            None => self.locked_pid = None,
+        }
+    }
+
+    /// Returns the columns to render, with Name and Command swapped while
+    /// `show_command` is set.
+    pub fn visible_columns(&self) -> Vec<ProcessColumn> {
+        if self.show_command {
+            ProcessColumn::swap_name_and_command(&self.columns)
+        } else {
+            self.columns.clone()
+        }
     }
 }
 
-/// Fixed-size ring buffer for time-series data storage.
-///
-/// Efficiently stores a bounded history of measurements using a circular
-/// buffer. When capacity is reached, oldest values are automatically
-/// discarded (FIFO behavior).
+/// A process and all of its descendants, watched as one group.
 ///
-/// # Type Parameters
-///
-/// * `T` - Element type
-/// * `N` - Maximum capacity (compile-time constant)
-///
-/// # Examples
-///
-/// ```ignore
-/// let mut ring: Ring<u64, 100> = Ring::new();
-/// ring.push(42);
-/// ring.push(100);
-/// // After 100 pushes, oldest values automatically removed
-/// ```
-#[derive(Debug)]
-pub struct Ring<T, const N: usize> {
-    inner: VecDeque<T>,
+/// The descendants are looked up from the parent PIDs on every tick, so
+/// children spawned or reaped after the watch started are picked up.
+#[derive(Debug, Clone)]
+pub struct WatchedSubtree {
+    /// PID at the top of the subtree
+    pub root_pid: u32,
+
+    /// Time of the previous disk usage measurement, `None` before the first
+    pub last_disk_update: Option<Instant>,
 }
 
-impl<T, const N: usize> Ring<T, N> {
-    /// Creates a new empty ring buffer with capacity `N`.
-    pub fn new() -> Self {
+impl WatchedSubtree {
+    /// Creates a watch on the subtree below `root_pid`.
+    pub fn new(root_pid: u32) -> Self {
         Self {
-            inner: VecDeque::with_capacity(N),
+            root_pid,
+            last_disk_update: None,
         }
     }
 
-    /// Pushes a value onto the ring buffer.
+    /// Returns the PIDs of the root and its descendants in `processes`.
     ///
-    /// If the buffer is at capacity, removes the oldest element before
-    /// adding the new value.
+    /// Empty once the root process exited.
     ///
     /// # Arguments
     ///
-    /// * `value` - Value to append
-    pub fn push(&mut self, value: T) {
-        if self.inner.len() == N {
-            self.inner.pop_front();
+    /// * `processes` - Current process list, read for the parent PIDs
+    pub fn pids(&self, processes: &[ProcessData]) -> HashSet<u32> {
+        if !processes.iter().any(|p| p.pid == self.root_pid) {
+            return HashSet::new();
         }
-        self.inner.push_back(value);
+        ProcessTree::build(processes)
+            .subtree(self.root_pid)
+            .into_iter()
+            .collect()
+    }
+
+    /// Label of the group in titles, e.g. `PID 42 subtree`.
+    pub fn label(&self) -> String {
+        format!("PID {} subtree", self.root_pid)
     }
 
-    /// Returns a mutable slice of the ring buffer's contents in contiguous memory.
+    /// Converts the summed disk usage of the subtree into read and write
+    /// rates in bytes per second.
     ///
-    /// Rearranges elements if necessary to make them contiguous, enabling
-    /// efficient iteration and visualization.
-    pub fn make_contiguous(&mut self) -> &mut [T] {
-        self.inner.make_contiguous()
+    /// # Arguments
+    ///
+    /// * `disk_usage` - Disk I/O of the subtree since the previous measurement
+    /// * `now` - Time of the measurement
+    /// * `refresh_interval` - Configured refresh interval in milliseconds,
+    ///   the elapsed time of the first measurement
+    pub fn disk_rates(
+        &mut self,
+        disk_usage: &DiskUsage,
+        now: Instant,
+        refresh_interval: u64,
+    ) -> (f64, f64) {
+        let elapsed = self
+            .last_disk_update
+            .map(|last| now.duration_since(last))
+            .unwrap_or(Duration::from_millis(refresh_interval));
+        self.last_disk_update = Some(now);
+        (
+            bytes_per_second(disk_usage.read_bytes, elapsed),
+            bytes_per_second(disk_usage.written_bytes, elapsed),
+        )
     }
 }
 
-impl<T, const N: usize> Ring<T, N>
-where
-    T: Copy + Ord + From<u8>,
-{
-    /// Pushes a value with statistical clamping to reduce spike distortion.
-    ///
-    /// Instead of blindly accepting extreme values that could distort
+/// Filter selecting the processes to monitor as one group.
+///
+/// Built from the `--match` argument. By default a process matches when the
+/// pattern is a case-insensitive substring of its name or its full command
+/// line, so `--match nginx` picks up every nginx worker and `--match cargo` a
+/// whole rust build.
+///
+/// A pattern wrapped in slashes (`--match '/^kworker/'`) is a regular
+/// expression instead, and regex mode can be toggled at runtime. The regex
+/// is compiled once whenever the query or mode changes. If it fails to
+/// compile the matcher falls back to a substring match and flags the
+/// pattern as invalid.
+#[derive(Debug, Clone)]
+pub struct ProcessMatcher {
+    /// Pattern as given on the command line, without the `/` delimiters
+    pub pattern: String,
+
+    /// Lowercased pattern used for substring matching
+    needle: String,
+
+    /// Whether the pattern is interpreted as a regular expression
+    pub regex_mode: bool,
+
+    /// Compiled pattern, `None` in substring mode or when the regex is invalid
+    regex: Option<Regex>,
+}
+
+impl ProcessMatcher {
+    /// Creates a matcher for the given pattern.
+    ///
+    /// `/pattern/` enables regex mode, anything else is a substring match.
+    pub fn new(pattern: &str) -> Self {
+        let (pattern, regex_mode) = match pattern
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+        {
+            Some(inner) => (inner, true),
+            None => (pattern, false),
+        };
+
+        let mut matcher = Self {
+            pattern: pattern.to_string(),
+            needle: pattern.to_lowercase(),
+            regex_mode,
+            regex: None,
+        };
+        matcher.compile();
+        matcher
+    }
+
+    /// Switches between regex and substring matching, recompiling the pattern.
+    pub fn toggle_regex_mode(&mut self) {
+        self.regex_mode = !self.regex_mode;
+        self.compile();
+    }
+
+    /// Returns whether regex mode is on but the pattern is not a valid regex.
+    pub fn is_invalid_regex(&self) -> bool {
+        self.regex_mode && self.regex.is_none()
+    }
+
+    /// Returns the pattern for display in titles, e.g. `'nginx'` or `/^kworker/`.
+    pub fn label(&self) -> String {
+        if self.is_invalid_regex() {
+            format!("'{}' (invalid regex)", self.pattern)
+        } else if self.regex_mode {
+            format!("/{}/", self.pattern)
+        } else {
+            format!("'{}'", self.pattern)
+        }
+    }
+
+    /// Returns whether the process name or command line matches the pattern.
+    pub fn matches(&self, process: &ProcessData) -> bool {
+        let command_line = process.command_line();
+        match &self.regex {
+            Some(regex) => regex.is_match(&process.name) || regex.is_match(&command_line),
+            None => {
+                process.name.to_lowercase().contains(&self.needle)
+                    || command_line.to_lowercase().contains(&self.needle)
+            }
+        }
+    }
+
+    fn compile(&mut self) {
+        self.regex = if self.regex_mode {
+            Regex::new(&self.pattern).ok()
+        } else {
+            None
+        };
+    }
+}
+
+/// State management for the process tree table.
+///
+/// Tracks table selection, the number of visible rows, the PID of the
+/// selected node and which nodes have their children collapsed.
+#[derive(Debug)]
+pub struct ProcessTreeUIState {
+    /// Ratatui table state for selection and scrolling
+    pub tree_list: TableState,
+
+    /// Number of currently visible rows in the tree
+    pub row_count: usize,
+
+    /// PID of the selected node (if any)
+    pub selected_pid: Option<u32>,
+
+    /// PIDs whose children are hidden
+    pub collapsed: HashSet<u32>,
+}
+
+impl ProcessTreeUIState {
+    /// Collapses the selected node if expanded, expands it otherwise.
+    pub fn toggle_selected(&mut self) {
+        if let Some(pid) = self.selected_pid {
+            if !self.collapsed.remove(&pid) {
+                self.collapsed.insert(pid);
+            }
+        }
+    }
+}
+
+/// State management for the cgroup table.
+///
+/// The selected cgroup is tracked by path, so the selection stays on the
+/// same cgroup while others appear or disappear above it.
+#[derive(Debug)]
+pub struct CgroupsUIState {
+    /// Ratatui table state for selection and scrolling
+    pub cgroup_list: TableState,
+
+    /// Number of rows in the table
+    pub row_count: usize,
+
+    /// Path of the selected cgroup (if any)
+    pub selected_path: Option<String>,
+}
+
+/// State management for the task table of the single process page.
+///
+/// Tasks can be grouped by thread name, each group shown as one row with
+/// the task count and summed usage, expandable to its individual tasks.
+/// Rows are only selectable while grouped.
+#[derive(Debug)]
+pub struct TasksUIState {
+    /// Ratatui table state for selection and scrolling
+    pub task_list: TableState,
+
+    /// Number of rows in the grouped table
+    pub row_count: usize,
+
+    /// Whether tasks are grouped by thread name
+    pub grouped: bool,
+
+    /// Thread name of the group the selected row belongs to (if any)
+    pub selected_group: Option<String>,
+
+    /// Thread names whose tasks are listed below their group row
+    pub expanded: HashSet<String>,
+}
+
+impl TasksUIState {
+    /// Expands the group of the selected row if collapsed, collapses it
+    /// otherwise.
+    pub fn toggle_selected(&mut self) {
+        if let Some(name) = &self.selected_group {
+            if !self.expanded.remove(name) {
+                self.expanded.insert(name.clone());
+            }
+        }
+    }
+}
+
+impl Default for UIState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl UIState {
+    /// Creates the UI state with the given history window size.
+    ///
+    /// # Arguments
+    ///
+    /// * `history_len` - Number of data points kept for sparklines. `None` uses
+    ///   `MAX_HISTORY_IN_MEMORY` for processes and `MAX_NETWORK_IN_MEMORY` for
+    ///   network interfaces. Values are clamped to `1..=MAX_HISTORY`.
+    pub fn new(history_len: Option<usize>) -> Self {
+        let clamp = |len: usize| len.clamp(1, MAX_HISTORY);
+        let process_history_len = clamp(history_len.unwrap_or(MAX_HISTORY_IN_MEMORY));
+        let network_history_len = clamp(history_len.unwrap_or(MAX_NETWORK_IN_MEMORY));
+
+        Self {
+            process_table: ProcessesUIState {
+                process_list: TableState::default().with_selected(0),
+                process_count: 0,
+                selected_pid: None,
+                zombies_only: false,
+                show_kernel_threads: false,
+                matcher: None,
+                show_command: false,
+                columns: ProcessColumn::DEFAULT.to_vec(),
+                column_menu: None,
+                visible_rows: 0,
+                locked_pid: None,
+                follow_top_cpu: false,
+                subtree: None,
+            },
+            single_process_disk_usage: SingleProcessDiskUsage::new(process_history_len),
+            single_process_network_usage: SingleProcessNetworkUsage::new(process_history_len),
+            single_process_memory_usage: SingleProcessMemoryUsage::new(process_history_len),
+            system_usage: SystemUsageHistory::new(process_history_len),
+            networks_state: None,
+            network_scroll: 0,
+            last_network_fetch: None,
+            network_elapsed: Duration::ZERO,
+            hidden_interfaces: HashSet::new(),
+            interface_menu: None,
+            process_tree: ProcessTreeUIState {
+                tree_list: TableState::default().with_selected(0),
+                row_count: 0,
+                selected_pid: None,
+                collapsed: HashSet::new(),
+            },
+            cgroups: CgroupsUIState {
+                cgroup_list: TableState::default().with_selected(0),
+                row_count: 0,
+                selected_path: None,
+            },
+            history_len: process_history_len,
+            network_history_len,
+            units: UnitConfig::default(),
+            exited_process: None,
+            custom_metrics: Vec::new(),
+            palette: Palette::default(),
+            density: Density::default(),
+            tasks: TasksUIState {
+                task_list: TableState::default().with_selected(0),
+                row_count: 0,
+                grouped: false,
+                selected_group: None,
+                expanded: HashSet::new(),
+            },
+        }
+    }
+
+    /// Records a refresh of the network interfaces, so their byte counts
+    /// can be turned into rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Time of the refresh
+    /// * `refresh_interval` - Refresh interval in milliseconds, assumed for
+    ///   the first refresh
+    pub fn record_network_fetch(&mut self, now: Instant, refresh_interval: u64) {
+        self.network_elapsed = self
+            .last_network_fetch
+            .map(|last| now.duration_since(last))
+            .unwrap_or(Duration::from_millis(refresh_interval));
+        self.last_network_fetch = Some(now);
+    }
+
+    /// Returns the names of every network interface seen so far, sorted,
+    /// hidden ones included. These are the entries of the interface menu.
+    pub fn interface_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .networks_state
+            .iter()
+            .flat_map(|map| map.keys().map(String::as_str))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+}
+
+/// Wrapper for single process data display.
+///
+/// Used to pass process details to the detailed process view.
+pub struct SingleProcessUI<'a> {
+    /// Process data including metrics and metadata
+    pub data: SingleProcessData<'a>,
+}
+
+/// Wrapper for the top-N processes display.
+///
+/// Used to pass the current process list to the Metrics page bar charts.
+pub struct TopProcessesUI {
+    /// All processes the top-N are picked from
+    pub processes: Vec<ProcessData>,
+
+    /// Units of the memory chart
+    pub units: UnitConfig,
+
+    /// Spacing of the charts
+    pub density: Density,
+}
+
+/// Wrapper for the custom metrics display.
+///
+/// Pairs the latest readings with their config entries, which decide
+/// between a gauge and a sparkline.
+pub struct CustomMetricsUI<'a> {
+    /// Latest reading of each metric, in config order
+    pub metrics: &'a [CustomMetric],
+
+    /// Config entry of each metric
+    pub definitions: &'a [CustomMetricConfig],
+
+    /// Decimal places of the values
+    pub units: UnitConfig,
+
+    /// Colors of the gauges and sparklines
+    pub palette: Palette,
+
+    /// Spacing of the gauges and sparklines
+    pub density: Density,
+}
+
+/// Wrapper for the pinned processes display.
+///
+/// Holds the running processes whose name is pinned, taken from the full
+/// process list so they show regardless of the active filters.
+pub struct PinnedProcessesUI<'a> {
+    /// Running processes with a pinned name
+    pub processes: Vec<ProcessData>,
+
+    /// Pinned names without a running process
+    pub not_running: Vec<&'a str>,
+
+    /// Columns to show, the same as the process table
+    pub columns: Vec<ProcessColumn>,
+
+    /// Units of the memory column
+    pub units: UnitConfig,
+
+    /// Colors of highlighted rows
+    pub palette: Palette,
+
+    /// Spacing of the table
+    pub density: Density,
+}
+
+/// Wrapper for the matched process group display.
+///
+/// Holds the processes selected by `--match` together with the system
+/// capacity their summed usage is measured against.
+pub struct MatchedProcessesUI<'a> {
+    /// Matcher label shown in the titles, see `ProcessMatcher::label`
+    pub label: &'a str,
+
+    /// Processes matching the pattern
+    pub processes: &'a [ProcessData],
+
+    /// Number of logical CPUs, CPU usage of the group is out of `100 * cpu_count`
+    pub cpu_count: usize,
+
+    /// Total system memory in bytes
+    pub total_memory: u64,
+
+    /// Units of the memory gauge
+    pub units: UnitConfig,
+
+    /// Colors of the gauges
+    pub palette: Palette,
+
+    /// Spacing of the gauges
+    pub density: Density,
+
+    /// Read and write rates of the group in bytes per second, `None` hides
+    /// the disk section
+    pub disk_rates: Option<(f64, f64)>,
+}
+
+/// Wrapper for process tree display.
+///
+/// Holds the process list together with its parent/child hierarchy.
+pub struct ProcessTreeUI {
+    /// All processes in the tree
+    pub processes: Vec<ProcessData>,
+
+    /// Parent -> children hierarchy of `processes`
+    pub tree: ProcessTree,
+}
+
+/// Wrapper for the cgroup hierarchy display.
+///
+/// Cgroup usage is summed over all processes in the cgroup and its
+/// children, the gauges compare it against the whole machine.
+pub struct CgroupTreeUI {
+    /// Usage of every cgroup with processes
+    pub tree: CgroupTree,
+
+    /// Number of logical CPUs, CPU usage of a cgroup is out of `100 * cpu_count`
+    pub cpu_count: usize,
+
+    /// Total system memory in bytes
+    pub total_memory: u64,
+}
+
+/// A single row of the cgroup table.
+pub struct CgroupTreeRow {
+    /// Cgroup shown in this row
+    pub node: CgroupTreeNode,
+
+    /// Units of the memory column
+    pub units: UnitConfig,
+}
+
+/// A single visible row of the process tree table.
+pub struct ProcessTreeRow<'a> {
+    /// Position of the row in the tree
+    pub node: ProcessTreeNode,
+
+    /// Process shown in this row
+    pub process: &'a ProcessData,
+
+    /// Units of the CPU and memory columns
+    pub units: UnitConfig,
+}
+
+/// Tasks of a process sharing a thread name.
+///
+/// Names are compared without a trailing number, so the `GC Thread#0`,
+/// `GC Thread#1`, ... of a JVM or the `pool-1-thread-N` of a thread pool
+/// form one group each.
+#[derive(Debug, PartialEq)]
+pub struct TaskGroup {
+    /// Thread name shared by the tasks, without the trailing number
+    pub name: String,
+
+    /// Positions of the tasks in the task list, in list order
+    pub indices: Vec<usize>,
+
+    /// Summed CPU usage of the tasks
+    pub cpu_usage: f32,
+
+    /// Memory of the tasks in bytes. Threads share the memory of their
+    /// process, so it's counted once rather than summed
+    pub memory: u64,
+}
+
+impl TaskGroup {
+    /// Groups tasks by name, largest group first and by name among groups
+    /// of the same size, so rows don't jump around as usage changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - Name, CPU usage and memory of each task, in list order
+    pub fn group(tasks: impl IntoIterator<Item = (String, f32, u64)>) -> Vec<TaskGroup> {
+        let mut groups: Vec<TaskGroup> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for (index, (name, cpu_usage, memory)) in tasks.into_iter().enumerate() {
+            let name = Self::group_name(&name).to_string();
+            let position = *positions.entry(name.clone()).or_insert_with(|| {
+                groups.push(TaskGroup {
+                    name,
+                    indices: Vec::new(),
+                    cpu_usage: 0.0,
+                    memory: 0,
+                });
+                groups.len() - 1
+            });
+            let group = &mut groups[position];
+            group.indices.push(index);
+            group.cpu_usage += cpu_usage;
+            group.memory = group.memory.max(memory);
+        }
+        groups.sort_by(|a, b| {
+            b.indices
+                .len()
+                .cmp(&a.indices.len())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        groups
+    }
+
+    /// Strips a trailing number and the separator before it, keeping names
+    /// that are only a number.
+    fn group_name(name: &str) -> &str {
+        let stripped = name
+            .trim_end_matches(|c: char| c.is_ascii_digit())
+            .trim_end_matches(['#', '-', '_', ' ', '/', ':']);
+        if stripped.is_empty() { name } else { stripped }
+    }
+}
+
+/// A single visible row of the grouped task table.
+pub struct TaskGroupRow<'a> {
+    /// Group the row belongs to
+    pub group: &'a TaskGroup,
+
+    /// Task shown in this row, `None` for the summary row of the group
+    pub task: Option<&'a Process>,
+
+    /// Whether the tasks of the group are listed below its summary row
+    pub expanded: bool,
+
+    /// Units of the CPU and memory columns
+    pub units: UnitConfig,
+}
+
+/// A column of the process table.
+///
+/// The set of shown columns and their order is stored in the config file
+/// and can be changed at runtime from the column menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessColumn {
+    Pid,
+    Name,
+    Command,
+    User,
+    Threads,
+    Cpu,
+    Memory,
+    Status,
+    StartTime,
+    Container,
+}
+
+impl ProcessColumn {
+    /// Every column in display order, as listed in the column menu
+    pub const ALL: [ProcessColumn; 10] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Name,
+        ProcessColumn::Command,
+        ProcessColumn::User,
+        ProcessColumn::Threads,
+        ProcessColumn::Cpu,
+        ProcessColumn::Memory,
+        ProcessColumn::Status,
+        ProcessColumn::StartTime,
+        ProcessColumn::Container,
+    ];
+
+    /// Columns shown when none are configured
+    pub const DEFAULT: [ProcessColumn; 6] = [
+        ProcessColumn::Pid,
+        ProcessColumn::Name,
+        ProcessColumn::Cpu,
+        ProcessColumn::Memory,
+        ProcessColumn::Status,
+        ProcessColumn::Container,
+    ];
+
+    /// Returns the header label of the column.
+    pub fn header(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Name => "Name",
+            ProcessColumn::Command => "Command",
+            ProcessColumn::User => "User",
+            ProcessColumn::Threads => "Threads",
+            ProcessColumn::Cpu => "CPU",
+            ProcessColumn::Memory => "Memory",
+            ProcessColumn::Status => "Status",
+            ProcessColumn::StartTime => "Start time",
+            ProcessColumn::Container => "Container",
+        }
+    }
+
+    /// Returns the width constraint of the column.
+    pub fn width(&self) -> Constraint {
+        match self {
+            ProcessColumn::Pid => Constraint::Length(8),
+            ProcessColumn::Name => Constraint::Min(20),
+            ProcessColumn::Command => Constraint::Min(30),
+            ProcessColumn::User => Constraint::Length(12),
+            ProcessColumn::Threads => Constraint::Length(8),
+            ProcessColumn::Cpu => Constraint::Length(10),
+            ProcessColumn::Memory => Constraint::Length(12),
+            ProcessColumn::Status => Constraint::Length(10),
+            ProcessColumn::StartTime => Constraint::Length(19),
+            ProcessColumn::Container => Constraint::Length(16),
+        }
+    }
+
+    /// Returns `columns` with Name and Command swapped, used by the
+    /// name / command line toggle.
+    pub fn swap_name_and_command(columns: &[ProcessColumn]) -> Vec<ProcessColumn> {
+        columns
+            .iter()
+            .map(|column| match column {
+                ProcessColumn::Name => ProcessColumn::Command,
+                ProcessColumn::Command => ProcessColumn::Name,
+                other => *other,
+            })
+            .collect()
+    }
+}
+
+/// A process table row restricted to a chosen set of columns.
+pub struct ProcessColumnsRow<'a> {
+    /// Process shown in this row
+    pub process: &'a ProcessData,
+
+    /// Columns to show, in order
+    pub columns: &'a [ProcessColumn],
+
+    /// Units of the CPU and memory columns
+    pub units: UnitConfig,
+}
+
+/// Time-series storage for a single process's disk I/O activity.
+///
+/// Maintains historical read and write byte counts for visualizing
+/// disk usage trends in sparkline charts. `sysinfo` reports the bytes
+/// read/written since the previous refresh, so each point is a per-refresh
+/// delta, not a cumulative total.
+#[derive(Debug)]
+pub struct SingleProcessDiskUsage {
+    /// PID of the process being tracked
+    pub pid: u32,
+
+    /// Historical disk bytes read per refresh (up to the configured history length)
+    pub disk_read_usage: Ring<u64>,
+
+    /// Historical disk bytes written per refresh (up to the configured history length)
+    pub disk_write_usage: Ring<u64>,
+
+    /// Latest read rate in bytes per second
+    pub read_rate: f64,
+
+    /// Latest write rate in bytes per second
+    pub write_rate: f64,
+
+    /// When the latest measurement was taken
+    pub last_update: Option<Instant>,
+}
+
+impl Default for SingleProcessDiskUsage {
+    fn default() -> Self {
+        Self::new(MAX_HISTORY_IN_MEMORY)
+    }
+}
+
+impl SingleProcessDiskUsage {
+    /// Creates an empty history holding up to `history_len` points.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            pid: 0,
+            disk_read_usage: Ring::new(history_len),
+            disk_write_usage: Ring::new(history_len),
+            read_rate: 0.0,
+            write_rate: 0.0,
+            last_update: None,
+        }
+    }
+
+    /// Updates disk I/O history with new measurements.
+    ///
+    /// Maintains a sliding window of disk read/write data. When the tracked
+    /// PID changes, clears the history to start fresh for the new process.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - Process ID of the current process
+    /// * `disk_usage` - Current disk I/O statistics
+    /// * `now` - Time of the measurement
+    /// * `refresh_interval` - Configured refresh interval in milliseconds
+    ///
+    /// # Behavior
+    ///
+    /// - If PID changes: Clears all history and updates tracked PID
+    /// - If history is full: Removes oldest entry (FIFO)
+    /// - Appends new read/write byte counts to history
+    /// - Computes the read/write rates from the time since the previous
+    ///   measurement, which can be shorter than the refresh interval when a
+    ///   key press redraws early. The first measurement of a process uses
+    ///   `refresh_interval`
+    pub fn update_disk_history(
+        &mut self,
+        pid: u32,
+        disk_usage: &DiskUsage,
+        now: Instant,
+        refresh_interval: u64,
+    ) {
+        // reset the UI state data for disk write/read when changed at current displaying pid
+        if pid != self.pid {
+            self.disk_read_usage.clear();
+            self.disk_write_usage.clear();
+            self.last_update = None;
+            self.pid = pid;
+        }
+
+        let elapsed = self
+            .last_update
+            .map(|last| now.duration_since(last))
+            .unwrap_or(Duration::from_millis(refresh_interval));
+        self.read_rate = bytes_per_second(disk_usage.read_bytes, elapsed);
+        self.write_rate = bytes_per_second(disk_usage.written_bytes, elapsed);
+        self.last_update = Some(now);
+
+        self.disk_read_usage.push(disk_usage.read_bytes);
+        self.disk_write_usage.push(disk_usage.written_bytes);
+    }
+}
+
+/// Time-series storage for a single process's network activity.
+///
+/// Stores the bytes received/transmitted between refreshes, derived from
+/// the cumulative counters reported by the collector.
+#[derive(Debug)]
+pub struct SingleProcessNetworkUsage {
+    /// PID of the process being tracked
+    pub pid: u32,
+
+    /// Cumulative counters from the previous refresh
+    pub last_usage: Option<ProcessNetworkUsage>,
+
+    /// Historical bytes received per refresh (up to the configured history length)
+    pub received: Ring<u64>,
+
+    /// Historical bytes transmitted per refresh (up to the configured history length)
+    pub transmitted: Ring<u64>,
+}
+
+impl Default for SingleProcessNetworkUsage {
+    fn default() -> Self {
+        Self::new(MAX_HISTORY_IN_MEMORY)
+    }
+}
+
+impl SingleProcessNetworkUsage {
+    /// Creates an empty history holding up to `history_len` points.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            pid: 0,
+            last_usage: None,
+            received: Ring::new(history_len),
+            transmitted: Ring::new(history_len),
+        }
+    }
+
+    /// Updates network history with new cumulative measurements.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - Process ID of the current process
+    /// * `network_usage` - Current cumulative network counters, `None` if unsupported
+    ///
+    /// # Behavior
+    ///
+    /// - If PID changes: Clears all history and updates tracked PID
+    /// - The first measurement only primes the counters, later ones push the
+    ///   difference since the previous refresh
+    /// - If history is full: Removes oldest entry (FIFO)
+    pub fn update_network_history(
+        &mut self,
+        pid: u32,
+        network_usage: Option<&ProcessNetworkUsage>,
+    ) {
+        if pid != self.pid {
+            self.received.clear();
+            self.transmitted.clear();
+            self.last_usage = None;
+            self.pid = pid;
+        }
+
+        let Some(usage) = network_usage else {
+            return;
+        };
+
+        if let Some(last) = self.last_usage {
+            self.received
+                .push(usage.received_bytes.saturating_sub(last.received_bytes));
+            self.transmitted.push(
+                usage
+                    .transmitted_bytes
+                    .saturating_sub(last.transmitted_bytes),
+            );
+        }
+        self.last_usage = Some(*usage);
+    }
+}
+
+/// Time-series storage for a single process's memory usage.
+///
+/// Keeps the resident memory of the viewed process on every refresh so
+/// steady growth can be flagged as a possible memory leak.
+#[derive(Debug)]
+pub struct SingleProcessMemoryUsage {
+    /// PID of the process being tracked
+    pub pid: u32,
+
+    /// Historical memory usage in bytes (up to the configured history length)
+    pub memory_usage: Ring<u64>,
+}
+
+impl Default for SingleProcessMemoryUsage {
+    fn default() -> Self {
+        Self::new(MAX_HISTORY_IN_MEMORY)
+    }
+}
+
+impl SingleProcessMemoryUsage {
+    /// Creates an empty history holding up to `history_len` points.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            pid: 0,
+            memory_usage: Ring::new(history_len),
+        }
+    }
+
+    /// Updates memory history with a new measurement.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - Process ID of the current process
+    /// * `memory` - Current memory usage in bytes
+    ///
+    /// # Behavior
+    ///
+    /// - If PID changes: Clears all history and updates tracked PID
+    /// - If history is full: Removes oldest entry (FIFO)
+    pub fn update_memory_history(&mut self, pid: u32, memory: u64) {
+        if pid != self.pid {
+            self.memory_usage.clear();
+            self.pid = pid;
+        }
+
+        self.memory_usage.push(memory);
+    }
+
+    /// Checks the history for a possible memory leak.
+    ///
+    /// A leak is suspected when at least `LEAK_MIN_SAMPLES` samples were
+    /// collected, memory never decreased over the window, and the linear
+    /// regression slope is at least `LEAK_MIN_SLOPE_BYTES` per refresh.
+    ///
+    /// # Returns
+    ///
+    /// The growth rate in bytes per refresh if a leak is suspected, `None` otherwise
+    pub fn possible_leak(&self) -> Option<f64> {
+        if self.memory_usage.len() < LEAK_MIN_SAMPLES {
+            return None;
+        }
+        let samples: Vec<u64> = self.memory_usage.iter().copied().collect();
+
+        let monotonic = samples.windows(2).all(|pair| pair[1] >= pair[0]);
+        if !monotonic {
+            return None;
+        }
+
+        let values: Vec<f64> = samples.iter().map(|v| *v as f64).collect();
+        linear_regression_slope(&values).filter(|slope| *slope >= LEAK_MIN_SLOPE_BYTES)
+    }
+}
+
+/// Time-series storage for system-wide CPU and memory usage.
+///
+/// Stores one usage percentage (0-100) per refresh for the Metrics page
+/// sparklines.
+#[derive(Debug)]
+pub struct SystemUsageHistory {
+    /// Historical CPU usage in percent
+    pub cpu_usage: Ring<u64>,
+
+    /// Historical memory usage in percent of total memory
+    pub memory_usage: Ring<u64>,
+}
+
+impl Default for SystemUsageHistory {
+    fn default() -> Self {
+        Self::new(MAX_HISTORY_IN_MEMORY)
+    }
+}
+
+impl SystemUsageHistory {
+    /// Creates an empty history holding up to `history_len` points.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            cpu_usage: Ring::new(history_len),
+            memory_usage: Ring::new(history_len),
+        }
+    }
+
+    /// Appends the current CPU and memory usage to the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `cpu_usage` - Overall CPU usage in percent
+    /// * `memory_used` - Used memory in bytes
+    /// * `memory_total` - Total memory in bytes
+    ///
+    /// # Behavior
+    ///
+    /// - Percentages are rounded to whole numbers
+    /// - If history is full: Removes oldest entry (FIFO)
+    pub fn update_history(&mut self, cpu_usage: f32, memory_used: u64, memory_total: u64) {
+        self.cpu_usage.push(cpu_usage.round() as u64);
+        self.memory_usage
+            .push(percentage(memory_used as f64, memory_total as f64).round() as u64);
+    }
+}
+
+/// Time-series storage for one custom metric.
+///
+/// Values are rounded to whole numbers for the sparkline, negative values
+/// are shown as zero.
+#[derive(Debug)]
+pub struct CustomMetricHistory {
+    /// Historical values of the metric
+    pub values: Ring<u64>,
+
+    /// Finish time of the last reading added, so that a reading shown on
+    /// several frames is only added once
+    pub last_reading: Option<DateTime<Utc>>,
+}
+
+impl CustomMetricHistory {
+    /// Creates an empty history holding up to `history_len` points.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            values: Ring::new(history_len),
+            last_reading: None,
+        }
+    }
+
+    /// Appends the latest reading of `metric` if it is new and succeeded.
+    pub fn update(&mut self, metric: &CustomMetric) {
+        if self.last_reading == Some(metric.timestamp) {
+            return;
+        }
+        if let Some(Ok(value)) = metric.value {
+            self.values.push(value.max(0.0).round() as u64);
+            self.last_reading = Some(metric.timestamp);
+        }
+    }
+}
+
+/// Time-series storage for a single network interface's statistics.
+///
+/// Maintains historical data for bytes, packets, and errors in both
+/// transmit and receive directions using ring buffers for efficient
+/// memory usage and visualization.
+#[derive(Debug)]
+pub struct NetworkInterfaceData {
+    /// Bytes received over time
+    pub received_bytes: Ring<u64>,
+    /// Bytes transmitted over time
+    pub transmitted_bytes: Ring<u64>,
+    /// Packets received over time
+    pub packets_received: Ring<u64>,
+    /// Packets transmitted over time
+    pub packets_transmitted: Ring<u64>,
+    /// Receive errors over time
+    pub errors_received: Ring<u64>,
+    /// Transmit errors over time
+    pub errors_transmitted: Ring<u64>,
+}
+
+impl Default for NetworkInterfaceData {
+    fn default() -> Self {
+        Self::new(MAX_NETWORK_IN_MEMORY)
+    }
+}
+
+impl NetworkInterfaceData {
+    /// Creates empty histories holding up to `history_len` points each.
+    pub fn new(history_len: usize) -> Self {
+        Self {
+            received_bytes: Ring::new(history_len),
+            transmitted_bytes: Ring::new(history_len),
+            packets_received: Ring::new(history_len),
+            packets_transmitted: Ring::new(history_len),
+            errors_received: Ring::new(history_len),
+            errors_transmitted: Ring::new(history_len),
+        }
+    }
+}
+
+impl NetworkInterfaceData {
+    /// Updates all network statistics with new measurements.
+    ///
+    /// Pushes new values to all six ring buffers with clamping to prevent
+    /// visualization distortion from transient spikes.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_data` - Current network interface statistics
+    ///
+    /// # Notes
+    ///
+    /// Uses `push_clamped` to smooth out extreme spikes that could distort
+    /// trend visualization while preserving genuine traffic patterns.
+    pub fn update_network_history(&mut self, network_data: &NetworkInterfaces) {
+        self.received_bytes
+            .push_clamped(network_data.bytes_received);
+        self.transmitted_bytes
+            .push_clamped(network_data.bytes_transmitted);
+        self.packets_received
+            .push_clamped(network_data.packets_received);
+        self.packets_transmitted
+            .push_clamped(network_data.packets_transmitted);
+        self.errors_received
+            .push_clamped(network_data.errors_on_received);
+        self.errors_transmitted
+            .push_clamped(network_data.errors_on_transmitted);
+    }
+}
+
+/// Bounded ring buffer for time-series data storage.
+///
+/// Efficiently stores a bounded history of measurements using a circular
+/// buffer. When capacity is reached, oldest values are automatically
+/// discarded (FIFO behavior). The capacity is chosen at runtime so the
+/// history window can be configured from the CLI.
+///
+/// # Type Parameters
+///
+/// * `T` - Element type
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut ring: Ring<u64> = Ring::new(100);
+/// ring.push(42);
+/// ring.push(100);
+/// // After 100 pushes, oldest values automatically removed
+/// ```
+#[derive(Debug)]
+pub struct Ring<T> {
+    inner: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> Ring<T> {
+    /// Creates a new empty ring buffer holding at most `capacity` values.
+    ///
+    /// A capacity of zero is treated as one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Pushes a value onto the ring buffer.
+    ///
+    /// If the buffer is at capacity, removes the oldest element before
+    /// adding the new value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to append
+    pub fn push(&mut self, value: T) {
+        if self.inner.len() >= self.capacity {
+            self.inner.pop_front();
+        }
+        self.inner.push_back(value);
+    }
+
+    /// Removes all values from the ring buffer, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns an iterator over the values, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.inner.iter()
+    }
+
+    /// Returns the number of values in the ring buffer.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the ring buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the most recently pushed value, `None` when empty.
+    pub fn latest(&self) -> Option<&T> {
+        self.inner.back()
+    }
+}
+
+impl<T: Copy + Ord> Ring<T> {
+    /// Returns the smallest value, `None` when empty.
+    pub fn min(&self) -> Option<T> {
+        self.inner.iter().copied().min()
+    }
+
+    /// Returns the largest value, `None` when empty.
+    pub fn max(&self) -> Option<T> {
+        self.inner.iter().copied().max()
+    }
+}
+
+impl Ring<u64> {
+    /// Returns the average of the values, `None` when empty.
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.inner.iter().map(|value| *value as f64).sum();
+        Some(sum / self.len() as f64)
+    }
+}
+
+impl<T> Ring<T>
+where
+    T: Copy + Ord + From<u8>,
+{
+    /// Pushes a value with statistical clamping to reduce spike distortion.
+    ///
+    /// Instead of blindly accepting extreme values that could distort
     /// visualization, this method clamps incoming values to a percentile
     /// threshold based on historical data. This preserves genuine trends
     /// while smoothing transient spikes.
@@ -458,13 +1989,13 @@ where
     /// # Examples
     ///
     /// ```ignore
-    /// let mut ring: Ring<u64, 100> = Ring::new();
+    /// let mut ring: Ring<u64> = Ring::new(100);
     /// ring.push_clamped(100);  // Normal value
     /// ring.push_clamped(500);  // Spike, may be clamped
     /// ring.push_clamped(120);  // Normal value
     /// ```
     pub fn push_clamped(&mut self, value: T) {
-        if self.inner.is_empty() {
+        if self.is_empty() {
             self.push(value);
             return;
         }
@@ -507,3 +2038,467 @@ pub struct InputWidgetState {
     /// Recoded message history
     pub messages: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(name: &str, command: &str) -> ProcessData {
+        ProcessData {
+            name: name.to_string(),
+            cmd: command.split_whitespace().map(String::from).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_group_tasks_by_name() {
+        let groups = TaskGroup::group([
+            ("java".to_string(), 1.0, 100),
+            ("GC Thread#0".to_string(), 2.0, 100),
+            ("C2 Compiler".to_string(), 5.0, 100),
+            ("GC Thread#1".to_string(), 3.0, 100),
+        ]);
+        let summary: Vec<(&str, &[usize], f32, u64)> = groups
+            .iter()
+            .map(|group| {
+                (
+                    group.name.as_str(),
+                    group.indices.as_slice(),
+                    group.cpu_usage,
+                    group.memory,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                // both threads report the same process memory
+                ("GC Thread", &[1, 3][..], 5.0, 100),
+                ("C2 Compiler", &[2][..], 5.0, 100),
+                ("java", &[0][..], 1.0, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_task_group_name() {
+        assert_eq!(TaskGroup::group_name("pool-1-thread-12"), "pool-1-thread");
+        assert_eq!(TaskGroup::group_name("kworker/3:1"), "kworker/3");
+        assert_eq!(TaskGroup::group_name("Chrome_ChildIOT"), "Chrome_ChildIOT");
+        assert_eq!(TaskGroup::group_name("1234"), "1234");
+    }
+
+    #[test]
+    fn test_custom_metric_history_adds_each_reading_once() {
+        let mut history = CustomMetricHistory::new(10);
+        let mut metric = CustomMetric {
+            name: "Queue depth".to_string(),
+            value: None,
+            timestamp: Utc::now(),
+        };
+        history.update(&metric);
+        assert!(history.values.is_empty());
+
+        metric.value = Some(Ok(41.6));
+        history.update(&metric);
+        history.update(&metric);
+        assert_eq!(history.values.iter().copied().collect::<Vec<_>>(), vec![42]);
+
+        metric.value = Some(Err("timed out after 5000 ms".to_string()));
+        metric.timestamp += chrono::Duration::seconds(1);
+        history.update(&metric);
+        metric.value = Some(Ok(-3.0));
+        metric.timestamp += chrono::Duration::seconds(1);
+        history.update(&metric);
+        assert_eq!(
+            history.values.iter().copied().collect::<Vec<_>>(),
+            vec![42, 0]
+        );
+    }
+
+    #[test]
+    fn test_ring_stats() {
+        let mut ring: Ring<u64> = Ring::new(4);
+        assert!(ring.is_empty());
+        assert_eq!(ring.latest(), None);
+        assert_eq!(ring.min(), None);
+        assert_eq!(ring.max(), None);
+        assert_eq!(ring.mean(), None);
+
+        for value in [4, 8, 2, 6] {
+            ring.push(value);
+        }
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.latest(), Some(&6));
+        assert_eq!(ring.min(), Some(2));
+        assert_eq!(ring.max(), Some(8));
+        assert_eq!(ring.mean(), Some(5.0));
+
+        // the oldest value, 4, is evicted
+        ring.push(10);
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.latest(), Some(&10));
+        assert_eq!(ring.min(), Some(2));
+        assert_eq!(ring.max(), Some(10));
+        assert_eq!(ring.mean(), Some(6.5));
+    }
+
+    fn ring_values(ring: &Ring<u64>) -> Vec<u64> {
+        ring.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_clamped_into_empty_ring() {
+        // no history to compare against, even a huge value is kept
+        let mut ring = Ring::new(10);
+        ring.push_clamped(1_000_000u64);
+        assert_eq!(ring_values(&ring), vec![1_000_000]);
+    }
+
+    #[test]
+    fn test_push_clamped_clamps_spike_to_percentile() {
+        let mut ring = Ring::new(30);
+        for _ in 0..20 {
+            ring.push_clamped(100u64);
+        }
+        // 21 values, the 95th percentile is index 19 of the sorted values,
+        // still one of the 100s
+        ring.push_clamped(10_000);
+        assert_eq!(ring.iter().last(), Some(&100));
+    }
+
+    #[test]
+    fn test_push_clamped_keeps_normal_values() {
+        let mut ring = Ring::new(30);
+        for value in [100u64, 120, 90, 110, 105] {
+            ring.push_clamped(value);
+        }
+        ring.push_clamped(95);
+        ring.push_clamped(120);
+        assert_eq!(ring_values(&ring), vec![100, 120, 90, 110, 105, 95, 120]);
+    }
+
+    #[test]
+    fn test_push_clamped_keeps_order_and_evicts_oldest() {
+        // select_nth_unstable reorders a copy, never the ring itself
+        let mut ring = Ring::new(3);
+        for value in [3u64, 1, 2, 4] {
+            ring.push_clamped(value);
+        }
+        assert_eq!(ring_values(&ring), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_exited_process_counts_down() {
+        let noticed_at = Instant::now();
+        let exited = ExitedProcess {
+            pid: 42,
+            noticed_at,
+        };
+        assert_eq!(exited.seconds_left(noticed_at), PROCESS_EXITED_RETURN_SECS);
+        assert!(!exited.return_due(noticed_at));
+
+        let almost = noticed_at + Duration::from_secs(PROCESS_EXITED_RETURN_SECS) / 2;
+        assert!(exited.seconds_left(almost) > 0);
+
+        let later = noticed_at + Duration::from_secs(PROCESS_EXITED_RETURN_SECS);
+        assert_eq!(exited.seconds_left(later), 0);
+        assert!(exited.return_due(later));
+    }
+
+    #[test]
+    fn test_move_selection_clamps_to_list() {
+        let mut state = UIState::new(None).process_table;
+        state.process_count = 25;
+
+        state.move_selection(10);
+        assert_eq!(state.process_list.selected(), Some(10));
+        assert_eq!(state.process_list.offset(), 10);
+
+        state.move_selection(100);
+        assert_eq!(state.process_list.selected(), Some(24));
+
+        state.move_selection(-100);
+        assert_eq!(state.process_list.selected(), Some(0));
+        assert_eq!(state.process_list.offset(), 0);
+    }
+
+    #[test]
+    fn test_move_selection_keeps_the_last_page_full() {
+        let mut state = UIState::new(None).process_table;
+        state.process_count = 25;
+        state.visible_rows = 10;
+
+        state.move_selection(10);
+        state.move_selection(10);
+        assert_eq!(state.process_list.selected(), Some(20));
+        // rows 15 to 24 fill the view
+        assert_eq!(state.process_list.offset(), 15);
+
+        state.move_selection(100);
+        assert_eq!(state.process_list.selected(), Some(24));
+        assert_eq!(state.process_list.offset(), 15);
+
+        // fewer processes than rows never scroll
+        state.process_count = 5;
+        state.move_selection(-100);
+        state.move_selection(3);
+        assert_eq!(state.process_list.offset(), 0);
+    }
+
+    #[test]
+    fn test_locked_pid_follows_row() {
+        let mut state = UIState::new(None).process_table;
+        state.process_list.select(Some(1));
+        state.selected_pid = Some(20);
+        state.toggle_lock();
+        assert_eq!(state.locked_pid, Some(20));
+
+        // the list re-sorted, PID 20 moved to the last row
+        state.follow_locked_pid([30, 10, 20]);
+        assert_eq!(state.process_list.selected(), Some(2));
+
+        // PID 20 exited, the selection stays put and the lock is released
+        state.follow_locked_pid([30, 10]);
+        assert_eq!(state.process_list.selected(), Some(2));
+        assert_eq!(state.locked_pid, None);
+    }
+
+    #[test]
+    fn test_follow_top_cpu_row() {
+        let mut state = UIState::new(None).process_table;
+        state.process_list.select(Some(0));
+
+        // not following, the selection stays put
+        state.follow_top_cpu_row([1.0, 50.0, 3.0]);
+        assert_eq!(state.process_list.selected(), Some(0));
+
+        state.toggle_follow_top_cpu();
+        state.follow_top_cpu_row([1.0, 50.0, 3.0]);
+        assert_eq!(state.process_list.selected(), Some(1));
+
+        // the busiest process changed on the next tick
+        state.follow_top_cpu_row([80.0, 50.0, 3.0]);
+        assert_eq!(state.process_list.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_lock_and_follow_top_cpu_exclude_each_other() {
+        let mut state = UIState::new(None).process_table;
+        state.selected_pid = Some(7);
+        state.toggle_follow_top_cpu();
+        state.toggle_lock();
+        assert_eq!(state.locked_pid, Some(7));
+        assert!(!state.follow_top_cpu);
+
+        state.toggle_follow_top_cpu();
+        assert_eq!(state.locked_pid, None);
+        assert!(state.follow_top_cpu);
+    }
+
+    #[test]
+    fn test_toggle_lock_releases_lock() {
+        let mut state = UIState::new(None).process_table;
+        state.selected_pid = Some(7);
+        state.toggle_lock();
+        state.toggle_lock();
+        assert_eq!(state.locked_pid, None);
+    }
+
+    #[test]
+    fn test_subtree_pids_follow_children() {
+        let with_parent = |pid, parent_pid| ProcessData {
+            pid,
+            parent_pid,
+            ..Default::default()
+        };
+        let mut processes = vec![
+            with_parent(1, None),
+            with_parent(10, Some(1)),
+            with_parent(11, Some(10)),
+            with_parent(12, Some(11)),
+            with_parent(20, Some(1)),
+        ];
+        let subtree = WatchedSubtree::new(10);
+        assert_eq!(subtree.pids(&processes), HashSet::from([10, 11, 12]));
+
+        // a child spawned after the watch started is picked up
+        processes.push(with_parent(13, Some(10)));
+        assert_eq!(subtree.pids(&processes), HashSet::from([10, 11, 12, 13]));
+
+        // the root exited
+        processes.retain(|p| p.pid != 10);
+        assert!(subtree.pids(&processes).is_empty());
+    }
+
+    #[test]
+    fn test_toggle_subtree_watches_selected_pid() {
+        let mut state = UIState::new(None).process_table;
+        state.toggle_subtree();
+        assert!(state.subtree.is_none());
+
+        state.selected_pid = Some(42);
+        state.process_list.select(Some(3));
+        state.toggle_subtree();
+        assert_eq!(state.subtree.as_ref().map(|s| s.root_pid), Some(42));
+        assert_eq!(state.process_list.selected(), Some(0));
+
+        state.toggle_subtree();
+        assert!(state.subtree.is_none());
+    }
+
+    #[test]
+    fn test_subtree_disk_rates() {
+        let mut subtree = WatchedSubtree::new(1);
+        let now = Instant::now();
+        let usage = DiskUsage {
+            read_bytes: 2000,
+            written_bytes: 500,
+            ..Default::default()
+        };
+        // the first measurement is over one refresh interval
+        assert_eq!(subtree.disk_rates(&usage, now, 1000), (2000.0, 500.0));
+        let later = now + Duration::from_millis(500);
+        assert_eq!(subtree.disk_rates(&usage, later, 1000), (4000.0, 1000.0));
+    }
+
+    #[test]
+    fn test_interface_names_are_sorted() {
+        let mut state = UIState::new(None);
+        assert!(state.interface_names().is_empty());
+
+        state.networks_state = Some(HashMap::from([
+            ("wlan0".to_string(), NetworkInterfaceData::new(10)),
+            ("docker0".to_string(), NetworkInterfaceData::new(10)),
+            ("lo".to_string(), NetworkInterfaceData::new(10)),
+        ]));
+        assert_eq!(state.interface_names(), vec!["docker0", "lo", "wlan0"]);
+    }
+
+    #[test]
+    fn test_visible_columns_swaps_name_and_command() {
+        let mut state = UIState::new(None).process_table;
+        state.columns = vec![ProcessColumn::Pid, ProcessColumn::Command];
+        assert_eq!(state.visible_columns(), state.columns);
+
+        state.show_command = true;
+        assert_eq!(
+            state.visible_columns(),
+            vec![ProcessColumn::Pid, ProcessColumn::Name]
+        );
+    }
+
+    #[test]
+    fn test_matcher_substring_is_case_insensitive() {
+        let matcher = ProcessMatcher::new("NGINX");
+        assert!(!matcher.regex_mode);
+        assert!(matcher.matches(&process("nginx", "nginx: worker process")));
+        assert!(matcher.matches(&process("sh", "/usr/sbin/nginx -g daemon off;")));
+        assert!(!matcher.matches(&process("bash", "bash")));
+    }
+
+    #[test]
+    fn test_matcher_slashes_enable_regex() {
+        let matcher = ProcessMatcher::new("/^kworker/");
+        assert!(matcher.regex_mode);
+        assert_eq!(matcher.pattern, "^kworker");
+        assert_eq!(matcher.label(), "/^kworker/");
+        assert!(matcher.matches(&process("kworker/0:1-events", "")));
+        assert!(!matcher.matches(&process("my-kworker", "")));
+    }
+
+    #[test]
+    fn test_matcher_invalid_regex_falls_back_to_substring() {
+        let matcher = ProcessMatcher::new("/cargo(/");
+        assert!(matcher.is_invalid_regex());
+        assert_eq!(matcher.label(), "'cargo(' (invalid regex)");
+        assert!(matcher.matches(&process("rustc", "cargo( build")));
+        assert!(!matcher.matches(&process("cargo", "cargo build")));
+    }
+
+    #[test]
+    fn test_matcher_toggle_regex_mode() {
+        let mut matcher = ProcessMatcher::new("^rust");
+        assert!(!matcher.matches(&process("rustc", "rustc --edition 2024")));
+
+        matcher.toggle_regex_mode();
+        assert!(matcher.regex_mode);
+        assert!(matcher.matches(&process("rustc", "rustc --edition 2024")));
+
+        matcher.toggle_regex_mode();
+        assert!(!matcher.regex_mode);
+        assert!(!matcher.matches(&process("rustc", "rustc --edition 2024")));
+    }
+
+    #[test]
+    fn test_disk_rates_use_elapsed_time() {
+        let mut disk = SingleProcessDiskUsage::new(10);
+        let usage = DiskUsage {
+            read_bytes: 4096,
+            written_bytes: 1024,
+            ..Default::default()
+        };
+        let start = Instant::now();
+
+        // the first measurement falls back to the refresh interval
+        disk.update_disk_history(1, &usage, start, 2000);
+        assert_eq!(disk.read_rate, 2048.0);
+        assert_eq!(disk.write_rate, 512.0);
+
+        disk.update_disk_history(1, &usage, start + Duration::from_millis(500), 2000);
+        assert_eq!(disk.read_rate, 8192.0);
+        assert_eq!(
+            disk.disk_read_usage.iter().copied().collect::<Vec<_>>(),
+            [4096, 4096]
+        );
+
+        // a new process starts over
+        disk.update_disk_history(2, &usage, start + Duration::from_secs(1), 1000);
+        assert_eq!(disk.read_rate, 4096.0);
+        assert_eq!(
+            disk.disk_read_usage.iter().copied().collect::<Vec<_>>(),
+            [4096]
+        );
+    }
+
+    fn memory_history(samples: &[u64]) -> SingleProcessMemoryUsage {
+        let mut history = SingleProcessMemoryUsage::new(MAX_HISTORY_IN_MEMORY);
+        for memory in samples {
+            history.update_memory_history(1, *memory);
+        }
+        history
+    }
+
+    #[test]
+    fn test_possible_leak_rising() {
+        let step = LEAK_MIN_SLOPE_BYTES as u64;
+        let samples: Vec<u64> = (0..LEAK_MIN_SAMPLES as u64).map(|i| i * step).collect();
+        assert_eq!(
+            memory_history(&samples).possible_leak(),
+            Some(LEAK_MIN_SLOPE_BYTES)
+        );
+    }
+
+    #[test]
+    fn test_possible_leak_flat_or_slow() {
+        let flat = vec![4096; LEAK_MIN_SAMPLES];
+        assert_eq!(memory_history(&flat).possible_leak(), None);
+
+        // rising, but slower than LEAK_MIN_SLOPE_BYTES per refresh
+        let slow: Vec<u64> = (0..LEAK_MIN_SAMPLES as u64).map(|i| i * 1024).collect();
+        assert_eq!(memory_history(&slow).possible_leak(), None);
+    }
+
+    #[test]
+    fn test_possible_leak_needs_samples_and_growth() {
+        let step = LEAK_MIN_SLOPE_BYTES as u64;
+        let short: Vec<u64> = (0..LEAK_MIN_SAMPLES as u64 - 1).map(|i| i * step).collect();
+        assert_eq!(memory_history(&short).possible_leak(), None);
+
+        // a single drop means the memory was released
+        let mut dropped: Vec<u64> = (0..LEAK_MIN_SAMPLES as u64).map(|i| i * step).collect();
+        dropped[5] = 0;
+        assert_eq!(memory_history(&dropped).possible_leak(), None);
+    }
+}
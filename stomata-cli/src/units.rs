@@ -0,0 +1,368 @@
+//! Units for memory and network values
+//!
+//! Memory is shown in binary (KiB, MiB, 1024-based) or decimal (kB, MB,
+//! 1000-based) units, network traffic in bytes or bits, with 0 to 3
+//! decimals, and process CPU usage per core or normalized to the whole
+//! machine. The choice is read from the `units` section of
+//! `~/.stomata/config.json`:
+//!
+//! ```json
+//! "units": {
+//!   "memory": "decimal",
+//!   "network": "bits",
+//!   "cpu": "normalized",
+//!   "precision": 0
+//! }
+//! ```
+//!
+//! Every label names the unit it was computed with, so a value is never
+//! ambiguous between MB and MiB.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{DEFAULT_PRECISION, MAX_PRECISION};
+
+/// Base of the memory units
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryUnit {
+    /// 1024-based: KiB, MiB, GiB, TiB
+    #[default]
+    Binary,
+
+    /// 1000-based: kB, MB, GB, TB
+    Decimal,
+}
+
+/// Unit of network traffic
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkUnit {
+    /// Bytes, scaled like memory
+    #[default]
+    Bytes,
+
+    /// Bits, always scaled by 1000 (kb, Mb, Gb and kbps, Mbps, Gbps for
+    /// rates) as link speeds are
+    Bits,
+}
+
+/// Scale of the CPU usage of processes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CpuUnit {
+    /// Percent of one core as reported by sysinfo, up to `100% * cpu_count`
+    #[default]
+    PerCore,
+
+    /// Percent of the whole machine, 0 to 100%
+    Normalized,
+}
+
+impl CpuUnit {
+    /// Returns the name of the scale shown in the table titles
+    pub fn label(self) -> &'static str {
+        match self {
+            CpuUnit::PerCore => "per core",
+            CpuUnit::Normalized => "normalized",
+        }
+    }
+
+    /// Returns the other scale, for the toggle key
+    pub fn toggled(self) -> Self {
+        match self {
+            CpuUnit::PerCore => CpuUnit::Normalized,
+            CpuUnit::Normalized => CpuUnit::PerCore,
+        }
+    }
+}
+
+/// Units and precision used to display memory, network and percentage values.
+///
+/// Defaults to binary memory units, network traffic in bytes, CPU usage
+/// per core and 2 decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitConfig {
+    /// Base of memory, disk and swap values
+    pub memory: MemoryUnit,
+
+    /// Bytes or bits for network traffic
+    pub network: NetworkUnit,
+
+    /// Per core or normalized CPU usage of processes
+    pub cpu: CpuUnit,
+
+    /// Decimals of gauges, paragraphs and scaled sizes, at most `MAX_PRECISION`
+    pub precision: u8,
+
+    /// Number of logical CPUs normalized CPU usage is divided by, set at
+    /// startup rather than configured
+    #[serde(skip)]
+    pub cpu_count: usize,
+}
+
+impl Default for UnitConfig {
+    fn default() -> Self {
+        Self {
+            memory: MemoryUnit::default(),
+            network: NetworkUnit::default(),
+            cpu: CpuUnit::default(),
+            precision: DEFAULT_PRECISION,
+            cpu_count: 1,
+        }
+    }
+}
+
+const BINARY_UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+const DECIMAL_UNITS: [&str; 4] = ["kB", "MB", "GB", "TB"];
+const BIT_UNITS: [&str; 4] = ["kb", "Mb", "Gb", "Tb"];
+const BIT_RATE_UNITS: [&str; 4] = ["kbps", "Mbps", "Gbps", "Tbps"];
+
+impl UnitConfig {
+    /// Returns the number of decimals to show, clamped to `MAX_PRECISION`.
+    pub fn precision(&self) -> usize {
+        self.precision.min(MAX_PRECISION) as usize
+    }
+
+    /// Formats a percentage with the configured decimals, e.g. `"45%"` or
+    /// `"45.00%"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Percentage between 0 and 100
+    pub fn format_percent(&self, value: f64) -> String {
+        format!("{value:.precision$}%", precision = self.precision())
+    }
+
+    /// Converts a process CPU usage in percent of one core to the
+    /// configured scale, see [`CpuUnit`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cpu_usage` - CPU usage as reported by sysinfo
+    pub fn to_cpu(&self, cpu_usage: f32) -> f32 {
+        match self.cpu {
+            CpuUnit::PerCore => cpu_usage,
+            CpuUnit::Normalized => cpu_usage / self.cpu_count.max(1) as f32,
+        }
+    }
+
+    /// Formats a process CPU usage for a table column, e.g. `"380.00%"`
+    /// per core or `"47.50%"` normalized on 8 CPUs.
+    ///
+    /// # Arguments
+    ///
+    /// * `cpu_usage` - CPU usage as reported by sysinfo
+    pub fn format_cpu(&self, cpu_usage: f32) -> String {
+        format!("{:.2}%", self.to_cpu(cpu_usage))
+    }
+
+    fn memory_base(&self) -> f64 {
+        match self.memory {
+            MemoryUnit::Binary => 1024.0,
+            MemoryUnit::Decimal => 1000.0,
+        }
+    }
+
+    /// Converts bytes to mebibytes or megabytes, see [`Self::mega_label`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Number of bytes to convert
+    pub fn to_mega(&self, bytes: u64) -> f64 {
+        bytes as f64 / self.memory_base().powi(2)
+    }
+
+    /// Returns the label of [`Self::to_mega`] values, `"MiB"` or `"MB"`.
+    pub fn mega_label(&self) -> &'static str {
+        match self.memory {
+            MemoryUnit::Binary => BINARY_UNITS[1],
+            MemoryUnit::Decimal => DECIMAL_UNITS[1],
+        }
+    }
+
+    /// Formats a memory size in mebibytes or megabytes, e.g. `"512.00 MiB"`
+    /// with the default precision.
+    ///
+    /// Used in table columns, where a fixed unit keeps the rows comparable.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Number of bytes
+    pub fn format_mega(&self, bytes: u64) -> String {
+        format!(
+            "{:.precision$} {}",
+            self.to_mega(bytes),
+            self.mega_label(),
+            precision = self.precision()
+        )
+    }
+
+    /// Formats a byte count in the largest fitting memory unit, e.g.
+    /// `"512 B"` or `"1.50 KiB"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Number of bytes, fractional for rates
+    pub fn format_bytes(&self, bytes: f64) -> String {
+        let units = match self.memory {
+            MemoryUnit::Binary => BINARY_UNITS,
+            MemoryUnit::Decimal => DECIMAL_UNITS,
+        };
+        scale(bytes, self.memory_base(), "B", units, self.precision())
+    }
+
+    /// Formats an amount of network traffic in bytes or bits, e.g.
+    /// `"1.50 KiB"` or `"12.29 kb"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Number of bytes transferred
+    pub fn format_network(&self, bytes: f64) -> String {
+        match self.network {
+            NetworkUnit::Bytes => self.format_bytes(bytes),
+            NetworkUnit::Bits => scale(bytes * 8.0, 1000.0, "b", BIT_UNITS, self.precision()),
+        }
+    }
+
+    /// Formats a network transfer rate in bytes or bits per second, e.g.
+    /// `"1.50 KiB/s"` or `"12.29 kbps"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes_per_second` - Bytes transferred per second
+    pub fn format_network_rate(&self, bytes_per_second: f64) -> String {
+        match self.network {
+            NetworkUnit::Bytes => format!("{}/s", self.format_bytes(bytes_per_second)),
+            NetworkUnit::Bits => scale(
+                bytes_per_second * 8.0,
+                1000.0,
+                "bps",
+                BIT_RATE_UNITS,
+                self.precision(),
+            ),
+        }
+    }
+}
+
+/// Divides `value` by `base` until it fits below `base` and appends the unit.
+///
+/// Whole units like bytes are never shown with decimals.
+fn scale(value: f64, base: f64, unit: &str, units: [&str; 4], precision: usize) -> String {
+    if value < base {
+        return format!("{value:.0} {unit}");
+    }
+    let mut value = value / base;
+    let mut index = 0;
+    while value >= base && index < units.len() - 1 {
+        value /= base;
+        index += 1;
+    }
+    format!("{value:.precision$} {}", units[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECIMAL: UnitConfig = UnitConfig {
+        memory: MemoryUnit::Decimal,
+        network: NetworkUnit::Bits,
+        cpu: CpuUnit::PerCore,
+        precision: DEFAULT_PRECISION,
+        cpu_count: 1,
+    };
+
+    #[test]
+    fn test_format_bytes() {
+        let binary = UnitConfig::default();
+        assert_eq!(binary.format_bytes(512.0), "512 B");
+        assert_eq!(binary.format_bytes(1536.0), "1.50 KiB");
+        assert_eq!(
+            binary.format_bytes(5.0 * 1024.0 * 1024.0 * 1024.0),
+            "5.00 GiB"
+        );
+
+        assert_eq!(DECIMAL.format_bytes(999.0), "999 B");
+        assert_eq!(DECIMAL.format_bytes(1500.0), "1.50 kB");
+        assert_eq!(DECIMAL.format_bytes(5e9), "5.00 GB");
+    }
+
+    #[test]
+    fn test_mega_matches_its_label() {
+        let binary = UnitConfig::default();
+        assert_eq!(binary.to_mega(3 * 1024 * 1024), 3.0);
+        assert_eq!(binary.format_mega(512 * 1024 * 1024), "512.00 MiB");
+
+        assert_eq!(DECIMAL.to_mega(3_000_000), 3.0);
+        assert_eq!(DECIMAL.format_mega(512 * 1024 * 1024), "536.87 MB");
+    }
+
+    #[test]
+    fn test_format_network_in_bits() {
+        assert_eq!(UnitConfig::default().format_network(1536.0), "1.50 KiB");
+        // 125 kB are a megabit
+        assert_eq!(DECIMAL.format_network(125_000.0), "1.00 Mb");
+        assert_eq!(DECIMAL.format_network(100.0), "800 b");
+    }
+
+    #[test]
+    fn test_format_network_rate() {
+        assert_eq!(
+            UnitConfig::default().format_network_rate(1536.0),
+            "1.50 KiB/s"
+        );
+        assert_eq!(DECIMAL.format_network_rate(125_000.0), "1.00 Mbps");
+        assert_eq!(DECIMAL.format_network_rate(100.0), "800 bps");
+    }
+
+    #[test]
+    fn test_format_cpu() {
+        let per_core = UnitConfig {
+            cpu_count: 8,
+            ..UnitConfig::default()
+        };
+        assert_eq!(per_core.format_cpu(380.0), "380.00%");
+
+        let normalized = UnitConfig {
+            cpu: per_core.cpu.toggled(),
+            ..per_core
+        };
+        assert_eq!(normalized.cpu, CpuUnit::Normalized);
+        assert_eq!(normalized.format_cpu(380.0), "47.50%");
+        assert_eq!(normalized.format_cpu(800.0), "100.00%");
+    }
+
+    #[test]
+    fn test_units_from_config() {
+        let units: UnitConfig = serde_json::from_str(r#"{"network": "bits"}"#).unwrap();
+        assert_eq!(units.memory, MemoryUnit::Binary);
+        assert_eq!(units.network, NetworkUnit::Bits);
+        assert_eq!(units.precision(), 2);
+    }
+
+    #[test]
+    fn test_precision() {
+        let integer = UnitConfig {
+            precision: 0,
+            ..UnitConfig::default()
+        };
+        assert_eq!(integer.format_percent(45.0), "45%");
+        assert_eq!(integer.format_mega(512 * 1024 * 1024), "512 MiB");
+        assert_eq!(integer.format_bytes(1536.0), "2 KiB");
+
+        let precise = UnitConfig {
+            precision: 3,
+            ..UnitConfig::default()
+        };
+        assert_eq!(precise.format_percent(45.0), "45.000%");
+
+        // more decimals than MAX_PRECISION are clamped
+        let too_precise = UnitConfig {
+            precision: 9,
+            ..UnitConfig::default()
+        };
+        assert_eq!(too_precise.format_percent(45.0), "45.000%");
+    }
+}
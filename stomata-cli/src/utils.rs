@@ -3,25 +3,336 @@
 //! Provides helper functions for converting between different units and
 //! formats commonly used in system monitoring displays.
 
-/// Converts bytes to megabytes.
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+
+use crate::constants::{
+    MAX_REFRESH_INTERVAL_MS, MIN_REFRESH_INTERVAL_MS, REFRESH_INTERVAL_STEP_MS,
+};
+
+/// Converts a byte count measured over `elapsed` into bytes per second.
+///
+/// Returns `0.0` for a zero duration instead of infinity.
+///
+/// # Arguments
+///
+/// * `bytes` - Bytes transferred during `elapsed`
+/// * `elapsed` - Time the bytes were measured over
+pub fn bytes_per_second(bytes: u64, elapsed: std::time::Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 { bytes as f64 / secs } else { 0.0 }
+}
+
+/// Formats a duration in seconds as `Dd HHh MMm SSs`.
+///
+/// # Arguments
+///
+/// * `secs` - Duration in seconds
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(format_duration(84213), "0d 23h 23m 33s");
+/// ```
+pub fn format_duration(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = (secs % 86_400) / 3_600;
+    let minutes = (secs % 3_600) / 60;
+    let seconds = secs % 60;
+    format!("{days}d {hours:02}h {minutes:02}m {seconds:02}s")
+}
+
+/// Formats a unix timestamp in seconds as a local `YYYY-MM-DD HH:MM:SS` datetime.
+///
+/// Returns `"unknown"` for timestamps chrono cannot represent.
+///
+/// # Arguments
+///
+/// * `timestamp` - Seconds since the unix epoch
+pub fn format_local_datetime(timestamp: u64) -> String {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(DateTime::from_timestamp_secs)
+        .map(|datetime| {
+            datetime
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Calculates `value` as a percentage of `total`.
 ///
-/// Performs binary conversion (1024-based) from bytes to megabytes,
-/// returning a floating-point value for precision in display.
+/// Returns `0.0` when `total` is zero (e.g. a system without swap)
+/// instead of producing `NaN` or infinity.
 ///
 /// # Arguments
 ///
-/// * `bytes` - Number of bytes to convert
+/// * `value` - The used amount
+/// * `total` - The total amount available
+pub fn percentage(value: f64, total: f64) -> f64 {
+    if total > 0.0 {
+        value / total * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Computes the least-squares linear regression slope of a series.
+///
+/// Values are treated as evenly spaced samples, so the slope is expressed
+/// in units per sample.
+///
+/// # Arguments
+///
+/// * `values` - Samples ordered from oldest to newest
 ///
 /// # Returns
 ///
-/// The equivalent value in megabytes as an f64
+/// `None` when there are fewer than two samples, otherwise the slope
+pub fn linear_regression_slope(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+
+    let n = values.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f64>() / n;
+
+    let (covariance, variance) =
+        values
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                let dx = x as f64 - mean_x;
+                (covariance + dx * (y - mean_y), variance + dx * dx)
+            });
+
+    Some(covariance / variance)
+}
+
+/// Returns true if a process status string denotes a zombie/defunct process.
+///
+/// Matches `"Zombie"` and `"Defunct"` case-insensitively, covering the
+/// status names reported by sysinfo on the supported platforms.
+///
+/// # Arguments
+///
+/// * `status` - Process status as displayed in the process table
+pub fn is_zombie_status(status: &str) -> bool {
+    status.eq_ignore_ascii_case("zombie") || status.eq_ignore_ascii_case("defunct")
+}
+
+/// Steps a refresh interval up or down by `REFRESH_INTERVAL_STEP_MS`.
 ///
-/// # Conversion
+/// The result is clamped between `MIN_REFRESH_INTERVAL_MS` and
+/// `MAX_REFRESH_INTERVAL_MS`.
+///
+/// # Arguments
+///
+/// * `interval_ms` - Current refresh interval in milliseconds
+/// * `increase` - `true` to slow down refreshing, `false` to speed it up
+pub fn step_refresh_interval(interval_ms: u64, increase: bool) -> u64 {
+    let stepped = if increase {
+        interval_ms.saturating_add(REFRESH_INTERVAL_STEP_MS)
+    } else {
+        interval_ms.saturating_sub(REFRESH_INTERVAL_STEP_MS)
+    };
+    stepped.clamp(MIN_REFRESH_INTERVAL_MS, MAX_REFRESH_INTERVAL_MS)
+}
+
+/// Picks the columns shown when not all of them fit.
 ///
-/// Uses binary (base-1024) conversion:
-/// - 1 KB = 1,024 bytes
-/// - 1 MB = 1,024 KB = 1,048,576 bytes
+/// As many columns as fit at `min_width` are shown, at least one. The
+/// offset is clamped so the last window is always full.
+///
+/// # Arguments
+///
+/// * `total` - Number of columns
+/// * `width` - Available width in cells
+/// * `min_width` - Narrowest width a column stays readable at
+/// * `offset` - Index of the first column the user scrolled to
+///
+/// # Returns
+///
+/// `(first, count)` of the visible columns
+pub fn visible_columns(total: usize, width: u16, min_width: u16, offset: usize) -> (usize, usize) {
+    let fitting = (width / min_width.max(1)).max(1) as usize;
+    let count = fitting.min(total);
+    (offset.min(total - count), count)
+}
+
+/// Parses and validates the `--interval` argument.
+///
+/// Intervals below `MIN_REFRESH_INTERVAL_MS` are rejected, a zero interval
+/// would turn the render loop into a busy loop.
+///
+/// # Arguments
+///
+/// * `value` - The raw argument value in milliseconds
+///
+/// # Returns
+///
+/// The interval, or a message explaining why it was rejected
+pub fn parse_refresh_interval(value: &str) -> Result<u64, String> {
+    let interval: u64 = value
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number of milliseconds"))?;
+    if interval < MIN_REFRESH_INTERVAL_MS {
+        return Err(format!(
+            "refresh interval must be at least {MIN_REFRESH_INTERVAL_MS} ms, got {interval} ms"
+        ));
+    }
+    Ok(interval)
+}
+
+/// Formats the age of the shown data for the status line, e.g.
+/// `updated 3s ago`, marked `(stale)` once it's older than `stale_after`.
+///
+/// # Arguments
+///
+/// * `age` - Time since the shown data was collected
+/// * `stale_after` - Age from which the data no longer reflects the
+///   refresh interval
+///
+/// # Returns
+///
+/// The label and whether the data is stale
+pub fn updated_ago(age: Duration, stale_after: Duration) -> (String, bool) {
+    let label = format!("updated {}s ago", age.as_secs());
+    if age > stale_after {
+        (format!("{label} (stale)"), true)
+    } else {
+        (label, false)
+    }
+}
+
+/// Formats how long collecting the shown data took for the status line,
+/// e.g. `collected in 120ms`, marked `(stale)` once it took longer than
+/// `stale_after`.
+///
+/// # Arguments
+///
+/// * `took` - Time spent collecting the shown data
+/// * `stale_after` - Duration from which the data no longer keeps up with
+///   the refresh interval
+///
+/// # Returns
+///
+/// The label and whether the data is stale
+pub fn collected_in(took: Duration, stale_after: Duration) -> (String, bool) {
+    let label = format!("collected in {}ms", took.as_millis());
+    if took > stale_after {
+        (format!("{label} (stale)"), true)
+    } else {
+        (label, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_updated_ago() {
+        let interval = Duration::from_millis(1000);
+        assert_eq!(
+            updated_ago(Duration::from_millis(40), interval),
+            ("updated 0s ago".to_string(), false)
+        );
+        assert_eq!(
+            updated_ago(Duration::from_millis(1000), interval),
+            ("updated 1s ago".to_string(), false)
+        );
+        assert_eq!(
+            updated_ago(Duration::from_millis(3500), interval),
+            ("updated 3s ago (stale)".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_linear_regression_slope() {
+        assert_eq!(linear_regression_slope(&[]), None);
+        assert_eq!(linear_regression_slope(&[5.0]), None);
+        assert_eq!(linear_regression_slope(&[3.0, 3.0, 3.0, 3.0]), Some(0.0));
+        assert_eq!(linear_regression_slope(&[1.0, 3.0]), Some(2.0));
+        assert_eq!(
+            linear_regression_slope(&[0.0, 10.0, 20.0, 30.0]),
+            Some(10.0)
+        );
+        assert_eq!(linear_regression_slope(&[30.0, 20.0, 10.0]), Some(-10.0));
+    }
+
+    #[test]
+    fn test_collected_in() {
+        let interval = Duration::from_millis(1000);
+        assert_eq!(
+            collected_in(Duration::from_millis(40), interval),
+            ("collected in 40ms".to_string(), false)
+        );
+        assert_eq!(
+            collected_in(Duration::from_millis(1000), interval),
+            ("collected in 1000ms".to_string(), false)
+        );
+        assert_eq!(
+            collected_in(Duration::from_millis(1500), interval),
+            ("collected in 1500ms (stale)".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn test_parse_refresh_interval() {
+        assert_eq!(parse_refresh_interval("1000"), Ok(1000));
+        assert_eq!(
+            parse_refresh_interval(&MIN_REFRESH_INTERVAL_MS.to_string()),
+            Ok(MIN_REFRESH_INTERVAL_MS)
+        );
+        assert!(parse_refresh_interval("0").is_err());
+        assert!(parse_refresh_interval("-5").is_err());
+        assert!(parse_refresh_interval("fast").is_err());
+    }
+
+    #[test]
+    fn test_format_duration_zero() {
+        assert_eq!(format_duration(0), "0d 00h 00m 00s");
+    }
+
+    #[test]
+    fn test_format_duration_under_a_day() {
+        assert_eq!(format_duration(84213), "0d 23h 23m 33s");
+    }
+
+    #[test]
+    fn test_format_duration_days() {
+        assert_eq!(format_duration(2 * 86_400 + 3_600 + 61), "2d 01h 01m 01s");
+    }
+
+    #[test]
+    fn test_bytes_per_second() {
+        use std::time::Duration;
+
+        assert_eq!(bytes_per_second(2048, Duration::from_millis(500)), 4096.0);
+        assert_eq!(bytes_per_second(2048, Duration::ZERO), 0.0);
+    }
+
+    #[test]
+    fn test_format_local_datetime_out_of_range() {
+        assert_eq!(format_local_datetime(u64::MAX), "unknown");
+    }
 
-pub fn bytes_to_mb(bytes: u64) -> f64 {
-    (bytes as f64) / (1024.0 * 1024.0)
+    #[test]
+    fn test_visible_columns() {
+        // everything fits
+        assert_eq!(visible_columns(3, 120, 32, 0), (0, 3));
+        // 12 bridges on a 100 cell terminal show 3 at a time
+        assert_eq!(visible_columns(12, 100, 32, 0), (0, 3));
+        assert_eq!(visible_columns(12, 100, 32, 4), (4, 3));
+        // scrolling past the end keeps the last window full
+        assert_eq!(visible_columns(12, 100, 32, 11), (9, 3));
+        // a terminal narrower than one column still shows one
+        assert_eq!(visible_columns(12, 10, 32, 0), (0, 1));
+        assert_eq!(visible_columns(0, 100, 32, 2), (0, 0));
+    }
 }
@@ -0,0 +1,187 @@
+use std::{
+    io::Read,
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+
+use crate::{collectors::custom::metrics::CustomMetric, constants::CUSTOM_METRIC_POLL_MS};
+
+/// Reads a metric from the stdout of a shell command
+///
+/// The command runs on a background thread so a slow one never stalls the
+/// caller. Each fetch picks up the result of the previous run and starts
+/// the next one, and runs exceeding the timeout are killed.
+#[derive(Debug)]
+pub struct CustomMetricCollector {
+    command: String,
+    timeout: Duration,
+    latest: CustomMetric,
+    running: Option<Receiver<Result<f64, String>>>,
+}
+
+impl CustomMetricCollector {
+    pub fn new(name: &str, command: &str, timeout: Duration) -> Self {
+        Self {
+            command: command.to_string(),
+            timeout,
+            latest: CustomMetric {
+                name: name.to_string(),
+                value: None,
+                timestamp: Utc::now(),
+            },
+            running: None,
+        }
+    }
+
+    /// Returns the latest reading, starting a new run if none is in flight
+    pub fn fetch(&mut self) -> &CustomMetric {
+        if let Some(receiver) = &self.running {
+            match receiver.try_recv() {
+                Ok(value) => {
+                    self.latest.value = Some(value);
+                    self.latest.timestamp = Utc::now();
+                    self.running = None;
+                }
+                Err(TryRecvError::Disconnected) => self.running = None,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+
+        if self.running.is_none() {
+            let (sender, receiver) = mpsc::channel();
+            let command = self.command.clone();
+            let timeout = self.timeout;
+            thread::spawn(move || {
+                let _ = sender.send(run(&command, timeout));
+            });
+            self.running = Some(receiver);
+        }
+
+        &self.latest
+    }
+}
+
+/// Runs `command` in the platform shell and parses its stdout
+fn run(command: &str, timeout: Duration) -> Result<f64, String> {
+    #[cfg(windows)]
+    let mut shell = Command::new("cmd");
+    #[cfg(windows)]
+    shell.args(["/C", command]);
+    #[cfg(not(windows))]
+    let mut shell = Command::new("sh");
+    #[cfg(not(windows))]
+    shell.args(["-c", command]);
+
+    let mut child = shell
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("failed to run: {err}"))?;
+
+    // the pipes are drained while the command runs, a command printing more
+    // than the pipe buffer would otherwise block and never exit
+    let stdout = child.stdout.take().map(read_pipe);
+    let stderr = child.stderr.take().map(read_pipe);
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("timed out after {} ms", timeout.as_millis()));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(CUSTOM_METRIC_POLL_MS)),
+            Err(err) => return Err(format!("failed to wait: {err}")),
+        }
+    };
+
+    let collect = |reader: Option<JoinHandle<Vec<u8>>>| {
+        reader
+            .map(|reader| reader.join().unwrap_or_default())
+            .unwrap_or_default()
+    };
+    let (stdout, stderr) = (collect(stdout), collect(stderr));
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        let reason = stderr.lines().next().unwrap_or_default().trim();
+        return Err(format!("{status}: {reason}"));
+    }
+    parse_output(&String::from_utf8_lossy(&stdout))
+}
+
+/// Reads `pipe` to the end on a new thread
+fn read_pipe(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+/// Parses the first word of `stdout` as a number, e.g. `42` or `0.5 ms`
+fn parse_output(stdout: &str) -> Result<f64, String> {
+    let word = stdout
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| "printed nothing".to_string())?;
+    word.parse()
+        .map_err(|_| format!("printed `{word}`, not a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output() {
+        assert_eq!(parse_output("42\n"), Ok(42.0));
+        assert_eq!(parse_output("0.5 ms"), Ok(0.5));
+        assert_eq!(parse_output("  -3.25\nignored"), Ok(-3.25));
+    }
+
+    #[test]
+    fn test_parse_output_rejects_empty_output() {
+        assert_eq!(parse_output(""), Err("printed nothing".to_string()));
+        assert_eq!(parse_output(" \n"), Err("printed nothing".to_string()));
+    }
+
+    #[test]
+    fn test_parse_output_rejects_non_numbers() {
+        assert_eq!(
+            parse_output("ok 42"),
+            Err("printed `ok`, not a number".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_times_out() {
+        let started = Instant::now();
+        let err = run("sleep 5", Duration::from_millis(100)).unwrap_err();
+        assert!(err.starts_with("timed out"), "{err}");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_reads_output_larger_than_the_pipe_buffer() {
+        // 1 MiB of output before the number would fill the pipe if it
+        // weren't read until the command exits
+        let command = "head -c 1048576 /dev/zero | tr '\\0' ' '; echo 7";
+        assert_eq!(run(command, Duration::from_secs(10)), Ok(7.0));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_reports_failures() {
+        let err = run("echo broken >&2; exit 3", Duration::from_secs(10)).unwrap_err();
+        assert!(err.ends_with(": broken"), "{err}");
+    }
+}
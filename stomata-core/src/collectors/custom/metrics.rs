@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+/// Latest reading of a user defined metric
+#[derive(Debug, Clone)]
+pub struct CustomMetric {
+    pub name: String,
+    /// Number printed by the last finished run, or why the run failed.
+    /// `None` until the first run finished
+    pub value: Option<Result<f64, String>>,
+    /// When the last run finished
+    pub timestamp: DateTime<Utc>,
+}
@@ -0,0 +1,5 @@
+pub mod collectors;
+pub mod metrics;
+
+pub use collectors::CustomMetricCollector;
+pub use metrics::CustomMetric;
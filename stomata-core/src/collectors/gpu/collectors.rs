@@ -0,0 +1,76 @@
+use std::fmt;
+
+use chrono::Utc;
+use nvml_wrapper::{Device, Nvml, enum_wrappers::device::TemperatureSensor};
+
+use crate::collectors::gpu::metrics::{GpuData, GpuMetrics};
+
+/// Reads NVIDIA GPU usage through NVML
+///
+/// NVML is loaded once, when the collector is created. Without an NVIDIA
+/// driver the collector keeps the reason and every fetch returns it, so
+/// machines without a GPU only see a message on the GPU page.
+pub struct GpuCollector {
+    nvml: Result<Nvml, String>,
+}
+
+impl Default for GpuCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GpuCollector {
+    pub fn new() -> Self {
+        Self {
+            nvml: Nvml::init().map_err(|err| format!("NVML unavailable: {err}")),
+        }
+    }
+
+    /// Returns the usage of every GPU, or why GPUs can't be read
+    pub fn fetch(&self) -> Result<GpuMetrics, String> {
+        let nvml = self.nvml.as_ref().map_err(Clone::clone)?;
+        let count = nvml
+            .device_count()
+            .map_err(|err| format!("failed to list GPUs: {err}"))?;
+        if count == 0 {
+            return Err("no NVIDIA GPU found".to_string());
+        }
+
+        let gpus = (0..count)
+            .filter_map(|index| {
+                // a GPU that fell off the bus is left out, not fatal
+                let device = nvml.device_by_index(index).ok()?;
+                Some(GpuData::fetch(index, &device))
+            })
+            .collect();
+
+        Ok(GpuMetrics {
+            timestamp: Utc::now(),
+            gpus,
+        })
+    }
+}
+
+impl fmt::Debug for GpuCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GpuCollector")
+            .field("available", &self.nvml.is_ok())
+            .finish()
+    }
+}
+
+impl GpuData {
+    /// Queries each value separately, consumer GPUs don't support all of them
+    fn fetch(index: u32, device: &Device<'_>) -> Self {
+        let memory = device.memory_info().ok();
+        Self {
+            index,
+            name: device.name().unwrap_or_else(|_| format!("GPU {index}")),
+            utilization: device.utilization_rates().ok().map(|rates| rates.gpu),
+            memory_used: memory.as_ref().map(|memory| memory.used),
+            memory_total: memory.as_ref().map(|memory| memory.total),
+            temperature: device.temperature(TemperatureSensor::Gpu).ok(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug)]
+pub struct GpuMetrics {
+    pub timestamp: DateTime<Utc>,
+    pub gpus: Vec<GpuData>,
+}
+
+/// Usage of one GPU, values the driver doesn't report for it are `None`
+#[derive(Debug, Default, Clone)]
+pub struct GpuData {
+    pub index: u32,
+    pub name: String,
+    /// Percent of time a kernel was running over the last sample period
+    pub utilization: Option<u32>,
+    pub memory_used: Option<u64>,
+    pub memory_total: Option<u64>,
+    /// Core temperature in °C
+    pub temperature: Option<u32>,
+}
@@ -0,0 +1,4 @@
+pub mod collectors;
+pub mod metrics;
+
+pub use metrics::{GpuData, GpuMetrics};
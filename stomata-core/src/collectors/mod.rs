@@ -1,9 +1,14 @@
+pub mod custom;
+#[cfg(feature = "gpu")]
+pub mod gpu;
 pub mod network;
 pub mod process;
+pub mod status;
 pub mod structs;
 pub mod structs_impls;
 pub mod system;
 pub mod system_info;
+pub mod temperature;
 
 pub use network::NetworkMetrics;
 pub use process::{ProcessData, SingleProcessData};
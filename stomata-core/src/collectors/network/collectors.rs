@@ -9,20 +9,27 @@ impl NetworkMetrics {
         let interfaces: Vec<NetworkInterfaces> = networks
             .list()
             .iter()
-            .map(|(name, data)| NetworkInterfaces {
-                name: name.clone(),
-                errors_on_received: data.errors_on_received(),
-                total_errors_on_received: data.total_errors_on_received(),
-                errors_on_transmitted: data.errors_on_transmitted(),
-                total_errors_on_transmitted: data.total_errors_on_transmitted(),
-                packets_received: data.packets_received(),
-                total_packets_received: data.total_packets_received(),
-                packets_transmitted: data.packets_transmitted(),
-                total_packets_transmitted: data.total_packets_transmitted(),
-                bytes_received: data.received(),
-                total_bytes_received: data.total_received(),
-                bytes_transmitted: data.transmitted(),
-                total_bytes_transmitted: data.total_transmitted(),
+            .map(|(name, data)| {
+                let (is_up, speed_mbps) = NetworkInterfaces::fetch_link_state(name);
+                let mac_address = data.mac_address();
+                NetworkInterfaces {
+                    name: name.clone(),
+                    errors_on_received: data.errors_on_received(),
+                    total_errors_on_received: data.total_errors_on_received(),
+                    errors_on_transmitted: data.errors_on_transmitted(),
+                    total_errors_on_transmitted: data.total_errors_on_transmitted(),
+                    packets_received: data.packets_received(),
+                    total_packets_received: data.total_packets_received(),
+                    packets_transmitted: data.packets_transmitted(),
+                    total_packets_transmitted: data.total_packets_transmitted(),
+                    bytes_received: data.received(),
+                    total_bytes_received: data.total_received(),
+                    bytes_transmitted: data.transmitted(),
+                    total_bytes_transmitted: data.total_transmitted(),
+                    is_up,
+                    mac_address: (!mac_address.is_unspecified()).then(|| mac_address.to_string()),
+                    speed_mbps,
+                }
             })
             .collect();
 
@@ -32,3 +39,54 @@ impl NetworkMetrics {
         }
     }
 }
+
+impl NetworkInterfaces {
+    /// Reads the link state and speed from `/sys/class/net/<name>/`
+    #[cfg(target_os = "linux")]
+    pub fn fetch_link_state(name: &str) -> (bool, Option<u32>) {
+        let dir = std::path::Path::new("/sys/class/net").join(name);
+        let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+
+        let is_up = match read("operstate") {
+            Some(operstate) => Self::parse_link_up(&operstate, read("flags").as_deref()),
+            // can't tell, don't report a link we know nothing about as down
+            None => true,
+        };
+        // reading `speed` fails on links that are down
+        let speed_mbps = read("speed").and_then(|speed| Self::parse_speed(&speed));
+        (is_up, speed_mbps)
+    }
+
+    /// Link state and speed are only available on Linux
+    #[cfg(not(target_os = "linux"))]
+    pub fn fetch_link_state(_name: &str) -> (bool, Option<u32>) {
+        (true, None)
+    }
+
+    /// `operstate` is `unknown` for interfaces without carrier detection,
+    /// such as loopback and tunnels, in which case the `IFF_UP` bit of
+    /// `flags` decides.
+    fn parse_link_up(operstate: &str, flags: Option<&str>) -> bool {
+        const IFF_UP: u32 = 0x1;
+        match operstate.trim() {
+            "up" => true,
+            "unknown" => flags
+                .and_then(|flags| {
+                    u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16).ok()
+                })
+                .is_some_and(|flags| flags & IFF_UP != 0),
+            _ => false,
+        }
+    }
+
+    /// Virtual interfaces report `-1`
+    fn parse_speed(speed: &str) -> Option<u32> {
+        speed
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .filter(|s| *s > 0)?
+            .try_into()
+            .ok()
+    }
+}
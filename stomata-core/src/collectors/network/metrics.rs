@@ -20,4 +20,10 @@ pub struct NetworkInterfaces {
     pub total_bytes_received: u64,
     pub bytes_transmitted: u64,
     pub total_bytes_transmitted: u64,
+    /// Whether the link is up, an existing but idle interface is still up
+    pub is_up: bool,
+    /// `None` when the platform doesn't report one
+    pub mac_address: Option<String>,
+    /// Negotiated link speed, `None` for virtual interfaces or when unknown
+    pub speed_mbps: Option<u32>,
 }
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use crate::collectors::process::metrics::ProcessData;
+
+/// Root of the cgroup hierarchy
+pub const ROOT_CGROUP: &str = "/";
+
+/// Summed usage of the processes in a cgroup and all of its children
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CgroupUsage {
+    /// Percent of one core, like the per-process values
+    pub cpu_usage: f32,
+    pub memory: u64,
+    pub process_count: usize,
+}
+
+/// Cgroup hierarchy with the usage of every cgroup that has processes
+///
+/// A container or systemd service is a cgroup, so the usage of a cgroup
+/// is how much the whole container or service uses.
+#[derive(Debug, Default, Clone)]
+pub struct CgroupTree {
+    /// Usage keyed by cgroup path
+    pub usage: BTreeMap<String, CgroupUsage>,
+}
+
+/// A single row of a flattened cgroup tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct CgroupTreeNode {
+    pub path: String,
+    pub depth: usize,
+    pub usage: CgroupUsage,
+}
+
+impl CgroupTree {
+    /// Builds the hierarchy from a process list.
+    ///
+    /// Each process is added to its own cgroup and every ancestor up to
+    /// the root. Processes without a cgroup are skipped.
+    pub fn build(processes: &[ProcessData]) -> Self {
+        let mut usage: BTreeMap<String, CgroupUsage> = BTreeMap::new();
+
+        for process in processes {
+            let Some(cgroup) = &process.cgroup else {
+                continue;
+            };
+            for path in Self::ancestors(cgroup) {
+                let entry = usage.entry(path.to_string()).or_default();
+                entry.cpu_usage += process.cpu_usage;
+                entry.memory += process.memory;
+                entry.process_count += 1;
+            }
+        }
+
+        Self { usage }
+    }
+
+    /// Returns `path` and all of its parents, from the root down
+    ///
+    /// `/system.slice/nginx.service` yields `/`, `/system.slice` and
+    /// `/system.slice/nginx.service`.
+    pub fn ancestors(path: &str) -> Vec<&str> {
+        let mut ancestors = vec![ROOT_CGROUP];
+        let trimmed = path.trim_end_matches('/');
+        ancestors.extend(
+            trimmed
+                .match_indices('/')
+                .skip(1)
+                .map(|(index, _)| &trimmed[..index])
+                .chain((trimmed.len() > 1).then_some(trimmed)),
+        );
+        ancestors
+    }
+
+    /// Flattens the tree into display order, a cgroup right before its
+    /// children
+    pub fn flatten(&self) -> Vec<CgroupTreeNode> {
+        let mut nodes: Vec<CgroupTreeNode> = self
+            .usage
+            .iter()
+            .map(|(path, usage)| CgroupTreeNode {
+                path: path.clone(),
+                depth: Self::ancestors(path).len() - 1,
+                usage: *usage,
+            })
+            .collect();
+        // by segment, a plain string order puts `/a-b` between `/a` and `/a/b`
+        nodes.sort_by(|a, b| a.path.split('/').cmp(b.path.split('/')));
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(cgroup: Option<&str>, cpu_usage: f32, memory: u64) -> ProcessData {
+        ProcessData {
+            cgroup: cgroup.map(String::from),
+            cpu_usage,
+            memory,
+            ..Default::default()
+        }
+    }
+
+    fn rows(nodes: &[CgroupTreeNode]) -> Vec<(&str, usize, usize)> {
+        nodes
+            .iter()
+            .map(|node| (node.path.as_str(), node.depth, node.usage.process_count))
+            .collect()
+    }
+
+    #[test]
+    fn test_ancestors() {
+        assert_eq!(CgroupTree::ancestors("/"), ["/"]);
+        assert_eq!(
+            CgroupTree::ancestors("/system.slice/nginx.service"),
+            ["/", "/system.slice", "/system.slice/nginx.service"]
+        );
+        assert_eq!(CgroupTree::ancestors("/user.slice/"), ["/", "/user.slice"]);
+    }
+
+    #[test]
+    fn test_build_sums_into_every_ancestor() {
+        let tree = CgroupTree::build(&[
+            process(Some("/system.slice/nginx.service"), 10.0, 100),
+            process(Some("/system.slice/cron.service"), 5.0, 50),
+            process(Some("/user.slice"), 1.0, 10),
+            process(None, 50.0, 500),
+        ]);
+        assert_eq!(
+            tree.usage["/"],
+            CgroupUsage {
+                cpu_usage: 16.0,
+                memory: 160,
+                process_count: 3,
+            }
+        );
+        assert_eq!(tree.usage["/system.slice"].memory, 150);
+        assert_eq!(tree.usage["/system.slice/nginx.service"].process_count, 1);
+    }
+
+    #[test]
+    fn test_flatten_puts_children_after_their_parent() {
+        let tree = CgroupTree::build(&[
+            process(Some("/a/b"), 0.0, 0),
+            process(Some("/a-b"), 0.0, 0),
+            process(Some("/a"), 0.0, 0),
+        ]);
+        assert_eq!(
+            rows(&tree.flatten()),
+            [("/", 0, 3), ("/a", 1, 2), ("/a/b", 2, 1), ("/a-b", 1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_flatten_orphan_path() {
+        // no process sits in `/kubepods` itself, it still heads its children
+        let tree = CgroupTree::build(&[process(Some("/kubepods/pod1/ctr"), 0.0, 0)]);
+        assert_eq!(
+            rows(&tree.flatten()),
+            [
+                ("/", 0, 1),
+                ("/kubepods", 1, 1),
+                ("/kubepods/pod1", 2, 1),
+                ("/kubepods/pod1/ctr", 3, 1),
+            ]
+        );
+    }
+}
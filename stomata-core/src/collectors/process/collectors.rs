@@ -1,25 +1,157 @@
+use std::ffi::OsStr;
+
 use sysinfo::{Pid, Process, System};
 
-use crate::collectors::process::metrics::{ProcessData, SingleProcessData};
+use crate::collectors::{
+    process::{
+        container::{parse_cgroup_path, parse_container_id, read_cgroup},
+        metrics::{ProcessData, ProcessNetworkUsage, SingleProcessData},
+        names::NameCache,
+    },
+    status::{CollectorStatus, report_io_error},
+};
+
+/// `PF_KTHREAD`, the flag of kernel threads in `/proc/<pid>/stat`
+const PF_KTHREAD: u64 = 0x0020_0000;
 
 impl From<&Process> for ProcessData {
     fn from(process: &Process) -> Self {
+        let status = ProcessData::fetch_status(process.pid().as_u32());
+        let cgroup = read_cgroup(process.pid().as_u32());
         ProcessData {
             pid: process.pid().as_u32(),
-            name: process.name().to_string_lossy().to_string(),
+            name: ProcessData::sanitize(process.name()),
+            cmd: process
+                .cmd()
+                .iter()
+                .map(|arg| ProcessData::sanitize(arg))
+                .collect(),
+            exe: process.exe().map(|exe| exe.to_path_buf()),
             cpu_usage: process.cpu_usage(),
             memory: process.memory(),
             status: process.status().to_string(),
+            parent_pid: process.parent().map(|pid| pid.as_u32()),
+            swap: status.as_deref().and_then(ProcessData::parse_vm_swap),
+            container_name: cgroup.as_deref().and_then(parse_container_id),
+            cgroup: cgroup.as_deref().and_then(parse_cgroup_path),
+            // resolved to a name in `fetch`, see `NameCache`
+            user: None,
+            // tasks aren't refreshed for the process list, count them from the status
+            threads: status.as_deref().and_then(ProcessData::parse_threads),
+            start_time: process.start_time(),
+            // kernel threads have no command line, only those are checked
+            kernel_thread: process.cmd().is_empty()
+                && ProcessData::fetch_kernel_thread_flag(process.pid().as_u32()),
         }
     }
 }
 
 impl ProcessData {
-    pub fn fetch(system: &System) -> Vec<Self> {
-        let processes: Vec<ProcessData> =
+    /// Converts a process name or argument to a string safe to display
+    ///
+    /// Invalid UTF-8 is replaced with `�` and control characters are
+    /// escaped, e.g. `\u{1b}` or `\n`, so a process named after an escape
+    /// sequence or with embedded newlines can't scramble a terminal UI.
+    pub fn sanitize(text: &OsStr) -> String {
+        text.to_string_lossy()
+            .chars()
+            .map(|c| {
+                if c.is_control() {
+                    c.escape_default().to_string()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    }
+
+    /// Whether this is a Linux kernel thread, such as `kworker/0:1`
+    ///
+    /// Kernel threads carry the `PF_KTHREAD` flag, read when the process is
+    /// collected so it holds for `--remote` hosts too. Being a child of PID
+    /// 2 isn't enough, in a container's PID namespace that's an ordinary
+    /// process. Always `false` on other systems.
+    pub fn is_kernel_thread(&self) -> bool {
+        self.kernel_thread
+    }
+
+    /// Full command line with the arguments joined by spaces
+    pub fn command_line(&self) -> String {
+        self.cmd.join(" ")
+    }
+
+    /// Lists every process, with user and container names from `names`
+    pub fn fetch(system: &System, names: &mut NameCache) -> Vec<Self> {
+        let mut processes: Vec<ProcessData> =
             system.processes().values().map(ProcessData::from).collect();
+        names.resolve(system, &mut processes);
         return processes;
     }
+
+    /// Reads `/proc/<pid>/status`, the source of the swap and thread counts
+    #[cfg(target_os = "linux")]
+    pub fn fetch_status(pid: u32) -> Option<String> {
+        std::fs::read_to_string(format!("/proc/{pid}/status"))
+            .inspect_err(|err| {
+                report_io_error(err, |error| CollectorStatus::StatusReadFailed {
+                    pid,
+                    error,
+                })
+            })
+            .ok()
+    }
+
+    /// Per-process swap usage and thread counts are only available on Linux
+    #[cfg(not(target_os = "linux"))]
+    pub fn fetch_status(_pid: u32) -> Option<String> {
+        None
+    }
+
+    /// Reads the `PF_KTHREAD` flag from `/proc/<pid>/stat`
+    #[cfg(target_os = "linux")]
+    fn fetch_kernel_thread_flag(pid: u32) -> bool {
+        std::fs::read_to_string(format!("/proc/{pid}/stat"))
+            .is_ok_and(|stat| Self::parse_kernel_thread_flag(&stat))
+    }
+
+    /// Kernel threads only exist on Linux
+    #[cfg(not(target_os = "linux"))]
+    fn fetch_kernel_thread_flag(_pid: u32) -> bool {
+        false
+    }
+
+    /// Checks `PF_KTHREAD` in the flags, the 9th field of a `stat` line
+    ///
+    /// The name in parentheses may contain spaces and parentheses itself,
+    /// so fields are counted from the last `)`.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    fn parse_kernel_thread_flag(stat: &str) -> bool {
+        stat.rsplit_once(')')
+            .and_then(|(_, fields)| fields.split_whitespace().nth(6)?.parse::<u64>().ok())
+            .is_some_and(|flags| flags & PF_KTHREAD != 0)
+    }
+
+    /// Returns the first value of a `Key:   value ...` status line
+    fn status_value<'a>(status: &'a str, key: &str) -> Option<&'a str> {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix(':'))?
+            .split_whitespace()
+            .next()
+    }
+
+    /// Parses the `VmSwap:   1234 kB` line into bytes
+    ///
+    /// Kernel threads have no `VmSwap` line and yield `None`.
+    fn parse_vm_swap(status: &str) -> Option<u64> {
+        let kilobytes: u64 = Self::status_value(status, "VmSwap")?.parse().ok()?;
+        Some(kilobytes * 1024)
+    }
+
+    /// Parses the `Threads:   4` line
+    fn parse_threads(status: &str) -> Option<usize> {
+        Self::status_value(status, "Threads")?.parse().ok()
+    }
 }
 
 // Single Process
@@ -34,6 +166,8 @@ impl<'a> From<(&'a Process, Vec<&'a Process>)> for SingleProcessData<'a> {
         let start_time = process.start_time();
         let running_time = process.run_time();
         let parent_pid = process.parent();
+        let network_usage = ProcessNetworkUsage::fetch(process.pid().as_u32());
+        let open_fds = SingleProcessData::fetch_open_fds(process.pid().as_u32());
 
         SingleProcessData {
             basic_process_data: ProcessData::from(process),
@@ -43,6 +177,9 @@ impl<'a> From<(&'a Process, Vec<&'a Process>)> for SingleProcessData<'a> {
             running_time,
             current_working_dir,
             parent_pid,
+            network_usage,
+            open_fds: open_fds.map(|(total, _)| total),
+            open_sockets: open_fds.map(|(_, sockets)| sockets),
         }
     }
 }
@@ -65,4 +202,193 @@ impl SingleProcessData<'_> {
             None
         }
     }
+
+    /// Counts the open file descriptors of a process and how many of them
+    /// are sockets, from the entries of `/proc/<pid>/fd`
+    ///
+    /// Reading another user's fds needs elevated privileges, `None` then.
+    #[cfg(target_os = "linux")]
+    pub fn fetch_open_fds(pid: u32) -> Option<(usize, usize)> {
+        let mut total = 0;
+        let mut sockets = 0;
+        for entry in std::fs::read_dir(format!("/proc/{pid}/fd")).ok()? {
+            let Ok(entry) = entry else {
+                continue;
+            };
+            total += 1;
+            // socket fds link to `socket:[<inode>]`
+            if std::fs::read_link(entry.path())
+                .is_ok_and(|target| target.to_string_lossy().starts_with("socket:"))
+            {
+                sockets += 1;
+            }
+        }
+        Some((total, sockets))
+    }
+
+    /// Open file descriptors are only available on Linux
+    #[cfg(not(target_os = "linux"))]
+    pub fn fetch_open_fds(_pid: u32) -> Option<(usize, usize)> {
+        None
+    }
+}
+
+impl ProcessNetworkUsage {
+    /// Reads the network usage visible to a process from `/proc/<pid>/net/dev`
+    ///
+    /// These are the totals of the process' network namespace, not traffic
+    /// of the process alone.
+    #[cfg(target_os = "linux")]
+    pub fn fetch(pid: u32) -> Option<Self> {
+        let net_dev = std::fs::read_to_string(format!("/proc/{pid}/net/dev")).ok()?;
+        Some(Self::parse_net_dev(&net_dev))
+    }
+
+    /// Namespace network usage is only available on Linux
+    #[cfg(not(target_os = "linux"))]
+    pub fn fetch(_pid: u32) -> Option<Self> {
+        None
+    }
+
+    /// Sums received and transmitted bytes over all non-loopback interfaces
+    ///
+    /// Each interface line looks like
+    /// `eth0: <rx bytes> <rx packets> ... <tx bytes> <tx packets> ...`
+    /// with 8 receive columns followed by 8 transmit columns.
+    #[cfg(target_os = "linux")]
+    fn parse_net_dev(net_dev: &str) -> Self {
+        let mut usage = Self::default();
+        // the first two lines are headers
+        for line in net_dev.lines().skip(2) {
+            let Some((interface, stats)) = line.split_once(':') else {
+                continue;
+            };
+            if interface.trim() == "lo" {
+                continue;
+            }
+            let fields: Vec<u64> = stats
+                .split_whitespace()
+                .filter_map(|field| field.parse().ok())
+                .collect();
+            if fields.len() >= 9 {
+                usage.received_bytes += fields[0];
+                usage.transmitted_bytes += fields[8];
+            }
+        }
+        usage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_net_dev_sums_non_loopback_interfaces() {
+        let net_dev = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 9000      90    0    0    0     0          0         0     9000      90    0    0    0     0       0          0
+  eth0: 1500      10    0    0    0     0          0         0     2500      20    0    0    0     0       0          0
+ wlan0:   500       5    0    0    0     0          0         0      700       7    0    0    0     0       0          0
+";
+        assert_eq!(
+            ProcessNetworkUsage::parse_net_dev(net_dev),
+            ProcessNetworkUsage {
+                received_bytes: 2000,
+                transmitted_bytes: 3200,
+            }
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_net_dev_skips_malformed_lines() {
+        let net_dev = "header\nheader\n  eth0: 1500 10\ngarbage\n";
+        assert_eq!(
+            ProcessNetworkUsage::parse_net_dev(net_dev),
+            ProcessNetworkUsage::default()
+        );
+    }
+
+    #[test]
+    fn test_sanitize_escapes_control_characters() {
+        assert_eq!(ProcessData::sanitize(OsStr::new("nginx")), "nginx");
+        assert_eq!(
+            ProcessData::sanitize(OsStr::new("evil\x1b[2J\x1b[31mname\nnext\tline")),
+            "evil\\u{1b}[2J\\u{1b}[31mname\\nnext\\tline"
+        );
+        // C1 control characters, e.g. the single byte CSI
+        assert_eq!(ProcessData::sanitize(OsStr::new("a\u{9b}b")), "a\\u{9b}b");
+        assert_eq!(
+            ProcessData::sanitize(OsStr::new("Web Content")),
+            "Web Content"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_kernel_thread_flag() {
+        // kthreadd and a kworker, flags 0x208040
+        assert!(ProcessData::parse_kernel_thread_flag(
+            "2 (kthreadd) S 0 0 0 0 -1 2129984 0 0 0 0 0 0 0 0 20 0 1 0 5 0 0"
+        ));
+        assert!(ProcessData::parse_kernel_thread_flag(
+            "87 (kworker/0:1-events) I 2 0 0 0 -1 69238880 0 0 0 0 0 0 0 0 20 0 1 0 9 0 0"
+        ));
+        // a process in a container, whose parent is PID 2 of its namespace
+        assert!(!ProcessData::parse_kernel_thread_flag(
+            "3 (sh) S 2 3 3 34816 3 4194560 450 0 0 0 0 0 0 0 20 0 1 0 42 0 0"
+        ));
+        // names may contain spaces and parentheses
+        assert!(!ProcessData::parse_kernel_thread_flag(
+            "4242 (tmux: server (1)) S 1 4242 4242 0 -1 4194624 0 0 0 0 0 0 0 0 20 0 1 0 7 0 0"
+        ));
+        assert!(!ProcessData::parse_kernel_thread_flag("4243 (cut"));
+    }
+
+    #[test]
+    fn test_parse_vm_swap_and_threads() {
+        let status = "Name:\tpostgres\nVmSwap:\t    1234 kB\nThreads:\t4\n";
+        assert_eq!(ProcessData::parse_vm_swap(status), Some(1234 * 1024));
+        assert_eq!(ProcessData::parse_threads(status), Some(4));
+    }
+
+    #[test]
+    fn test_parse_vm_swap_missing_for_kernel_threads() {
+        let status = "Name:\tkthreadd\nState:\tS (sleeping)\nThreads:\t1\n";
+        assert_eq!(ProcessData::parse_vm_swap(status), None);
+        assert_eq!(ProcessData::parse_threads(status), Some(1));
+    }
+
+    #[test]
+    fn test_parse_vm_swap_and_threads_malformed_values() {
+        let status = "VmSwap:\tabc kB\nThreads:\t-1\n";
+        assert_eq!(ProcessData::parse_vm_swap(status), None);
+        assert_eq!(ProcessData::parse_threads(status), None);
+        assert_eq!(ProcessData::parse_vm_swap("VmSwap:\n"), None);
+    }
+
+    #[test]
+    fn test_child_of_pid_2_with_a_command_line_is_not_a_kernel_thread() {
+        let process = ProcessData {
+            pid: 3,
+            parent_pid: Some(2),
+            cmd: vec!["sh".to_string(), "-c".to_string(), "sleep 1".to_string()],
+            ..Default::default()
+        };
+        assert!(!process.is_kernel_thread());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sanitize_replaces_invalid_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_eq!(
+            ProcessData::sanitize(OsStr::from_bytes(b"bad\xffname")),
+            "bad\u{fffd}name"
+        );
+    }
 }
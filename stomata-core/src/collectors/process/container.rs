@@ -0,0 +1,221 @@
+//! Container detection for processes
+//!
+//! Processes running in Docker, containerd, CRI-O or Podman containers sit
+//! in a cgroup whose path contains the 64 character container ID, e.g.
+//! `0::/system.slice/docker-<id>.scope` or `12:memory:/docker/<id>`. The ID
+//! is mapped to the container name through the Docker API when the socket
+//! is readable, otherwise the short 12 character ID is used as the name.
+
+use std::collections::HashMap;
+
+use crate::collectors::status::{CollectorStatus, report, report_io_error};
+
+/// Length of the short container ID shown by `docker ps`
+pub const SHORT_CONTAINER_ID_LEN: usize = 12;
+
+/// Cgroup path segment prefixes used by the different container runtimes
+const RUNTIME_PREFIXES: [&str; 4] = ["docker-", "cri-containerd-", "crio-", "libpod-"];
+
+/// Reads `/proc/<pid>/cgroup`, parsed with [`parse_container_id`] and
+/// [`parse_cgroup_path`]
+#[cfg(target_os = "linux")]
+pub fn read_cgroup(pid: u32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{pid}/cgroup"))
+        .inspect_err(|err| {
+            report_io_error(err, |error| CollectorStatus::CgroupReadFailed {
+                pid,
+                error,
+            })
+        })
+        .ok()
+}
+
+/// Cgroups and containers are only detected on Linux
+#[cfg(not(target_os = "linux"))]
+pub fn read_cgroup(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Returns the cgroup path of a process, e.g. `/system.slice/nginx.service`
+///
+/// The cgroup v2 entry `0::<path>` is preferred. On cgroup v1 hosts the
+/// path of the `cpu` controller is used, falling back to the first entry.
+pub fn parse_cgroup_path(cgroup: &str) -> Option<String> {
+    let entries: Vec<(&str, &str)> = cgroup
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let hierarchy = fields.next()?;
+            let controllers = fields.next()?;
+            let path = fields.next()?;
+            Some((if hierarchy == "0" { "" } else { controllers }, path))
+        })
+        .collect();
+
+    entries
+        .iter()
+        .find(|(controllers, _)| controllers.is_empty())
+        .or_else(|| {
+            entries
+                .iter()
+                .find(|(controllers, _)| controllers.split(',').any(|c| c == "cpu"))
+        })
+        .or(entries.first())
+        .map(|(_, path)| path.to_string())
+}
+
+/// Finds a container ID in the cgroup paths, returning its short form
+///
+/// Each line looks like `<hierarchy>:<controllers>:<path>`, the ID is a
+/// path segment, possibly wrapped as `<runtime>-<id>.scope`.
+pub fn parse_container_id(cgroup: &str) -> Option<String> {
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .flat_map(|path| path.split('/').rev())
+        .map(|segment| {
+            let segment = segment.strip_suffix(".scope").unwrap_or(segment);
+            RUNTIME_PREFIXES
+                .iter()
+                .find_map(|prefix| segment.strip_prefix(prefix))
+                .unwrap_or(segment)
+        })
+        .find(|id| id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(|id| id[..SHORT_CONTAINER_ID_LEN].to_string())
+}
+
+/// Fetches the names of running Docker containers keyed by short ID
+///
+/// Queries `/containers/json` on `/var/run/docker.sock`. Returns an empty
+/// map when Docker isn't running or the socket isn't accessible, which is
+/// common without membership in the `docker` group. Failures other than a
+/// missing daemon are reported as [`CollectorStatus::DockerUnavailable`].
+#[cfg(unix)]
+pub fn docker_container_names() -> HashMap<String, String> {
+    use std::io::ErrorKind;
+
+    fetch_docker_containers().unwrap_or_else(|err| {
+        if !matches!(
+            err.kind(),
+            ErrorKind::NotFound | ErrorKind::ConnectionRefused
+        ) {
+            report(CollectorStatus::DockerUnavailable(err.to_string()));
+        }
+        HashMap::new()
+    })
+}
+
+/// The Docker socket is only queried on Unix
+#[cfg(not(unix))]
+pub fn docker_container_names() -> HashMap<String, String> {
+    HashMap::new()
+}
+
+#[cfg(unix)]
+fn fetch_docker_containers() -> std::io::Result<HashMap<String, String>> {
+    use std::{
+        io::{self, Read, Write},
+        os::unix::net::UnixStream,
+        time::Duration,
+    };
+
+    use crate::constants::{DOCKER_SOCKET_PATH, DOCKER_SOCKET_TIMEOUT_MS};
+
+    let mut stream = UnixStream::connect(DOCKER_SOCKET_PATH)?;
+    let timeout = Some(Duration::from_millis(DOCKER_SOCKET_TIMEOUT_MS));
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(timeout)?;
+
+    // HTTP/1.0 so the body is neither chunked nor kept alive
+    stream.write_all(b"GET /containers/json HTTP/1.0\r\nHost: docker\r\n\r\n")?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed HTTP response"))?;
+    let containers: Vec<serde_json::Value> = serde_json::from_str(body)?;
+
+    Ok(containers
+        .iter()
+        .filter_map(|container| {
+            let id = container["Id"].as_str()?;
+            let name = container["Names"][0].as_str()?;
+            Some((
+                id.get(..SHORT_CONTAINER_ID_LEN)?.to_string(),
+                name.trim_start_matches('/').to_string(),
+            ))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "4f1c2a9b8e7d6c5b4a39281706f5e4d3c2b1a09f8e7d6c5b4a3928170615a4b3";
+
+    #[test]
+    fn test_parse_container_id_per_runtime() {
+        let cases = [
+            format!("0::/system.slice/docker-{ID}.scope"),
+            format!("0::/kubepods.slice/kubepods-pod1.slice/cri-containerd-{ID}.scope"),
+            format!("0::/kubepods.slice/kubepods-pod1.slice/crio-{ID}.scope"),
+            format!("0::/machine.slice/libpod-{ID}.scope"),
+            format!("12:memory:/docker/{ID}\n11:cpu,cpuacct:/docker/{ID}"),
+        ];
+        for cgroup in cases {
+            assert_eq!(
+                parse_container_id(&cgroup).as_deref(),
+                Some(&ID[..SHORT_CONTAINER_ID_LEN]),
+                "{cgroup}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_container_id_outside_containers() {
+        let cases = [
+            "0::/system.slice/nginx.service",
+            "0::/user.slice/user-1000.slice/session-2.scope",
+            "12:memory:/\n11:cpu,cpuacct:/",
+            // too short to be a container ID
+            "0::/system.slice/docker-4f1c2a9b8e7d.scope",
+            "",
+        ];
+        for cgroup in cases {
+            assert_eq!(parse_container_id(cgroup), None, "{cgroup}");
+        }
+    }
+
+    #[test]
+    fn test_parse_cgroup_path() {
+        let cases = [
+            // cgroup v2
+            (
+                "0::/system.slice/nginx.service\n",
+                Some("/system.slice/nginx.service"),
+            ),
+            // hybrid hosts list the v2 entry last, it's still preferred
+            (
+                "12:memory:/user.slice\n4:cpu,cpuacct:/user.slice\n0::/system.slice/cron.service",
+                Some("/system.slice/cron.service"),
+            ),
+            // cgroup v1 uses the cpu controller
+            (
+                "12:memory:/memory-path\n4:cpu,cpuacct:/system.slice/sshd.service\n1:name=systemd:/init.scope",
+                Some("/system.slice/sshd.service"),
+            ),
+            // `cpuset` doesn't count as `cpu`, the first entry is used
+            (
+                "5:cpuset:/cpuset-path\n12:memory:/memory-path",
+                Some("/cpuset-path"),
+            ),
+            ("", None),
+            ("malformed", None),
+        ];
+        for (cgroup, expected) in cases {
+            assert_eq!(parse_cgroup_path(cgroup).as_deref(), expected, "{cgroup}");
+        }
+    }
+}
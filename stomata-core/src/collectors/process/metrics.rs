@@ -1,12 +1,34 @@
+use std::path::PathBuf;
+
 use sysinfo::{DiskUsage, Pid, Process};
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct ProcessData {
     pub pid: u32,
     pub name: String,
+    /// Command line arguments, including the program, empty when unreadable
+    pub cmd: Vec<String>,
+    /// Path of the executable, `None` when unreadable (e.g. other users' processes)
+    pub exe: Option<PathBuf>,
     pub cpu_usage: f32,
     pub memory: u64,
     pub status: String,
+    pub parent_pid: Option<u32>,
+    /// Memory swapped out to disk in bytes, `None` where unsupported (non-Linux) or unreadable
+    pub swap: Option<u64>,
+    /// Name of the container the process runs in, or its short ID when the
+    /// name can't be resolved. `None` outside containers and on non-Linux
+    pub container_name: Option<String>,
+    /// Path of the cgroup the process runs in, `None` on non-Linux
+    pub cgroup: Option<String>,
+    /// Name of the user owning the process, `None` when unknown
+    pub user: Option<String>,
+    /// Number of threads, `None` where unsupported (non-Linux)
+    pub threads: Option<usize>,
+    /// Start time as a unix timestamp in seconds
+    pub start_time: u64,
+    /// Linux kernel thread, such as `kworker/0:1`, see `is_kernel_thread`
+    pub kernel_thread: bool,
 }
 
 #[derive(Default, Clone)]
@@ -18,4 +40,22 @@ pub struct SingleProcessData<'a> {
     pub running_time: u64,
     pub current_working_dir: Option<String>,
     pub parent_pid: Option<Pid>,
+    /// Cumulative network usage, `None` where unsupported (non-Linux) or unreadable
+    pub network_usage: Option<ProcessNetworkUsage>,
+    /// Number of open file descriptors, `None` where unsupported (non-Linux)
+    /// or unreadable (other users' processes)
+    pub open_fds: Option<usize>,
+    /// How many of `open_fds` are sockets
+    pub open_sockets: Option<usize>,
+}
+
+/// Cumulative bytes received/transmitted as seen from a process
+///
+/// Read from `/proc/<pid>/net/dev`, summed over all non-loopback interfaces.
+/// Linux accounts these per network namespace, so processes sharing a
+/// namespace (e.g. all host processes) report the same totals.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ProcessNetworkUsage {
+    pub received_bytes: u64,
+    pub transmitted_bytes: u64,
 }
@@ -1,4 +1,11 @@
+pub mod cgroup;
 pub mod collectors;
+pub mod container;
 pub mod metrics;
+pub mod names;
+pub mod tree;
 
-pub use metrics::{ProcessData, SingleProcessData};
+pub use cgroup::{CgroupTree, CgroupTreeNode, CgroupUsage};
+pub use metrics::{ProcessData, ProcessNetworkUsage, SingleProcessData};
+pub use names::NameCache;
+pub use tree::{ProcessTree, ProcessTreeNode};
@@ -0,0 +1,111 @@
+//! User and container names of processes
+//!
+//! Listing the users and asking Docker for its containers takes far longer
+//! than reading the process list, so the names are cached between fetches
+//! and only reloaded when a process shows up with a uid or container ID
+//! that isn't known yet.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use sysinfo::{Pid, System, Uid, Users};
+
+use crate::{
+    collectors::process::{container::docker_container_names, metrics::ProcessData},
+    constants::NAME_CACHE_TTL_SECS,
+};
+
+/// Names of users by uid and of containers by short ID
+#[derive(Debug, Default)]
+pub struct NameCache {
+    users: HashMap<Uid, String>,
+    containers: HashMap<String, String>,
+    users_loaded: Option<Instant>,
+    containers_loaded: Option<Instant>,
+}
+
+impl NameCache {
+    /// Sets the user and container names of `processes`
+    ///
+    /// `container_name` holds the short container ID until resolved here,
+    /// IDs Docker doesn't know, e.g. of containerd pods, are kept as is.
+    pub fn resolve(&mut self, system: &System, processes: &mut [ProcessData]) {
+        let uids: Vec<Option<Uid>> = processes
+            .iter()
+            .map(|process| {
+                system
+                    .process(Pid::from_u32(process.pid))
+                    .and_then(|p| p.user_id())
+                    .cloned()
+            })
+            .collect();
+
+        let now = Instant::now();
+        let unknown_uid = uids
+            .iter()
+            .flatten()
+            .any(|uid| !self.users.contains_key(uid));
+        if reload_due(self.users_loaded, unknown_uid, now) {
+            self.users = Users::new_with_refreshed_list()
+                .iter()
+                .map(|user| (user.id().clone(), user.name().to_string()))
+                .collect();
+            self.users_loaded = Some(now);
+        }
+
+        let unknown_container = processes.iter().any(|process| {
+            process
+                .container_name
+                .as_ref()
+                .is_some_and(|id| !self.containers.contains_key(id))
+        });
+        if reload_due(self.containers_loaded, unknown_container, now) {
+            self.containers = docker_container_names();
+            self.containers_loaded = Some(now);
+        }
+
+        for (process, uid) in processes.iter_mut().zip(uids) {
+            process.user = uid.and_then(|uid| self.users.get(&uid).cloned());
+            if let Some(name) = process
+                .container_name
+                .as_ref()
+                .and_then(|id| self.containers.get(id))
+            {
+                process.container_name = Some(name.clone());
+            }
+        }
+    }
+}
+
+/// Whether names should be reloaded because one is missing
+///
+/// Lookups that keep missing, e.g. a container Docker doesn't run, are
+/// retried at most every `NAME_CACHE_TTL_SECS`.
+fn reload_due(loaded: Option<Instant>, missing: bool, now: Instant) -> bool {
+    match loaded {
+        None => true,
+        Some(loaded) => {
+            missing && now.duration_since(loaded) >= Duration::from_secs(NAME_CACHE_TTL_SECS)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reload_due() {
+        let now = Instant::now();
+        let ttl = Duration::from_secs(NAME_CACHE_TTL_SECS);
+        // nothing loaded yet
+        assert!(reload_due(None, false, now));
+        // everything known, the cache is kept however old
+        assert!(!reload_due(Some(now), false, now + ttl * 10));
+        // a missing name waits for the TTL
+        assert!(!reload_due(Some(now), true, now + ttl / 2));
+        assert!(reload_due(Some(now), true, now + ttl));
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::collectors::process::metrics::ProcessData;
+
+/// PID that orphaned processes are re-parented under
+const INIT_PID: u32 = 1;
+
+/// Parent -> children hierarchy built from a process list
+#[derive(Debug, Default, Clone)]
+pub struct ProcessTree {
+    /// Processes without a parent, sorted by PID
+    pub roots: Vec<u32>,
+    /// Children of each process, sorted by PID
+    pub children: HashMap<u32, Vec<u32>>,
+}
+
+/// A single visible row of a flattened process tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessTreeNode {
+    pub pid: u32,
+    pub depth: usize,
+    pub has_children: bool,
+    pub collapsed: bool,
+}
+
+impl ProcessTree {
+    /// Builds the hierarchy from a process list.
+    ///
+    /// Processes whose parent is no longer in the list (the parent exited)
+    /// are attached under PID 1 when it exists, otherwise they become roots.
+    pub fn build(processes: &[ProcessData]) -> Self {
+        let pids: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        let mut roots = Vec::new();
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+
+        for process in processes {
+            let parent = match process.parent_pid {
+                Some(ppid) if ppid != process.pid && pids.contains(&ppid) => Some(ppid),
+                Some(_) if process.pid != INIT_PID && pids.contains(&INIT_PID) => Some(INIT_PID),
+                _ => None,
+            };
+
+            match parent {
+                Some(ppid) => children.entry(ppid).or_default().push(process.pid),
+                None => roots.push(process.pid),
+            }
+        }
+
+        roots.sort_unstable();
+        for child_pids in children.values_mut() {
+            child_pids.sort_unstable();
+        }
+
+        Self { roots, children }
+    }
+
+    /// Flattens the tree into depth-first display order.
+    ///
+    /// Children of any PID in `collapsed` are skipped.
+    pub fn flatten(&self, collapsed: &HashSet<u32>) -> Vec<ProcessTreeNode> {
+        let mut nodes = Vec::new();
+        // stack of (pid, depth); roots pushed in reverse so the lowest PID comes out first
+        let mut stack: Vec<(u32, usize)> = self.roots.iter().rev().map(|pid| (*pid, 0)).collect();
+
+        while let Some((pid, depth)) = stack.pop() {
+            let child_pids = self.children.get(&pid);
+            let has_children = child_pids.is_some_and(|c| !c.is_empty());
+            let is_collapsed = has_children && collapsed.contains(&pid);
+
+            nodes.push(ProcessTreeNode {
+                pid,
+                depth,
+                has_children,
+                collapsed: is_collapsed,
+            });
+
+            if let Some(child_pids) = child_pids
+                && !is_collapsed
+            {
+                stack.extend(child_pids.iter().rev().map(|child| (*child, depth + 1)));
+            }
+        }
+
+        nodes
+    }
+
+    /// Returns `pid` followed by all of its descendants, depth-first.
+    pub fn subtree(&self, pid: u32) -> Vec<u32> {
+        let mut pids = Vec::new();
+        let mut stack = vec![pid];
+
+        while let Some(pid) = stack.pop() {
+            pids.push(pid);
+            if let Some(child_pids) = self.children.get(&pid) {
+                stack.extend(child_pids.iter().rev());
+            }
+        }
+
+        pids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent_pid: Option<u32>) -> ProcessData {
+        ProcessData {
+            pid,
+            parent_pid,
+            ..Default::default()
+        }
+    }
+
+    fn rows(nodes: &[ProcessTreeNode]) -> Vec<(u32, usize)> {
+        nodes.iter().map(|node| (node.pid, node.depth)).collect()
+    }
+
+    #[test]
+    fn test_flatten_depth_first_by_pid() {
+        let tree = ProcessTree::build(&[
+            process(30, Some(1)),
+            process(1, None),
+            process(12, Some(10)),
+            process(10, Some(1)),
+            process(11, Some(10)),
+        ]);
+        assert_eq!(tree.roots, [1]);
+        assert_eq!(
+            rows(&tree.flatten(&HashSet::new())),
+            [(1, 0), (10, 1), (11, 2), (12, 2), (30, 1)]
+        );
+    }
+
+    #[test]
+    fn test_orphans_are_reparented_under_init() {
+        let tree = ProcessTree::build(&[
+            process(1, None),
+            // parent 500 already exited
+            process(20, Some(500)),
+            process(21, Some(20)),
+        ]);
+        assert_eq!(tree.roots, [1]);
+        assert_eq!(tree.children[&1], [20]);
+        assert_eq!(
+            rows(&tree.flatten(&HashSet::new())),
+            [(1, 0), (20, 1), (21, 2)]
+        );
+    }
+
+    #[test]
+    fn test_orphans_become_roots_without_init() {
+        // e.g. inside a PID namespace without PID 1 in view
+        let tree = ProcessTree::build(&[process(20, Some(500)), process(7, Some(7))]);
+        assert_eq!(tree.roots, [7, 20]);
+        assert!(tree.children.is_empty());
+    }
+
+    #[test]
+    fn test_flatten_skips_collapsed_subtrees() {
+        let tree = ProcessTree::build(&[
+            process(1, None),
+            process(10, Some(1)),
+            process(11, Some(10)),
+            process(12, Some(11)),
+            process(20, Some(1)),
+        ]);
+        // collapsing a childless process changes nothing
+        let nodes = tree.flatten(&HashSet::from([10, 20]));
+        assert_eq!(rows(&nodes), [(1, 0), (10, 1), (20, 1)]);
+        assert!(nodes[1].has_children && nodes[1].collapsed);
+        assert!(!nodes[2].has_children && !nodes[2].collapsed);
+        assert_eq!(tree.subtree(10), [10, 11, 12]);
+    }
+}
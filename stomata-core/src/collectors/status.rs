@@ -0,0 +1,68 @@
+//! Warnings raised while collecting metrics
+//!
+//! Collectors fall back to empty values when a source can't be read so a
+//! single failure doesn't break the whole snapshot. They report why through
+//! this channel, letting UIs tell a missing metric apart from a zero.
+//! Warnings are queued until [`take_warnings`] drains them, usually once per
+//! refresh.
+
+use std::{fmt, io, sync::Mutex};
+
+use crate::constants::MAX_PENDING_COLLECTOR_WARNINGS;
+
+static WARNINGS: Mutex<Vec<CollectorStatus>> = Mutex::new(Vec::new());
+
+/// A collection step that failed and fell back to a default value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CollectorStatus {
+    /// `/proc/<pid>/cgroup` couldn't be read, the container is unknown
+    CgroupReadFailed { pid: u32, error: String },
+    /// `/proc/<pid>/status` couldn't be read, swap and threads are unknown
+    StatusReadFailed { pid: u32, error: String },
+    /// The Docker API couldn't be queried, containers are shown by ID
+    DockerUnavailable(String),
+}
+
+impl fmt::Display for CollectorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectorStatus::CgroupReadFailed { pid, error } => {
+                write!(f, "cgroup read failed for pid {pid}: {error}")
+            }
+            CollectorStatus::StatusReadFailed { pid, error } => {
+                write!(f, "status read failed for pid {pid}: {error}")
+            }
+            CollectorStatus::DockerUnavailable(error) => {
+                write!(f, "docker API unavailable: {error}")
+            }
+        }
+    }
+}
+
+/// Queues a warning, ignoring duplicates of a warning already queued
+pub fn report(status: CollectorStatus) {
+    if let Ok(mut warnings) = WARNINGS.lock()
+        && warnings.len() < MAX_PENDING_COLLECTOR_WARNINGS
+        && !warnings.contains(&status)
+    {
+        warnings.push(status);
+    }
+}
+
+/// Queues a warning for a failed read, unless the file is simply gone
+///
+/// Processes exit between listing and reading their `/proc` entries, a
+/// missing file is expected and not worth a warning.
+pub fn report_io_error(err: &io::Error, status: impl FnOnce(String) -> CollectorStatus) {
+    if err.kind() != io::ErrorKind::NotFound {
+        report(status(err.to_string()));
+    }
+}
+
+/// Drains the queued warnings, oldest first
+pub fn take_warnings() -> Vec<CollectorStatus> {
+    WARNINGS
+        .lock()
+        .map(|mut warnings| std::mem::take(&mut *warnings))
+        .unwrap_or_default()
+}
@@ -1,24 +1,67 @@
-use std::collections::VecDeque;
-use sysinfo::{Networks, System};
+use std::{collections::VecDeque, time::Instant};
+use sysinfo::{Components, Networks, System};
 
+#[cfg(feature = "gpu")]
+use crate::collectors::gpu::{GpuMetrics, collectors::GpuCollector};
 use crate::collectors::{
     SystemInfo,
+    custom::{CustomMetric, CustomMetricCollector},
     network::metrics::NetworkMetrics,
-    process::metrics::{ProcessData, SingleProcessData},
+    process::{
+        metrics::{ProcessData, SingleProcessData},
+        names::NameCache,
+    },
     system::metrics::{SystemCollector, SystemMetrics},
+    temperature::TemperatureCollector,
 };
 
+/// Keep a single instance alive and fetch from it on every tick, sysinfo
+/// computes CPU usage from the difference between two refreshes.
 #[derive(Debug)]
 pub struct StomataSystemMetrics {
     pub system: System,
     pub network: Networks,
+    /// Temperature sensors, listed once at startup
+    pub components: Components,
+    /// Created on the first GPU fetch, loading NVML is slow
+    #[cfg(feature = "gpu")]
+    pub gpu: Option<GpuCollector>,
+    /// User defined metrics, empty unless configured
+    pub custom: Vec<CustomMetricCollector>,
+    /// Last global CPU sample, see `refresh_metrics`
+    pub(crate) last_cpu_refresh: Instant,
+    /// Last sample of all processes
+    pub(crate) last_processes_refresh: Instant,
+    /// Process list built from the last sample, shared by every fetch
+    /// until the next one
+    processes: Option<(Instant, Vec<ProcessData>)>,
+    /// User and container names of processes
+    names: NameCache,
+    /// Last sample of a single process and its pid
+    pub(crate) last_process_refresh: Option<(u32, Instant)>,
 }
 
 impl StomataSystemMetrics {
+    /// Takes the first CPU and process sample, usage is known from the
+    /// next refresh on.
     pub fn new() -> Self {
         let system = System::new_all();
         let network = Networks::new();
-        Self { system, network }
+        let components = Components::new_with_refreshed_list();
+        let now = Instant::now();
+        Self {
+            system,
+            network,
+            components,
+            #[cfg(feature = "gpu")]
+            gpu: None,
+            custom: Vec::new(),
+            last_cpu_refresh: now,
+            last_processes_refresh: now,
+            last_process_refresh: None,
+            processes: None,
+            names: NameCache::default(),
+        }
     }
 
     pub fn fetch(&mut self, fetch_metrics: MetricsToFetch) -> Metrics<'_> {
@@ -30,7 +73,18 @@ impl StomataSystemMetrics {
             }
             MetricsToFetch::Process => {
                 self.refresh_metrics(MetricsCategory::ProcessesWithoutTasks);
-                Metrics::Processes(ProcessData::fetch(&self.system))
+                // fetches between two samples, e.g. of a redraw or the
+                // session summary, reuse the list instead of reading /proc
+                let sampled = self.last_processes_refresh;
+                let processes = match &self.processes {
+                    Some((collected, processes)) if *collected == sampled => processes.clone(),
+                    _ => {
+                        let processes = ProcessData::fetch(&self.system, &mut self.names);
+                        self.processes = Some((sampled, processes.clone()));
+                        processes
+                    }
+                };
+                Metrics::Processes(processes)
             }
             MetricsToFetch::SingleProcessPid(pid) => {
                 self.refresh_metrics(MetricsCategory::ProcessWithPid(pid));
@@ -40,6 +94,20 @@ impl StomataSystemMetrics {
                 self.refresh_metrics(MetricsCategory::Networks);
                 Metrics::Networks(NetworkMetrics::fetch(&self.network))
             }
+            MetricsToFetch::Temperatures => {
+                self.refresh_metrics(MetricsCategory::Components);
+                Metrics::Temperatures(TemperatureCollector::fetch(&self.components))
+            }
+            #[cfg(feature = "gpu")]
+            MetricsToFetch::Gpu => {
+                Metrics::Gpu(self.gpu.get_or_insert_with(GpuCollector::new).fetch())
+            }
+            MetricsToFetch::Custom => Metrics::Custom(
+                self.custom
+                    .iter_mut()
+                    .map(|collector| collector.fetch().clone())
+                    .collect(),
+            ),
         }
     }
 }
@@ -50,6 +118,10 @@ pub enum MetricsToFetch {
     Process,
     SingleProcessPid(u32),
     Networks,
+    Temperatures,
+    #[cfg(feature = "gpu")]
+    Gpu,
+    Custom,
 }
 
 // Response metrics
@@ -59,10 +131,16 @@ pub enum Metrics<'a> {
     Processes(Vec<ProcessData>),
     SingleProcessPid(Option<SingleProcessData<'a>>),
     Networks(NetworkMetrics),
+    Temperatures(TemperatureCollector),
+    /// GPU usage, or why GPUs can't be read
+    #[cfg(feature = "gpu")]
+    Gpu(Result<GpuMetrics, String>),
+    /// Latest reading of every user defined metric
+    Custom(Vec<CustomMetric>),
 }
 
 pub enum MetricsCategory {
-    ProcessesWithoutTasks, // refreshes processes but not tasks
+    ProcessesWithoutTasks, // refreshes the fields of the process list, no tasks
     Processes,             // refreshes all processes with tasks
     ProcessWithPid(u32),
     Memory,
@@ -70,6 +148,7 @@ pub enum MetricsCategory {
     AllResources, // refreshes everything
     Basic,        // refreshes CPU + Memory usage
     Networks,
+    Components, // refreshes temperature sensors
 }
 
 #[derive(Debug)]
@@ -1,18 +1,75 @@
-use sysinfo::{Pid, ProcessRefreshKind};
+use std::time::Instant;
+
+use sysinfo::{DiskUsage, MINIMUM_CPU_UPDATE_INTERVAL, Pid, ProcessRefreshKind, UpdateKind};
 
 use crate::collectors::structs::{MetricsCategory, StomataSystemMetrics};
 
+/// Returns whether a new CPU sample is due and records it as taken.
+///
+/// Samples closer than `MINIMUM_CPU_UPDATE_INTERVAL` report zero or spiky
+/// usage, e.g. when a key press redraws right after a tick.
+fn cpu_sample_due(last_refresh: &mut Instant) -> bool {
+    let now = Instant::now();
+    if now.duration_since(*last_refresh) < MINIMUM_CPU_UPDATE_INTERVAL {
+        return false;
+    }
+    *last_refresh = now;
+    true
+}
+
 impl StomataSystemMetrics {
+    /// Refreshes the disk usage of `pids` and returns their sum.
+    ///
+    /// The process list skips disk usage, so `read_bytes` and
+    /// `written_bytes` cover the time since the previous call for the same
+    /// processes. PIDs that exited are ignored.
+    pub fn disk_usage_of(&mut self, pids: &[u32]) -> DiskUsage {
+        let pids: Vec<Pid> = pids.iter().map(|pid| Pid::from_u32(*pid)).collect();
+        self.system.refresh_processes_specifics(
+            sysinfo::ProcessesToUpdate::Some(&pids),
+            false,
+            ProcessRefreshKind::nothing().with_disk_usage(),
+        );
+
+        let mut total = DiskUsage::default();
+        for process in pids.iter().filter_map(|pid| self.system.process(*pid)) {
+            let usage = process.disk_usage();
+            total.read_bytes += usage.read_bytes;
+            total.written_bytes += usage.written_bytes;
+            total.total_read_bytes += usage.total_read_bytes;
+            total.total_written_bytes += usage.total_written_bytes;
+        }
+        total
+    }
+
+    /// Refreshes `refresh_category` on the cached `System`.
+    ///
+    /// CPU and process refreshes are skipped until `MINIMUM_CPU_UPDATE_INTERVAL`
+    /// passed since the previous one, the last sample is kept meanwhile.
     pub fn refresh_metrics(&mut self, refresh_category: MetricsCategory) {
         match refresh_category {
             MetricsCategory::ProcessesWithoutTasks => {
+                if !cpu_sample_due(&mut self.last_processes_refresh) {
+                    return;
+                }
+                // only what the process list shows, reading the environment,
+                // cwd and disk usage of every process is the costly part
                 let _processes_updated = self.system.refresh_processes_specifics(
                     sysinfo::ProcessesToUpdate::All,
                     true,
-                    ProcessRefreshKind::everything().without_tasks(),
+                    ProcessRefreshKind::nothing()
+                        .with_cpu()
+                        .with_memory()
+                        .with_cmd(UpdateKind::OnlyIfNotSet)
+                        .with_exe(UpdateKind::OnlyIfNotSet)
+                        .with_user(UpdateKind::OnlyIfNotSet)
+                        .without_tasks(),
                 );
             }
             MetricsCategory::Processes => {
+                if !cpu_sample_due(&mut self.last_processes_refresh) {
+                    return;
+                }
                 let _processes_updated = self.system.refresh_processes_specifics(
                     sysinfo::ProcessesToUpdate::All,
                     true,
@@ -20,27 +77,50 @@ impl StomataSystemMetrics {
                 );
             }
             MetricsCategory::ProcessWithPid(pid) => {
-                self.system.refresh_processes(
+                // a newly selected process is sampled right away for its tasks
+                match &mut self.last_process_refresh {
+                    Some((last_pid, last_refresh)) if *last_pid == pid => {
+                        if !cpu_sample_due(last_refresh) {
+                            return;
+                        }
+                    }
+                    last_process_refresh => {
+                        *last_process_refresh = Some((pid, Instant::now()));
+                    }
+                }
+                // the process list skips the cwd and disk usage the details show
+                self.system.refresh_processes_specifics(
                     sysinfo::ProcessesToUpdate::Some(&[Pid::from_u32(pid)]),
                     true,
+                    ProcessRefreshKind::everything(),
                 );
             }
             MetricsCategory::CPU => {
-                self.system.refresh_cpu_usage();
+                if cpu_sample_due(&mut self.last_cpu_refresh) {
+                    self.system.refresh_cpu_usage();
+                }
             }
             MetricsCategory::Memory => {
                 self.system.refresh_memory(); // includes swap too
             }
             MetricsCategory::AllResources => {
                 self.system.refresh_all();
+                let now = Instant::now();
+                self.last_cpu_refresh = now;
+                self.last_processes_refresh = now;
             }
             MetricsCategory::Basic => {
                 self.system.refresh_memory();
-                self.system.refresh_cpu_usage();
+                if cpu_sample_due(&mut self.last_cpu_refresh) {
+                    self.system.refresh_cpu_usage();
+                }
             }
             MetricsCategory::Networks => {
                 self.network.refresh(true);
             }
+            MetricsCategory::Components => {
+                self.components.refresh(false);
+            }
         }
     }
 }
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct SystemMetrics {
     pub timestamp: DateTime<Utc>,
     pub cpu_count: usize,
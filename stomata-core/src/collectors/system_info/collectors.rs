@@ -9,6 +9,11 @@ impl SystemInfo {
             os_version: System::os_version().unwrap_or_else(|| "Unknown".to_string()),
             kernel_version: System::kernel_version().unwrap_or_else(|| "Unknown".to_string()),
             hostname: System::host_name().unwrap_or_else(|| "Unknown".to_string()),
+            cpu_arch: Some(System::cpu_arch())
+                .filter(|arch| !arch.is_empty())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            boot_time: System::boot_time(),
+            uptime: System::uptime(),
         }
     }
 }
@@ -4,4 +4,9 @@ pub struct SystemInfo {
     pub os_version: String,
     pub kernel_version: String,
     pub hostname: String,
+    pub cpu_arch: String,
+    /// Seconds since the epoch, 0 when unknown
+    pub boot_time: u64,
+    /// Seconds since boot
+    pub uptime: u64,
 }
@@ -0,0 +1,109 @@
+use chrono::Utc;
+use sysinfo::Components;
+
+use crate::{
+    collectors::temperature::metrics::{SensorTemperature, TemperatureCollector, ThermalState},
+    constants::THERMAL_WARNING_MARGIN,
+};
+
+impl TemperatureCollector {
+    pub fn fetch(components: &Components) -> Self {
+        let sensors = components
+            .list()
+            .iter()
+            .map(|component| SensorTemperature {
+                label: component.label().to_string(),
+                current: component.temperature(),
+                max: component.max(),
+                critical: component.critical(),
+            })
+            .collect();
+
+        Self {
+            timestamp: Utc::now(),
+            sensors,
+        }
+    }
+}
+
+impl SensorTemperature {
+    /// Classifies the current temperature against the critical one
+    ///
+    /// Without a critical temperature there is nothing to throttle at, so a
+    /// readable sensor is `Normal`. `max` is the highest value seen, not a
+    /// limit, and isn't considered.
+    pub fn thermal_state(&self) -> ThermalState {
+        match (self.current, self.critical) {
+            // some drivers report 0 for an unknown critical temperature
+            (Some(current), Some(critical)) if critical > 0.0 => {
+                if current >= critical {
+                    ThermalState::Critical
+                } else if current >= critical - THERMAL_WARNING_MARGIN {
+                    ThermalState::Hot
+                } else {
+                    ThermalState::Normal
+                }
+            }
+            (Some(_), _) => ThermalState::Normal,
+            (None, _) => ThermalState::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor(current: Option<f32>, max: Option<f32>, critical: Option<f32>) -> SensorTemperature {
+        SensorTemperature {
+            label: "cpu".to_string(),
+            current,
+            max,
+            critical,
+        }
+    }
+
+    #[test]
+    fn test_thermal_state_boundaries() {
+        let state = |current| sensor(Some(current), None, Some(100.0)).thermal_state();
+        assert_eq!(state(89.9), ThermalState::Normal);
+        assert_eq!(state(100.0 - THERMAL_WARNING_MARGIN), ThermalState::Hot);
+        assert_eq!(state(99.9), ThermalState::Hot);
+        assert_eq!(state(100.0), ThermalState::Critical);
+        assert_eq!(state(105.0), ThermalState::Critical);
+    }
+
+    #[test]
+    fn test_thermal_state_without_critical() {
+        assert_eq!(
+            sensor(Some(95.0), None, None).thermal_state(),
+            ThermalState::Normal
+        );
+        // drivers report 0 for an unknown critical temperature
+        assert_eq!(
+            sensor(Some(95.0), None, Some(0.0)).thermal_state(),
+            ThermalState::Normal
+        );
+        // the highest value seen isn't a limit
+        assert_eq!(
+            sensor(Some(95.0), Some(95.0), None).thermal_state(),
+            ThermalState::Normal
+        );
+        assert_eq!(
+            sensor(Some(95.0), Some(0.0), Some(0.0)).thermal_state(),
+            ThermalState::Normal
+        );
+    }
+
+    #[test]
+    fn test_thermal_state_unreadable() {
+        assert_eq!(
+            sensor(None, Some(80.0), Some(100.0)).thermal_state(),
+            ThermalState::Unknown
+        );
+        assert_eq!(
+            sensor(None, None, None).thermal_state(),
+            ThermalState::Unknown
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+/// Temperatures of every sensor the OS exposes, empty in most VMs
+#[derive(Debug)]
+pub struct TemperatureCollector {
+    pub timestamp: DateTime<Utc>,
+    pub sensors: Vec<SensorTemperature>,
+}
+
+/// One temperature sensor, all values in °C
+#[derive(Debug, Default, Clone)]
+pub struct SensorTemperature {
+    pub label: String,
+    /// `None` when the sensor couldn't be read this refresh
+    pub current: Option<f32>,
+    /// Highest temperature seen since the sensor was listed
+    pub max: Option<f32>,
+    /// Temperature at which the hardware throttles or shuts down
+    pub critical: Option<f32>,
+}
+
+/// How close a sensor is to its critical temperature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalState {
+    Normal,
+    /// Within `THERMAL_WARNING_MARGIN` of critical, throttling is likely
+    Hot,
+    /// At or above critical
+    Critical,
+    /// No current temperature, the sensor couldn't be read
+    Unknown,
+}
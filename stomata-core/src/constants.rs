@@ -1 +1,11 @@
 pub const MAX_HISTORY: usize = 1000;
+pub const DOCKER_SOCKET_PATH: &str = "/var/run/docker.sock";
+pub const DOCKER_SOCKET_TIMEOUT_MS: u64 = 200;
+// warnings queued between two drains, further ones are dropped
+pub const MAX_PENDING_COLLECTOR_WARNINGS: usize = 64;
+// degrees °C below critical at which a sensor counts as hot
+pub const THERMAL_WARNING_MARGIN: f32 = 10.0;
+// how often a running custom metric command is checked for exit or timeout
+pub const CUSTOM_METRIC_POLL_MS: u64 = 10;
+// how long user and container names are kept before an unknown one reloads them
+pub const NAME_CACHE_TTL_SECS: u64 = 30;
@@ -1,4 +1,7 @@
 pub mod collectors;
 pub mod constants;
+pub mod prometheus;
+pub mod snapshot;
+pub mod snapshot_diff;
 
 pub use collectors::{NetworkMetrics, ProcessData, SingleProcessData, SystemInfo};
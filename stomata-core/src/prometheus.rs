@@ -0,0 +1,169 @@
+//! Prometheus exposition format
+//!
+//! Renders system and process metrics in the Prometheus text format, so
+//! they can be picked up by the node_exporter textfile collector or served
+//! over HTTP and scraped directly.
+
+use std::fmt::Write;
+
+use crate::collectors::{process::metrics::ProcessData, system::metrics::SystemMetrics};
+
+/// Renders system and per-process metrics in Prometheus text format
+///
+/// Produces `stomata_cpu_usage`, `stomata_memory_used_bytes` and the other
+/// system gauges, followed by `stomata_process_cpu` and
+/// `stomata_process_memory_bytes` labelled with `pid` and `name` for every
+/// process.
+pub fn render(system: &SystemMetrics, processes: &[ProcessData]) -> String {
+    let mut output = String::new();
+
+    let system_gauges: [(&str, &str, f64); 7] = [
+        (
+            "stomata_cpu_usage",
+            "Total CPU usage in percent",
+            system.cpu_usage as f64,
+        ),
+        (
+            "stomata_cpu_count",
+            "Number of logical CPUs",
+            system.cpu_count as f64,
+        ),
+        (
+            "stomata_memory_used_bytes",
+            "Used memory in bytes",
+            system.memory_used as f64,
+        ),
+        (
+            "stomata_memory_total_bytes",
+            "Total memory in bytes",
+            system.memory_total as f64,
+        ),
+        (
+            "stomata_swap_used_bytes",
+            "Used swap in bytes",
+            system.swap_used as f64,
+        ),
+        (
+            "stomata_swap_total_bytes",
+            "Total swap in bytes",
+            system.swap_total as f64,
+        ),
+        (
+            "stomata_process_count",
+            "Number of running processes",
+            processes.len() as f64,
+        ),
+    ];
+    for (name, help, value) in system_gauges {
+        write_header(&mut output, name, help);
+        let _ = writeln!(output, "{name} {value}");
+    }
+
+    write_header(
+        &mut output,
+        "stomata_process_cpu",
+        "CPU usage of a process in percent of one core",
+    );
+    for process in processes {
+        let _ = writeln!(
+            output,
+            "stomata_process_cpu{{{}}} {}",
+            process_labels(process),
+            process.cpu_usage
+        );
+    }
+
+    write_header(
+        &mut output,
+        "stomata_process_memory_bytes",
+        "Resident memory of a process in bytes",
+    );
+    for process in processes {
+        let _ = writeln!(
+            output,
+            "stomata_process_memory_bytes{{{}}} {}",
+            process_labels(process),
+            process.memory
+        );
+    }
+
+    output
+}
+
+fn write_header(output: &mut String, name: &str, help: &str) {
+    let _ = writeln!(output, "# HELP {name} {help}");
+    let _ = writeln!(output, "# TYPE {name} gauge");
+}
+
+fn process_labels(process: &ProcessData) -> String {
+    format!(
+        "pid=\"{}\",name=\"{}\"",
+        process.pid,
+        escape_label_value(&process.name)
+    )
+}
+
+/// Escapes backslashes, double quotes and newlines as required for label values
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let system = SystemMetrics {
+            cpu_count: 4,
+            cpu_usage: 12.5,
+            memory_used: 1024,
+            memory_total: 4096,
+            ..Default::default()
+        };
+        let processes = [ProcessData {
+            pid: 42,
+            name: "nginx".to_string(),
+            cpu_usage: 1.5,
+            memory: 2048,
+            ..Default::default()
+        }];
+        let output = render(&system, &processes);
+
+        assert!(output.starts_with(
+            "# HELP stomata_cpu_usage Total CPU usage in percent\n\
+             # TYPE stomata_cpu_usage gauge\n\
+             stomata_cpu_usage 12.5\n"
+        ));
+        for line in [
+            "stomata_cpu_count 4",
+            "stomata_memory_used_bytes 1024",
+            "stomata_swap_total_bytes 0",
+            "stomata_process_count 1",
+            "# TYPE stomata_process_cpu gauge",
+            "stomata_process_cpu{pid=\"42\",name=\"nginx\"} 1.5",
+            "stomata_process_memory_bytes{pid=\"42\",name=\"nginx\"} 2048",
+        ] {
+            assert!(output.lines().any(|l| l == line), "missing {line}");
+        }
+    }
+
+    #[test]
+    fn test_render_escapes_process_names() {
+        let processes = [ProcessData {
+            pid: 7,
+            name: "a\\b \"c\"\nd".to_string(),
+            ..Default::default()
+        }];
+        let output = render(&SystemMetrics::default(), &processes);
+        assert!(
+            output
+                .lines()
+                .any(|l| l == r#"stomata_process_cpu{pid="7",name="a\\b \"c\"\nd"} 0"#),
+            "{output}"
+        );
+    }
+}
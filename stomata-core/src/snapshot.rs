@@ -0,0 +1,185 @@
+//! JSON snapshots of the system and process metrics
+//!
+//! The document served at `/snapshot.json` and printed once per line by
+//! `stomata core --stream`, which is how `--remote` reads another machine.
+
+use std::ffi::OsStr;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use crate::collectors::{process::metrics::ProcessData, system::metrics::SystemMetrics};
+
+/// Builds the snapshot document of the given metrics
+pub fn to_json(system: &SystemMetrics, processes: &[ProcessData]) -> Value {
+    let process_values: Vec<Value> = processes
+        .iter()
+        .map(|process| {
+            json!({
+                "pid": process.pid,
+                "name": process.name,
+                "cmd": process.cmd,
+                "exe": process.exe,
+                "cpu_usage": process.cpu_usage,
+                "memory": process.memory,
+                "swap": process.swap,
+                "status": process.status,
+                "parent_pid": process.parent_pid,
+                "container_name": process.container_name,
+                "cgroup": process.cgroup,
+                "user": process.user,
+                "threads": process.threads,
+                "start_time": process.start_time,
+                "kernel_thread": process.kernel_thread,
+            })
+        })
+        .collect();
+    json!({
+        "timestamp": system.timestamp.to_rfc3339(),
+        "system": {
+            "cpu_count": system.cpu_count,
+            "cpu_usage": system.cpu_usage,
+            "memory_used": system.memory_used,
+            "memory_total": system.memory_total,
+            "swap_used": system.swap_used,
+            "swap_total": system.swap_total,
+        },
+        "processes": process_values,
+    })
+}
+
+/// Parses a snapshot document back into the system and process metrics
+///
+/// Missing system fields default to zero, the process list follows the
+/// rules of [`crate::snapshot_diff::parse_snapshot`].
+pub fn parse(json: &str) -> Result<(SystemMetrics, Vec<ProcessData>)> {
+    let value: Value = serde_json::from_str(json).context("Snapshot is not valid JSON")?;
+    let system = &value["system"];
+    if !system.is_object() {
+        anyhow::bail!("Snapshot has no `system` object");
+    }
+    let number = |key: &str| system[key].as_u64().unwrap_or_default();
+
+    let system_metrics = SystemMetrics {
+        timestamp: value["timestamp"]
+            .as_str()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .unwrap_or_default(),
+        cpu_count: number("cpu_count") as usize,
+        cpu_usage: system["cpu_usage"].as_f64().unwrap_or_default() as f32,
+        memory_used: number("memory_used"),
+        memory_total: number("memory_total"),
+        swap_used: number("swap_used"),
+        swap_total: number("swap_total"),
+    };
+    let processes: Vec<ProcessData> = value["processes"]
+        .as_array()
+        .context("Snapshot has no `processes` list")?
+        .iter()
+        .enumerate()
+        .map(|(index, process)| {
+            process_from_json(process).with_context(|| format!("Invalid process at index {index}"))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((system_metrics, processes))
+}
+
+/// Reads one entry of the `processes` list, `None` if a required field is missing
+///
+/// The document may come from another machine, the shown strings go
+/// through [`ProcessData::sanitize`] again like locally collected ones.
+pub(crate) fn process_from_json(value: &Value) -> Option<ProcessData> {
+    let sanitize = |text: &str| ProcessData::sanitize(OsStr::new(text));
+    let optional_string = |key: &str| value[key].as_str().map(sanitize);
+    Some(ProcessData {
+        pid: value["pid"].as_u64()?.try_into().ok()?,
+        name: sanitize(value["name"].as_str()?),
+        cmd: value["cmd"]
+            .as_array()
+            .map(|args| {
+                args.iter()
+                    .filter_map(|arg| arg.as_str().map(sanitize))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        exe: value["exe"].as_str().map(Into::into),
+        cpu_usage: value["cpu_usage"].as_f64()? as f32,
+        memory: value["memory"].as_u64()?,
+        status: optional_string("status").unwrap_or_default(),
+        parent_pid: value["parent_pid"]
+            .as_u64()
+            .and_then(|pid| pid.try_into().ok()),
+        swap: value["swap"].as_u64(),
+        container_name: optional_string("container_name"),
+        cgroup: optional_string("cgroup"),
+        user: optional_string("user"),
+        threads: value["threads"]
+            .as_u64()
+            .and_then(|threads| threads.try_into().ok()),
+        start_time: value["start_time"].as_u64().unwrap_or_default(),
+        kernel_thread: value["kernel_thread"].as_bool().unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json_parse_round_trip() {
+        let system = SystemMetrics {
+            timestamp: DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            cpu_count: 8,
+            cpu_usage: 12.5,
+            memory_used: 4096,
+            memory_total: 16384,
+            swap_used: 0,
+            swap_total: 2048,
+        };
+        let process = ProcessData {
+            pid: 42,
+            name: "nginx".to_string(),
+            cmd: vec!["nginx".to_string(), "-g".to_string()],
+            exe: Some("/usr/sbin/nginx".into()),
+            cpu_usage: 1.5,
+            memory: 1024,
+            status: "Sleeping".to_string(),
+            parent_pid: Some(1),
+            swap: Some(64),
+            container_name: Some("web".to_string()),
+            cgroup: Some("/system.slice/nginx.service".to_string()),
+            user: Some("www-data".to_string()),
+            threads: Some(4),
+            start_time: 1_714_560_000,
+            kernel_thread: false,
+        };
+        let json = to_json(&system, std::slice::from_ref(&process)).to_string();
+        let (parsed_system, parsed_processes) = parse(&json).unwrap();
+        assert_eq!(parsed_system, system);
+        assert_eq!(parsed_processes, [process]);
+    }
+
+    #[test]
+    fn test_parse_sanitizes_remote_strings() {
+        let json = r#"{
+            "system": {},
+            "processes": [{
+                "pid": 7,
+                "name": "evil\u001b[2Jname",
+                "cmd": ["run\nnext"],
+                "cpu_usage": 0.0,
+                "memory": 0,
+                "user": "ro\u001bot"
+            }]
+        }"#;
+        let (_, processes) = parse(json).unwrap();
+        assert_eq!(processes[0].name, "evil\\u{1b}[2Jname");
+        assert_eq!(processes[0].cmd, ["run\\nnext"]);
+        assert_eq!(processes[0].user.as_deref(), Some("ro\\u{1b}ot"));
+    }
+}
@@ -0,0 +1,174 @@
+//! Diff of two JSON snapshots
+//!
+//! Compares the process lists of two `/snapshot.json` captures, e.g. taken
+//! before and after a deployment. Processes are matched by PID and name, so
+//! a reused PID shows up as one process disappearing and another appearing.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use crate::{collectors::process::metrics::ProcessData, snapshot::process_from_json};
+
+/// A process present in both snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessChange {
+    pub before: ProcessData,
+    pub after: ProcessData,
+}
+
+impl ProcessChange {
+    /// Change in CPU usage, in percentage points
+    pub fn cpu_delta(&self) -> f32 {
+        self.after.cpu_usage - self.before.cpu_usage
+    }
+
+    /// Change in memory usage, in bytes
+    pub fn memory_delta(&self) -> i64 {
+        self.after.memory as i64 - self.before.memory as i64
+    }
+}
+
+/// Differences between two process lists
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    /// Processes only in the second snapshot, by PID
+    pub appeared: Vec<ProcessData>,
+    /// Processes only in the first snapshot, by PID
+    pub disappeared: Vec<ProcessData>,
+    /// Processes in both snapshots, largest CPU change first
+    pub changed: Vec<ProcessChange>,
+}
+
+/// Parses the process list of a snapshot
+///
+/// Accepts the `/snapshot.json` document, or just its `processes` array.
+/// Fields other than `pid`, `name`, `cpu_usage` and `memory` are optional.
+pub fn parse_snapshot(json: &str) -> Result<Vec<ProcessData>> {
+    let value: Value = serde_json::from_str(json).context("Snapshot is not valid JSON")?;
+    let processes = match &value {
+        Value::Array(processes) => processes,
+        _ => value["processes"]
+            .as_array()
+            .context("Snapshot has no `processes` list")?,
+    };
+
+    processes
+        .iter()
+        .enumerate()
+        .map(|(index, process)| {
+            process_from_json(process).with_context(|| format!("Invalid process at index {index}"))
+        })
+        .collect()
+}
+
+/// Compares two process lists
+pub fn diff(before: &[ProcessData], after: &[ProcessData]) -> SnapshotDiff {
+    let key = |process: &ProcessData| (process.pid, process.name.clone());
+    let before_by_key: HashMap<_, &ProcessData> = before.iter().map(|p| (key(p), p)).collect();
+    let after_by_key: HashMap<_, &ProcessData> = after.iter().map(|p| (key(p), p)).collect();
+
+    let mut diff = SnapshotDiff::default();
+    for process in after {
+        match before_by_key.get(&key(process)) {
+            Some(previous) => diff.changed.push(ProcessChange {
+                before: (*previous).clone(),
+                after: process.clone(),
+            }),
+            None => diff.appeared.push(process.clone()),
+        }
+    }
+    diff.disappeared = before
+        .iter()
+        .filter(|process| !after_by_key.contains_key(&key(process)))
+        .cloned()
+        .collect();
+
+    diff.appeared.sort_by_key(|p| p.pid);
+    diff.disappeared.sort_by_key(|p| p.pid);
+    diff.changed.sort_by(|a, b| {
+        b.cpu_delta()
+            .abs()
+            .total_cmp(&a.cpu_delta().abs())
+            .then_with(|| b.memory_delta().abs().cmp(&a.memory_delta().abs()))
+            .then_with(|| a.after.pid.cmp(&b.after.pid))
+    });
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, name: &str, cpu_usage: f32, memory: u64) -> ProcessData {
+        ProcessData {
+            pid,
+            name: name.to_string(),
+            cpu_usage,
+            memory,
+            ..Default::default()
+        }
+    }
+
+    fn pids(processes: &[ProcessData]) -> Vec<u32> {
+        processes.iter().map(|p| p.pid).collect()
+    }
+
+    #[test]
+    fn test_diff_appeared_and_exited() {
+        let before = [process(30, "cron", 0.0, 10), process(10, "sshd", 0.0, 10)];
+        let after = [
+            process(10, "sshd", 0.0, 10),
+            process(50, "nginx", 1.0, 10),
+            process(40, "nginx", 1.0, 10),
+        ];
+        let diff = diff(&before, &after);
+        assert_eq!(pids(&diff.appeared), [40, 50]);
+        assert_eq!(pids(&diff.disappeared), [30]);
+        assert_eq!(diff.changed.len(), 1);
+    }
+
+    #[test]
+    fn test_diff_reused_pid_is_a_different_process() {
+        let diff = diff(
+            &[process(10, "cron", 0.0, 0)],
+            &[process(10, "nginx", 0.0, 0)],
+        );
+        assert_eq!(diff.appeared[0].name, "nginx");
+        assert_eq!(diff.disappeared[0].name, "cron");
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_changed_largest_cpu_change_first() {
+        let before = [
+            process(1, "a", 10.0, 1000),
+            process(2, "b", 50.0, 1000),
+            process(3, "c", 5.0, 1000),
+        ];
+        let after = [
+            process(1, "a", 12.0, 4000),
+            process(2, "b", 20.0, 1000),
+            process(3, "c", 7.0, 500),
+        ];
+        let diff = diff(&before, &after);
+        // ties on CPU are broken by the memory change
+        let order: Vec<u32> = diff.changed.iter().map(|c| c.after.pid).collect();
+        assert_eq!(order, [2, 1, 3]);
+        assert_eq!(diff.changed[0].cpu_delta(), -30.0);
+        assert_eq!(diff.changed[1].memory_delta(), 3000);
+        assert_eq!(diff.changed[2].memory_delta(), -500);
+    }
+
+    #[test]
+    fn test_parse_snapshot() {
+        let json =
+            r#"{"processes": [{"pid": 1, "name": "init", "cpu_usage": 0.5, "memory": 2048}]}"#;
+        let processes = parse_snapshot(json).unwrap();
+        assert_eq!(processes[0].pid, 1);
+        assert_eq!(processes[0].memory, 2048);
+        assert!(parse_snapshot(r#"{"system": {}}"#).is_err());
+        assert!(parse_snapshot("[{\"pid\": 1}]").is_err());
+    }
+}
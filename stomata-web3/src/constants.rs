@@ -1 +1,69 @@
 pub const EVM_ADDRESS_HEX_LENGTH: usize = 42;
+pub const MIN_PASSWORD_ENTROPY_BITS: f64 = 50.0;
+pub const KEY_ENCRYPTION_ALGORITHM: &str = "aes-256-gcm+argon2id";
+pub const KEY_STORE_VERSION: u32 = 1;
+pub const BASE58_ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+pub const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+pub const BECH32_CONST: u32 = 1;
+pub const BECH32M_CONST: u32 = 0x2bc830a3;
+// mainnet, testnet and regtest segwit prefixes
+pub const BITCOIN_BECH32_HRPS: [&str; 3] = ["bc", "tb", "bcrt"];
+// P2PKH and P2SH version bytes for mainnet and testnet
+pub const BITCOIN_BASE58_VERSIONS: [u8; 4] = [0x00, 0x05, 0x6f, 0xc4];
+pub const SOLANA_ADDRESS_BYTES: usize = 32;
+pub const RPC_TIMEOUT_SECS: u64 = 10;
+pub const RPC_MAX_ATTEMPTS: u32 = 4;
+pub const RPC_BASE_RETRY_DELAY_MS: u64 = 250;
+pub const RPC_MAX_RETRY_DELAY_MS: u64 = 4_000;
+// JSON-RPC error codes providers use for rate limiting (EIP-1474 "limit exceeded" and common variants)
+pub const RPC_RATE_LIMIT_ERROR_CODES: [i64; 3] = [-32005, -32029, 429];
+pub const RPC_URLS_ENV_PREFIX: &str = "STOMATA_RPC_URLS_";
+pub const RPC_URL_SCHEMES: [&str; 4] = ["http", "https", "ws", "wss"];
+// tried in order, primary first
+pub const DEFAULT_ETHEREUM_RPC_URLS: &[&str] = &[
+    "https://rpc.fullsend.to",
+    "https://ethereum-rpc.publicnode.com",
+];
+pub const EXPLORER_API_KEY_ENV: &str = "STOMATA_EXPLORER_API_KEY";
+pub const EXPLORER_URL_ENV: &str = "STOMATA_EXPLORER_URL";
+// Etherscan V2 API, the chain is selected with `chainid`
+pub const DEFAULT_EXPLORER_URL: &str = "https://api.etherscan.io/v2/api?chainid=1";
+pub const PRICE_API_URL_ENV: &str = "STOMATA_PRICE_API_URL";
+pub const PRICE_API_KEY_ENV: &str = "STOMATA_PRICE_API_KEY";
+pub const DEFAULT_PRICE_API_URL: &str = "https://api.coingecko.com/api/v3";
+pub const PRICE_CACHE_TTL_SECS: u64 = 60;
+// CoinGecko id of the native asset of the supported EVM chain
+pub const NATIVE_PRICE_ASSET_ID: &str = "ethereum";
+pub const KEY_STORE_ENV: &str = "STOMATA_KEY_STORE";
+pub const KEYRING_SERVICE: &str = "stomata";
+// not a valid key name, so it can't collide with a stored key
+pub const KEYRING_INDEX_ENTRY: &str = ".index";
+// EIP-1967 storage slots, keccak256("eip1967.proxy.implementation") - 1 and
+// keccak256("eip1967.proxy.admin") - 1
+pub const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+pub const EIP1967_ADMIN_SLOT: &str =
+    "0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103";
+// Ethereum slot time, how often the latest block is polled without a subscription
+pub const BLOCK_POLL_INTERVAL_SECS: u64 = 12;
+// a few missed slots, after which a quiet subscription is given up for polling
+pub const NEW_HEADS_TIMEOUT_SECS: u64 = 60;
+// conventional burn addresses, lowercase without the 0x prefix
+pub const EVM_BURN_ADDRESSES: [&str; 2] = [
+    "000000000000000000000000000000000000dead",
+    "dead000000000000000042069420694206942069",
+];
+// addresses 0x1 to 0x9 hold the precompiled contracts up to Istanbul
+pub const EVM_PRECOMPILE_RANGE: std::ops::RangeInclusive<u8> = 1..=9;
+// order of the secp256k1 group, private keys are in 1..n
+pub const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+// scrypt memory refused when importing keystores, geth's default uses 256 MiB
+pub const KEYSTORE_MAX_SCRYPT_BYTES: usize = 1 << 30;
+// scrypt parallelism refused when importing keystores, wallets use 1
+pub const KEYSTORE_MAX_SCRYPT_P: u32 = 16;
+// pbkdf2 rounds refused when importing keystores, geth uses 262144
+pub const KEYSTORE_MAX_PBKDF2_ROUNDS: u32 = 10_000_000;
@@ -1,19 +1,125 @@
+use std::fmt;
+
+use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
-use crate::constants::EVM_ADDRESS_HEX_LENGTH;
+use crate::constants::{
+    BASE58_ALPHABET, BECH32_CHARSET, BECH32_CONST, BECH32M_CONST, BITCOIN_BASE58_VERSIONS,
+    BITCOIN_BECH32_HRPS, EVM_ADDRESS_HEX_LENGTH, EVM_BURN_ADDRESSES, EVM_PRECOMPILE_RANGE,
+    SOLANA_ADDRESS_BYTES,
+};
 
 pub struct AddressValidator;
 
+/// The chain an address was recognised as belonging to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    Evm,
+    Bitcoin,
+    Solana,
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Chain::Evm => "EVM",
+            Chain::Bitcoin => "Bitcoin",
+            Chain::Solana => "Solana",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Well-formed EVM addresses that almost never belong to a real wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialAddressKind {
+    /// `0x0000000000000000000000000000000000000000`
+    ZeroAddress,
+    /// A conventional burn address such as `0x000000000000000000000000000000000000dEaD`
+    BurnAddress,
+    /// `0x1` to `0x9`, the precompiled contracts
+    PrecompileRange,
+}
+
+impl SpecialAddressKind {
+    /// Explains why funds sent to this kind of address are usually lost
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            SpecialAddressKind::ZeroAddress => {
+                "the zero address has no owner, funds sent to it are lost"
+            }
+            SpecialAddressKind::BurnAddress => {
+                "burn addresses have no known key, funds sent to them are lost"
+            }
+            SpecialAddressKind::PrecompileRange => {
+                "precompiled contracts can't hold or return funds"
+            }
+        }
+    }
+}
+
+impl fmt::Display for SpecialAddressKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SpecialAddressKind::ZeroAddress => "zero address",
+            SpecialAddressKind::BurnAddress => "burn address",
+            SpecialAddressKind::PrecompileRange => "precompile address",
+        };
+        write!(f, "{name}")
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ValidationResult {
-    Valid { checksummed: String },
+    /// `checksummed` is the canonical form of the address: EIP-55 for EVM,
+    /// lowercase for bech32 and unchanged for base58 addresses.
+    Valid {
+        chain: Chain,
+        checksummed: String,
+    },
     InvalidLength,
     InvalidPrefix,
     InvalidCharacters,
+    InvalidChecksum {
+        chain: Chain,
+    },
+    /// A mixed-case EVM address whose casing does not match its EIP-55
+    /// checksum, which usually means the address was corrupted.
+    ChecksumMismatch {
+        expected: String,
+    },
+    /// A well-formed EVM address that is almost always a mistake in user
+    /// input, e.g. the zero or a burn address.
+    SpecialAddress {
+        kind: SpecialAddressKind,
+        checksummed: String,
+    },
 }
 
 impl AddressValidator {
+    /// Detects the chain of `address` and validates it.
+    ///
+    /// Addresses starting with `0x` are validated as EVM, `bc1`/`tb1`/`bcrt1`
+    /// as Bitcoin bech32, and other base58 strings as Bitcoin base58check
+    /// (25 bytes) or Solana (32 bytes). Anything else falls through to the
+    /// EVM rules so the error reported stays the same as before.
     pub fn validate(address: &str) -> ValidationResult {
+        if address.starts_with("0x") {
+            return Self::validate_evm(address);
+        }
+
+        if let Some(result) = Self::validate_bech32(address) {
+            return result;
+        }
+
+        if let Some(result) = Self::validate_base58(address) {
+            return result;
+        }
+
+        Self::validate_evm(address)
+    }
+
+    fn validate_evm(address: &str) -> ValidationResult {
         // checking length 0x + 40 hex characters
         if address.len() != EVM_ADDRESS_HEX_LENGTH {
             return ValidationResult::InvalidLength;
@@ -32,12 +138,143 @@ impl AddressValidator {
         }
 
         // checksum
-        let checksummed = Self::checksum_encode(addr_without_prefix);
+        let checksummed = format!("0x{}", Self::checksum_encode(addr_without_prefix));
+
+        // only mixed-case addresses carry an EIP-55 checksum to verify
+        if Self::is_mixed_case(addr_without_prefix) && address != checksummed {
+            return ValidationResult::ChecksumMismatch {
+                expected: checksummed,
+            };
+        }
+
+        if let Some(kind) = Self::special_evm_kind(addr_without_prefix) {
+            return ValidationResult::SpecialAddress { kind, checksummed };
+        }
+
         return ValidationResult::Valid {
-            checksummed: format!("0x{checksummed}"),
+            chain: Chain::Evm,
+            checksummed,
         };
     }
 
+    /// Classifies the 40 hex characters of an EVM address as a zero, burn
+    /// or precompile address.
+    fn special_evm_kind(address: &str) -> Option<SpecialAddressKind> {
+        let lower = address.to_ascii_lowercase();
+        if EVM_BURN_ADDRESSES.contains(&lower.as_str()) {
+            return Some(SpecialAddressKind::BurnAddress);
+        }
+
+        // the zero and precompile addresses are all zeros but the last byte
+        let (high, last_byte) = lower.split_at(lower.len() - 2);
+        if high.chars().any(|c| c != '0') {
+            return None;
+        }
+        match u8::from_str_radix(last_byte, 16).ok()? {
+            0 => Some(SpecialAddressKind::ZeroAddress),
+            byte if EVM_PRECOMPILE_RANGE.contains(&byte) => {
+                Some(SpecialAddressKind::PrecompileRange)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if `address` contains both lowercase and uppercase letters.
+    fn is_mixed_case(address: &str) -> bool {
+        address.chars().any(|c| c.is_ascii_lowercase())
+            && address.chars().any(|c| c.is_ascii_uppercase())
+    }
+
+    /// Validates base58 Bitcoin (P2PKH/P2SH) and Solana addresses.
+    ///
+    /// Returns `None` if the string is not base58 or decodes to a length
+    /// that neither chain uses.
+    fn validate_base58(address: &str) -> Option<ValidationResult> {
+        let decoded = base58_decode(address)?;
+
+        if decoded.len() == SOLANA_ADDRESS_BYTES {
+            return Some(ValidationResult::Valid {
+                chain: Chain::Solana,
+                checksummed: address.to_string(),
+            });
+        }
+
+        // version byte + 20 byte hash + 4 byte checksum
+        if decoded.len() != 25 || !BITCOIN_BASE58_VERSIONS.contains(&decoded[0]) {
+            return None;
+        }
+
+        let (payload, checksum) = decoded.split_at(21);
+        let hash = Sha256::digest(Sha256::digest(payload));
+        if &hash[..4] != checksum {
+            return Some(ValidationResult::InvalidChecksum {
+                chain: Chain::Bitcoin,
+            });
+        }
+
+        Some(ValidationResult::Valid {
+            chain: Chain::Bitcoin,
+            checksummed: address.to_string(),
+        })
+    }
+
+    /// Validates Bitcoin segwit addresses (BIP-173 bech32 and BIP-350 bech32m).
+    ///
+    /// Returns `None` if the address does not start with a known Bitcoin
+    /// human readable part.
+    fn validate_bech32(address: &str) -> Option<ValidationResult> {
+        let lower = address.to_ascii_lowercase();
+        let separator = lower.rfind('1')?;
+        let (hrp, data) = (&lower[..separator], &lower[separator + 1..]);
+        if !BITCOIN_BECH32_HRPS.contains(&hrp) {
+            return None;
+        }
+
+        // mixed case is not allowed
+        if address != lower && address != address.to_ascii_uppercase() {
+            return Some(ValidationResult::InvalidCharacters);
+        }
+
+        if address.len() > 90 || data.len() < 7 {
+            return Some(ValidationResult::InvalidLength);
+        }
+
+        let values: Option<Vec<u8>> = data
+            .bytes()
+            .map(|c| BECH32_CHARSET.iter().position(|&b| b == c).map(|v| v as u8))
+            .collect();
+        let Some(values) = values else {
+            return Some(ValidationResult::InvalidCharacters);
+        };
+
+        // witness version 0 uses bech32, versions 1-16 use bech32m
+        let witness_version = values[0];
+        let expected_const = match witness_version {
+            0 => BECH32_CONST,
+            1..=16 => BECH32M_CONST,
+            _ => return Some(ValidationResult::InvalidCharacters),
+        };
+        if bech32_polymod(hrp, &values) != expected_const {
+            return Some(ValidationResult::InvalidChecksum {
+                chain: Chain::Bitcoin,
+            });
+        }
+
+        let program = convert_bits(&values[1..values.len() - 6], 5, 8)?;
+        let valid_length = match witness_version {
+            0 => program.len() == 20 || program.len() == 32,
+            _ => (2..=40).contains(&program.len()),
+        };
+        if !valid_length {
+            return Some(ValidationResult::InvalidLength);
+        }
+
+        Some(ValidationResult::Valid {
+            chain: Chain::Bitcoin,
+            checksummed: lower,
+        })
+    }
+
     fn checksum_encode(address: &str) -> String {
         let address_lower = address.to_lowercase();
         let hash = Self::keccak256(address_lower.as_bytes());
@@ -69,6 +306,81 @@ impl AddressValidator {
     }
 }
 
+/// Decodes a base58 string, returning `None` on any non-alphabet character.
+fn base58_decode(input: &str) -> Option<Vec<u8>> {
+    if input.is_empty() {
+        return None;
+    }
+
+    // big-endian base256 accumulator
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in input.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&b| b == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // each leading '1' encodes a leading zero byte
+    let leading_zeros = input.bytes().take_while(|&c| c == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+    Some(decoded)
+}
+
+/// Computes the bech32 checksum polymod over the expanded HRP and data.
+fn bech32_polymod(hrp: &str, data: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let expanded_hrp = hrp
+        .bytes()
+        .map(|b| b >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|b| b & 31));
+
+    expanded_hrp
+        .chain(data.iter().copied())
+        .fold(1u32, |chk, value| {
+            let top = chk >> 25;
+            let mut chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+            for (i, g) in GENERATOR.iter().enumerate() {
+                if (top >> i) & 1 == 1 {
+                    chk ^= g;
+                }
+            }
+            chk
+        })
+}
+
+/// Regroups `data` from `from`-bit to `to`-bit words without padding.
+fn convert_bits(data: &[u8], from: u32, to: u32) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::new();
+
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+
+    // leftover bits must be fewer than `from` and all zero
+    if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +419,219 @@ mod tests {
         let result = AddressValidator::validate(addr);
         assert_eq!(result, ValidationResult::InvalidCharacters);
     }
+
+    #[test]
+    fn test_valid_uppercase_address() {
+        let addr = "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Evm,
+                checksummed: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let addr = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::ChecksumMismatch {
+                expected: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_zero_address() {
+        let addr = "0x0000000000000000000000000000000000000000";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::SpecialAddress {
+                kind: SpecialAddressKind::ZeroAddress,
+                checksummed: addr.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_burn_addresses() {
+        for addr in [
+            "0x000000000000000000000000000000000000dEaD",
+            "0xdead000000000000000042069420694206942069",
+        ] {
+            let result = AddressValidator::validate(addr);
+            assert!(matches!(
+                result,
+                ValidationResult::SpecialAddress {
+                    kind: SpecialAddressKind::BurnAddress,
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_precompile_range() {
+        let addr = "0x0000000000000000000000000000000000000001";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(
+            result,
+            ValidationResult::SpecialAddress {
+                kind: SpecialAddressKind::PrecompileRange,
+                ..
+            }
+        ));
+
+        // 0x0a is past the range
+        let addr = "0x000000000000000000000000000000000000000a";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(result, ValidationResult::Valid { .. }));
+    }
+
+    #[test]
+    fn test_evm_chain_detected() {
+        let addr = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Evm,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_valid_bitcoin_p2pkh() {
+        let addr = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Bitcoin,
+                checksummed: addr.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_bitcoin_p2sh() {
+        let addr = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Bitcoin,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_bitcoin_base58_bad_checksum() {
+        let addr = "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::InvalidChecksum {
+                chain: Chain::Bitcoin
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_bitcoin_bech32() {
+        let addr = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Bitcoin,
+                checksummed: addr.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_bitcoin_bech32_uppercase() {
+        let addr = "BC1QAR0SRRR7XFKVY5L643LYDNW9RE59GTZZWF5MDQ";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Bitcoin,
+                checksummed: addr.to_ascii_lowercase()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_bitcoin_bech32m_taproot() {
+        let addr = "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Bitcoin,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_bitcoin_bech32_bad_checksum() {
+        let addr = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdp";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::InvalidChecksum {
+                chain: Chain::Bitcoin
+            }
+        );
+    }
+
+    #[test]
+    fn test_bitcoin_bech32_mixed_case() {
+        let addr = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdQ";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(result, ValidationResult::InvalidCharacters);
+    }
+
+    #[test]
+    fn test_valid_solana_address() {
+        let addr = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Solana,
+                checksummed: addr.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_solana_system_program() {
+        let addr = "11111111111111111111111111111111";
+        let result = AddressValidator::validate(addr);
+        assert!(matches!(
+            result,
+            ValidationResult::Valid {
+                chain: Chain::Solana,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_base58_wrong_length_falls_back_to_evm_rules() {
+        let addr = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ";
+        let result = AddressValidator::validate(addr);
+        assert_eq!(result, ValidationResult::InvalidLength);
+    }
 }
@@ -0,0 +1,48 @@
+use crate::{
+    constants::{DEFAULT_EXPLORER_URL, EXPLORER_API_KEY_ENV, EXPLORER_URL_ENV},
+    providers::explorer::structs::ExplorerConfig,
+};
+
+/// Returns the explorer API configuration, `None` without an API key.
+///
+/// The key is read from `STOMATA_EXPLORER_API_KEY` and the base URL from
+/// `STOMATA_EXPLORER_URL`, defaulting to Etherscan for Ethereum mainnet.
+/// Any Etherscan-compatible API works as base URL.
+pub fn explorer_config() -> Option<ExplorerConfig> {
+    let api_key = std::env::var(EXPLORER_API_KEY_ENV).ok()?;
+    let base_url = std::env::var(EXPLORER_URL_ENV).ok();
+    build_explorer_config(&api_key, base_url.as_deref())
+}
+
+fn build_explorer_config(api_key: &str, base_url: Option<&str>) -> Option<ExplorerConfig> {
+    let api_key = api_key.trim();
+    if api_key.is_empty() {
+        return None;
+    }
+    let base_url = base_url
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_EXPLORER_URL);
+
+    Some(ExplorerConfig {
+        base_url: base_url.to_string(),
+        api_key: api_key.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explorer_config_needs_api_key() {
+        assert!(build_explorer_config("  ", Some("https://explorer.example/api")).is_none());
+    }
+
+    #[test]
+    fn test_explorer_config_default_url() {
+        let config = build_explorer_config("KEY", Some(" ")).unwrap();
+        assert_eq!(config.base_url, DEFAULT_EXPLORER_URL);
+        assert_eq!(config.api_key, "KEY");
+    }
+}
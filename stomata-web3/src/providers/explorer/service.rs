@@ -0,0 +1,217 @@
+use anyhow::{Result, anyhow, bail};
+use chrono::DateTime;
+use reqwest::Url;
+use serde_json::Value;
+
+use crate::providers::{
+    explorer::structs::{ExplorerProvider, RecentTransaction, TransactionDirection},
+    rpc::helper::wei_to_eth,
+};
+
+impl ExplorerProvider {
+    /// Fetches the latest `limit` normal transactions of `address`, newest
+    /// first, from the explorer's `account/txlist` endpoint.
+    ///
+    /// JSON-RPC has no way to list an address's history, hence the explorer.
+    pub async fn get_recent_transactions(
+        &self,
+        address: &str,
+        limit: usize,
+    ) -> Result<Vec<RecentTransaction>> {
+        let limit = limit.to_string();
+        // keeps query parameters already in the base URL, e.g. `chainid`
+        let url = Url::parse_with_params(
+            &self.config.base_url,
+            [
+                ("module", "account"),
+                ("action", "txlist"),
+                ("address", address),
+                ("page", "1"),
+                ("offset", limit.as_str()),
+                ("sort", "desc"),
+                ("apikey", self.config.api_key.as_str()),
+            ],
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+
+        parse_txlist(address, &response)
+    }
+
+    /// Checks whether the source of the contract at `address` is verified,
+    /// using the explorer's `contract/getsourcecode` endpoint.
+    pub async fn is_source_verified(&self, address: &str) -> Result<bool> {
+        let url = Url::parse_with_params(
+            &self.config.base_url,
+            [
+                ("module", "contract"),
+                ("action", "getsourcecode"),
+                ("address", address),
+                ("apikey", self.config.api_key.as_str()),
+            ],
+        )?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+
+        parse_source_verified(&response)
+    }
+}
+
+/// Parses an Etherscan-style `getsourcecode` response.
+///
+/// Unverified contracts come back with an empty `SourceCode`.
+fn parse_source_verified(response: &Value) -> Result<bool> {
+    let result = response
+        .get("result")
+        .ok_or_else(|| anyhow!("Missing result field in explorer response"))?;
+
+    let Some(entry) = result.as_array().and_then(|entries| entries.first()) else {
+        let message = response
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown error");
+        bail!("Explorer error {}: {}", message, result);
+    };
+
+    let source_code = entry
+        .get("SourceCode")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Missing SourceCode in explorer response"))?;
+    Ok(!source_code.is_empty())
+}
+
+/// Parses an Etherscan-style `txlist` response.
+///
+/// Errors come back with `status` `"0"` and the reason in `result`, except
+/// for addresses without transactions, which also use status `"0"`.
+fn parse_txlist(address: &str, response: &Value) -> Result<Vec<RecentTransaction>> {
+    let result = response
+        .get("result")
+        .ok_or_else(|| anyhow!("Missing result field in explorer response"))?;
+
+    let Value::Array(entries) = result else {
+        let message = response
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown error");
+        bail!("Explorer error {}: {}", message, result);
+    };
+
+    entries
+        .iter()
+        .map(|entry| parse_transaction(address, entry))
+        .collect()
+}
+
+fn parse_transaction(address: &str, entry: &Value) -> Result<RecentTransaction> {
+    let field = |name: &str| {
+        entry
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("Missing {} in explorer transaction", name))
+    };
+
+    let value: u128 = field("value")?.parse()?;
+    let timestamp = DateTime::from_timestamp(field("timeStamp")?.parse()?, 0)
+        .ok_or_else(|| anyhow!("Invalid transaction timestamp"))?;
+
+    // contract creations have an empty `to`
+    let from_self = field("from")?.eq_ignore_ascii_case(address);
+    let to_self = field("to").is_ok_and(|to| to.eq_ignore_ascii_case(address));
+    let direction = match (from_self, to_self) {
+        (true, true) => TransactionDirection::SelfTransfer,
+        (true, false) => TransactionDirection::Outgoing,
+        (false, _) => TransactionDirection::Incoming,
+    };
+
+    Ok(RecentTransaction {
+        hash: field("hash")?.to_string(),
+        value: wei_to_eth(value),
+        timestamp,
+        direction,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use serde_json::json;
+
+    const ADDRESS: &str = "0xdadB0d80178819F2319190D340ce9A924f783711";
+
+    #[test]
+    fn test_parse_txlist_directions() {
+        let response = json!({
+            "status": "1",
+            "message": "OK",
+            "result": [
+                {
+                    "hash": "0x01",
+                    "from": "0xdadb0d80178819f2319190d340ce9a924f783711",
+                    "to": "0x0000000000000000000000000000000000000001",
+                    "value": "1500000000000000000",
+                    "timeStamp": "1700000000"
+                },
+                {
+                    "hash": "0x02",
+                    "from": "0x0000000000000000000000000000000000000001",
+                    "to": "0xdadb0d80178819f2319190d340ce9a924f783711",
+                    "value": "0",
+                    "timeStamp": "1700000100"
+                }
+            ]
+        });
+        let transactions = parse_txlist(ADDRESS, &response).unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].direction, TransactionDirection::Outgoing);
+        assert_eq!(transactions[0].value, Decimal::new(15, 1));
+        assert_eq!(transactions[0].timestamp.timestamp(), 1_700_000_000);
+        assert_eq!(transactions[1].direction, TransactionDirection::Incoming);
+    }
+
+    #[test]
+    fn test_parse_txlist_no_transactions() {
+        let response = json!({"status": "0", "message": "No transactions found", "result": []});
+        assert!(parse_txlist(ADDRESS, &response).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_source_verified() {
+        let verified = json!({
+            "status": "1",
+            "message": "OK",
+            "result": [{"SourceCode": "pragma solidity ^0.8.0;", "ContractName": "Token"}]
+        });
+        assert!(parse_source_verified(&verified).unwrap());
+
+        let unverified = json!({
+            "status": "1",
+            "message": "OK",
+            "result": [{"SourceCode": "", "ABI": "Contract source code not verified"}]
+        });
+        assert!(!parse_source_verified(&unverified).unwrap());
+
+        let error = json!({"status": "0", "message": "NOTOK", "result": "Invalid API Key"});
+        assert!(parse_source_verified(&error).is_err());
+    }
+
+    #[test]
+    fn test_parse_txlist_error() {
+        let response = json!({"status": "0", "message": "NOTOK", "result": "Invalid API Key"});
+        let err = parse_txlist(ADDRESS, &response).unwrap_err().to_string();
+        assert!(err.contains("Invalid API Key"));
+    }
+}
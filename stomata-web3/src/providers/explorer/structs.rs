@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use rust_decimal::Decimal;
+
+use crate::constants::RPC_TIMEOUT_SECS;
+
+/// Etherscan-compatible explorer API endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplorerConfig {
+    /// API URL, may carry query parameters such as `chainid`
+    pub base_url: String,
+    pub api_key: String,
+}
+
+pub struct ExplorerProvider {
+    pub config: ExplorerConfig,
+    /// HTTP client shared by every call so connections are pooled
+    pub client: Client,
+}
+
+impl ExplorerProvider {
+    /// Creates a provider for the explorer API in `config`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `Client::new`.
+    pub fn new(config: ExplorerConfig) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(RPC_TIMEOUT_SECS))
+            .build()
+            .expect("failed to initialize the explorer http client");
+
+        Self { config, client }
+    }
+}
+
+/// Direction of a transaction relative to the looked up address
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransactionDirection {
+    Incoming,
+    Outgoing,
+    /// Sent from the address to itself
+    SelfTransfer,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentTransaction {
+    pub hash: String,
+    /// Transferred native value in ETH
+    pub value: Decimal,
+    pub timestamp: DateTime<Utc>,
+    pub direction: TransactionDirection,
+}
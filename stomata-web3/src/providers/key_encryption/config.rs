@@ -0,0 +1,50 @@
+use crate::{
+    constants::KEY_STORE_ENV,
+    providers::key_encryption::{errors::StorageError, structs::KeyStoreBackend},
+};
+
+/// Returns the key store backend to use.
+///
+/// Read from `STOMATA_KEY_STORE`, either `file` (the default) or `keyring`
+/// for the OS keychain / Secret Service.
+pub fn key_store_backend() -> Result<KeyStoreBackend, StorageError> {
+    let backend = std::env::var(KEY_STORE_ENV).ok();
+    parse_key_store_backend(backend.as_deref())
+}
+
+fn parse_key_store_backend(backend: Option<&str>) -> Result<KeyStoreBackend, StorageError> {
+    match backend.map(|b| b.trim().to_lowercase()).as_deref() {
+        None | Some("") | Some("file") => Ok(KeyStoreBackend::File),
+        Some("keyring") => Ok(KeyStoreBackend::Keyring),
+        Some(other) => Err(StorageError::BackendError(format!(
+            "Unknown key store '{}', expected 'file' or 'keyring'",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_store_backend_defaults_to_file() {
+        assert_eq!(
+            parse_key_store_backend(None).unwrap(),
+            KeyStoreBackend::File
+        );
+        assert_eq!(
+            parse_key_store_backend(Some(" ")).unwrap(),
+            KeyStoreBackend::File
+        );
+    }
+
+    #[test]
+    fn test_parse_key_store_backend() {
+        assert_eq!(
+            parse_key_store_backend(Some("Keyring")).unwrap(),
+            KeyStoreBackend::Keyring
+        );
+        assert!(parse_key_store_backend(Some("vault")).is_err());
+    }
+}
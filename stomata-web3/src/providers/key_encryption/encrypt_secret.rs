@@ -1,15 +1,17 @@
 use aes_gcm::{Aes256Gcm, KeyInit, Nonce, aead::Aead};
 use argon2::Argon2;
 use rand::random;
+use zeroize::Zeroizing;
 
 use crate::providers::key_encryption::structs::{CryptoData, EncryptPrivateKey};
 
 // ==== Core Encryption Functions ====
 
-fn derive_key(password: &str, salt: &[u8]) -> [u8; 32] {
-    let mut key = [0u8; 32];
+/// Derives the AES key from the password, wiped from memory on drop
+fn derive_key(password: &str, salt: &[u8]) -> Zeroizing<[u8; 32]> {
+    let mut key = Zeroizing::new([0u8; 32]);
     Argon2::default()
-        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .hash_password_into(password.as_bytes(), salt, key.as_mut())
         .unwrap();
     key
 }
@@ -18,7 +20,7 @@ pub fn encrypt_private_key(pk: &[u8], password: &str) -> Option<EncryptPrivateKe
     let salt = random::<[u8; 16]>();
     let nonce = random::<[u8; 12]>();
     let key = derive_key(password, &salt);
-    let cipher = match Aes256Gcm::new_from_slice(&key) {
+    let cipher = match Aes256Gcm::new_from_slice(key.as_ref()) {
         Ok(res) => res,
         Err(_err) => {
             return None;
@@ -43,7 +45,11 @@ pub fn encrypt_private_key(pk: &[u8], password: &str) -> Option<EncryptPrivateKe
     })
 }
 
-pub fn decrypt_private_key(data: &EncryptPrivateKey, password: &str) -> Option<Vec<u8>> {
+/// Decrypts a private key
+///
+/// The plaintext is wrapped in `Zeroizing` so it is wiped from memory when
+/// dropped. Errors are logged without any key material.
+pub fn decrypt_private_key(data: &EncryptPrivateKey, password: &str) -> Option<Zeroizing<Vec<u8>>> {
     let salt = match hex::decode(&data.crypto_key.salt) {
         Ok(salt) => salt,
         Err(err) => {
@@ -69,7 +75,7 @@ pub fn decrypt_private_key(data: &EncryptPrivateKey, password: &str) -> Option<V
     };
 
     let key = derive_key(password, &salt);
-    let cipher = match Aes256Gcm::new_from_slice(&key) {
+    let cipher = match Aes256Gcm::new_from_slice(key.as_ref()) {
         Ok(cipher) => cipher,
         Err(err) => {
             eprintln!("Error in generating cipher {:?}", err);
@@ -78,7 +84,7 @@ pub fn decrypt_private_key(data: &EncryptPrivateKey, password: &str) -> Option<V
     };
 
     match cipher.decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref()) {
-        Ok(res) => Some(res),
+        Ok(res) => Some(Zeroizing::new(res)),
         Err(err) => {
             eprintln!("Error in decrypting key {:?}", err);
             return None;
@@ -146,7 +152,7 @@ mod tests {
         let decrypted =
             decrypt_private_key(&encrypted, password).expect("Decryption should succeed");
 
-        assert_eq!(private_key, decrypted);
+        assert_eq!(private_key, *decrypted);
     }
 
     #[test]
@@ -257,7 +263,7 @@ mod tests {
         let decrypted =
             decrypt_private_key(&encrypted, password).expect("Decryption should succeed");
 
-        assert_eq!(private_key, decrypted);
+        assert_eq!(private_key, *decrypted);
     }
 
     #[test]
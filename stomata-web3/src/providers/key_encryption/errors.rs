@@ -7,6 +7,8 @@ pub enum StorageError {
     KeyNotFound(String),
     KeyAlreadyExists(String),
     InvalidKeyName(String),
+    /// The key store backend is unavailable or failed
+    BackendError(String),
 }
 
 impl From<io::Error> for StorageError {
@@ -21,6 +23,13 @@ impl From<serde_json::Error> for StorageError {
     }
 }
 
+#[cfg(feature = "keyring")]
+impl From<keyring::Error> for StorageError {
+    fn from(err: keyring::Error) -> Self {
+        StorageError::BackendError(format!("Keyring error: {}", err))
+    }
+}
+
 impl std::fmt::Display for StorageError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -29,6 +38,7 @@ impl std::fmt::Display for StorageError {
             StorageError::KeyNotFound(name) => write!(f, "Key '{}' not found", name),
             StorageError::KeyAlreadyExists(name) => write!(f, "Key '{}' already exists", name),
             StorageError::InvalidKeyName(name) => write!(f, "Invalid key name: '{}'", name),
+            StorageError::BackendError(msg) => write!(f, "Key store error: {}", msg),
         }
     }
 }
@@ -0,0 +1,284 @@
+//! Parsing of private keys generated elsewhere, for importing them into the
+//! key store
+//!
+//! Keys are accepted as 64 hex characters (optionally `0x` prefixed) or as
+//! a Web3 Secret Storage (v3 keystore) JSON file, as written by geth,
+//! MetaMask and most wallets. The keystore KDFs (scrypt, PBKDF2-HMAC-SHA256)
+//! and AES-128-CTR come from the RustCrypto crates.
+
+use aes::Aes128;
+use anyhow::{Result, anyhow, bail};
+use ctr::{
+    Ctr128BE,
+    cipher::{KeyIvInit, StreamCipher},
+};
+use serde_json::Value;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::constants::{
+    KEYSTORE_MAX_PBKDF2_ROUNDS, KEYSTORE_MAX_SCRYPT_BYTES, KEYSTORE_MAX_SCRYPT_P, SECP256K1_ORDER,
+};
+
+/// Parses a private key from the contents of a key file.
+///
+/// Input starting with `{` is read as a v3 keystore and decrypted with the
+/// password returned by `keystore_password`, which is only called for
+/// keystores. Anything else must be a hex encoded 32 byte key.
+///
+/// # Errors
+///
+/// Returns an error if the input is neither format, the keystore password
+/// is wrong or the key is outside the secp256k1 range. Messages never
+/// include key material.
+pub fn parse_private_key<F>(input: &str, keystore_password: F) -> Result<Zeroizing<Vec<u8>>>
+where
+    F: FnOnce() -> Zeroizing<String>,
+{
+    let input = input.trim();
+    let key = if input.starts_with('{') {
+        decrypt_keystore(input, &keystore_password())?
+    } else {
+        parse_hex_private_key(input)?
+    };
+    validate_private_key(&key)?;
+    Ok(key)
+}
+
+/// Decodes a hex private key, with or without the `0x` prefix
+fn parse_hex_private_key(input: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let hex_key = input.strip_prefix("0x").unwrap_or(input);
+    if hex_key.len() != 64 {
+        bail!(
+            "Expected a 32 byte key as 64 hex characters, got {} characters",
+            hex_key.len()
+        );
+    }
+    // the decode error would quote the offending character
+    hex::decode(hex_key)
+        .map(Zeroizing::new)
+        .map_err(|_| anyhow!("Key is not valid hex"))
+}
+
+/// Checks that `key` is a plausible secp256k1 private key, 32 bytes in
+/// `1..n`
+fn validate_private_key(key: &[u8]) -> Result<()> {
+    if key.len() != 32 {
+        bail!("Expected a 32 byte key, got {} bytes", key.len());
+    }
+    if key.iter().all(|byte| *byte == 0) || key >= SECP256K1_ORDER.as_slice() {
+        bail!("Key is outside the valid secp256k1 range");
+    }
+    Ok(())
+}
+
+/// Decrypts a v3 keystore with `aes-128-ctr` and a `scrypt` or `pbkdf2` KDF.
+fn decrypt_keystore(json: &str, password: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let keystore: Value = serde_json::from_str(json)?;
+    if keystore.get("version").and_then(Value::as_u64) != Some(3) {
+        bail!("Unsupported keystore version, only version 3 is supported");
+    }
+    // some wallets capitalize the field
+    let crypto = keystore
+        .get("crypto")
+        .or_else(|| keystore.get("Crypto"))
+        .ok_or_else(|| anyhow!("Missing crypto in keystore"))?;
+    let field = |object: &Value, name: &str| -> Result<Value> {
+        object
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Missing {name} in keystore"))
+    };
+    let hex_field = |object: &Value, name: &str| -> Result<Vec<u8>> {
+        let value = field(object, name)?;
+        let text = value
+            .as_str()
+            .ok_or_else(|| anyhow!("Invalid {name} in keystore"))?;
+        hex::decode(text.trim_start_matches("0x"))
+            .map_err(|_| anyhow!("Invalid {name} in keystore"))
+    };
+    let number = |object: &Value, name: &str| -> Result<u64> {
+        field(object, name)?
+            .as_u64()
+            .ok_or_else(|| anyhow!("Invalid {name} in keystore"))
+    };
+
+    let cipher = field(crypto, "cipher")?;
+    if cipher.as_str() != Some("aes-128-ctr") {
+        bail!("Unsupported keystore cipher {cipher}, only aes-128-ctr is supported");
+    }
+    let iv: [u8; 16] = hex_field(&field(crypto, "cipherparams")?, "iv")?
+        .try_into()
+        .map_err(|_| anyhow!("Invalid iv in keystore"))?;
+    let ciphertext = hex_field(crypto, "ciphertext")?;
+    let mac = hex_field(crypto, "mac")?;
+
+    let kdf_params = field(crypto, "kdfparams")?;
+    let salt = hex_field(&kdf_params, "salt")?;
+    let dklen = number(&kdf_params, "dklen")? as usize;
+    if !(32..=64).contains(&dklen) {
+        bail!("Invalid dklen in keystore, 32 to 64 bytes are supported");
+    }
+    let mut derived = Zeroizing::new(vec![0u8; dklen]);
+    match field(crypto, "kdf")?.as_str() {
+        Some("scrypt") => {
+            let n = number(&kdf_params, "n")?;
+            let r = u32::try_from(number(&kdf_params, "r")?)?;
+            let p = u32::try_from(number(&kdf_params, "p")?)?;
+            if n < 2 || !n.is_power_of_two() || r == 0 || p == 0 {
+                bail!("Invalid scrypt parameters in keystore");
+            }
+            if (n as usize).saturating_mul(r as usize).saturating_mul(128)
+                > KEYSTORE_MAX_SCRYPT_BYTES
+            {
+                bail!("Keystore scrypt parameters need too much memory");
+            }
+            if p > KEYSTORE_MAX_SCRYPT_P {
+                bail!("Keystore scrypt p is above {KEYSTORE_MAX_SCRYPT_P}");
+            }
+            let params = scrypt::Params::new(n.trailing_zeros() as u8, r, p, dklen)
+                .map_err(|_| anyhow!("Invalid scrypt parameters in keystore"))?;
+            scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived)
+                .map_err(|_| anyhow!("Invalid dklen in keystore"))?;
+        }
+        Some("pbkdf2") => {
+            if kdf_params.get("prf").and_then(Value::as_str) != Some("hmac-sha256") {
+                bail!("Unsupported keystore pbkdf2 prf, only hmac-sha256 is supported");
+            }
+            let rounds = u32::try_from(number(&kdf_params, "c")?)?;
+            if rounds == 0 || rounds > KEYSTORE_MAX_PBKDF2_ROUNDS {
+                bail!("Keystore pbkdf2 c must be 1 to {KEYSTORE_MAX_PBKDF2_ROUNDS}");
+            }
+            pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, rounds, &mut derived);
+        }
+        _ => bail!("Unsupported keystore kdf, only scrypt and pbkdf2 are supported"),
+    }
+
+    let expected_mac = Keccak256::new()
+        .chain_update(&derived[16..32])
+        .chain_update(&ciphertext)
+        .finalize();
+    if !bool::from(expected_mac.as_slice().ct_eq(&mac)) {
+        bail!("Wrong keystore password or corrupted keystore");
+    }
+
+    let mut key = Zeroizing::new(ciphertext);
+    // the whole iv is a big-endian counter
+    Ctr128BE::<Aes128>::new(derived[..16].into(), &iv.into()).apply_keystream(&mut key);
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+    const PASSWORD: &str = "testpassword";
+
+    fn password() -> Zeroizing<String> {
+        Zeroizing::new(PASSWORD.to_string())
+    }
+
+    fn keystore(kdf: &str, kdf_params: Value, ciphertext: &str, mac: &str) -> String {
+        serde_json::json!({
+            "crypto": {
+                "cipher": "aes-128-ctr",
+                "cipherparams": {"iv": "83dbcc02d8ccb40e466191a123791e0e"},
+                "ciphertext": ciphertext,
+                "kdf": kdf,
+                "kdfparams": kdf_params,
+                "mac": mac
+            },
+            "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+            "version": 3
+        })
+        .to_string()
+    }
+
+    fn scrypt_keystore() -> String {
+        // the Web3 Secret Storage test vector with a cheaper n
+        keystore(
+            "scrypt",
+            serde_json::json!({
+                "dklen": 32, "n": 1024, "r": 8, "p": 1,
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            }),
+            "01a05c7f05b697274227d8bd0825a6caa89967e24643426c0fcfa2fb663052d7",
+            "d60a6540bbdeaa746e4c7b4359c74e4bb0b679bedce5b4d129ad96150d200274",
+        )
+    }
+
+    #[test]
+    fn test_parse_hex_private_key() {
+        for input in [PRIVATE_KEY.to_string(), format!("0x{PRIVATE_KEY}\n")] {
+            let key = parse_private_key(&input, || unreachable!()).unwrap();
+            assert_eq!(hex::encode(key.as_slice()), PRIVATE_KEY);
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_private_key_rejects_bad_input() {
+        assert!(parse_private_key(&PRIVATE_KEY[..62], password).is_err());
+        assert!(parse_private_key(&PRIVATE_KEY.replace('a', "g"), password).is_err());
+        assert!(parse_private_key(&"0".repeat(64), password).is_err());
+        assert!(parse_private_key(&hex::encode(SECP256K1_ORDER), password).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_scrypt_keystore() {
+        let key = parse_private_key(&scrypt_keystore(), password).unwrap();
+        assert_eq!(hex::encode(key.as_slice()), PRIVATE_KEY);
+    }
+
+    #[test]
+    fn test_decrypt_pbkdf2_keystore() {
+        let keystore = keystore(
+            "pbkdf2",
+            serde_json::json!({
+                "c": 1024, "dklen": 32, "prf": "hmac-sha256",
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            }),
+            "cd0049568e41620bb294a26bdf1e3088e3070f2f784293fe4a4d9a6b5a415ad1",
+            "f8438cbefa2ca738411030fea72aab77ea85c77fd7d07219d362df3804de1f6a",
+        );
+        let key = parse_private_key(&keystore, password).unwrap();
+        assert_eq!(hex::encode(key.as_slice()), PRIVATE_KEY);
+    }
+
+    #[test]
+    fn test_keystore_work_is_bounded() {
+        let salt = "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19";
+        let scrypt = keystore(
+            "scrypt",
+            serde_json::json!({"dklen": 32, "n": 1024, "r": 8, "p": u32::MAX, "salt": salt}),
+            "00",
+            "00",
+        );
+        let err = parse_private_key(&scrypt, password).unwrap_err();
+        assert!(err.to_string().contains("scrypt p"), "{err}");
+
+        let pbkdf2 = keystore(
+            "pbkdf2",
+            serde_json::json!({"c": u32::MAX, "dklen": 32, "prf": "hmac-sha256", "salt": salt}),
+            "00",
+            "00",
+        );
+        let err = parse_private_key(&pbkdf2, password).unwrap_err();
+        assert!(err.to_string().contains("pbkdf2 c"), "{err}");
+    }
+
+    #[test]
+    fn test_keystore_wrong_password() {
+        let result = parse_private_key(&scrypt_keystore(), || {
+            Zeroizing::new("wrong password".to_string())
+        });
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Wrong keystore password")
+        );
+    }
+}
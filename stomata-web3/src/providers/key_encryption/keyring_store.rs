@@ -0,0 +1,109 @@
+use keyring::Entry;
+
+use crate::{
+    constants::{KEYRING_INDEX_ENTRY, KEYRING_SERVICE},
+    providers::key_encryption::{
+        errors::StorageError,
+        store_secrets::{validate_key_name, with_metadata},
+        structs::EncryptPrivateKey,
+        traits::KeyStore,
+    },
+};
+
+/// Stores the encrypted keys in the OS keyring (macOS Keychain, Windows
+/// Credential Manager or the Secret Service on Linux)
+///
+/// Keyrings can't be enumerated, so the key names are kept in an extra
+/// index entry next to the keys.
+pub struct KeyringKeyStore {
+    service: String,
+}
+
+impl KeyringKeyStore {
+    pub fn new() -> Self {
+        Self {
+            service: KEYRING_SERVICE.to_string(),
+        }
+    }
+
+    fn entry(&self, name: &str) -> Result<Entry, StorageError> {
+        Ok(Entry::new(&self.service, name)?)
+    }
+
+    fn read_index(&self) -> Result<Vec<String>, StorageError> {
+        match self.entry(KEYRING_INDEX_ENTRY)?.get_password() {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(keyring::Error::NoEntry) => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write_index(&self, names: &[String]) -> Result<(), StorageError> {
+        let json = serde_json::to_string(names)?;
+        self.entry(KEYRING_INDEX_ENTRY)?.set_password(&json)?;
+        Ok(())
+    }
+}
+
+impl Default for KeyringKeyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyStore for KeyringKeyStore {
+    fn save(&self, name: &str, encrypted: &EncryptPrivateKey) -> Result<(), StorageError> {
+        validate_key_name(name)?;
+        if self.exists(name)? {
+            return Err(StorageError::KeyAlreadyExists(name.to_string()));
+        }
+
+        let json = serde_json::to_string(&with_metadata(name, encrypted))?;
+        self.entry(name)?.set_password(&json)?;
+
+        let mut names = self.read_index()?;
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+            names.sort();
+            self.write_index(&names)?;
+        }
+        Ok(())
+    }
+
+    fn load(&self, name: &str) -> Result<EncryptPrivateKey, StorageError> {
+        validate_key_name(name)?;
+        match self.entry(name)?.get_password() {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(keyring::Error::NoEntry) => Err(StorageError::KeyNotFound(name.to_string())),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn list(&self) -> Result<Vec<String>, StorageError> {
+        self.read_index()
+    }
+
+    fn delete(&self, name: &str) -> Result<(), StorageError> {
+        validate_key_name(name)?;
+        match self.entry(name)?.delete_credential() {
+            Ok(()) => {}
+            Err(keyring::Error::NoEntry) => {
+                return Err(StorageError::KeyNotFound(name.to_string()));
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut names = self.read_index()?;
+        names.retain(|n| n != name);
+        self.write_index(&names)
+    }
+
+    fn exists(&self, name: &str) -> Result<bool, StorageError> {
+        validate_key_name(name)?;
+        match self.entry(name)?.get_password() {
+            Ok(_) => Ok(true),
+            Err(keyring::Error::NoEntry) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -1,4 +1,10 @@
+pub mod config;
 pub mod encrypt_secret;
 pub mod errors;
+pub mod import_key;
+#[cfg(feature = "keyring")]
+pub mod keyring_store;
+pub mod password_strength;
 pub mod store_secrets;
 pub mod structs;
+pub mod traits;
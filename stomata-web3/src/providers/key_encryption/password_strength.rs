@@ -0,0 +1,104 @@
+use crate::constants::MIN_PASSWORD_ENTROPY_BITS;
+
+// ==== Passphrase strength estimation ====
+
+/// Estimate the entropy (in bits) of a passphrase
+///
+/// Uses the size of the character pool the passphrase draws from (lowercase,
+/// uppercase, digits, symbols, non-ascii) and its effective length. Repeated
+/// characters are penalised so that inputs like `aaaaaaaaaaaa` are not
+/// rated as strong just because they are long.
+pub fn estimate_entropy(password: &str) -> f64 {
+    if password.is_empty() {
+        return 0.0;
+    }
+
+    let mut pool_size = 0u32;
+    if password.chars().any(|c| c.is_ascii_lowercase()) {
+        pool_size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_uppercase()) {
+        pool_size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_digit()) {
+        pool_size += 10;
+    }
+    if password
+        .chars()
+        .any(|c| c.is_ascii_punctuation() || c == ' ')
+    {
+        pool_size += 33;
+    }
+    if !password.is_ascii() {
+        pool_size += 100;
+    }
+
+    let length = password.chars().count();
+    let mut unique_chars: Vec<char> = password.chars().collect();
+    unique_chars.sort_unstable();
+    unique_chars.dedup();
+
+    // each distinct character is allowed to contribute at most twice
+    let effective_length = length.min(unique_chars.len() * 2);
+
+    effective_length as f64 * (pool_size as f64).log2()
+}
+
+/// Check if a passphrase is too weak to protect a private key
+pub fn is_weak_password(password: &str) -> bool {
+    estimate_entropy(password) < MIN_PASSWORD_ENTROPY_BITS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_password_has_no_entropy() {
+        assert_eq!(estimate_entropy(""), 0.0);
+        assert!(is_weak_password(""));
+    }
+
+    #[test]
+    fn test_common_weak_passwords() {
+        for password in ["password", "12345678", "qwerty", "letmein", "abc123"] {
+            assert!(
+                is_weak_password(password),
+                "{password} should be rated as weak"
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_characters_are_weak() {
+        assert!(is_weak_password("aaaaaaaaaaaaaaaaaaaaaaaa"));
+        assert!(is_weak_password("abababababababababab"));
+    }
+
+    #[test]
+    fn test_strong_passwords() {
+        for password in [
+            "correct horse battery staple",
+            "Tr0ub4dor&3-extended",
+            "p@ssw0rd!#$%^&*()_+-=[]{}|;:,.<>?/~`",
+            "G7$kq!2Lm#9zXw",
+        ] {
+            assert!(
+                !is_weak_password(password),
+                "{password} should be rated as strong"
+            );
+        }
+    }
+
+    #[test]
+    fn test_character_classes_increase_entropy() {
+        let lower = estimate_entropy("abcdefgh");
+        let mixed = estimate_entropy("abcdEFGH");
+        let with_digits = estimate_entropy("abcDEF12");
+        let with_symbols = estimate_entropy("abD1!@ef");
+
+        assert!(mixed > lower);
+        assert!(with_digits > mixed);
+        assert!(with_symbols > with_digits);
+    }
+}
@@ -1,10 +1,21 @@
-use std::{fs, io, path::PathBuf};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
-use crate::providers::{
-    encrypt_secret::{decrypt_private_key, encrypt_private_key},
-    key_encryption::{
-        errors::StorageError,
-        structs::{EncryptPrivateKey, KeyMetadata},
+use zeroize::Zeroizing;
+
+use crate::{
+    constants::{KEY_ENCRYPTION_ALGORITHM, KEY_STORE_VERSION},
+    providers::{
+        encrypt_secret::{decrypt_private_key, encrypt_private_key},
+        key_encryption::{
+            config::key_store_backend,
+            errors::StorageError,
+            structs::{EncryptPrivateKey, KeyInfo, KeyMetadata, KeyStoreBackend},
+            traits::KeyStore,
+        },
     },
 };
 
@@ -47,7 +58,7 @@ pub fn init_storage() -> Result<(), StorageError> {
 }
 
 /// Validate key name (no path separators, special chars, etc.)
-fn validate_key_name(name: &str) -> Result<(), StorageError> {
+pub(crate) fn validate_key_name(name: &str) -> Result<(), StorageError> {
     if name.is_empty() {
         return Err(StorageError::InvalidKeyName(
             "Key name cannot be empty".to_string(),
@@ -81,33 +92,49 @@ pub fn save_encrypted_key(name: &str, encrypted: &EncryptPrivateKey) -> Result<(
     init_storage()?;
 
     let key_path = get_key_path(name)?;
+    write_key_file(&key_path, name, encrypted)
+}
 
-    // Check if key already exists
-    if key_path.exists() {
-        return Err(StorageError::KeyAlreadyExists(name.to_string()));
+/// Writes an encrypted key to a new file at `key_path`
+///
+/// The file is created atomically, so an existing key is never overwritten
+/// even if another process stores the same name concurrently. On Unix it is
+/// only readable by the owner from the start.
+fn write_key_file(
+    key_path: &Path,
+    name: &str,
+    encrypted: &EncryptPrivateKey,
+) -> Result<(), StorageError> {
+    let json = serde_json::to_string_pretty(&with_metadata(name, encrypted))?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    // Set restrictive permissions on Unix
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
     }
+    let mut file = options.open(key_path).map_err(|err| match err.kind() {
+        io::ErrorKind::AlreadyExists => StorageError::KeyAlreadyExists(name.to_string()),
+        _ => StorageError::IoError(err),
+    })?;
+    file.write_all(json.as_bytes())?;
 
-    // Add metadata
-    let encrypted_with_meta = EncryptPrivateKey {
+    Ok(())
+}
+
+/// Copy of the encrypted key with fresh metadata attached
+pub(crate) fn with_metadata(name: &str, encrypted: &EncryptPrivateKey) -> EncryptPrivateKey {
+    EncryptPrivateKey {
         crypto_key: encrypted.crypto_key.clone(),
         metadata: Some(KeyMetadata {
             name: name.to_string(),
             created_at: chrono::Utc::now().to_rfc3339(),
+            algorithm: Some(KEY_ENCRYPTION_ALGORITHM.to_string()),
+            version: Some(KEY_STORE_VERSION),
         }),
-    };
-
-    let json = serde_json::to_string_pretty(&encrypted_with_meta)?;
-    fs::write(&key_path, json)?;
-
-    // Set restrictive permissions on Unix
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let permissions = fs::Permissions::from_mode(0o600);
-        fs::set_permissions(&key_path, permissions)?;
     }
-
-    Ok(())
 }
 
 /// Load an encrypted key from disk
@@ -124,8 +151,8 @@ pub fn load_encrypted_key(name: &str) -> Result<EncryptPrivateKey, StorageError>
     Ok(encrypted)
 }
 
-/// List all stored key names
-pub fn list_keys() -> Result<Vec<String>, StorageError> {
+/// List all key names stored on disk
+pub fn list_key_files() -> Result<Vec<String>, StorageError> {
     let keys_dir = get_keys_dir()?;
 
     if !keys_dir.exists() {
@@ -149,8 +176,52 @@ pub fn list_keys() -> Result<Vec<String>, StorageError> {
     Ok(keys)
 }
 
-/// Delete a stored key
-pub fn delete_key(name: &str) -> Result<(), StorageError> {
+/// List all stored keys along with their metadata
+///
+/// Keys stored without metadata, or which can't be read, are still listed
+/// with the missing fields left as `None`.
+pub fn list_keys_with_metadata() -> Result<Vec<KeyInfo>, StorageError> {
+    let store = key_store()?;
+    let keys = store
+        .list()?
+        .into_iter()
+        .map(|name| match store.load(&name) {
+            Ok(encrypted) => key_info(name, &encrypted),
+            Err(_) => KeyInfo {
+                name,
+                created_at: None,
+                algorithm: None,
+                version: None,
+            },
+        })
+        .collect();
+
+    Ok(keys)
+}
+
+/// Build the listing summary for a loaded key
+fn key_info(name: String, encrypted: &EncryptPrivateKey) -> KeyInfo {
+    match &encrypted.metadata {
+        Some(metadata) => KeyInfo {
+            name,
+            created_at: Some(metadata.created_at.clone()),
+            algorithm: metadata
+                .algorithm
+                .clone()
+                .or_else(|| Some(encrypted.crypto_key.cipher.clone())),
+            version: metadata.version,
+        },
+        None => KeyInfo {
+            name,
+            created_at: None,
+            algorithm: Some(encrypted.crypto_key.cipher.clone()),
+            version: None,
+        },
+    }
+}
+
+/// Delete a key file from disk
+pub fn delete_key_file(name: &str) -> Result<(), StorageError> {
     let key_path = get_key_path(name)?;
 
     if !key_path.exists() {
@@ -161,27 +232,95 @@ pub fn delete_key(name: &str) -> Result<(), StorageError> {
     Ok(())
 }
 
-/// Check if a key exists
-pub fn key_exists(name: &str) -> Result<bool, StorageError> {
+/// Check if a key file exists on disk
+pub fn key_file_exists(name: &str) -> Result<bool, StorageError> {
     let key_path = get_key_path(name)?;
     Ok(key_path.exists())
 }
 
+// ==== Key Store Backends ====
+
+/// Stores the encrypted keys as JSON files in `~/.stomataKeys/keys`
+#[derive(Debug, Default)]
+pub struct FileKeyStore;
+
+impl KeyStore for FileKeyStore {
+    fn save(&self, name: &str, encrypted: &EncryptPrivateKey) -> Result<(), StorageError> {
+        save_encrypted_key(name, encrypted)
+    }
+
+    fn load(&self, name: &str) -> Result<EncryptPrivateKey, StorageError> {
+        load_encrypted_key(name)
+    }
+
+    fn list(&self) -> Result<Vec<String>, StorageError> {
+        list_key_files()
+    }
+
+    fn delete(&self, name: &str) -> Result<(), StorageError> {
+        delete_key_file(name)
+    }
+
+    fn exists(&self, name: &str) -> Result<bool, StorageError> {
+        key_file_exists(name)
+    }
+}
+
+/// Returns the key store for `backend`
+pub fn key_store_for(backend: KeyStoreBackend) -> Result<Box<dyn KeyStore>, StorageError> {
+    match backend {
+        KeyStoreBackend::File => Ok(Box::new(FileKeyStore)),
+        #[cfg(feature = "keyring")]
+        KeyStoreBackend::Keyring => Ok(Box::new(
+            crate::providers::key_encryption::keyring_store::KeyringKeyStore::new(),
+        )),
+        #[cfg(not(feature = "keyring"))]
+        KeyStoreBackend::Keyring => Err(StorageError::BackendError(
+            "The keyring key store needs stomata to be built with the `keyring` feature"
+                .to_string(),
+        )),
+    }
+}
+
+/// Returns the configured key store, see [`key_store_backend`]
+pub fn key_store() -> Result<Box<dyn KeyStore>, StorageError> {
+    key_store_for(key_store_backend()?)
+}
+
 // === High-level convenience functions ===
 
+/// List all stored key names
+pub fn list_keys() -> Result<Vec<String>, StorageError> {
+    key_store()?.list()
+}
+
+/// Delete a stored key
+pub fn delete_key(name: &str) -> Result<(), StorageError> {
+    key_store()?.delete(name)
+}
+
+/// Check if a key exists
+pub fn key_exists(name: &str) -> Result<bool, StorageError> {
+    key_store()?.exists(name)
+}
+
 /// Store a new private key with encryption
+///
+/// Fails with `StorageError::KeyAlreadyExists` if the name is taken, a
+/// stored key is never replaced.
 pub fn store_key(name: &str, private_key: &[u8], password: &str) -> Result<(), StorageError> {
     let encrypted = encrypt_private_key(private_key, password).ok_or_else(|| {
         StorageError::IoError(io::Error::new(io::ErrorKind::Other, "Encryption failed"))
     })?;
 
-    save_encrypted_key(name, &encrypted)?;
-    Ok(())
+    key_store()?.save(name, &encrypted)
 }
 
 /// Retrieve and decrypt a private key
-pub fn retrieve_key(name: &str, password: &str) -> Result<Vec<u8>, StorageError> {
-    let encrypted = load_encrypted_key(name)?;
+///
+/// The key is wiped from memory when the returned value is dropped.
+pub fn retrieve_key(name: &str, password: &str) -> Result<Zeroizing<Vec<u8>>, StorageError> {
+    let encrypted = key_store()?.load(name)?;
 
     decrypt_private_key(&encrypted, password).ok_or_else(|| {
         StorageError::IoError(io::Error::new(
@@ -190,3 +329,92 @@ pub fn retrieve_key(name: &str, password: &str) -> Result<Vec<u8>, StorageError>
         ))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::key_encryption::structs::CryptoData;
+
+    fn crypto_data() -> CryptoData {
+        CryptoData {
+            cipher: "aes-256-gcm".to_string(),
+            salt: String::new(),
+            nonce: String::new(),
+            ciphertext: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_write_key_file_refuses_existing_name() {
+        let key_path = std::env::temp_dir().join(format!(
+            "stomata-key-collision-{}-{}.json",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let first = EncryptPrivateKey {
+            crypto_key: crypto_data(),
+            metadata: None,
+        };
+        let mut second = EncryptPrivateKey {
+            crypto_key: crypto_data(),
+            metadata: None,
+        };
+        second.crypto_key.ciphertext = "replacement".to_string();
+
+        write_key_file(&key_path, "wallet", &first).unwrap();
+        let result = write_key_file(&key_path, "wallet", &second);
+        let stored: EncryptPrivateKey =
+            serde_json::from_str(&fs::read_to_string(&key_path).unwrap()).unwrap();
+        fs::remove_file(&key_path).unwrap();
+
+        assert!(matches!(result, Err(StorageError::KeyAlreadyExists(name)) if name == "wallet"));
+        assert_eq!(stored.crypto_key.ciphertext, first.crypto_key.ciphertext);
+    }
+
+    #[test]
+    fn test_key_info_with_metadata() {
+        let encrypted = EncryptPrivateKey {
+            crypto_key: crypto_data(),
+            metadata: Some(KeyMetadata {
+                name: "wallet".to_string(),
+                created_at: "2025-01-01T00:00:00+00:00".to_string(),
+                algorithm: Some(KEY_ENCRYPTION_ALGORITHM.to_string()),
+                version: Some(KEY_STORE_VERSION),
+            }),
+        };
+
+        let info = key_info("wallet".to_string(), &encrypted);
+        assert_eq!(
+            info.created_at.as_deref(),
+            Some("2025-01-01T00:00:00+00:00")
+        );
+        assert_eq!(info.algorithm.as_deref(), Some(KEY_ENCRYPTION_ALGORITHM));
+        assert_eq!(info.version, Some(KEY_STORE_VERSION));
+    }
+
+    #[test]
+    fn test_key_info_without_metadata() {
+        let encrypted = EncryptPrivateKey {
+            crypto_key: crypto_data(),
+            metadata: None,
+        };
+
+        let info = key_info("old".to_string(), &encrypted);
+        assert_eq!(info.created_at, None);
+        assert_eq!(info.algorithm.as_deref(), Some("aes-256-gcm"));
+        assert_eq!(info.version, None);
+    }
+
+    #[test]
+    fn test_legacy_metadata_deserializes() {
+        let json = r#"{
+            "crypto_key": {"cipher": "aes-256-gcm", "salt": "", "nonce": "", "ciphertext": ""},
+            "metadata": {"name": "legacy", "created_at": "2024-06-01T00:00:00+00:00"}
+        }"#;
+
+        let encrypted: EncryptPrivateKey = serde_json::from_str(json).unwrap();
+        let metadata = encrypted.metadata.as_ref().unwrap();
+        assert_eq!(metadata.algorithm, None);
+        assert_eq!(metadata.version, None);
+    }
+}
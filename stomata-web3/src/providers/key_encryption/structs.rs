@@ -10,6 +10,31 @@ pub struct EncryptPrivateKey {
 pub struct KeyMetadata {
     pub name: String,
     pub created_at: String,
+    /// Encryption scheme used for the key. Missing on keys stored by older versions
+    #[serde(default)]
+    pub algorithm: Option<String>,
+    /// Key store format version. Missing on keys stored by older versions
+    #[serde(default)]
+    pub version: Option<u32>,
+}
+
+/// Where the encrypted keys are stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyStoreBackend {
+    /// JSON files in `~/.stomataKeys/keys`
+    #[default]
+    File,
+    /// The OS keyring, requires the `keyring` feature
+    Keyring,
+}
+
+/// Summary of a stored key used when listing the key store
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyInfo {
+    pub name: String,
+    pub created_at: Option<String>,
+    pub algorithm: Option<String>,
+    pub version: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,22 @@
+use crate::providers::key_encryption::{errors::StorageError, structs::EncryptPrivateKey};
+
+/// Backend holding the encrypted keys
+///
+/// Keys are always encrypted with the user's password before they reach the
+/// store, a backend only decides where the encrypted data lives.
+pub trait KeyStore {
+    /// Save an encrypted key, failing if the name is already taken
+    fn save(&self, name: &str, encrypted: &EncryptPrivateKey) -> Result<(), StorageError>;
+
+    /// Load an encrypted key
+    fn load(&self, name: &str) -> Result<EncryptPrivateKey, StorageError>;
+
+    /// Names of all stored keys, sorted
+    fn list(&self) -> Result<Vec<String>, StorageError>;
+
+    /// Delete a stored key
+    fn delete(&self, name: &str) -> Result<(), StorageError>;
+
+    /// Check if a key exists
+    fn exists(&self, name: &str) -> Result<bool, StorageError>;
+}
@@ -1,9 +1,18 @@
 pub mod address;
+pub mod explorer;
 mod key_encryption;
 pub mod portfolio;
+pub mod price;
 pub mod rpc;
 
 pub use key_encryption::{
     encrypt_secret,
-    store_secrets::{delete_key, list_keys, retrieve_key, store_key},
+    import_key::parse_private_key,
+    password_strength::{estimate_entropy, is_weak_password},
+    store_secrets::{
+        delete_key, key_exists, key_store, list_keys, list_keys_with_metadata, retrieve_key,
+        store_key,
+    },
+    structs::{KeyInfo, KeyStoreBackend},
+    traits::KeyStore,
 };
@@ -1,18 +1,52 @@
 use anyhow::Result;
 
+use rust_decimal::Decimal;
+
 use crate::providers::{
-    portfolio::structs::Portfolio,
-    rpc::{structs::EVMProvider, traits::ChainProvider},
+    portfolio::structs::{AccountType, Portfolio},
+    price::service::usd_value,
+    rpc::{
+        helper::{account_type_from_code, bytecode_size_from_code},
+        structs::EVMProvider,
+        traits::ChainProvider,
+    },
 };
 
 pub async fn get_portfolio(provider: EVMProvider) -> Result<Portfolio> {
+    // one round-trip when the endpoint supports JSON-RPC batches
+    let mut portfolio = match provider.batch_portfolio().await {
+        Ok(portfolio) => portfolio,
+        Err(_) => sequential_portfolio(&provider).await?,
+    };
+    if let AccountType::CONTRACT = portfolio.account_type {
+        // best effort, the rest of the portfolio is still useful without it
+        portfolio.proxy = provider.detect_proxy().await.ok().flatten();
+    }
+    Ok(portfolio)
+}
+
+/// Fetches the portfolio one call at a time, for endpoints without batch
+/// support
+async fn sequential_portfolio(provider: &EVMProvider) -> Result<Portfolio> {
     let chain_info = provider.chain_info().await?;
-    let native_balance = provider.native_balance().await.unwrap();
-    let account_type = provider.account_type().await.unwrap();
-    let transaction_count = provider.transaction_count().await;
+    let native_balance = provider.native_balance().await?;
+    let code = provider.code().await?;
+    let transaction_count = provider.transaction_count().await?;
     Ok(Portfolio {
         native_balance,
-        account_type,
-        transaction_count: transaction_count,
+        account_type: account_type_from_code(&code),
+        bytecode_size: bytecode_size_from_code(&code),
+        proxy: None,
+        transaction_count,
+        native_usd_price: None,
+        source_verified: None,
     })
 }
+
+impl Portfolio {
+    /// USD value of the native balance, `None` without a price
+    pub fn native_usd_value(&self) -> Option<Decimal> {
+        self.native_usd_price
+            .map(|price| usd_value(self.native_balance, price))
+    }
+}
@@ -1,5 +1,7 @@
 use rust_decimal::Decimal;
 
+use crate::providers::rpc::structs::ProxyInfo;
+
 pub struct Inputs {
     pub rpc_url: String,
     pub user_address: String,
@@ -15,6 +17,16 @@ pub enum AccountType {
 #[derive(Default, Debug)]
 pub struct Portfolio {
     pub account_type: AccountType,
+    /// Size of the deployed bytecode in bytes, `None` for EOAs
+    pub bytecode_size: Option<usize>,
+    /// EIP-1967 proxy slots, `None` for EOAs and contracts that aren't proxies
+    pub proxy: Option<ProxyInfo>,
+    /// Native balance in ETH
     pub native_balance: Decimal,
     pub transaction_count: u64,
+    /// USD price of the native asset, `None` when unavailable or not fetched
+    pub native_usd_price: Option<Decimal>,
+    /// Whether the contract source is verified on the explorer, `None` for
+    /// EOAs or when not fetched
+    pub source_verified: Option<bool>,
 }
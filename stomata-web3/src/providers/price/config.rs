@@ -0,0 +1,50 @@
+use crate::{
+    constants::{DEFAULT_PRICE_API_URL, PRICE_API_KEY_ENV, PRICE_API_URL_ENV},
+    providers::price::structs::PriceConfig,
+};
+
+/// Returns the price API configuration.
+///
+/// The base URL is read from `STOMATA_PRICE_API_URL`, defaulting to the
+/// public CoinGecko API, and the optional API key from
+/// `STOMATA_PRICE_API_KEY`.
+pub fn price_config() -> PriceConfig {
+    let base_url = std::env::var(PRICE_API_URL_ENV).ok();
+    let api_key = std::env::var(PRICE_API_KEY_ENV).ok();
+    build_price_config(base_url.as_deref(), api_key.as_deref())
+}
+
+fn build_price_config(base_url: Option<&str>, api_key: Option<&str>) -> PriceConfig {
+    let base_url = base_url
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_PRICE_API_URL);
+    let api_key = api_key
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(String::from);
+
+    PriceConfig {
+        base_url: base_url.trim_end_matches('/').to_string(),
+        api_key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_config_defaults() {
+        let config = build_price_config(None, Some(" "));
+        assert_eq!(config.base_url, DEFAULT_PRICE_API_URL);
+        assert!(config.api_key.is_none());
+    }
+
+    #[test]
+    fn test_price_config_trims_trailing_slash() {
+        let config = build_price_config(Some("https://prices.example/api/"), Some("KEY"));
+        assert_eq!(config.base_url, "https://prices.example/api");
+        assert_eq!(config.api_key.as_deref(), Some("KEY"));
+    }
+}
@@ -0,0 +1,118 @@
+use std::{str::FromStr, time::Instant};
+
+use anyhow::{Result, anyhow};
+use reqwest::Url;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::Value;
+
+use crate::providers::price::{structs::CoinGeckoPriceProvider, traits::PriceProvider};
+
+impl PriceProvider for CoinGeckoPriceProvider {
+    async fn usd_price(&self, asset_id: &str) -> Result<Decimal> {
+        if let Some(price) = self.cached_price(asset_id) {
+            return Ok(price);
+        }
+
+        let url = Url::parse_with_params(
+            &format!("{}/simple/price", self.config.base_url),
+            [("ids", asset_id), ("vs_currencies", "usd")],
+        )?;
+        let mut request = self.client.get(url);
+        if let Some(api_key) = &self.config.api_key {
+            request = request.header("x-cg-demo-api-key", api_key);
+        }
+        let response = request
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+
+        let price = parse_usd_price(asset_id, &response)?;
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(asset_id.to_string(), (price, Instant::now()));
+        }
+        Ok(price)
+    }
+}
+
+impl CoinGeckoPriceProvider {
+    /// Returns the cached price of `asset_id` if it is younger than the TTL
+    fn cached_price(&self, asset_id: &str) -> Option<Decimal> {
+        let cache = self.cache.lock().ok()?;
+        let (price, fetched_at) = cache.get(asset_id)?;
+        (fetched_at.elapsed() < self.ttl).then_some(*price)
+    }
+}
+
+/// Parses a `{"<asset_id>": {"usd": 1234.56}}` response.
+///
+/// The number is read from its JSON text so no precision is lost to `f64`.
+fn parse_usd_price(asset_id: &str, response: &Value) -> Result<Decimal> {
+    let price = response
+        .get(asset_id)
+        .and_then(|prices| prices.get("usd"))
+        .filter(|price| price.is_number())
+        .ok_or_else(|| anyhow!("No USD price for {}", asset_id))?;
+
+    Decimal::from_str(&price.to_string())
+        .or_else(|_| Decimal::from_scientific(&price.to_string()))
+        .map_err(|err| anyhow!("Invalid USD price for {}: {}", asset_id, err))
+}
+
+/// Value of `amount` units at `price` USD each, rounded to cents
+pub fn usd_value(amount: Decimal, price: Decimal) -> Decimal {
+    (amount * price).round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::providers::price::structs::PriceConfig;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_usd_price() {
+        let response = json!({"ethereum": {"usd": 3512.47}});
+        assert_eq!(
+            parse_usd_price("ethereum", &response).unwrap(),
+            Decimal::new(351247, 2)
+        );
+    }
+
+    #[test]
+    fn test_parse_usd_price_missing_asset() {
+        let response = json!({});
+        assert!(parse_usd_price("ethereum", &response).is_err());
+    }
+
+    #[test]
+    fn test_usd_value_rounds_to_cents() {
+        let amount = Decimal::new(15, 1); // 1.5
+        let price = Decimal::new(200_005, 2); // 2000.05
+        assert_eq!(usd_value(amount, price), Decimal::new(300_008, 2));
+    }
+
+    #[tokio::test]
+    async fn test_cached_price_is_reused_until_ttl() {
+        // nothing listens on port 1, a request would fail
+        let provider = CoinGeckoPriceProvider::new(PriceConfig {
+            base_url: "http://127.0.0.1:1".to_string(),
+            api_key: None,
+        })
+        .with_ttl(Duration::from_secs(60));
+        provider.cache.lock().unwrap().insert(
+            "ethereum".to_string(),
+            (Decimal::new(3500, 0), Instant::now()),
+        );
+        assert_eq!(
+            provider.usd_price("ethereum").await.unwrap(),
+            Decimal::new(3500, 0)
+        );
+
+        let provider = provider.with_ttl(Duration::ZERO);
+        assert!(provider.usd_price("ethereum").await.is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use reqwest::Client;
+use rust_decimal::Decimal;
+
+use crate::constants::{PRICE_CACHE_TTL_SECS, RPC_TIMEOUT_SECS};
+
+/// CoinGecko-compatible price API endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceConfig {
+    pub base_url: String,
+    /// Sent as `x-cg-demo-api-key`, public endpoints work without one
+    pub api_key: Option<String>,
+}
+
+/// Price provider for CoinGecko-compatible `simple/price` APIs
+///
+/// Prices are cached per asset for `ttl` so repeated portfolio lookups
+/// don't run into the API's rate limits.
+pub struct CoinGeckoPriceProvider {
+    pub config: PriceConfig,
+    /// How long a fetched price is reused
+    pub ttl: Duration,
+    /// HTTP client shared by every call so connections are pooled
+    pub client: Client,
+    /// Asset id -> USD price and when it was fetched
+    pub(crate) cache: Mutex<HashMap<String, (Decimal, Instant)>>,
+}
+
+impl CoinGeckoPriceProvider {
+    /// Creates a provider for the price API in `config`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `Client::new`.
+    pub fn new(config: PriceConfig) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(RPC_TIMEOUT_SECS))
+            .build()
+            .expect("failed to initialize the price http client");
+
+        Self {
+            config,
+            ttl: Duration::from_secs(PRICE_CACHE_TTL_SECS),
+            client,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
@@ -0,0 +1,7 @@
+use anyhow::Result;
+use rust_decimal::Decimal;
+
+pub trait PriceProvider {
+    /// USD price of one unit of `asset_id` (e.g. `"ethereum"`)
+    fn usd_price(&self, asset_id: &str) -> impl Future<Output = Result<Decimal>> + Send;
+}
@@ -0,0 +1,70 @@
+use crate::constants::{DEFAULT_ETHEREUM_RPC_URLS, RPC_URLS_ENV_PREFIX};
+
+/// Returns the ordered list of RPC endpoints for `chain`.
+///
+/// Endpoints are read from the `STOMATA_RPC_URLS_<CHAIN>` environment
+/// variable (e.g. `STOMATA_RPC_URLS_ETHEREUM`) as a comma separated list,
+/// primary first. Falls back to the built-in defaults when the variable is
+/// unset or empty.
+pub fn rpc_endpoints(chain: &str) -> Vec<String> {
+    let env_key = format!("{}{}", RPC_URLS_ENV_PREFIX, chain.to_uppercase());
+    let configured = std::env::var(env_key)
+        .map(|urls| parse_rpc_urls(&urls))
+        .unwrap_or_default();
+
+    if !configured.is_empty() {
+        return configured;
+    }
+
+    default_rpc_endpoints(chain)
+}
+
+fn default_rpc_endpoints(chain: &str) -> Vec<String> {
+    match chain.to_lowercase().as_str() {
+        "ethereum" => DEFAULT_ETHEREUM_RPC_URLS
+            .iter()
+            .map(|url| url.to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn parse_rpc_urls(urls: &str) -> Vec<String> {
+    urls.split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rpc_urls_keeps_order() {
+        let urls = parse_rpc_urls("https://a.example, https://b.example ,,https://c.example");
+        assert_eq!(
+            urls,
+            vec![
+                "https://a.example".to_string(),
+                "https://b.example".to_string(),
+                "https://c.example".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rpc_urls_empty() {
+        assert!(parse_rpc_urls(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_default_endpoints_for_unknown_chain() {
+        assert!(rpc_endpoints("not-a-chain").is_empty());
+        assert_eq!(
+            default_rpc_endpoints("Ethereum").len(),
+            DEFAULT_ETHEREUM_RPC_URLS.len()
+        );
+    }
+}
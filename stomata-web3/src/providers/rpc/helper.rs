@@ -1,12 +1,75 @@
 use rust_decimal::Decimal;
 
+use crate::providers::{
+    address::{AddressValidator, ValidationResult},
+    portfolio::structs::AccountType,
+};
+
 pub fn parse_hex_u128(s: &str) -> Result<u128, Box<dyn std::error::Error>> {
     let s = s.trim_start_matches("0x");
     Ok(u128::from_str_radix(s, 16)?)
 }
 
+/// Classifies an account from its `eth_getCode` result; EOAs have no code ("0x")
+pub fn account_type_from_code(code: &str) -> AccountType {
+    if code.trim_start_matches("0x").is_empty() {
+        AccountType::EOA
+    } else {
+        AccountType::CONTRACT
+    }
+}
+
+/// Size in bytes of the bytecode in an `eth_getCode` result, `None` for EOAs
+pub fn bytecode_size_from_code(code: &str) -> Option<usize> {
+    let hex = code.trim_start_matches("0x");
+    (!hex.is_empty()).then(|| hex.len() / 2)
+}
+
+/// Extracts the checksummed address held in a 32 byte storage word, as
+/// returned by `eth_getStorageAt`. `None` when the word is zero.
+pub fn address_from_storage_word(word: &str) -> Option<String> {
+    let hex = word.trim_start_matches("0x");
+    // addresses are right-aligned in the word
+    let address = hex.get(hex.len().checked_sub(40)?..)?;
+    if address.chars().all(|c| c == '0') {
+        return None;
+    }
+    match AddressValidator::validate(&format!("0x{}", address.to_lowercase())) {
+        ValidationResult::Valid { checksummed, .. } => Some(checksummed),
+        _ => None,
+    }
+}
+
 pub fn wei_to_eth(wei: u128) -> Decimal {
     let wei_dec = Decimal::from(wei);
     let scale = Decimal::from(10u128.pow(18));
     wei_dec / scale
 }
+
+/// Converts wei to Gwei, the unit gas prices are quoted in
+pub fn wei_to_gwei(wei: u128) -> Decimal {
+    Decimal::from(wei) / Decimal::from(10u128.pow(9))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wei_to_eth() {
+        assert_eq!(wei_to_eth(0), Decimal::ZERO);
+        assert_eq!(wei_to_eth(10u128.pow(18)), Decimal::ONE);
+        // 1.5 ETH from the hex an eth_getBalance returns
+        let wei = parse_hex_u128("0x14d1120d7b160000").unwrap();
+        assert_eq!(wei_to_eth(wei).normalize().to_string(), "1.5");
+    }
+
+    #[test]
+    fn test_wei_to_gwei() {
+        assert_eq!(wei_to_gwei(10u128.pow(9)), Decimal::ONE);
+        assert_eq!(
+            wei_to_gwei(12_345_678_901).normalize().to_string(),
+            "12.345678901"
+        );
+    }
+}
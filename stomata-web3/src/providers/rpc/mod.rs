@@ -1,4 +1,7 @@
+pub mod config;
 pub mod helper;
 pub mod service;
 pub mod structs;
+pub mod subscription;
 pub mod traits;
+pub mod transport;
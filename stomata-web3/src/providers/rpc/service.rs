@@ -1,21 +1,41 @@
-use anyhow::{Error, Result, anyhow, bail};
-use reqwest::Client;
+use std::{collections::HashMap, future::Future, time::Duration};
+
+use anyhow::{Result, anyhow, bail};
+use rand::random_range;
 use rust_decimal::Decimal;
 use serde::de::DeserializeOwned;
 use serde_json::{Value, json};
 
-use crate::providers::{
-    portfolio::structs::AccountType,
-    rpc::{
-        helper::parse_hex_u128,
-        structs::{ChainInfo, EVMProvider},
-        traits::ChainProvider,
+use crate::{
+    constants::{EIP1967_ADMIN_SLOT, EIP1967_IMPLEMENTATION_SLOT, RPC_RATE_LIMIT_ERROR_CODES},
+    providers::{
+        portfolio::structs::{AccountType, Portfolio},
+        rpc::{
+            helper::{
+                account_type_from_code, address_from_storage_word, bytecode_size_from_code,
+                parse_hex_u128, wei_to_eth,
+            },
+            structs::{ChainInfo, EVMProvider, LatestBlock, ProxyInfo, RetryConfig},
+            traits::ChainProvider,
+            transport::{RpcAttemptError, RpcTransport, Transport},
+        },
     },
 };
 
-async fn rpc_call<T: DeserializeOwned>(rpc_url: &str, method: &str, params: Value) -> Result<T> {
-    let request_client = Client::new();
-
+/// Sends a JSON-RPC request to each of the provider's endpoints in order
+/// until one succeeds.
+///
+/// Each endpoint is reached over HTTP or a WebSocket depending on its URL
+/// scheme. Both bound a request by `RPC_TIMEOUT_SECS` so a dead endpoint
+/// can't hang the caller. Rate
+/// limited requests are retried according to `provider.retry` before moving
+/// on. If every endpoint fails, the returned error lists the failure of
+/// each one.
+async fn rpc_call<T: DeserializeOwned>(
+    provider: &EVMProvider,
+    method: &str,
+    params: Value,
+) -> Result<T> {
     let payload = json!({
         "jsonrpc": "2.0",
         "id": 1,
@@ -23,30 +43,281 @@ async fn rpc_call<T: DeserializeOwned>(rpc_url: &str, method: &str, params: Valu
         "params": params,
     });
 
-    let resp = request_client
-        .post(rpc_url)
-        .json(&payload)
-        .send()
-        .await?
-        .json::<Value>()
-        .await?;
+    let payload = &payload;
+    let result = call_endpoints(provider, method, |transport| async move {
+        let resp = transport.send(payload).await?;
+        rpc_result(&resp)
+    })
+    .await?;
+
+    Ok(serde_json::from_value(result)?)
+}
+
+/// Sends several JSON-RPC requests in a single batch (a JSON array of
+/// request objects), trying the provider's endpoints in order.
+///
+/// # Arguments
+///
+/// * `provider` - Provider whose endpoints, client and retry policy are used
+/// * `requests` - `(method, params)` pairs, given ids `1..=requests.len()`
+///
+/// # Returns
+///
+/// The `result` of every request in the order of `requests`. Fails if an
+/// endpoint rejects batches or any request in the batch returns an error,
+/// so callers can fall back to sequential `rpc_call`s.
+async fn rpc_batch_call(provider: &EVMProvider, requests: &[(&str, Value)]) -> Result<Vec<Value>> {
+    let payload: Vec<Value> = requests
+        .iter()
+        .enumerate()
+        .map(|(i, (method, params))| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": i + 1,
+                "method": method,
+                "params": params,
+            })
+        })
+        .collect();
+    let payload = &Value::Array(payload);
+
+    let results = call_endpoints(provider, "batch", |transport| async move {
+        let resp = transport.send(payload).await?;
+        batch_results(resp, requests.len())
+    })
+    .await?;
+
+    match results {
+        Value::Array(results) => Ok(results),
+        _ => bail!("Unexpected batch result"),
+    }
+}
+
+/// Runs `attempt` against each endpoint in order, with retries, until one
+/// succeeds. The returned error lists the failure of every endpoint.
+async fn call_endpoints<'a, F, Fut>(
+    provider: &'a EVMProvider,
+    label: &str,
+    attempt: F,
+) -> Result<Value>
+where
+    F: Fn(&'a Transport) -> Fut,
+    Fut: Future<Output = Result<Value, RpcAttemptError>>,
+{
+    if provider.transports.is_empty() {
+        bail!("No RPC endpoints configured");
+    }
 
+    let mut failures = Vec::new();
+    for transport in &provider.transports {
+        match with_retry(&provider.retry, || attempt(transport)).await {
+            Ok(result) => return Ok(result),
+            Err(err) => failures.push(format!("{}: {err}", transport.url())),
+        }
+    }
+
+    Err(anyhow!(
+        "All RPC endpoints failed for {}:\n  {}",
+        label,
+        failures.join("\n  ")
+    ))
+}
+
+/// Runs `attempt`, retrying with exponential backoff and jitter while it
+/// is rate limited or the endpoint returns a server error.
+async fn with_retry<T, F, Fut>(retry: &RetryConfig, attempt: F) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, RpcAttemptError>>,
+{
+    let max_attempts = retry.max_attempts.max(1);
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match attempt().await {
+            Ok(result) => return Ok(result),
+            Err(RpcAttemptError::Fatal(err)) => return Err(err),
+            Err(RpcAttemptError::Retryable(err)) if attempts >= max_attempts => {
+                return Err(err.context(format!("gave up after {attempts} attempts")));
+            }
+            Err(RpcAttemptError::Retryable(_)) => {
+                // up to 50% jitter so concurrent clients don't retry in lockstep
+                let delay = retry.delay_for(attempts - 1);
+                let jitter_ms = random_range(0..=delay.as_millis() as u64 / 2);
+                tokio::time::sleep(delay + Duration::from_millis(jitter_ms)).await;
+            }
+        }
+    }
+}
+
+/// Returns true if a JSON-RPC error object signals rate limiting.
+fn is_rate_limit_error(err: &Value) -> bool {
+    let code_matches = err
+        .get("code")
+        .and_then(Value::as_i64)
+        .is_some_and(|code| RPC_RATE_LIMIT_ERROR_CODES.contains(&code));
+    let message_matches = err
+        .get("message")
+        .and_then(Value::as_str)
+        .map(str::to_lowercase)
+        .is_some_and(|msg| msg.contains("rate limit") || msg.contains("too many requests"));
+
+    code_matches || message_matches
+}
+
+/// Extracts the `result` of a single JSON-RPC response object.
+fn rpc_result(resp: &Value) -> Result<Value, RpcAttemptError> {
     if let Some(err) = resp.get("error") {
         let code = err.get("code").unwrap_or(&Value::Null);
         let msg = err.get("message").unwrap_or(&Value::Null);
-        return Err(anyhow!("RPC error {}: {}", code, msg));
+        let rpc_error = anyhow!("RPC error {}: {}", code, msg);
+        return Err(if is_rate_limit_error(err) {
+            RpcAttemptError::Retryable(rpc_error)
+        } else {
+            RpcAttemptError::Fatal(rpc_error)
+        });
     }
 
-    let result = resp
-        .get("result")
-        .ok_or_else(|| anyhow!("Missing result field in RPC response"))?;
+    resp.get("result")
+        .cloned()
+        .ok_or_else(|| RpcAttemptError::Fatal(anyhow!("Missing result field in RPC response")))
+}
+
+/// Reads the number, base fee and timestamp of an `eth_getBlockByNumber`
+/// result. The base fee is `None` for blocks without one.
+fn parse_latest_block(block: &Value) -> Result<LatestBlock> {
+    let hex_field = |name: &str| {
+        block
+            .get(name)
+            .and_then(Value::as_str)
+            .map(|hex| parse_hex_u128(hex).map_err(|err| anyhow!("Invalid {name}: {err}")))
+    };
+    let required = |name: &str| -> Result<u64> {
+        let value = hex_field(name).ok_or_else(|| anyhow!("Missing {name} in block"))??;
+        Ok(u64::try_from(value)?)
+    };
 
-    Ok(serde_json::from_value(result.clone())?)
+    Ok(LatestBlock {
+        number: required("number")?,
+        base_fee_per_gas: hex_field("baseFeePerGas").transpose()?,
+        timestamp: required("timestamp")?,
+    })
+}
+
+/// Matches a batch response to request ids `1..=count`.
+///
+/// Batch responses may come back in any order, so entries are looked up by
+/// id. The results are returned as a JSON array in request order.
+fn batch_results(resp: Value, count: usize) -> Result<Value, RpcAttemptError> {
+    let Value::Array(entries) = resp else {
+        // endpoints without batch support answer with a single error object
+        return Err(RpcAttemptError::Fatal(anyhow!(
+            "Endpoint does not support batch requests"
+        )));
+    };
+
+    let by_id: HashMap<u64, &Value> = entries
+        .iter()
+        .filter_map(|entry| Some((entry.get("id")?.as_u64()?, entry)))
+        .collect();
+
+    let results = (1..=count as u64)
+        .map(|id| {
+            let entry = by_id.get(&id).ok_or_else(|| {
+                RpcAttemptError::Fatal(anyhow!("Missing response for batch request {id}"))
+            })?;
+            rpc_result(entry)
+        })
+        .collect::<Result<Vec<Value>, RpcAttemptError>>()?;
+
+    Ok(Value::Array(results))
+}
+
+impl EVMProvider {
+    /// Fetches the portfolio with a single batch request.
+    ///
+    /// Sends `eth_chainId`, `eth_getBalance`, `eth_getCode` and
+    /// `eth_getTransactionCount` in one round-trip. Returns an error if the
+    /// endpoints don't support batches, in which case the individual
+    /// `ChainProvider` calls should be used instead.
+    pub async fn batch_portfolio(&self) -> Result<Portfolio> {
+        let requests = [
+            ("eth_chainId", json!([])),
+            ("eth_getBalance", json!([self.address, "latest"])),
+            ("eth_getCode", json!([self.address, "latest"])),
+            ("eth_getTransactionCount", json!([self.address, "latest"])),
+        ];
+        let results = rpc_batch_call(self, &requests).await?;
+
+        let as_str = |index: usize| {
+            results[index]
+                .as_str()
+                .ok_or_else(|| anyhow!("Unexpected {} result", requests[index].0))
+        };
+
+        let native_balance = parse_hex_u128(as_str(1)?)
+            .map(wei_to_eth)
+            .map_err(|err| anyhow!("Invalid balance: {}", err))?;
+        let code = as_str(2)?;
+        let transaction_count = u64::from_str_radix(as_str(3)?.trim_start_matches("0x"), 16)?;
+
+        Ok(Portfolio {
+            native_balance,
+            account_type: account_type_from_code(code),
+            bytecode_size: bytecode_size_from_code(code),
+            proxy: None,
+            transaction_count,
+            native_usd_price: None,
+            source_verified: None,
+        })
+    }
+}
+
+impl EVMProvider {
+    /// Reads the 32 byte storage word at `slot` of the provider's address.
+    pub async fn get_storage_at(&self, slot: &str) -> Result<String> {
+        rpc_call(
+            self,
+            "eth_getStorageAt",
+            json!([self.address, slot, "latest"]),
+        )
+        .await
+    }
+
+    /// Fetches the number, base fee and timestamp of the latest block,
+    /// without its transactions.
+    pub async fn latest_block(&self) -> Result<LatestBlock> {
+        let block: Value = rpc_call(self, "eth_getBlockByNumber", json!(["latest", false])).await?;
+        parse_latest_block(&block)
+    }
+
+    /// Fetches the current gas price in wei.
+    pub async fn gas_price(&self) -> Result<u128> {
+        let gas_price: String = rpc_call(self, "eth_gasPrice", json!([])).await?;
+        parse_hex_u128(&gas_price).map_err(|err| anyhow!("Invalid gas price: {err}"))
+    }
+
+    /// Detects an EIP-1967 proxy from its implementation and admin slots.
+    ///
+    /// Returns `None` when the implementation slot is empty, i.e. the
+    /// address isn't a proxy using the standard slots.
+    pub async fn detect_proxy(&self) -> Result<Option<ProxyInfo>> {
+        let implementation = self.get_storage_at(EIP1967_IMPLEMENTATION_SLOT).await?;
+        let Some(implementation) = address_from_storage_word(&implementation) else {
+            return Ok(None);
+        };
+        let admin = self.get_storage_at(EIP1967_ADMIN_SLOT).await?;
+
+        Ok(Some(ProxyInfo {
+            implementation,
+            admin: address_from_storage_word(&admin),
+        }))
+    }
 }
 
 impl ChainProvider for EVMProvider {
     async fn chain_info(&self) -> anyhow::Result<ChainInfo> {
-        let hex_id: String = rpc_call(&self.rpc_url, "eth_chainId", json!([])).await?;
+        let hex_id: String = rpc_call(self, "eth_chainId", json!([])).await?;
 
         // remove 0x and parse hex
         let id = u64::from_str_radix(hex_id.trim_start_matches("0x"), 16)?;
@@ -54,46 +325,45 @@ impl ChainProvider for EVMProvider {
         Ok(ChainInfo { chain_id: id })
     }
 
-    async fn native_balance(&self) -> Option<Decimal> {
-        let hex_balance: String = rpc_call(
-            &self.rpc_url,
-            "eth_getBalance",
-            json!([self.address, "latest"]),
-        )
-        .await
-        .unwrap();
-        match parse_hex_u128(&hex_balance) {
-            Ok(val) => Some(val.into()),
-            Err(err) => None,
-        }
+    async fn native_balance(&self) -> anyhow::Result<Decimal> {
+        let hex_balance: String =
+            rpc_call(self, "eth_getBalance", json!([self.address, "latest"])).await?;
+        let balance =
+            parse_hex_u128(&hex_balance).map_err(|err| anyhow!("Invalid balance: {}", err))?;
+        Ok(wei_to_eth(balance))
     }
 
-    async fn account_type(&self) -> Option<AccountType> {
-        let code: String = rpc_call(
-            &self.rpc_url,
-            "eth_getCode",
-            json!([self.address, "latest"]),
-        )
-        .await
-        .unwrap();
+    async fn account_type(&self) -> anyhow::Result<AccountType> {
+        let code = self.code().await?;
 
-        if code.len() == 0 {
-            Some(AccountType::EOA)
-        } else {
-            Some(AccountType::CONTRACT)
-        }
+        Ok(account_type_from_code(&code))
     }
 
-    async fn transaction_count(&self) -> u64 {
+    async fn code(&self) -> anyhow::Result<String> {
+        rpc_call(self, "eth_getCode", json!([self.address, "latest"])).await
+    }
+
+    async fn transaction_count(&self) -> anyhow::Result<u64> {
         let transaction_count: String = rpc_call(
-            &self.rpc_url,
+            self,
             "eth_getTransactionCount",
             json!([self.address, "latest"]),
         )
-        .await
-        .unwrap();
+        .await?;
+
+        Ok(u64::from_str_radix(
+            transaction_count.trim_start_matches("0x"),
+            16,
+        )?)
+    }
+
+    async fn block_number(&self) -> anyhow::Result<u64> {
+        let block_number: String = rpc_call(self, "eth_blockNumber", json!([])).await?;
 
-        u64::from_str_radix(transaction_count.trim_start_matches("0x"), 16).unwrap()
+        Ok(u64::from_str_radix(
+            block_number.trim_start_matches("0x"),
+            16,
+        )?)
     }
 }
 
@@ -108,7 +378,193 @@ mod tests {
             .expect("Ethereum mainnet rpc not found in env");
         let user_address =
             std::env::var("TEST_EVM_ADDRESS").expect("Test evm address not found in env");
-        EVMProvider::new(user_address, rpc_url)
+        EVMProvider::new(user_address, rpc_url).expect("invalid ethereum mainnet rpc url")
+    }
+
+    /// Answers every JSON-RPC request with `result`, returning the URL of
+    /// the local endpoint
+    async fn serve_canned_result(result: Value) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let body = json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                // read the whole request before answering, headers and body
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                while let Ok(n) = stream.read(&mut buffer).await {
+                    request.extend_from_slice(&buffer[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    let complete = text
+                        .split_once("\r\n\r\n")
+                        .is_some_and(|(headers, payload)| {
+                            let length = headers
+                                .lines()
+                                .filter_map(|line| line.split_once(':'))
+                                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                                .and_then(|(_, value)| value.trim().parse().ok())
+                                .unwrap_or(0);
+                            payload.len() >= length
+                        });
+                    if n == 0 || complete {
+                        break;
+                    }
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_reports_every_failed_endpoint() {
+        // nothing listens on port 1, so both connections are refused
+        let provider = EVMProvider::with_fallbacks(
+            String::new(),
+            vec![
+                "http://127.0.0.1:1".to_string(),
+                "http://127.0.0.1:1/fallback".to_string(),
+            ],
+        )
+        .unwrap();
+        let err = rpc_call::<String>(&provider, "eth_chainId", json!([]))
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("All RPC endpoints failed"));
+        assert!(err.contains("http://127.0.0.1:1/:"));
+        assert!(err.contains("http://127.0.0.1:1/fallback:"));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_falls_back_from_websocket() {
+        let provider = EVMProvider::with_fallbacks(
+            String::new(),
+            vec![
+                "ws://127.0.0.1:1".to_string(),
+                "http://127.0.0.1:1".to_string(),
+            ],
+        )
+        .unwrap();
+        let err = rpc_call::<String>(&provider, "eth_chainId", json!([]))
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("ws://127.0.0.1:1/:"));
+        assert!(err.contains("http://127.0.0.1:1/:"));
+    }
+
+    #[test]
+    fn test_bytecode_size_from_code() {
+        assert_eq!(bytecode_size_from_code("0x"), None);
+        assert_eq!(bytecode_size_from_code("0x6080604052"), Some(5));
+    }
+
+    #[test]
+    fn test_address_from_storage_word() {
+        let zero = format!("0x{}", "0".repeat(64));
+        assert_eq!(address_from_storage_word(&zero), None);
+
+        let word = "0x000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045";
+        assert_eq!(
+            address_from_storage_word(word).as_deref(),
+            Some("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045")
+        );
+        assert_eq!(address_from_storage_word("0x"), None);
+    }
+
+    #[test]
+    fn test_rate_limit_error_by_code() {
+        let err = json!({"code": -32005, "message": "limit exceeded"});
+        assert!(is_rate_limit_error(&err));
+    }
+
+    #[test]
+    fn test_rate_limit_error_by_message() {
+        let err = json!({"code": -32000, "message": "Too Many Requests, slow down"});
+        assert!(is_rate_limit_error(&err));
+    }
+
+    #[test]
+    fn test_regular_rpc_error_is_not_rate_limit() {
+        let err = json!({"code": -32602, "message": "invalid argument 0"});
+        assert!(!is_rate_limit_error(&err));
+    }
+
+    #[test]
+    fn test_batch_results_matches_ids_out_of_order() {
+        let resp = json!([
+            {"jsonrpc": "2.0", "id": 2, "result": "0x2"},
+            {"jsonrpc": "2.0", "id": 1, "result": "0x1"},
+        ]);
+        let Ok(results) = batch_results(resp, 2) else {
+            panic!("batch response should parse");
+        };
+        assert_eq!(results, json!(["0x1", "0x2"]));
+    }
+
+    #[test]
+    fn test_batch_results_missing_entry() {
+        let resp = json!([{"jsonrpc": "2.0", "id": 1, "result": "0x1"}]);
+        assert!(batch_results(resp, 2).is_err());
+    }
+
+    #[test]
+    fn test_batch_results_rejected_batch() {
+        let resp = json!({"jsonrpc": "2.0", "id": null, "error": {"code": -32600, "message": "batch not supported"}});
+        assert!(matches!(
+            batch_results(resp, 2),
+            Err(RpcAttemptError::Fatal(_))
+        ));
+    }
+
+    #[test]
+    fn test_batch_results_rate_limited_entry_is_retryable() {
+        let resp = json!([
+            {"jsonrpc": "2.0", "id": 1, "error": {"code": -32005, "message": "limit exceeded"}},
+        ]);
+        assert!(matches!(
+            batch_results(resp, 1),
+            Err(RpcAttemptError::Retryable(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_latest_block() {
+        let block = json!({
+            "number": "0x1406f40",
+            "baseFeePerGas": "0x2dfd2f56a",
+            "timestamp": "0x66a7c2cb",
+            "transactions": [],
+        });
+        let latest_block = parse_latest_block(&block).unwrap();
+        assert_eq!(
+            latest_block,
+            LatestBlock {
+                number: 21_000_000,
+                base_fee_per_gas: Some(12_345_079_146),
+                timestamp: 1_722_270_411,
+            }
+        );
+
+        // blocks before London have no base fee
+        let block = json!({"number": "0x1", "timestamp": "0x55ba4224"});
+        assert_eq!(parse_latest_block(&block).unwrap().base_fee_per_gas, None);
+
+        assert!(parse_latest_block(&json!({"timestamp": "0x1"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_without_endpoints() {
+        let provider = EVMProvider::with_fallbacks(String::new(), Vec::new()).unwrap();
+        let result = rpc_call::<String>(&provider, "eth_chainId", json!([])).await;
+        assert!(result.is_err());
     }
 
     #[tokio::test]
@@ -122,20 +578,67 @@ mod tests {
     async fn test_native_balance_fetch() {
         let evm_provider = init_evm_provider();
         let native_balance = evm_provider.native_balance().await;
-        assert!(native_balance.is_some(), "Failed to fetch native balance");
+        assert!(native_balance.is_ok(), "Failed to fetch native balance");
     }
 
     #[tokio::test]
     async fn test_account_type() {
         let evm_provider = init_evm_provider();
         let account_type = evm_provider.account_type().await;
-        assert!(account_type.is_some(), "Failed to fetch account_type");
+        assert!(account_type.is_ok(), "Failed to fetch account_type");
+    }
+
+    #[tokio::test]
+    async fn test_latest_block_from_canned_response() {
+        let url = serve_canned_result(json!({
+            "number": "0x1406f40",
+            "baseFeePerGas": "0x2dfd2f56a",
+            "timestamp": "0x66a7c2cb",
+        }))
+        .await;
+        let provider = EVMProvider::new(String::new(), url).unwrap();
+        let latest_block = provider.latest_block().await.unwrap();
+        assert_eq!(latest_block.number, 21_000_000);
+        assert_eq!(latest_block.base_fee_per_gas, Some(12_345_079_146));
+    }
+
+    #[tokio::test]
+    #[ignore = "calls the RPC in ETHEREUM_MAINNET_RPC_URL"]
+    async fn test_latest_block_fetch() {
+        let evm_provider = init_evm_provider();
+        let latest_block = evm_provider
+            .latest_block()
+            .await
+            .expect("Failed to fetch the latest block");
+        assert!(latest_block.number > 0);
+        assert!(latest_block.base_fee_per_gas.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_gas_price_from_canned_response() {
+        let url = serve_canned_result(json!("0x2dfd2f56a")).await;
+        let provider = EVMProvider::new(String::new(), url).unwrap();
+        assert_eq!(provider.gas_price().await.unwrap(), 12_345_079_146);
+    }
+
+    #[tokio::test]
+    #[ignore = "calls the RPC in ETHEREUM_MAINNET_RPC_URL"]
+    async fn test_gas_price_fetch() {
+        let evm_provider = init_evm_provider();
+        let gas_price = evm_provider
+            .gas_price()
+            .await
+            .expect("Failed to fetch the gas price");
+        assert!(gas_price > 0);
     }
 
     #[tokio::test]
     async fn test_transaction_count() {
         let evm_provider = init_evm_provider();
-        let nonce: u64 = evm_provider.transaction_count().await;
+        let nonce: u64 = evm_provider
+            .transaction_count()
+            .await
+            .expect("Failed to fetch transaction count");
         assert!(nonce > 0, "Failed to fetch transaction count");
     }
 }
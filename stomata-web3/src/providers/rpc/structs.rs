@@ -1,11 +1,111 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use reqwest::Client;
+
+use crate::{
+    constants::{
+        RPC_BASE_RETRY_DELAY_MS, RPC_MAX_ATTEMPTS, RPC_MAX_RETRY_DELAY_MS, RPC_TIMEOUT_SECS,
+    },
+    providers::rpc::transport::{Transport, normalize_rpc_url},
+};
+
 pub struct EVMProvider {
     pub address: String,
-    pub rpc_url: String,
+    /// Transports of the RPC endpoints, tried in order until one succeeds
+    pub(crate) transports: Vec<Transport>,
+    /// Retry policy applied to each endpoint when rate limited
+    pub retry: RetryConfig,
 }
 
 impl EVMProvider {
-    pub fn new(address: String, rpc_url: String) -> Self {
-        Self { address, rpc_url }
+    /// Creates a provider for a single endpoint.
+    ///
+    /// The URL is normalized first, see `with_fallbacks`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rpc_url` doesn't parse or its scheme isn't
+    /// `http`, `https`, `ws` or `wss`.
+    pub fn new(address: String, rpc_url: String) -> anyhow::Result<Self> {
+        Self::with_fallbacks(address, vec![rpc_url])
+    }
+
+    /// Creates a provider trying `rpc_urls` in order.
+    ///
+    /// Every URL is normalized first, see `normalize_rpc_url`, so a mistyped
+    /// endpoint, e.g. in `STOMATA_RPC_URLS_<CHAIN>`, is reported here rather
+    /// than on the first call. `ws://` and `wss://` endpoints are called
+    /// over a WebSocket that stays open between calls, every other endpoint
+    /// over HTTP POST with a client shared by all of them so connections
+    /// are pooled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the position of the first URL that doesn't
+    /// parse or whose scheme isn't `http`, `https`, `ws` or `wss`. The URL
+    /// itself is left out since it often contains an API key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized, like `Client::new`.
+    pub fn with_fallbacks(address: String, rpc_urls: Vec<String>) -> anyhow::Result<Self> {
+        let rpc_urls = rpc_urls
+            .iter()
+            .enumerate()
+            .map(|(index, url)| {
+                normalize_rpc_url(url)
+                    .with_context(|| format!("RPC endpoint {} is invalid", index + 1))
+            })
+            .collect::<anyhow::Result<Vec<String>>>()?;
+        let client = Client::builder()
+            .timeout(Duration::from_secs(RPC_TIMEOUT_SECS))
+            .build()
+            .expect("failed to initialize the RPC http client");
+
+        Ok(Self {
+            address,
+            transports: rpc_urls
+                .iter()
+                .map(|url| Transport::for_url(url, &client))
+                .collect(),
+            retry: RetryConfig::default(),
+        })
+    }
+
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+/// Exponential backoff policy for rate limited (HTTP 429/5xx) RPC requests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryConfig {
+    /// Total attempts per endpoint, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every retry after it
+    pub base_delay: Duration,
+    /// Upper bound for a single delay, before jitter
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: RPC_MAX_ATTEMPTS,
+            base_delay: Duration::from_millis(RPC_BASE_RETRY_DELAY_MS),
+            max_delay: Duration::from_millis(RPC_MAX_RETRY_DELAY_MS),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Backoff delay before retry number `retry` (0-based), without jitter
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
     }
 }
 
@@ -13,3 +113,94 @@ impl EVMProvider {
 pub struct ChainInfo {
     pub chain_id: u64,
 }
+
+/// Header fields of the latest block a gas price scheduler polls
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatestBlock {
+    pub number: u64,
+    /// EIP-1559 base fee in wei, `None` on chains or blocks before London
+    pub base_fee_per_gas: Option<u128>,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+}
+
+/// Addresses stored in the EIP-1967 slots of an upgradeable proxy
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxyInfo {
+    /// Checksummed address of the contract the proxy delegates to
+    pub implementation: String,
+    /// Checksummed address allowed to upgrade the proxy, `None` if unset
+    pub admin: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::providers::rpc::transport::RpcTransport;
+
+    #[test]
+    fn test_new_normalizes_rpc_url() {
+        let provider =
+            EVMProvider::new(String::new(), " WSS://Eth.Example/ws ".to_string()).unwrap();
+        assert!(matches!(provider.transports[..], [Transport::WebSocket(_)]));
+        assert_eq!(provider.transports[0].url(), "wss://eth.example/ws");
+    }
+
+    #[test]
+    fn test_new_rejects_unsupported_schemes() {
+        for url in ["ftp://eth.example", "htps://eth.example", "eth.example"] {
+            assert!(EVMProvider::new(String::new(), url.to_string()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_with_fallbacks_validates_every_url() {
+        let provider = EVMProvider::with_fallbacks(
+            String::new(),
+            vec![
+                "HTTPS://Eth.Example".to_string(),
+                " wss://eth.example/ws".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(provider.transports[0].url(), "https://eth.example/");
+        assert_eq!(provider.transports[1].url(), "wss://eth.example/ws");
+
+        let err = EVMProvider::with_fallbacks(
+            String::new(),
+            vec![
+                "https://eth.example".to_string(),
+                "htps://eth.example/v2/secret-key".to_string(),
+            ],
+        )
+        .err()
+        .unwrap();
+        let err = format!("{err:#}");
+        assert!(err.starts_with("RPC endpoint 2 is invalid"), "{err}");
+        assert!(!err.contains("secret-key"), "{err}");
+    }
+
+    #[test]
+    fn test_retry_delay_doubles() {
+        let retry = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+        };
+        assert_eq!(retry.delay_for(0), Duration::from_millis(100));
+        assert_eq!(retry.delay_for(1), Duration::from_millis(200));
+        assert_eq!(retry.delay_for(3), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        let retry = RetryConfig {
+            max_attempts: 50,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        assert_eq!(retry.delay_for(3), Duration::from_millis(500));
+        assert_eq!(retry.delay_for(40), Duration::from_millis(500));
+    }
+}
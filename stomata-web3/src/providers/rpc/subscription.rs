@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use anyhow::{Result, anyhow, bail};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::{
+    constants::{BLOCK_POLL_INTERVAL_SECS, NEW_HEADS_TIMEOUT_SECS},
+    providers::rpc::{
+        structs::EVMProvider,
+        traits::ChainProvider,
+        transport::{RpcAttemptError, connect, is_websocket_url},
+    },
+};
+
+/// Reports the latest block number of the chain behind `rpc_urls` until
+/// `on_block` returns false.
+///
+/// Follows an `eth_subscribe("newHeads")` subscription on the first
+/// WebSocket endpoint. Without one, or once the subscription fails or
+/// goes quiet for `NEW_HEADS_TIMEOUT_SECS`, the endpoints are polled with
+/// `eth_blockNumber` every `BLOCK_POLL_INTERVAL_SECS` instead.
+pub async fn watch_latest_block(rpc_urls: Vec<String>, mut on_block: impl FnMut(u64) -> bool) {
+    if let Some(url) = rpc_urls.iter().find(|url| is_websocket_url(url)) {
+        // Ok means `on_block` asked to stop
+        let read_timeout = Duration::from_secs(NEW_HEADS_TIMEOUT_SECS);
+        if subscribe_new_heads(url, read_timeout, &mut on_block)
+            .await
+            .is_ok()
+        {
+            return;
+        }
+    }
+
+    // without a valid endpoint there is nothing to poll
+    let Ok(provider) = EVMProvider::with_fallbacks(String::new(), rpc_urls) else {
+        return;
+    };
+    loop {
+        // a failed poll is skipped, the next one may reach the chain again
+        if let Ok(block_number) = provider.block_number().await
+            && !on_block(block_number)
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_secs(BLOCK_POLL_INTERVAL_SECS)).await;
+    }
+}
+
+/// Subscribes to new block headers on a dedicated connection and passes
+/// each block number to `on_block`.
+///
+/// Returns `Ok` once `on_block` returns false and an error if the
+/// subscription can't be set up, the connection drops or no message
+/// arrives within `read_timeout`. A half-open connection never closes,
+/// so the timeout is what lets the caller fall back to polling.
+async fn subscribe_new_heads(
+    url: &str,
+    read_timeout: Duration,
+    on_block: &mut impl FnMut(u64) -> bool,
+) -> Result<()> {
+    let mut stream = connect(url)
+        .await
+        .map_err(|(RpcAttemptError::Retryable(err) | RpcAttemptError::Fatal(err))| err)?;
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_subscribe",
+        "params": ["newHeads"],
+    });
+    stream.send(Message::text(request.to_string())).await?;
+
+    while let Some(message) = timeout(read_timeout, stream.next())
+        .await
+        .map_err(|_| anyhow!("No new block within {read_timeout:?}"))?
+    {
+        let Message::Text(text) = message? else {
+            continue;
+        };
+        let message: Value = serde_json::from_str(&text)?;
+        if let Some(err) = message.get("error") {
+            bail!("eth_subscribe failed: {err}");
+        }
+        if let Some(block_number) = new_head_block_number(&message)
+            && !on_block(block_number)
+        {
+            return Ok(());
+        }
+    }
+
+    bail!("Subscription to {url} closed")
+}
+
+/// Extracts the block number of an `eth_subscription` notification for a
+/// new block header. `None` for any other message, e.g. the subscription id.
+fn new_head_block_number(message: &Value) -> Option<u64> {
+    if message.get("method")?.as_str()? != "eth_subscription" {
+        return None;
+    }
+    let number = message.pointer("/params/result/number")?.as_str()?;
+    u64::from_str_radix(number.trim_start_matches("0x"), 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_head_block_number() {
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": {
+                "subscription": "0x9ce59a13059e417087c02d3236a0b1cc",
+                "result": {"number": "0x1b4", "hash": "0xdc0818cf"}
+            }
+        });
+        assert_eq!(new_head_block_number(&notification), Some(436));
+    }
+
+    #[test]
+    fn test_subscription_id_is_not_a_block() {
+        let response = json!({"jsonrpc": "2.0", "id": 1, "result": "0x9ce59a13"});
+        assert_eq!(new_head_block_number(&response), None);
+    }
+
+    /// Accepts one WebSocket connection, sends `messages` after the
+    /// subscription request and then keeps the connection open silently,
+    /// returning the URL of the local endpoint
+    async fn serve_then_go_quiet(messages: Vec<Value>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _subscribe = socket.next().await;
+            for message in messages {
+                let _ = socket.send(Message::text(message.to_string())).await;
+            }
+            std::future::pending::<()>().await;
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_quiet_subscription_times_out() {
+        let head = json!({
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": {"subscription": "0x1", "result": {"number": "0x10"}}
+        });
+        let url = serve_then_go_quiet(vec![head]).await;
+
+        let mut blocks = Vec::new();
+        let result = subscribe_new_heads(&url, Duration::from_millis(200), &mut |block| {
+            blocks.push(block);
+            true
+        })
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("No new block"));
+        assert_eq!(blocks, vec![16]);
+    }
+}
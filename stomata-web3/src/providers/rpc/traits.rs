@@ -4,8 +4,12 @@ use rust_decimal::Decimal;
 use crate::providers::{portfolio::structs::AccountType, rpc::structs::ChainInfo};
 
 pub trait ChainProvider {
-    async fn chain_info(&self) -> Result<ChainInfo>;
-    async fn native_balance(&self) -> Option<Decimal>;
-    async fn account_type(&self) -> Option<AccountType>;
-    async fn transaction_count(&self) -> u64;
+    fn chain_info(&self) -> impl Future<Output = Result<ChainInfo>> + Send;
+    fn native_balance(&self) -> impl Future<Output = Result<Decimal>> + Send;
+    fn account_type(&self) -> impl Future<Output = Result<AccountType>> + Send;
+    /// Deployed bytecode as returned by `eth_getCode`, "0x" for EOAs
+    fn code(&self) -> impl Future<Output = Result<String>> + Send;
+    fn transaction_count(&self) -> impl Future<Output = Result<u64>> + Send;
+    /// Number of the latest block
+    fn block_number(&self) -> impl Future<Output = Result<u64>> + Send;
 }
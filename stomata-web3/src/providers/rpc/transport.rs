@@ -0,0 +1,323 @@
+use std::time::Duration;
+
+use anyhow::{Context, Error, anyhow, bail};
+use futures_util::{SinkExt, StreamExt};
+use reqwest::{Client, StatusCode, Url};
+use serde_json::Value;
+use tokio::{net::TcpStream, sync::Mutex, time::timeout};
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream, connect_async,
+    tungstenite::{self, Message},
+};
+
+use crate::constants::{RPC_TIMEOUT_SECS, RPC_URL_SCHEMES};
+
+/// Failure of a single request to a single endpoint
+pub(crate) enum RpcAttemptError {
+    /// Rate limited or a server error, worth retrying the same endpoint
+    Retryable(Error),
+    /// Anything else, move on to the next endpoint
+    Fatal(Error),
+}
+
+/// Delivers JSON-RPC payloads to one endpoint.
+///
+/// Payloads are built once by the caller, a request object or a batch
+/// array, and the raw response is returned so the same result handling
+/// works for every transport.
+pub(crate) trait RpcTransport {
+    /// URL of the endpoint, used in error messages
+    fn url(&self) -> &str;
+
+    /// Sends `payload` and returns the endpoint's response
+    async fn send(&self, payload: &Value) -> Result<Value, RpcAttemptError>;
+}
+
+/// Transport of an RPC endpoint, picked from its URL scheme
+pub(crate) enum Transport {
+    Http(HttpTransport),
+    WebSocket(Box<WsTransport>),
+}
+
+impl Transport {
+    /// Creates the transport for `url`: `ws://` and `wss://` endpoints use
+    /// a WebSocket, anything else HTTP POST through `client`.
+    pub(crate) fn for_url(url: &str, client: &Client) -> Self {
+        if is_websocket_url(url) {
+            Transport::WebSocket(Box::new(WsTransport::new(url)))
+        } else {
+            Transport::Http(HttpTransport {
+                url: url.to_string(),
+                client: client.clone(),
+            })
+        }
+    }
+}
+
+impl RpcTransport for Transport {
+    fn url(&self) -> &str {
+        match self {
+            Transport::Http(transport) => transport.url(),
+            Transport::WebSocket(transport) => transport.url(),
+        }
+    }
+
+    async fn send(&self, payload: &Value) -> Result<Value, RpcAttemptError> {
+        match self {
+            Transport::Http(transport) => transport.send(payload).await,
+            Transport::WebSocket(transport) => transport.send(payload).await,
+        }
+    }
+}
+
+/// Parses an RPC endpoint URL and returns it normalized, with surrounding
+/// whitespace removed and the scheme and host lowercased.
+///
+/// Only `http`, `https`, `ws` and `wss` endpoints are accepted, so a typo
+/// is reported up front instead of on the first call. Errors leave the URL
+/// out, RPC URLs often contain an API key.
+pub(crate) fn normalize_rpc_url(url: &str) -> anyhow::Result<String> {
+    let parsed = Url::parse(url.trim()).context("Invalid RPC URL")?;
+    if !RPC_URL_SCHEMES.contains(&parsed.scheme()) {
+        bail!(
+            "Unsupported RPC URL scheme '{}', expected one of {}",
+            parsed.scheme(),
+            RPC_URL_SCHEMES.join(", ")
+        );
+    }
+    Ok(parsed.to_string())
+}
+
+/// Returns true for `ws://` and `wss://` URLs
+pub(crate) fn is_websocket_url(url: &str) -> bool {
+    let scheme = url
+        .split_once("://")
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+    matches!(scheme.as_deref(), Some("ws" | "wss"))
+}
+
+/// JSON-RPC over HTTP POST
+pub(crate) struct HttpTransport {
+    url: String,
+    /// HTTP client shared by every endpoint so connections are pooled
+    client: Client,
+}
+
+impl RpcTransport for HttpTransport {
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// POSTs the payload, treating HTTP 429 and 5xx as retryable.
+    async fn send(&self, payload: &Value) -> Result<Value, RpcAttemptError> {
+        let fatal = |err: reqwest::Error| RpcAttemptError::Fatal(err.into());
+
+        let response = self
+            .client
+            .post(&self.url)
+            .json(payload)
+            .send()
+            .await
+            .map_err(fatal)?;
+
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            return Err(RpcAttemptError::Retryable(anyhow!("HTTP {}", status)));
+        }
+
+        response
+            .error_for_status()
+            .map_err(fatal)?
+            .json::<Value>()
+            .await
+            .map_err(fatal)
+    }
+}
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// JSON-RPC over a WebSocket
+///
+/// The connection is opened on the first request and kept for the ones
+/// after it. Requests take turns on the connection, so the next response
+/// on it always belongs to the request in flight.
+pub(crate) struct WsTransport {
+    url: String,
+    /// Open connection, `None` before the first request or after a failure
+    connection: Mutex<Option<WsStream>>,
+}
+
+impl WsTransport {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            connection: Mutex::new(None),
+        }
+    }
+}
+
+impl RpcTransport for WsTransport {
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Sends the payload as a text frame and waits for the response,
+    /// connecting first if needed. Bounded by `RPC_TIMEOUT_SECS` like HTTP.
+    async fn send(&self, payload: &Value) -> Result<Value, RpcAttemptError> {
+        let mut connection = self.connection.lock().await;
+        let attempt = async {
+            let mut stream = match connection.take() {
+                Some(stream) => stream,
+                None => connect(&self.url).await?,
+            };
+            let response = exchange(&mut stream, payload).await?;
+            // a failed connection is dropped here and reopened next time
+            *connection = Some(stream);
+            Ok(response)
+        };
+
+        timeout(Duration::from_secs(RPC_TIMEOUT_SECS), attempt)
+            .await
+            .unwrap_or_else(|_| {
+                Err(RpcAttemptError::Fatal(anyhow!(
+                    "WebSocket request timed out after {RPC_TIMEOUT_SECS}s"
+                )))
+            })
+    }
+}
+
+/// Opens a WebSocket, treating HTTP 429 and 5xx handshake responses as
+/// retryable.
+pub(crate) async fn connect(url: &str) -> Result<WsStream, RpcAttemptError> {
+    match connect_async(url).await {
+        Ok((stream, _)) => Ok(stream),
+        Err(tungstenite::Error::Http(response))
+            if response.status().as_u16() == StatusCode::TOO_MANY_REQUESTS.as_u16()
+                || response.status().is_server_error() =>
+        {
+            Err(RpcAttemptError::Retryable(anyhow!(
+                "HTTP {}",
+                response.status()
+            )))
+        }
+        Err(err) => Err(RpcAttemptError::Fatal(err.into())),
+    }
+}
+
+/// Sends one payload and reads frames until its response arrives.
+async fn exchange(stream: &mut WsStream, payload: &Value) -> Result<Value, RpcAttemptError> {
+    let fatal = |err: tungstenite::Error| RpcAttemptError::Fatal(err.into());
+
+    stream
+        .send(Message::text(payload.to_string()))
+        .await
+        .map_err(fatal)?;
+
+    while let Some(message) = stream.next().await {
+        let text = match message.map_err(fatal)? {
+            Message::Text(text) => text.to_string(),
+            Message::Binary(data) => String::from_utf8_lossy(&data).into_owned(),
+            Message::Close(_) => break,
+            // pings are answered by tungstenite
+            _ => continue,
+        };
+        let response: Value =
+            serde_json::from_str(&text).map_err(|err| RpcAttemptError::Fatal(err.into()))?;
+        if is_response(&response) {
+            return Ok(response);
+        }
+    }
+
+    Err(RpcAttemptError::Fatal(anyhow!(
+        "WebSocket closed before responding"
+    )))
+}
+
+/// Returns true for a response to a request or batch, as opposed to a
+/// subscription notification, which carries a `method` but no `id`.
+fn is_response(message: &Value) -> bool {
+    message.is_array() || message.get("id").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transport_picked_by_scheme() {
+        let client = Client::new();
+        for url in [
+            "wss://eth.example/ws",
+            "ws://127.0.0.1:8546",
+            "WSS://eth.example",
+        ] {
+            assert!(matches!(
+                Transport::for_url(url, &client),
+                Transport::WebSocket(_)
+            ));
+        }
+        for url in ["https://eth.example", "http://127.0.0.1:8545"] {
+            assert!(matches!(
+                Transport::for_url(url, &client),
+                Transport::Http(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_normalize_rpc_url() {
+        assert_eq!(
+            normalize_rpc_url(" HTTPS://Eth.Example/v2/key ").unwrap(),
+            "https://eth.example/v2/key"
+        );
+        assert_eq!(
+            normalize_rpc_url("wss://eth.example").unwrap(),
+            "wss://eth.example/"
+        );
+    }
+
+    #[test]
+    fn test_normalize_rpc_url_rejects_other_schemes() {
+        for url in [
+            "ftp://eth.example",
+            "file:///tmp/rpc",
+            "mailto:rpc@eth.example",
+        ] {
+            let err = normalize_rpc_url(url).unwrap_err().to_string();
+            assert!(err.contains("Unsupported RPC URL scheme"), "{url}: {err}");
+        }
+        // the URL may hold an API key, it's never part of the error
+        let err = format!(
+            "{:#}",
+            normalize_rpc_url("htps://eth.example/v2/secret-key").unwrap_err()
+        );
+        assert!(!err.contains("secret-key"), "{err}");
+        let err = format!("{:#}", normalize_rpc_url("secret-key").unwrap_err());
+        assert!(!err.contains("secret-key"), "{err}");
+        // a missing scheme or host doesn't parse at all
+        for url in ["eth.example", "", "https://"] {
+            let err = normalize_rpc_url(url).unwrap_err().to_string();
+            assert!(err.contains("Invalid RPC URL"), "{url}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_subscription_notification_is_not_a_response() {
+        assert!(is_response(
+            &json!({"jsonrpc": "2.0", "id": 1, "result": "0x1"})
+        ));
+        assert!(is_response(
+            &json!([{"jsonrpc": "2.0", "id": 1, "result": "0x1"}])
+        ));
+        // rejected batches answer with a null id
+        assert!(is_response(
+            &json!({"jsonrpc": "2.0", "id": null, "error": {}})
+        ));
+        assert!(!is_response(&json!({
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": {"subscription": "0x1", "result": {}}
+        })));
+    }
+}